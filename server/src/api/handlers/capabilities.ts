@@ -0,0 +1,26 @@
+/**
+ * @fileoverview Capability discovery endpoint handler.
+ */
+
+import { HttpServerResponse } from "@effect/platform";
+import { Effect } from "effect";
+import { VERSION } from "./health.js";
+
+/**
+ * Optional features this server implements, beyond the core issue endpoints.
+ * Clients hide whatever isn't listed here.
+ */
+const CAPABILITIES = [
+	// POST /api/v1/issues
+	"manual_issues",
+] as const;
+
+/**
+ * GET /api/v1/capabilities
+ */
+export const capabilitiesHandler = Effect.gen(function* () {
+	return yield* HttpServerResponse.json({
+		version: VERSION,
+		capabilities: CAPABILITIES,
+	});
+});
@@ -3,13 +3,17 @@
  */
 
 import { HttpServerRequest, HttpServerResponse } from "@effect/platform";
-import { Effect, Option } from "effect";
+import { Effect, Option, Schema } from "effect";
 import { ConversationRepository, SentryIssueRepository } from "../../db/index.js";
-import type { Issue } from "../../domain/issue.js";
-import { IssueState } from "../../domain/issue.js";
+import type { Issue, SentrySourceData } from "../../domain/issue.js";
+import { IssueSource, IssueState, getSourceCommon } from "../../domain/issue.js";
 import { AgentService, EventBufferService, type AnalysisEvent } from "../../services/agent/index.js";
 import { buildAnalysisPrompt } from "../../services/prompts/index.js";
-import { SentryService } from "../../services/sentry/index.js";
+import {
+	parseIssueReference,
+	type SentryEventData,
+	SentryService,
+} from "../../services/sentry/index.js";
 
 // =============================================================================
 // Response Mappers
@@ -19,19 +23,23 @@ import { SentryService } from "../../services/sentry/index.js";
  * Maps an Issue to the list response format.
  */
 const mapIssueToListItem = (issue: Issue) => {
-	const common = issue.source._tag === "Sentry" ? issue.source.data : null;
+	const common = getSourceCommon(issue.source);
+	const sentry = issue.source._tag === "Sentry" ? issue.source.data : null;
 	
 	return {
 		id: issue.id,
 		sourceType: issue.source._tag.toLowerCase(),
-		title: common?.title ?? "Unknown",
-		shortId: common?.shortId ?? issue.id,
+		title: common.title,
+		shortId: common.shortId,
 		status: issue.state._tag.replace(/([A-Z])/g, "_$1").toLowerCase().replace(/^_/, ""),
-		eventCount: common?.count ?? 0,
-		userCount: common?.userCount ?? 0,
-		firstSeen: common?.firstSeen?.toISOString() ?? issue.createdAt.toISOString(),
-		lastSeen: common?.lastSeen?.toISOString() ?? issue.updatedAt.toISOString(),
+		eventCount: common.count ?? 0,
+		userCount: common.userCount ?? 0,
+		firstSeen: common.firstSeen.toISOString(),
+		lastSeen: common.lastSeen.toISOString(),
 		updatedAt: issue.updatedAt.toISOString(),
+		culprit: sentry?.culprit,
+		environment: sentry?.environment,
+		release: sentry?.release,
 	};
 };
 
@@ -111,6 +119,17 @@ const mapIssueToDetail = (issue: Issue) => {
 			user: data.user,
 			contexts: data.contexts,
 		};
+	} else if (issue.source._tag === "Manual") {
+		const data = issue.source.data;
+
+		source = {
+			title: data.title,
+			shortId: data.shortId,
+			firstSeen: data.firstSeen.toISOString(),
+			lastSeen: data.lastSeen.toISOString(),
+			description: data.description,
+			stacktrace: data.stacktrace,
+		};
 	}
 	
 	return {
@@ -156,6 +175,61 @@ const enrichWithProposal = (issue: Issue) =>
 		};
 	});
 
+/**
+ * Issue data with the details of one of its events (only the fields the
+ * event has).
+ */
+const withEventData = (
+	data: SentrySourceData,
+	eventData: SentryEventData,
+): SentrySourceData => ({
+	...data,
+	...(eventData.exceptions && { exceptions: eventData.exceptions }),
+	...(eventData.breadcrumbs && { breadcrumbs: eventData.breadcrumbs }),
+	...(eventData.environment && { environment: eventData.environment }),
+	...(eventData.release && { release: eventData.release }),
+	...(eventData.tags && { tags: eventData.tags }),
+	...(eventData.request && { request: eventData.request }),
+	...(eventData.user && { user: eventData.user }),
+	...(eventData.contexts && { contexts: eventData.contexts }),
+});
+
+/**
+ * Fetches an issue and its latest event from Sentry and upserts the merged
+ * data into the local database, keeping the issue's state if it exists.
+ */
+const fetchAndStoreIssue = (sentryId: string) =>
+	Effect.gen(function* () {
+		const sentry = yield* SentryService;
+		const issueRepo = yield* SentryIssueRepository;
+
+		const [issueSource, eventData] = yield* Effect.all([
+			sentry.getIssue(sentryId),
+			sentry.getLatestEvent(sentryId),
+		]).pipe(
+			Effect.mapError((error) => ({
+				_tag: "SentryError" as const,
+				error,
+			})),
+		);
+
+		if (issueSource._tag !== "Sentry") {
+			return yield* Effect.fail({ _tag: "UnexpectedSource" as const });
+		}
+
+		// Keyed by the ID Sentry returned, which differs from a short ID
+		return yield* issueRepo.upsert({
+			id: issueSource.data.sentryId,
+			project: issueSource.project,
+			data: withEventData(issueSource.data, eventData),
+		}).pipe(
+			Effect.mapError((error) => ({
+				_tag: "DbError" as const,
+				error,
+			})),
+		);
+	});
+
 // =============================================================================
 // Handlers
 // =============================================================================
@@ -309,9 +383,9 @@ export const refreshIssuesHandler = Effect.gen(function* () {
  *
  * Fetches fresh data for a single issue from Sentry, updates the local database,
  * and returns the updated issue detail (same format as GET /issues/:id).
+ * Manual issues are returned as stored.
  */
 export const refreshIssueHandler = Effect.gen(function* () {
-	const sentry = yield* SentryService;
 	const issueRepo = yield* SentryIssueRepository;
 	const request = yield* HttpServerRequest.HttpServerRequest;
 
@@ -346,6 +420,12 @@ export const refreshIssueHandler = Effect.gen(function* () {
 		issue = Option.isSome(maybeIssue2) ? maybeIssue2.value : null;
 	}
 
+	if (issue?.source._tag === "Manual") {
+		// Nothing upstream to refresh from
+		const enrichedIssue = yield* enrichWithProposal(issue);
+		return yield* HttpServerResponse.json(mapIssueToDetail(enrichedIssue));
+	}
+
 	if (!issue || issue.source._tag !== "Sentry") {
 		return yield* HttpServerResponse.json(
 			{
@@ -358,21 +438,27 @@ export const refreshIssueHandler = Effect.gen(function* () {
 		);
 	}
 
-	const sentryId = issue.source.data.sentryId;
+	// Fetch fresh data from Sentry and upsert it
+	const updatedIssue = yield* fetchAndStoreIssue(issue.source.data.sentryId);
 
-	// Fetch fresh data from Sentry
-	const [issueSource, eventData] = yield* Effect.all([
-		sentry.getIssue(sentryId),
-		sentry.getLatestEvent(sentryId),
-	]).pipe(
-		Effect.mapError((error) => ({
-			_tag: "SentryError" as const,
-			error,
-		})),
-	);
+	// Enrich with proposal if in PendingApproval state
+	const enrichedIssue = yield* enrichWithProposal(updatedIssue);
 
-	if (issueSource._tag !== "Sentry") {
-		return yield* HttpServerResponse.json(
+	return yield* HttpServerResponse.json(mapIssueToDetail(enrichedIssue));
+}).pipe(
+	Effect.catchTag("SentryError", (e) =>
+		HttpServerResponse.json(
+			{
+				error: {
+					code: "SENTRY_ERROR",
+					message: `Failed to fetch issue from Sentry: ${e.error._tag}`,
+				},
+			},
+			{ status: 502 },
+		),
+	),
+	Effect.catchTag("UnexpectedSource", () =>
+		HttpServerResponse.json(
 			{
 				error: {
 					code: "INTERNAL_ERROR",
@@ -380,36 +466,121 @@ export const refreshIssueHandler = Effect.gen(function* () {
 				},
 			},
 			{ status: 500 },
+		),
+	),
+	Effect.catchTag("DbError", () =>
+		HttpServerResponse.json(
+			{
+				error: {
+					code: "INTERNAL_ERROR",
+					message: "Failed to update issue in database",
+				},
+			},
+			{ status: 500 },
+		),
+	),
+);
+
+/**
+ * Body of POST /api/v1/issues.
+ */
+const CreateIssueBody = Schema.Struct({
+	title: Schema.String,
+	description: Schema.String,
+	/** Stack trace as pasted, for bugs reported with one */
+	stacktrace: Schema.optional(Schema.String),
+});
+
+/**
+ * POST /api/v1/issues
+ *
+ * Creates a manual issue for a bug that never reached Sentry and returns its
+ * detail (same format as GET /issues/:id). It starts in `pending` and is
+ * analyzed like any other issue.
+ *
+ * Body: `{ "title": "Export hangs", "description": "...", "stacktrace": "..." }`
+ */
+export const createIssueHandler = Effect.gen(function* () {
+	const issueRepo = yield* SentryIssueRepository;
+	const request = yield* HttpServerRequest.HttpServerRequest;
+
+	const body = yield* request.json.pipe(
+		Effect.flatMap(Schema.decodeUnknown(CreateIssueBody)),
+		Effect.option,
+	);
+	const title = Option.isSome(body) ? body.value.title.trim() : "";
+
+	if (Option.isNone(body) || !title) {
+		return yield* HttpServerResponse.json(
+			{
+				error: {
+					code: "VALIDATION_ERROR",
+					message: Option.isNone(body)
+						? "Invalid issue: title and description must be strings"
+						: "Issue title is required",
+				},
+			},
+			{ status: 400 },
 		);
 	}
 
-	// Merge issue data with event data (only include defined optional fields)
-	const mergedData = {
-		...issueSource.data,
-		...(eventData.exceptions && { exceptions: eventData.exceptions }),
-		...(eventData.breadcrumbs && { breadcrumbs: eventData.breadcrumbs }),
-		...(eventData.environment && { environment: eventData.environment }),
-		...(eventData.release && { release: eventData.release }),
-		...(eventData.tags && { tags: eventData.tags }),
-		...(eventData.request && { request: eventData.request }),
-		...(eventData.user && { user: eventData.user }),
-		...(eventData.contexts && { contexts: eventData.contexts }),
-	};
+	const stacktrace = body.value.stacktrace?.trim();
+	const issue = yield* issueRepo.createManual({
+		title,
+		description: body.value.description.trim(),
+		...(stacktrace ? { stacktrace } : {}),
+	});
 
-	// Upsert the updated issue
-	const updatedIssue = yield* issueRepo.upsert({
-		id: sentryId,
-		project: issueSource.project,
-		data: mergedData,
-	}).pipe(
-		Effect.mapError((error) => ({
-			_tag: "DbError" as const,
-			error,
-		})),
-	);
+	return yield* HttpServerResponse.json(mapIssueToDetail(issue));
+}).pipe(
+	Effect.catchTag("DbError", () =>
+		HttpServerResponse.json(
+			{
+				error: {
+					code: "INTERNAL_ERROR",
+					message: "Failed to store issue in database",
+				},
+			},
+			{ status: 500 },
+		),
+	),
+);
 
-	// Enrich with proposal if in PendingApproval state
-	const enrichedIssue = yield* enrichWithProposal(updatedIssue);
+/**
+ * POST /api/v1/issues/import
+ *
+ * Adds a Sentry issue by URL, numeric ID or short ID, whether or not it
+ * matches the configured query, and returns its detail (same format as
+ * GET /issues/:id). Importing an issue already in the database refreshes it.
+ *
+ * Body: `{ "issue": "https://acme.sentry.io/issues/12345/" }`
+ */
+export const importIssueHandler = Effect.gen(function* () {
+	const request = yield* HttpServerRequest.HttpServerRequest;
+
+	const body = yield* request.json.pipe(Effect.catchAll(() => Effect.succeed(null)));
+	const reference =
+		body && typeof body === "object" && "issue" in body && typeof body.issue === "string"
+			? body.issue
+			: "";
+	const sentryId = parseIssueReference(reference);
+
+	if (!sentryId) {
+		return yield* HttpServerResponse.json(
+			{
+				error: {
+					code: "VALIDATION_ERROR",
+					message: reference
+						? `Not a Sentry issue URL or ID: ${reference}`
+						: "Issue URL or ID is required",
+				},
+			},
+			{ status: 400 },
+		);
+	}
+
+	const issue = yield* fetchAndStoreIssue(sentryId);
+	const enrichedIssue = yield* enrichWithProposal(issue);
 
 	return yield* HttpServerResponse.json(mapIssueToDetail(enrichedIssue));
 }).pipe(
@@ -417,11 +588,22 @@ export const refreshIssueHandler = Effect.gen(function* () {
 		HttpServerResponse.json(
 			{
 				error: {
-					code: "SENTRY_ERROR",
+					code: e.error._tag === "NotFoundError" ? "NOT_FOUND" : "SENTRY_ERROR",
 					message: `Failed to fetch issue from Sentry: ${e.error._tag}`,
 				},
 			},
-			{ status: 502 },
+			{ status: e.error._tag === "NotFoundError" ? 404 : 502 },
+		),
+	),
+	Effect.catchTag("UnexpectedSource", () =>
+		HttpServerResponse.json(
+			{
+				error: {
+					code: "INTERNAL_ERROR",
+					message: "Unexpected issue source type",
+				},
+			},
+			{ status: 500 },
 		),
 	),
 	Effect.catchTag("DbError", () =>
@@ -429,7 +611,7 @@ export const refreshIssueHandler = Effect.gen(function* () {
 			{
 				error: {
 					code: "INTERNAL_ERROR",
-					message: "Failed to update issue in database",
+					message: "Failed to store issue in database",
 				},
 			},
 			{ status: 500 },
@@ -437,6 +619,16 @@ export const refreshIssueHandler = Effect.gen(function* () {
 	),
 );
 
+/**
+ * Body of POST /api/v1/issues/:id/analyze; every field is optional.
+ */
+const AnalyzeIssueBody = Schema.Struct({
+	/** Extra guidance added to the agent's prompt */
+	instructions: Schema.optional(Schema.String),
+	/** Sentry event to analyze instead of the one stored with the issue */
+	eventId: Schema.optional(Schema.String),
+});
+
 /**
  * POST /api/v1/issues/:id/analyze
  *
@@ -444,6 +636,8 @@ export const refreshIssueHandler = Effect.gen(function* () {
  * The analysis runs in background; this returns immediately with session info.
  *
  * Valid only when issue is in `pending` or `error` state.
+ *
+ * Body (optional): `{ "instructions": "Focus on the retry logic", "eventId": "abc123" }`
  */
 export const analyzeIssueHandler = Effect.gen(function* () {
 	const agentService = yield* AgentService;
@@ -469,6 +663,25 @@ export const analyzeIssueHandler = Effect.gen(function* () {
 		);
 	}
 
+	// An empty body is the same as `{}`
+	const body = yield* request.json.pipe(
+		Effect.catchAll(() => Effect.succeed({})),
+		Effect.flatMap(Schema.decodeUnknown(AnalyzeIssueBody)),
+		Effect.option,
+	);
+
+	if (Option.isNone(body)) {
+		return yield* HttpServerResponse.json(
+			{
+				error: {
+					code: "VALIDATION_ERROR",
+					message: "Invalid analyze request: instructions and eventId must be strings",
+				},
+			},
+			{ status: 400 },
+		);
+	}
+
 	// Look up the issue
 	const maybeIssue = yield* issueRepo.getById(id).pipe(
 		Effect.catchAll(() => Effect.succeed(Option.none<Issue>())),
@@ -510,6 +723,34 @@ export const analyzeIssueHandler = Effect.gen(function* () {
 		);
 	}
 
+	// Analyze a chosen sample instead of the event stored with the issue
+	const { eventId } = body.value;
+	if (eventId) {
+		if (issue.source._tag !== "Sentry") {
+			return yield* HttpServerResponse.json(
+				{
+					error: {
+						code: "VALIDATION_ERROR",
+						message: "Only Sentry issues have events to choose from",
+					},
+				},
+				{ status: 400 },
+			);
+		}
+		const { project, data } = issue.source;
+		const sentry = yield* SentryService;
+		const eventData = yield* sentry.getEvent(data.sentryId, eventId).pipe(
+			Effect.mapError((error) => ({
+				_tag: "SentryError" as const,
+				error,
+			})),
+		);
+		issue = {
+			...issue,
+			source: IssueSource.Sentry({ project, data: withEventData(data, eventData) }),
+		};
+	}
+
 	// Create analysis session
 	const sessionHandle = yield* agentService.createAnalysisSession().pipe(
 		Effect.mapError((error) => ({
@@ -530,7 +771,7 @@ export const analyzeIssueHandler = Effect.gen(function* () {
 	);
 
 	// Build the analysis prompt
-	const prompt = buildAnalysisPrompt(issue);
+	const prompt = buildAnalysisPrompt(issue, body.value);
 
 	// Capture variables for the background task
 	const issueId = issue.id;
@@ -661,6 +902,17 @@ export const analyzeIssueHandler = Effect.gen(function* () {
 			{ status: 500 },
 		),
 	),
+	Effect.catchTag("SentryError", (e) =>
+		HttpServerResponse.json(
+			{
+				error: {
+					code: e.error._tag === "NotFoundError" ? "NOT_FOUND" : "SENTRY_ERROR",
+					message: `Failed to fetch event from Sentry: ${e.error._tag}`,
+				},
+			},
+			{ status: e.error._tag === "NotFoundError" ? 404 : 502 },
+		),
+	),
 	Effect.catchTag("DbError", () =>
 		HttpServerResponse.json(
 			{
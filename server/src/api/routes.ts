@@ -11,8 +11,9 @@ import {
 	HttpServerResponse,
 } from "@effect/platform";
 import { Effect, Layer } from "effect";
+import { capabilitiesHandler } from "./handlers/capabilities.js";
 import { healthHandler } from "./handlers/health.js";
-import { listIssuesHandler, getIssueHandler, refreshIssuesHandler, refreshIssueHandler, analyzeIssueHandler, eventsHandler } from "./handlers/issues.js";
+import { listIssuesHandler, createIssueHandler, getIssueHandler, refreshIssuesHandler, refreshIssueHandler, importIssueHandler, analyzeIssueHandler, eventsHandler } from "./handlers/issues.js";
 
 // =============================================================================
 // Router
@@ -22,9 +23,14 @@ const router = HttpRouter.empty.pipe(
 	// Health check
 	HttpRouter.get("/health", healthHandler),
 
+	// Optional features, so clients can hide what's missing
+	HttpRouter.get("/api/v1/capabilities", capabilitiesHandler),
+
 	// Issues - more specific routes first
 	HttpRouter.get("/api/v1/issues", listIssuesHandler),
+	HttpRouter.post("/api/v1/issues", createIssueHandler),
 	HttpRouter.post("/api/v1/issues/refresh", refreshIssuesHandler),
+	HttpRouter.post("/api/v1/issues/import", importIssueHandler),
 	HttpRouter.get("/api/v1/issues/:id/events", eventsHandler),
 	HttpRouter.post("/api/v1/issues/:id/refresh", refreshIssueHandler),
 	HttpRouter.post("/api/v1/issues/:id/analyze", analyzeIssueHandler),
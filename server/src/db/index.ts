@@ -50,7 +50,7 @@ export {
 	SentryIssueRepository,
 	type SentryIssueRepositoryService,
 };
-export type { IssueStatus, UpsertSentryIssue } from "./repositories/issues.js";
+export type { CreateManualIssue, IssueStatus, UpsertSentryIssue } from "./repositories/issues.js";
 export { getStatusFromState } from "./repositories/issues.js";
 export { DbError, DbNotFoundError } from "./errors.js";
 export { runMigrations } from "./migrations.js";
@@ -15,6 +15,7 @@ import { Effect, type Layer } from "effect";
 import migration0001 from "./migrations/0001_initial_schema.js";
 import migration0002 from "./migrations/0002_sentry_event_fields.js";
 import migration0003 from "./migrations/0003_sentry_context_fields.js";
+import migration0004 from "./migrations/0004_manual_issues.js";
 
 /**
  * Array of all migrations in order.
@@ -25,6 +26,7 @@ const migrations: Migrator.ResolvedMigration[] = [
 	[1, "initial_schema", Effect.succeed(migration0001)],
 	[2, "sentry_event_fields", Effect.succeed(migration0002)],
 	[3, "sentry_context_fields", Effect.succeed(migration0003)],
+	[4, "manual_issues", Effect.succeed(migration0004)],
 ];
 
 /**
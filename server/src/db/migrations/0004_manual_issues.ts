@@ -0,0 +1,37 @@
+/**
+ * @fileoverview Migration to store manual issues alongside Sentry issues.
+ *
+ * Adds columns for issues written by hand rather than imported:
+ * - source_type: "sentry" or "manual"
+ * - description: The reporter's description of the bug
+ * - stacktrace: Stack trace as pasted by the reporter
+ *
+ * Manual short IDs (MANUAL-<n>) are numbered by the server, so they must
+ * be unique; Sentry's are left alone.
+ */
+
+import { SqlClient } from "@effect/sql";
+import { Effect } from "effect";
+
+export default Effect.gen(function* () {
+	const sql = yield* SqlClient.SqlClient;
+
+	// Existing rows all came from Sentry
+	yield* sql`
+		ALTER TABLE sentry_issues ADD COLUMN source_type TEXT NOT NULL DEFAULT 'sentry'
+			CHECK(source_type IN ('sentry', 'manual'))
+	`;
+
+	yield* sql`
+		ALTER TABLE sentry_issues ADD COLUMN description TEXT
+	`;
+
+	yield* sql`
+		ALTER TABLE sentry_issues ADD COLUMN stacktrace TEXT
+	`;
+
+	yield* sql`
+		CREATE UNIQUE INDEX IF NOT EXISTS idx_sentry_issues_manual_short_id
+			ON sentry_issues(short_id) WHERE source_type = 'manual'
+	`;
+});
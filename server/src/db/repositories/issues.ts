@@ -2,7 +2,8 @@
  * @fileoverview Sentry issue repository for database persistence.
  *
  * Provides CRUD operations for Sentry issues, handling the conversion
- * between domain types and database rows.
+ * between domain types and database rows. Manual issues share the table,
+ * marked by their source_type.
  */
 
 import { SqlClient } from "@effect/sql";
@@ -14,6 +15,7 @@ import type {
 	Issue,
 	IssueSource,
 	IssueState,
+	ManualSourceData,
 	RequestInfo,
 	SentrySourceData,
 	UserInfo,
@@ -83,6 +85,10 @@ const SentryIssueRowSchema = Schema.Struct({
 	request: Schema.NullOr(Schema.parseJson(Schema.Unknown)),
 	user_info: Schema.NullOr(Schema.parseJson(Schema.Unknown)),
 	contexts: Schema.NullOr(Schema.parseJson(Schema.Unknown)),
+	// Manual issue fields
+	source_type: Schema.Literal("sentry", "manual"),
+	description: Schema.NullOr(Schema.String),
+	stacktrace: Schema.NullOr(Schema.String),
 	// Workflow state fields
 	status: Schema.String,
 	analysis_session_id: Schema.NullOr(Schema.String),
@@ -177,9 +183,25 @@ const rowToState = (row: SentryIssueRow): IssueState => {
 };
 
 /**
- * Convert a database row to a domain Issue.
+ * Convert a database row to the source of a manual issue.
  */
-const rowToIssue = (row: SentryIssueRow): Issue => {
+const rowToManualSource = (row: SentryIssueRow): IssueSource => {
+	const data: ManualSourceData = {
+		title: row.title,
+		shortId: row.short_id,
+		firstSeen: new Date(row.first_seen),
+		lastSeen: new Date(row.last_seen),
+		description: row.description ?? "",
+		...(row.stacktrace !== null ? { stacktrace: row.stacktrace } : {}),
+	};
+
+	return IssueSourceEnum.Manual({ data });
+};
+
+/**
+ * Convert a database row to the source of a Sentry issue.
+ */
+const rowToSentrySource = (row: SentryIssueRow): IssueSource => {
 	// Build source data with only defined optional fields
 	// The row.id is the Sentry issue ID (we store that as the primary key)
 	const sourceData: SentrySourceData = {
@@ -204,11 +226,17 @@ const rowToIssue = (row: SentryIssueRow): Issue => {
 		...(row.contexts !== null ? { contexts: row.contexts as ContextInfo } : {}),
 	};
 
-	const source: IssueSource = IssueSourceEnum.Sentry({
+	return IssueSourceEnum.Sentry({
 		project: row.project,
 		data: sourceData,
 	});
+};
 
+/**
+ * Convert a database row to a domain Issue.
+ */
+const rowToIssue = (row: SentryIssueRow): Issue => {
+	const source = row.source_type === "manual" ? rowToManualSource(row) : rowToSentrySource(row);
 	const state = rowToState(row);
 
 	return {
@@ -256,6 +284,16 @@ export interface UpsertSentryIssue {
 	readonly data: SentrySourceData;
 }
 
+/**
+ * Input type for creating a manual issue.
+ */
+export interface CreateManualIssue {
+	readonly title: string;
+	readonly description: string;
+	/** Stack trace as pasted by the reporter */
+	readonly stacktrace?: string;
+}
+
 // =============================================================================
 // Repository Interface
 // =============================================================================
@@ -292,6 +330,13 @@ export interface SentryIssueRepositoryService {
 	 */
 	readonly upsert: (issue: UpsertSentryIssue) => Effect.Effect<Issue, DbError>;
 
+	/**
+	 * Create a manual issue in the Pending state.
+	 * Short IDs are numbered in creation order (MANUAL-1, MANUAL-2, ...),
+	 * one past the highest so far.
+	 */
+	readonly createManual: (issue: CreateManualIssue) => Effect.Effect<Issue, DbError>;
+
 	/**
 	 * Update the workflow state of an issue.
 	 */
@@ -427,6 +472,32 @@ const make = Effect.gen(function* () {
 			return Option.getOrThrow(result);
 		}).pipe(Effect.mapError((cause) => new DbError({ method: "upsert", cause })));
 
+	const createManual: SentryIssueRepositoryService["createManual"] = (issue) =>
+		Effect.gen(function* () {
+			const id = `manual-${crypto.randomUUID()}`;
+			const now = new Date().toISOString();
+
+			// Numbered within the INSERT, so concurrent creates can't share a number
+			yield* sql`
+        INSERT INTO sentry_issues (
+          id, source_type, project, title, short_id, culprit,
+          first_seen, last_seen, metadata,
+          description, stacktrace,
+          status
+        )
+        SELECT
+          ${id}, 'manual', '', ${issue.title},
+          'MANUAL-' || (COALESCE(MAX(CAST(SUBSTR(short_id, 8) AS INTEGER)), 0) + 1), '',
+          ${now}, ${now}, '{}',
+          ${issue.description}, ${issue.stacktrace ?? null},
+          'pending'
+        FROM sentry_issues WHERE source_type = 'manual'
+      `;
+
+			const result = yield* getById(id);
+			return Option.getOrThrow(result);
+		}).pipe(Effect.mapError((cause) => new DbError({ method: "createManual", cause })));
+
 	const updateState: SentryIssueRepositoryService["updateState"] = (id, state) =>
 		Effect.gen(function* () {
 			const status = getStatusFromState(state);
@@ -488,6 +559,7 @@ const make = Effect.gen(function* () {
 		listByStatuses,
 		listAll,
 		upsert,
+		createManual,
 		updateState,
 	} satisfies SentryIssueRepositoryService;
 });
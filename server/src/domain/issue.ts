@@ -234,6 +234,15 @@ export interface TicketSourceData extends IssueSourceCommon {
 	readonly priority: number;
 }
 
+/**
+ * An issue written by hand, for bugs that never reached Sentry.
+ */
+export interface ManualSourceData extends IssueSourceCommon {
+	readonly description: string;
+	/** Stack trace as pasted by the reporter */
+	readonly stacktrace?: string;
+}
+
 /**
  * Tagged union of all issue sources.
  * Use Match.tag for exhaustive handling in UI components and prompt builders.
@@ -242,6 +251,7 @@ export type IssueSource = Data.TaggedEnum<{
 	Sentry: { readonly project: string; readonly data: SentrySourceData };
 	GitHub: { readonly data: GitHubSourceData };
 	Ticket: { readonly data: TicketSourceData };
+	Manual: { readonly data: ManualSourceData };
 }>;
 
 export const IssueSource = Data.taggedEnum<IssueSource>();
@@ -255,6 +265,7 @@ export const getSourceCommon = (source: IssueSource): IssueSourceCommon =>
 		Match.tag("Sentry", ({ data }) => data),
 		Match.tag("GitHub", ({ data }) => data),
 		Match.tag("Ticket", ({ data }) => data),
+		Match.tag("Manual", ({ data }) => data),
 		Match.exhaustive,
 	);
 
@@ -262,11 +273,14 @@ export const getSourceCommon = (source: IssueSource): IssueSourceCommon =>
  * Get the source type string from an IssueSource.
  * Used for composite ID generation and database storage.
  */
-export const getSourceType = (source: IssueSource): "sentry" | "github" | "ticket" =>
+export const getSourceType = (
+	source: IssueSource,
+): "sentry" | "github" | "ticket" | "manual" =>
 	Match.value(source).pipe(
 		Match.tag("Sentry", () => "sentry" as const),
 		Match.tag("GitHub", () => "github" as const),
 		Match.tag("Ticket", () => "ticket" as const),
+		Match.tag("Manual", () => "manual" as const),
 		Match.exhaustive,
 	);
 
@@ -0,0 +1,53 @@
+/**
+ * @fileoverview Command-line arguments of glass-server.
+ *
+ * `glass-server [--port <port>] [project-path]`
+ */
+
+/**
+ * Port the server listens on unless told otherwise.
+ */
+export const DEFAULT_PORT = 7420;
+
+/**
+ * Parsed command-line arguments.
+ */
+export interface ServerArgs {
+	/** Project to serve, if one was given */
+	readonly projectPath?: string;
+	/** Port to listen on */
+	readonly port: number;
+}
+
+/**
+ * Parses the server's arguments (without the runtime and script).
+ * The port may be given as `--port 7421` or `--port=7421`.
+ *
+ * @param args - e.g. `process.argv.slice(2)`
+ * @returns The parsed arguments
+ * @throws Error if the port isn't a valid port number
+ */
+export const parseServerArgs = (args: readonly string[]): ServerArgs => {
+	let port: string | undefined;
+	let projectPath: string | undefined;
+
+	for (let i = 0; i < args.length; i++) {
+		const arg = args[i]!;
+		if (arg === "--port") {
+			port = args[++i];
+		} else if (arg.startsWith("--port=")) {
+			port = arg.slice("--port=".length);
+		} else if (!arg.startsWith("-") && projectPath === undefined) {
+			projectPath = arg;
+		}
+	}
+
+	if (port === undefined) {
+		return { projectPath, port: DEFAULT_PORT };
+	}
+	const parsed = Number(port);
+	if (!/^\d+$/.test(port) || parsed < 1 || parsed > 65535) {
+		throw new Error(`Invalid port: ${port}`);
+	}
+	return { projectPath, port: parsed };
+};
@@ -9,6 +9,7 @@ import { BunContext, BunHttpServer, BunRuntime } from "@effect/platform-bun";
 import { Effect, Layer } from "effect";
 import { ConfigLive } from "./config/index.js";
 import { DatabaseLive } from "./db/index.js";
+import { parseServerArgs } from "./lib/args.js";
 import { FileLoggerLive } from "./lib/logger.js";
 import { ProjectPath } from "./lib/project.js";
 import { AgentServiceLive, EventBufferServiceLive } from "./services/agent/index.js";
@@ -19,7 +20,7 @@ import { ApiLive } from "./api/routes.js";
 // Configuration
 // =============================================================================
 
-const PORT = 7420;
+const { projectPath: projectArg, port: PORT } = parseServerArgs(process.argv.slice(2));
 
 // =============================================================================
 // Layer Setup
@@ -28,11 +29,7 @@ const PORT = 7420;
 /**
  * Get the project path from CLI arguments or current working directory.
  */
-const getProjectPath = (): string => {
-	const args = process.argv.slice(2);
-	const projectArg = args.find((arg) => !arg.startsWith("-"));
-	return projectArg ?? process.cwd();
-};
+const getProjectPath = (): string => projectArg ?? process.cwd();
 
 /**
  * Creates the full application layer with all services.
@@ -11,7 +11,7 @@
  */
 
 import { Match } from "effect";
-import type { Issue, IssueSource, SentrySourceData } from "../../domain/issue.js";
+import type { Issue, IssueSource, ManualSourceData, SentrySourceData } from "../../domain/issue.js";
 import {
 	formatBreadcrumbs,
 	formatContexts,
@@ -25,24 +25,36 @@ import {
 // Analysis Prompt Builder
 // =============================================================================
 
+/**
+ * Options for an analysis prompt.
+ */
+export interface AnalysisPromptOptions {
+	/** Extra guidance from the user, e.g. when re-analyzing a proposal */
+	readonly instructions?: string;
+	/** The event the issue's details come from, when one was picked */
+	readonly eventId?: string;
+}
+
 /**
  * Build the analysis prompt for an issue.
  *
- * Currently supports Sentry issues. Will be extended for GitHub and Ticket
- * sources as they are implemented.
+ * Currently supports Sentry and manual issues. Will be extended for GitHub
+ * and Ticket sources as they are implemented.
  *
  * @param issue - The issue to analyze
+ * @param options - Guidance to include in the prompt
  * @returns The prompt string to send to the agent
  */
-export const buildAnalysisPrompt = (issue: Issue): string =>
+export const buildAnalysisPrompt = (issue: Issue, options: AnalysisPromptOptions = {}): string =>
 	Match.value(issue.source).pipe(
-		Match.tag("Sentry", ({ project, data }) => buildSentryAnalysisPrompt(project, data)),
+		Match.tag("Sentry", ({ project, data }) => buildSentryAnalysisPrompt(project, data, options)),
 		Match.tag("GitHub", () => {
 			throw new Error("GitHub issue analysis not yet implemented");
 		}),
 		Match.tag("Ticket", () => {
 			throw new Error("Ticket analysis not yet implemented");
 		}),
+		Match.tag("Manual", ({ data }) => buildManualAnalysisPrompt(data, options)),
 		Match.exhaustive,
 	);
 
@@ -53,7 +65,11 @@ export const buildAnalysisPrompt = (issue: Issue): string =>
 /**
  * Build the analysis prompt for a Sentry issue.
  */
-const buildSentryAnalysisPrompt = (project: string, data: SentrySourceData): string => {
+const buildSentryAnalysisPrompt = (
+	project: string,
+	data: SentrySourceData,
+	options: AnalysisPromptOptions,
+): string => {
 	const sections: string[] = [];
 
 	// Header
@@ -74,6 +90,9 @@ const buildSentryAnalysisPrompt = (project: string, data: SentrySourceData): str
 		sections.push(`**Culprit:** ${data.culprit}`);
 	}
 	sections.push(`**Project:** ${project}`);
+	if (options.eventId) {
+		sections.push(`**Event:** ${options.eventId} (chosen for this analysis)`);
+	}
 	sections.push("");
 
 	// Statistics
@@ -151,6 +170,74 @@ const buildSentryAnalysisPrompt = (project: string, data: SentrySourceData): str
 		sections.push("");
 	}
 
+	pushTask(sections, options);
+
+	return sections.join("\n");
+};
+
+// =============================================================================
+// Manual Issue Prompt
+// =============================================================================
+
+/**
+ * Build the analysis prompt for an issue written by hand.
+ */
+const buildManualAnalysisPrompt = (
+	data: ManualSourceData,
+	options: AnalysisPromptOptions,
+): string => {
+	const sections: string[] = [];
+
+	sections.push(`# Issue Analysis: ${data.shortId}`);
+	sections.push("");
+
+	sections.push("## Bug Report");
+	sections.push("");
+	sections.push(`**Title:** ${data.title}`);
+	sections.push(`**Reported:** ${data.firstSeen.toISOString()}`);
+	sections.push("");
+
+	const description = data.description.trim();
+	if (description) {
+		sections.push("## Description");
+		sections.push("");
+		sections.push(description);
+		sections.push("");
+	}
+
+	const stacktrace = data.stacktrace?.trim();
+	if (stacktrace) {
+		sections.push("## Stacktrace");
+		sections.push("");
+		sections.push("```");
+		sections.push(stacktrace);
+		sections.push("```");
+		sections.push("");
+	}
+
+	pushTask(sections, options);
+
+	return sections.join("\n");
+};
+
+// =============================================================================
+// Shared Sections
+// =============================================================================
+
+/**
+ * Append the user's instructions and the task description every analysis
+ * prompt ends with.
+ */
+const pushTask = (sections: string[], options: AnalysisPromptOptions): void => {
+	// Guidance from the user, which takes precedence over the defaults below
+	const instructions = options.instructions?.trim();
+	if (instructions) {
+		sections.push("## Additional Instructions");
+		sections.push("");
+		sections.push(instructions);
+		sections.push("");
+	}
+
 	// Instructions
 	sections.push("---");
 	sections.push("");
@@ -183,8 +270,6 @@ const buildSentryAnalysisPrompt = (project: string, data: SentrySourceData): str
 	sections.push("");
 	sections.push("#### Testing Recommendations");
 	sections.push("Suggest how to verify the fix works and doesn't introduce regressions.");
-
-	return sections.join("\n");
 };
 
 // =============================================================================
@@ -223,5 +308,6 @@ export const extractStacktraceFiles = (source: IssueSource): string[] =>
 		}),
 		Match.tag("GitHub", () => []),
 		Match.tag("Ticket", () => []),
+		Match.tag("Manual", () => []),
 		Match.exhaustive,
 	);
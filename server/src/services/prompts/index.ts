@@ -4,7 +4,7 @@
  * @module
  */
 
-export { buildAnalysisPrompt, extractStacktraceFiles } from "./analysis.js";
+export { type AnalysisPromptOptions, buildAnalysisPrompt, extractStacktraceFiles } from "./analysis.js";
 export {
 	formatBreadcrumb,
 	formatBreadcrumbs,
@@ -77,7 +77,7 @@ export interface ListIssuesOptions {
 }
 
 /**
- * Event data returned by getLatestEvent.
+ * Event data returned by getEvent and getLatestEvent.
  * Contains the full event details including exceptions and breadcrumbs.
  */
 export interface SentryEventData {
@@ -147,6 +147,19 @@ export interface SentryServiceImpl {
 	 * @returns Full event data including exceptions and breadcrumbs
 	 */
 	readonly getLatestEvent: (issueId: string) => Effect.Effect<SentryEventData, SentryError>;
+
+	/**
+	 * Get one of an issue's events with full details, e.g. a sample picked
+	 * for analysis instead of the latest.
+	 *
+	 * @param issueId - The Sentry issue ID (numeric string)
+	 * @param eventId - The event ID
+	 * @returns Full event data including exceptions and breadcrumbs
+	 */
+	readonly getEvent: (
+		issueId: string,
+		eventId: string,
+	) => Effect.Effect<SentryEventData, SentryError>;
 }
 
 /**
@@ -675,13 +688,15 @@ const make = Effect.gen(function* () {
 		});
 
 	/**
-	 * Get the latest event for an issue.
+	 * Get an issue's event; Sentry accepts "latest" as the event ID.
 	 */
-	const getLatestEvent: SentryServiceImpl["getLatestEvent"] = (issueId) =>
+	const getEvent: SentryServiceImpl["getEvent"] = (issueId, eventId) =>
 		Effect.gen(function* () {
-			const path = `/organizations/${sentryConfig.organization}/issues/${issueId}/events/latest/`;
+			const path = `/organizations/${sentryConfig.organization}/issues/${issueId}/events/${eventId}/`;
+
+			const id = eventId === "latest" ? issueId : eventId;
 
-			const { data: event } = yield* request(path, SentryEventSchema, "event", issueId);
+			const { data: event } = yield* request(path, SentryEventSchema, "event", id);
 
 			// Extract release version from various formats
 			let release: string | undefined;
@@ -713,10 +728,17 @@ const make = Effect.gen(function* () {
 			return eventData;
 		});
 
+	/**
+	 * Get the latest event for an issue.
+	 */
+	const getLatestEvent: SentryServiceImpl["getLatestEvent"] = (issueId) =>
+		getEvent(issueId, "latest");
+
 	return {
 		listIssues,
 		getIssue,
 		getLatestEvent,
+		getEvent,
 	} satisfies SentryServiceImpl;
 });
 
@@ -38,6 +38,9 @@ export {
 	type SentryServiceImpl,
 } from "./client.js";
 
+// Re-export issue reference parsing
+export { parseIssueReference } from "./reference.js";
+
 // Re-export useful types from the types module
 export {
 	type Breadcrumb,
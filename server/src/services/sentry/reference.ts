@@ -0,0 +1,42 @@
+/**
+ * @fileoverview Parsing issue references pasted from Sentry.
+ *
+ * @module
+ */
+
+/**
+ * Short IDs as Sentry shows them, e.g. `BACKEND-1A2`.
+ */
+const SHORT_ID = /^[A-Za-z0-9_]+(?:-[A-Za-z0-9_]+)*-[A-Za-z0-9]+$/;
+
+/**
+ * Extracts the issue ID from a Sentry issue URL, numeric ID or short ID.
+ *
+ * URLs may be in either form Sentry links to, e.g.
+ * `https://acme.sentry.io/issues/12345/?project=1` or
+ * `https://sentry.io/organizations/acme/issues/12345/events/abc/`.
+ *
+ * @param input - What was pasted
+ * @returns The ID to look the issue up by, or null if it isn't one
+ */
+export const parseIssueReference = (input: string): string | null => {
+	const trimmed = input.trim();
+	if (/^\d+$/.test(trimmed)) {
+		return trimmed;
+	}
+
+	if (/^https?:\/\//i.test(trimmed)) {
+		let url: URL;
+		try {
+			url = new URL(trimmed);
+		} catch {
+			return null;
+		}
+		const segments = url.pathname.split("/").filter((s) => s.length > 0);
+		const index = segments.indexOf("issues");
+		const id = index >= 0 ? segments[index + 1] : undefined;
+		return id && (/^\d+$/.test(id) || SHORT_ID.test(id)) ? id : null;
+	}
+
+	return SHORT_ID.test(trimmed) ? trimmed.toUpperCase() : null;
+};
@@ -6,11 +6,12 @@
 
 import { BunContext } from "@effect/platform-bun";
 import { describe, it } from "@effect/vitest";
-import { Effect, Layer, Option } from "effect";
+import { Deferred, Effect, FiberId, Layer, Option } from "effect";
 import { expect } from "vitest";
 import { DatabaseTestLive, SentryIssueRepository, ConversationRepository } from "../../../src/db/index.js";
 import { IssueSource } from "../../../src/domain/issue.js";
 import {
+	type SentryEventData,
 	SentryService,
 	type SentryServiceImpl,
 	SentryError,
@@ -23,7 +24,12 @@ import {
 } from "../../../src/services/agent/index.js";
 import { AgentError } from "../../../src/services/agent/errors.js";
 import type { AgentSessionHandle } from "../../../src/services/agent/types.js";
-import { refreshIssuesHandler, analyzeIssueHandler } from "../../../src/api/handlers/issues.js";
+import {
+	refreshIssuesHandler,
+	createIssueHandler,
+	importIssueHandler,
+	analyzeIssueHandler,
+} from "../../../src/api/handlers/issues.js";
 import { HttpServerRequest } from "@effect/platform";
 
 // =============================================================================
@@ -60,12 +66,52 @@ const createMockSentryService = (
 	getIssue: () => Effect.fail(SentryError.NotFoundError({ resource: "issue", id: "unknown" })),
 	getLatestEvent: () =>
 		Effect.fail(SentryError.NotFoundError({ resource: "event", id: "unknown" })),
+	getEvent: (_issueId, eventId) =>
+		Effect.fail(SentryError.NotFoundError({ resource: "event", id: eventId })),
+});
+
+const makeEvent = (eventId: string, environment: string): SentryEventData => ({
+	eventId,
+	title: "",
+	message: "",
+	platform: "node",
+	dateCreated: "2024-01-02T00:00:00Z",
+	culprit: "src/app.ts",
+	exceptions: [],
+	breadcrumbs: [],
+	environment,
+	release: undefined,
+	tags: {},
+	request: undefined,
+	user: undefined,
+	contexts: undefined,
+});
+
+const createImportSentryService = (
+	issues: readonly ReturnType<typeof makeSentrySource>[],
+): SentryServiceImpl => ({
+	listIssues: () => Effect.succeed([]),
+	getIssue: (issueId) => {
+		const found = issues.find(
+			(issue) => issue.data.sentryId === issueId || issue.data.shortId === issueId,
+		);
+		return found
+			? Effect.succeed(found)
+			: Effect.fail(SentryError.NotFoundError({ resource: "issue", id: issueId }));
+	},
+	getLatestEvent: (issueId) => Effect.succeed(makeEvent(`event-${issueId}`, "production")),
+	// Older samples come from staging, to tell them from the latest
+	getEvent: (_issueId, eventId) =>
+		eventId === "missing"
+			? Effect.fail(SentryError.NotFoundError({ resource: "event", id: eventId }))
+			: Effect.succeed(makeEvent(eventId, "staging")),
 });
 
 const createFailingSentryService = (error: SentryError): SentryServiceImpl => ({
 	listIssues: () => Effect.fail(error),
 	getIssue: () => Effect.fail(error),
 	getLatestEvent: () => Effect.fail(error),
+	getEvent: () => Effect.fail(error),
 });
 
 // =============================================================================
@@ -75,14 +121,17 @@ const createFailingSentryService = (error: SentryError): SentryServiceImpl => ({
 const createMockAgentService = (options?: {
 	failOnCreate?: boolean;
 	sessionId?: string;
+	/** Completed with the first prompt sent to a session */
+	prompted?: Deferred.Deferred<string>;
 }): AgentServiceInterface => {
 	const sessionId = options?.sessionId ?? "test-session-123";
+	const prompted = options?.prompted;
 
 	const mockHandle: AgentSessionHandle = {
 		sessionId,
 		session: {} as AgentSessionHandle["session"],
 		type: "analysis",
-		prompt: () => Effect.void,
+		prompt: (message) => (prompted ? Deferred.succeed(prompted, message).pipe(Effect.asVoid) : Effect.void),
 		subscribe: () => () => {},
 		abort: () => Effect.void,
 	};
@@ -103,7 +152,7 @@ const createMockAgentService = (options?: {
 // Mock HttpServerRequest
 // =============================================================================
 
-const createMockRequest = (path: string) =>
+const createMockRequest = (path: string, body?: unknown) =>
 	Layer.succeed(HttpServerRequest.HttpServerRequest, {
 		url: `http://localhost${path}`,
 		method: "POST",
@@ -118,7 +167,8 @@ const createMockRequest = (path: string) =>
 		modify: () => null as unknown,
 		arrayBuffer: Effect.die("not implemented"),
 		formData: Effect.die("not implemented"),
-		json: Effect.die("not implemented"),
+		// Like a request without a body, which fails to parse
+		json: body === undefined ? Effect.fail(new Error("empty body")) : Effect.succeed(body),
 		stream: null as unknown,
 		text: Effect.die("not implemented"),
 		urlParamsBody: Effect.die("not implemented"),
@@ -134,10 +184,14 @@ const createTestLayer = (sentryImpl: SentryServiceImpl) =>
 		Layer.succeed(SentryService, sentryImpl),
 	);
 
-const createAnalyzeTestLayer = (agentImpl: AgentServiceInterface) =>
+const createAnalyzeTestLayer = (
+	agentImpl: AgentServiceInterface,
+	sentryImpl: SentryServiceImpl = createImportSentryService([]),
+) =>
 	Layer.mergeAll(
 		DatabaseTestLive.pipe(Layer.provide(BunContext.layer)),
 		Layer.succeed(AgentService, agentImpl),
+		Layer.succeed(SentryService, sentryImpl),
 		EventBufferServiceLive,
 	);
 
@@ -336,6 +390,163 @@ describe("refreshIssuesHandler", () => {
 	);
 });
 
+// =============================================================================
+// importIssueHandler Tests
+// =============================================================================
+
+describe("importIssueHandler", () => {
+	const importRequest = (issue: unknown) =>
+		createMockRequest("/api/v1/issues/import", { issue });
+
+	it.effect("stores an issue given by URL and returns its detail", () =>
+		Effect.gen(function* () {
+			const response = yield* importIssueHandler.pipe(
+				Effect.provide(importRequest("https://acme.sentry.io/issues/77/?project=1")),
+			);
+
+			expect(response.status).toBe(200);
+
+			const rawBody = (response.body as { body: Uint8Array }).body;
+			const body = JSON.parse(new TextDecoder().decode(rawBody)) as {
+				id: string;
+				status: string;
+				source: { title: string; environment: string };
+			};
+
+			expect(body.id).toBe("77");
+			expect(body.status).toBe("pending");
+			expect(body.source.title).toBe("Escalated");
+			expect(body.source.environment).toBe("production");
+
+			const repo = yield* SentryIssueRepository;
+			const stored = yield* repo.getById("77");
+			expect(Option.isSome(stored)).toBe(true);
+		}).pipe(
+			Effect.provide(
+				createTestLayer(createImportSentryService([makeSentrySource("77", "Escalated")])),
+			),
+		),
+	);
+
+	it.effect("stores an issue given by short ID under its Sentry ID", () =>
+		Effect.gen(function* () {
+			const response = yield* importIssueHandler.pipe(
+				Effect.provide(importRequest("test-77")),
+			);
+
+			expect(response.status).toBe(200);
+
+			const repo = yield* SentryIssueRepository;
+			const stored = yield* repo.listAll();
+			expect(stored.map((i) => i.id)).toEqual(["77"]);
+		}).pipe(
+			Effect.provide(
+				createTestLayer(createImportSentryService([makeSentrySource("77", "Escalated")])),
+			),
+		),
+	);
+
+	it.effect("returns 400 for something that isn't an issue", () =>
+		Effect.gen(function* () {
+			const response = yield* importIssueHandler.pipe(
+				Effect.provide(importRequest("https://example.com/tickets/9")),
+			);
+
+			expect(response.status).toBe(400);
+
+			const missing = yield* importIssueHandler.pipe(Effect.provide(importRequest(42)));
+			expect(missing.status).toBe(400);
+		}).pipe(Effect.provide(createTestLayer(createImportSentryService([])))),
+	);
+
+	it.effect("returns 404 when Sentry doesn't know the issue", () =>
+		Effect.gen(function* () {
+			const response = yield* importIssueHandler.pipe(Effect.provide(importRequest("404")));
+
+			expect(response.status).toBe(404);
+
+			const repo = yield* SentryIssueRepository;
+			const stored = yield* repo.listAll();
+			expect(stored.length).toBe(0);
+		}).pipe(Effect.provide(createTestLayer(createImportSentryService([])))),
+	);
+});
+
+describe("createIssueHandler", () => {
+	const createRequest = (body: unknown) => createMockRequest("/api/v1/issues", body);
+
+	it.effect("stores a manual issue and returns its detail", () =>
+		Effect.gen(function* () {
+			const response = yield* createIssueHandler.pipe(
+				Effect.provide(
+					createRequest({
+						title: "  Export hangs  ",
+						description: "Exporting a large report never finishes",
+						stacktrace: "Error: timeout\n    at export (src/export.ts:12)",
+					}),
+				),
+			);
+
+			expect(response.status).toBe(200);
+
+			const rawBody = (response.body as { body: Uint8Array }).body;
+			const body = JSON.parse(new TextDecoder().decode(rawBody)) as {
+				id: string;
+				sourceType: string;
+				status: string;
+				source: { title: string; shortId: string; description: string; stacktrace: string };
+			};
+
+			expect(body.sourceType).toBe("manual");
+			expect(body.status).toBe("pending");
+			expect(body.source.title).toBe("Export hangs");
+			expect(body.source.shortId).toBe("MANUAL-1");
+			expect(body.source.description).toBe("Exporting a large report never finishes");
+			expect(body.source.stacktrace).toContain("src/export.ts:12");
+
+			const repo = yield* SentryIssueRepository;
+			const stored = yield* repo.getById(body.id);
+			expect(Option.isSome(stored) && stored.value.source._tag).toBe("Manual");
+		}).pipe(Effect.provide(createTestLayer(createMockSentryService([])))),
+	);
+
+	it.effect("numbers manual issues in creation order", () =>
+		Effect.gen(function* () {
+			yield* createIssueHandler.pipe(
+				Effect.provide(createRequest({ title: "First", description: "" })),
+			);
+			yield* createIssueHandler.pipe(
+				Effect.provide(createRequest({ title: "Second", description: "" })),
+			);
+
+			const repo = yield* SentryIssueRepository;
+			const stored = yield* repo.listAll();
+			const shortIds = stored.map((issue) =>
+				issue.source._tag === "Manual" ? issue.source.data.shortId : null,
+			);
+			expect(shortIds.sort()).toEqual(["MANUAL-1", "MANUAL-2"]);
+		}).pipe(Effect.provide(createTestLayer(createMockSentryService([])))),
+	);
+
+	it.effect("returns 400 without a title", () =>
+		Effect.gen(function* () {
+			const blank = yield* createIssueHandler.pipe(
+				Effect.provide(createRequest({ title: "  ", description: "Something broke" })),
+			);
+			expect(blank.status).toBe(400);
+
+			const missing = yield* createIssueHandler.pipe(
+				Effect.provide(createRequest({ description: "Something broke" })),
+			);
+			expect(missing.status).toBe(400);
+
+			const repo = yield* SentryIssueRepository;
+			const stored = yield* repo.listAll();
+			expect(stored.length).toBe(0);
+		}).pipe(Effect.provide(createTestLayer(createMockSentryService([])))),
+	);
+});
+
 // =============================================================================
 // analyzeIssueHandler Tests
 // =============================================================================
@@ -387,6 +598,131 @@ describe("analyzeIssueHandler", () => {
 		}).pipe(Effect.provide(createAnalyzeTestLayer(createMockAgentService()))),
 	);
 
+	it.effect("passes instructions from the body to the agent prompt", () => {
+		const prompted = Deferred.unsafeMake<string>(FiberId.none);
+		return Effect.gen(function* () {
+			const repo = yield* SentryIssueRepository;
+
+			yield* repo.upsert({
+				id: "guided",
+				project: "test-project",
+				data: {
+					sentryId: "guided",
+					title: "Guided Analysis",
+					shortId: "TEST-5",
+					culprit: "src/app.ts",
+					firstSeen: new Date("2024-01-01T00:00:00Z"),
+					lastSeen: new Date("2024-01-02T00:00:00Z"),
+					count: 10,
+					userCount: 5,
+					metadata: {},
+				},
+			});
+
+			const response = yield* analyzeIssueHandler.pipe(
+				Effect.provide(
+					createMockRequest("/api/v1/issues/guided/analyze", {
+						instructions: "Focus on the retry logic in payments.rs",
+					}),
+				),
+			);
+
+			expect(response.status).toBe(200);
+
+			const prompt = yield* Deferred.await(prompted).pipe(Effect.timeout("1 second"));
+			expect(prompt).toContain("## Additional Instructions");
+			expect(prompt).toContain("Focus on the retry logic in payments.rs");
+		}).pipe(Effect.provide(createAnalyzeTestLayer(createMockAgentService({ prompted }))));
+	});
+
+	it.effect("analyzes the chosen event instead of the stored one", () => {
+		const prompted = Deferred.unsafeMake<string>(FiberId.none);
+		return Effect.gen(function* () {
+			const repo = yield* SentryIssueRepository;
+
+			yield* repo.upsert({
+				id: "sampled",
+				project: "test-project",
+				data: {
+					sentryId: "sampled",
+					title: "Sampled Analysis",
+					shortId: "TEST-6",
+					culprit: "src/app.ts",
+					firstSeen: new Date("2024-01-01T00:00:00Z"),
+					lastSeen: new Date("2024-01-02T00:00:00Z"),
+					count: 10,
+					userCount: 5,
+					metadata: {},
+					environment: "production",
+				},
+			});
+
+			const response = yield* analyzeIssueHandler.pipe(
+				Effect.provide(
+					createMockRequest("/api/v1/issues/sampled/analyze", { eventId: "sample-7" }),
+				),
+			);
+
+			expect(response.status).toBe(200);
+
+			const prompt = yield* Deferred.await(prompted).pipe(Effect.timeout("1 second"));
+			expect(prompt).toContain("**Event:** sample-7");
+			expect(prompt).toContain("**Environment:** staging");
+			expect(prompt).not.toContain("**Environment:** production");
+		}).pipe(Effect.provide(createAnalyzeTestLayer(createMockAgentService({ prompted }))));
+	});
+
+	it.effect("returns 404 when the chosen event doesn't exist", () =>
+		Effect.gen(function* () {
+			const repo = yield* SentryIssueRepository;
+
+			yield* repo.upsert({
+				id: "no-sample",
+				project: "test-project",
+				data: {
+					sentryId: "no-sample",
+					title: "Missing Sample",
+					shortId: "TEST-7",
+					culprit: "src/app.ts",
+					firstSeen: new Date("2024-01-01T00:00:00Z"),
+					lastSeen: new Date("2024-01-02T00:00:00Z"),
+					count: 10,
+					userCount: 5,
+					metadata: {},
+				},
+			});
+
+			const response = yield* analyzeIssueHandler.pipe(
+				Effect.provide(
+					createMockRequest("/api/v1/issues/no-sample/analyze", { eventId: "missing" }),
+				),
+			);
+
+			expect(response.status).toBe(404);
+
+			// Still pending; nothing was started
+			const stored = yield* repo.getById("no-sample");
+			expect(Option.isSome(stored) && stored.value.state._tag).toBe("Pending");
+		}).pipe(Effect.provide(createAnalyzeTestLayer(createMockAgentService()))),
+	);
+
+	it.effect("returns 400 for instructions that aren't text", () =>
+		Effect.gen(function* () {
+			const response = yield* analyzeIssueHandler.pipe(
+				Effect.provide(createMockRequest("/api/v1/issues/any/analyze", { instructions: 42 })),
+			);
+
+			expect(response.status).toBe(400);
+
+			const rawBody = (response.body as { body: Uint8Array }).body;
+			const body = JSON.parse(new TextDecoder().decode(rawBody)) as {
+				error: { code: string; message: string };
+			};
+
+			expect(body.error.code).toBe("VALIDATION_ERROR");
+		}).pipe(Effect.provide(createAnalyzeTestLayer(createMockAgentService()))),
+	);
+
 	it.effect("returns 404 for non-existent issue", () =>
 		Effect.gen(function* () {
 			const response = yield* analyzeIssueHandler.pipe(
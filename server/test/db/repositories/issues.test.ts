@@ -122,6 +122,55 @@ describe("SentryIssueRepository", () => {
 		);
 	});
 
+	describe("createManual", () => {
+		it.effect("inserts a manual issue with Pending state", () =>
+			Effect.gen(function* () {
+				const repo = yield* SentryIssueRepository;
+
+				const issue = yield* repo.createManual({
+					title: "Export hangs",
+					description: "Large reports never finish",
+					stacktrace: "Error: timeout",
+				});
+
+				expect(issue.id).toMatch(/^manual-/);
+				expect(issue.state._tag).toBe("Pending");
+				expect(issue.source._tag).toBe("Manual");
+				if (issue.source._tag === "Manual") {
+					expect(issue.source.data.shortId).toBe("MANUAL-1");
+					expect(issue.source.data.description).toBe("Large reports never finish");
+					expect(issue.source.data.stacktrace).toBe("Error: timeout");
+				}
+
+				// Sentry issues don't count towards manual short IDs
+				yield* repo.upsert(makeSentryIssue("123"));
+				const second = yield* repo.createManual({ title: "Second", description: "" });
+				if (second.source._tag === "Manual") {
+					expect(second.source.data.shortId).toBe("MANUAL-2");
+					expect(second.source.data.stacktrace).toBeUndefined();
+				}
+			}).pipe(Effect.provide(TestLayer)),
+		);
+
+		it.effect("gives concurrent creates distinct short IDs", () =>
+			Effect.gen(function* () {
+				const repo = yield* SentryIssueRepository;
+
+				const issues = yield* Effect.all(
+					Array.from({ length: 5 }, (_, i) =>
+						repo.createManual({ title: `Issue ${i}`, description: "" }),
+					),
+					{ concurrency: "unbounded" },
+				);
+
+				const shortIds = issues.map((issue) =>
+					issue.source._tag === "Manual" ? issue.source.data.shortId : null,
+				);
+				expect(new Set(shortIds).size).toBe(5);
+			}).pipe(Effect.provide(TestLayer)),
+		);
+	});
+
 	describe("getById", () => {
 		it.effect("returns None for non-existent issue", () =>
 			Effect.gen(function* () {
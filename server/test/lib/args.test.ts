@@ -0,0 +1,34 @@
+/**
+ * @fileoverview Tests for command-line argument parsing.
+ */
+
+import { describe, it } from "@effect/vitest";
+import { expect } from "vitest";
+import { DEFAULT_PORT, parseServerArgs } from "../../src/lib/args.js";
+
+describe("parseServerArgs", () => {
+	it("defaults the port and leaves the project unset", () => {
+		expect(parseServerArgs([])).toEqual({ projectPath: undefined, port: DEFAULT_PORT });
+	});
+
+	it("takes the project path and port in either order", () => {
+		expect(parseServerArgs(["/work/web", "--port", "7421"])).toEqual({
+			projectPath: "/work/web",
+			port: 7421,
+		});
+		expect(parseServerArgs(["--port=7422", "/work/web"])).toEqual({
+			projectPath: "/work/web",
+			port: 7422,
+		});
+	});
+
+	it("doesn't mistake the port for the project path", () => {
+		expect(parseServerArgs(["--port", "7421"]).projectPath).toBeUndefined();
+	});
+
+	it("rejects ports that aren't port numbers", () => {
+		expect(() => parseServerArgs(["--port", "web"])).toThrow("Invalid port: web");
+		expect(() => parseServerArgs(["--port=70000"])).toThrow();
+		expect(() => parseServerArgs(["--port"])).not.toThrow();
+	});
+});
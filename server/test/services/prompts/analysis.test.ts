@@ -263,6 +263,73 @@ describe("buildAnalysisPrompt", () => {
 		expect(prompt).toContain("#### Risk Assessment");
 		expect(prompt).toContain("#### Testing Recommendations");
 	});
+
+	it("includes additional instructions when given", () => {
+		const issue = createIssue(createSentryData());
+		const prompt = buildAnalysisPrompt(issue, {
+			instructions: "Focus on the retry logic in payments.rs",
+		});
+
+		expect(prompt).toContain("## Additional Instructions");
+		expect(prompt).toContain("Focus on the retry logic in payments.rs");
+		expect(prompt.indexOf("Focus on the retry logic")).toBeLessThan(prompt.indexOf("## Your Task"));
+	});
+
+	it("names the event chosen for the analysis", () => {
+		const issue = createIssue(createSentryData());
+
+		expect(buildAnalysisPrompt(issue, { eventId: "abc123" })).toContain(
+			"**Event:** abc123 (chosen for this analysis)",
+		);
+		expect(buildAnalysisPrompt(issue)).not.toContain("**Event:**");
+	});
+
+	it("omits additional instructions when blank", () => {
+		const issue = createIssue(createSentryData());
+		const prompt = buildAnalysisPrompt(issue, { instructions: "  " });
+
+		expect(prompt).not.toContain("## Additional Instructions");
+	});
+});
+
+describe("buildAnalysisPrompt for manual issues", () => {
+	const createManualIssue = (stacktrace?: string): Issue => ({
+		id: "manual-1",
+		source: IssueSource.Manual({
+			data: {
+				title: "Export hangs on large reports",
+				shortId: "MANUAL-1",
+				firstSeen: new Date("2024-01-01T10:00:00Z"),
+				lastSeen: new Date("2024-01-01T10:00:00Z"),
+				description: "Exporting a report with 10k rows never finishes",
+				...(stacktrace ? { stacktrace } : {}),
+			},
+		}),
+		state: IssueState.Pending(),
+		createdAt: new Date(),
+		updatedAt: new Date(),
+	});
+
+	it("includes the title, description and pasted stacktrace", () => {
+		const prompt = buildAnalysisPrompt(
+			createManualIssue("Error: timeout\n    at exportReport (src/export.ts:42)"),
+			{ instructions: "Check the pagination" },
+		);
+
+		expect(prompt).toContain("# Issue Analysis: MANUAL-1");
+		expect(prompt).toContain("**Title:** Export hangs on large reports");
+		expect(prompt).toContain("Exporting a report with 10k rows never finishes");
+		expect(prompt).toContain("## Stacktrace");
+		expect(prompt).toContain("at exportReport (src/export.ts:42)");
+		expect(prompt).toContain("## Additional Instructions");
+		expect(prompt).toContain("## Your Task");
+	});
+
+	it("omits the stacktrace section without one", () => {
+		const prompt = buildAnalysisPrompt(createManualIssue());
+
+		expect(prompt).not.toContain("## Stacktrace");
+	});
 });
 
 describe("extractStacktraceFiles", () => {
@@ -545,6 +545,29 @@ describe("SentryService", () => {
 		);
 	});
 
+	describe("getEvent", () => {
+		it.effect("fetches the given event of the issue", () =>
+			Effect.gen(function* () {
+				const sentry = yield* SentryService;
+				const event = yield* sentry.getEvent("12345", "abc123");
+
+				expect(event.eventId).toBe("abc123");
+				expect(event.exceptions).toHaveLength(1);
+			}).pipe(
+				Effect.provide(
+					createTestLayer(({ url }) => {
+						expect(url).toContain("/issues/12345/events/abc123/");
+						return {
+							status: 200,
+							headers: {},
+							body: MOCK_EVENT,
+						};
+					}),
+				),
+			),
+		);
+	});
+
 	describe("error handling", () => {
 		it.effect("returns AuthError for 401", () =>
 			Effect.gen(function* () {
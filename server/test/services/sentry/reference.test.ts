@@ -0,0 +1,34 @@
+/**
+ * Tests for parsing issue references.
+ */
+
+import { describe, it } from "@effect/vitest";
+import { expect } from "vitest";
+import { parseIssueReference } from "../../../src/services/sentry/index.js";
+
+describe("parseIssueReference", () => {
+	it("accepts numeric IDs", () => {
+		expect(parseIssueReference("12345")).toBe("12345");
+		expect(parseIssueReference("  12345\n")).toBe("12345");
+	});
+
+	it("accepts short IDs", () => {
+		expect(parseIssueReference("BACKEND-1A2")).toBe("BACKEND-1A2");
+		expect(parseIssueReference("web-app-3f")).toBe("WEB-APP-3F");
+	});
+
+	it("extracts the ID from issue URLs", () => {
+		expect(parseIssueReference("https://acme.sentry.io/issues/12345/?project=1")).toBe("12345");
+		expect(
+			parseIssueReference("https://sentry.io/organizations/acme/issues/12345/events/abc123/"),
+		).toBe("12345");
+		expect(parseIssueReference("https://acme.sentry.io/issues/BACKEND-1A2/")).toBe("BACKEND-1A2");
+	});
+
+	it("rejects anything else", () => {
+		expect(parseIssueReference("")).toBeNull();
+		expect(parseIssueReference("not an issue")).toBeNull();
+		expect(parseIssueReference("https://acme.sentry.io/projects/backend/")).toBeNull();
+		expect(parseIssueReference("https://acme.sentry.io/issues/")).toBeNull();
+	});
+});
@@ -0,0 +1,92 @@
+//! Frame cost of the proposal screen as proposals get longer.
+//!
+//! Run with `cargo bench --bench proposal`. Steady-state frames should cost
+//! about the same whatever the proposal's length; only the first frame after
+//! a proposal arrives (or the terminal is resized) lays the whole text out.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use glass_tui::api::{IssueDetail, IssueState};
+use glass_tui::app::{App, Screen};
+use ratatui::{backend::TestBackend, Terminal};
+
+const FRAMES: u32 = 200;
+
+/// A proposal of about `lines` lines mixing prose, lists and code.
+fn proposal(lines: usize) -> String {
+    let section = "\
+## Proposed Fix
+
+The handler reads `user.profile` before checking the session, so requests
+from expired sessions crash instead of redirecting to the login page.
+
+- Check the session first
+- Return early with a redirect
+* Keep the profile lookup as it is
+
+```diff
+--- a/src/handlers/user.ts
++++ b/src/handlers/user.ts
+@@ -10,6 +10,9 @@ export function getUser(req: Request) {
+   const session = req.session;
++  if (!session) {
++    return redirect('/login');
++  }
+   const user = session.user;
+```
+
+```rust
+fn main() {
+    println!(\"{}\", 42);
+}
+```
+
+> Tested against the failing request from the Sentry event.
+
+";
+    let per_section = section.lines().count();
+    section.repeat(lines.div_ceil(per_section))
+}
+
+fn app_with_proposal(lines: usize) -> App {
+    let json = std::fs::read_to_string("tests/fixtures/issue_detail_pending_approval.json")
+        .expect("Failed to load fixture issue_detail_pending_approval");
+    let mut detail: IssueDetail = serde_json::from_str(&json).expect("Failed to parse fixture");
+    detail.state = IssueState::PendingApproval {
+        analysis_session_id: "bench".to_string(),
+        proposal: proposal(lines),
+    };
+
+    let mut app = App::new("http://localhost:1".into(), None);
+    app.state.current_issue = Some(detail);
+    app.state.screen = Screen::Proposal;
+    app
+}
+
+fn main() {
+    println!("{:>8} {:>14} {:>14}", "lines", "first frame", "per frame");
+    for lines in [100, 1_000, 5_000, 20_000] {
+        let mut app = app_with_proposal(lines);
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+
+        let started = Instant::now();
+        terminal.draw(|f| glass_tui::ui::draw(f, &app)).unwrap();
+        let first = started.elapsed();
+
+        // Scroll to the middle, where a whole-text render would do the most
+        // wasted work on both sides
+        app.state.proposal_scroll.scroll_by((app.state.proposal_scroll.max_offset() / 2) as i32);
+        let started = Instant::now();
+        for _ in 0..FRAMES {
+            terminal.draw(|f| glass_tui::ui::draw(f, black_box(&app))).unwrap();
+        }
+        let per_frame = started.elapsed() / FRAMES;
+
+        println!("{:>8} {:>14} {:>14}", lines, format(first), format(per_frame));
+    }
+}
+
+fn format(duration: Duration) -> String {
+    format!("{:.1}µs", duration.as_secs_f64() * 1e6)
+}
@@ -0,0 +1,84 @@
+//! Round-trip times of API requests, for the status bar's latency
+//! indicator.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Requests the rolling percentile is taken over.
+const WINDOW: usize = 50;
+/// A 95th percentile at or above this counts as a slow server.
+pub const SLOW_P95: Duration = Duration::from_secs(1);
+
+/// The most recent request times, shared by clones of a client.
+#[derive(Debug, Default)]
+pub struct LatencyTracker {
+    samples: Mutex<VecDeque<Duration>>,
+}
+
+/// The last request's time and the rolling 95th percentile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyStats {
+    pub last: Duration,
+    pub p95: Duration,
+}
+
+impl LatencyStats {
+    pub fn is_slow(&self) -> bool {
+        self.p95 >= SLOW_P95
+    }
+}
+
+impl LatencyTracker {
+    pub fn record(&self, elapsed: Duration) {
+        let Ok(mut samples) = self.samples.lock() else {
+            return;
+        };
+        if samples.len() == WINDOW {
+            samples.pop_front();
+        }
+        samples.push_back(elapsed);
+    }
+
+    /// `None` until a request has completed.
+    pub fn stats(&self) -> Option<LatencyStats> {
+        let samples = self.samples.lock().ok()?;
+        let last = *samples.back()?;
+        let mut sorted: Vec<Duration> = samples.iter().copied().collect();
+        sorted.sort();
+        // Nearest rank
+        let rank = (sorted.len() * 95).div_ceil(100);
+        Some(LatencyStats {
+            last,
+            p95: sorted[rank - 1],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p95_over_a_rolling_window() {
+        let tracker = LatencyTracker::default();
+        assert_eq!(tracker.stats(), None);
+
+        // One slow request among twenty is the 95th percentile
+        for ms in 1..=20 {
+            tracker.record(Duration::from_millis(if ms == 7 { 2000 } else { ms }));
+        }
+        let stats = tracker.stats().unwrap();
+        assert_eq!(stats.last, Duration::from_millis(20));
+        assert_eq!(stats.p95, Duration::from_millis(20));
+
+        tracker.record(Duration::from_millis(3000));
+        assert!(tracker.stats().unwrap().is_slow());
+
+        // and falls out of the window eventually
+        for _ in 0..WINDOW {
+            tracker.record(Duration::from_millis(40));
+        }
+        assert_eq!(tracker.stats().unwrap().p95, Duration::from_millis(40));
+    }
+}
@@ -1,64 +1,262 @@
 //! API client for Glass server communication.
 
+mod latency;
 mod types;
 
+pub use latency::{LatencyStats, LatencyTracker, SLOW_P95};
 pub use types::*;
 
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Instant;
+
 use anyhow::{Context, Result};
-use reqwest::Client;
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use reqwest::{Client, RequestBuilder};
+use serde::Deserialize;
 use tracing::{debug, error};
 
+/// A bearer token for a server that requires one, e.g. on a shared box.
+///
+/// Checked when it's read, so a token that can't go in a header is an
+/// error up front rather than on every request. Never logged.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct AuthToken(HeaderValue);
+
+impl FromStr for AuthToken {
+    type Err = String;
+
+    fn from_str(token: &str) -> Result<Self, String> {
+        let token = token.trim();
+        if token.is_empty() {
+            return Err("the auth token is empty".to_string());
+        }
+        let mut value = HeaderValue::from_str(&format!("Bearer {}", token))
+            .map_err(|_| "the auth token has characters that can't be sent in a header".to_string())?;
+        value.set_sensitive(true);
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<String> for AuthToken {
+    type Error = String;
+
+    fn try_from(token: String) -> Result<Self, String> {
+        token.parse()
+    }
+}
+
+impl fmt::Debug for AuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AuthToken(<redacted>)")
+    }
+}
+
+/// A non-success response from the Glass server.
+///
+/// Returned inside `anyhow::Error` by the client methods; downcast to get at
+/// the parsed details.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{message} (HTTP {status})")]
+pub struct ApiError {
+    pub status: u16,
+    /// Machine-readable error code, if the body parsed
+    pub code: Option<String>,
+    /// Server message, or the raw body if it didn't parse
+    pub message: String,
+    pub request_id: Option<String>,
+    /// Raw response body
+    pub body: String,
+}
+
+impl ApiError {
+    /// Build from a failed response. `request_id` comes from the
+    /// `X-Request-Id` header and is overridden by one in the body.
+    pub fn new(status: u16, request_id: Option<String>, body: String) -> Self {
+        match serde_json::from_str::<ErrorResponse>(&body) {
+            Ok(parsed) => Self {
+                status,
+                code: Some(parsed.error.code),
+                message: parsed.error.message,
+                request_id: parsed.error.request_id.or(request_id),
+                body,
+            },
+            Err(_) => Self {
+                status,
+                code: None,
+                message: body.trim().to_string(),
+                request_id,
+                body,
+            },
+        }
+    }
+
+    /// Status code with its reason phrase, e.g. "422 Unprocessable Entity".
+    pub fn status_text(&self) -> String {
+        match reqwest::StatusCode::from_u16(self.status)
+            .ok()
+            .and_then(|s| s.canonical_reason())
+        {
+            Some(reason) => format!("{} {}", self.status, reason),
+            None => self.status.to_string(),
+        }
+    }
+}
+
 /// Client for communicating with the Glass server.
 #[derive(Clone)]
 pub struct ApiClient {
     base_url: String,
     client: Client,
+    token: Option<AuthToken>,
+    latency: Arc<LatencyTracker>,
 }
 
 impl ApiClient {
-    pub fn new(base_url: String) -> Self {
+    /// A client for the server at `base_url`, sending `token` with every
+    /// request (event streams included) if it needs one.
+    pub fn new(base_url: String, token: Option<AuthToken>) -> Self {
         Self {
             base_url,
             client: Client::new(),
+            token,
+            latency: Arc::default(),
+        }
+    }
+
+    /// Start a GET request, authorized if the server needs it.
+    fn get(&self, url: &str) -> RequestBuilder {
+        self.authorize(self.client.get(url))
+    }
+
+    /// Start a POST request, authorized if the server needs it.
+    fn post(&self, url: &str) -> RequestBuilder {
+        self.authorize(self.client.post(url))
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.token {
+            Some(AuthToken(header)) => request.header(AUTHORIZATION, header.clone()),
+            None => request,
         }
     }
 
+    /// Recent request times, `None` before the first response.
+    pub fn latency(&self) -> Option<LatencyStats> {
+        self.latency.stats()
+    }
+
     /// Get the events URL for SSE subscription.
     pub fn events_url(&self, id: &str) -> String {
         format!("{}/api/v1/issues/{}/events", self.base_url, id)
     }
 
-    /// Helper to make a GET request and parse JSON response with logging.
-    async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T> {
-        debug!(%url, "GET request");
-        let response = self.client.get(url).send().await?;
+    /// Build the SSE request for analysis events, resuming after
+    /// `last_event_id` if given.
+    pub fn events_request(&self, id: &str, last_event_id: Option<&str>) -> reqwest::RequestBuilder {
+        let request = self.get(&self.events_url(id));
+        match last_event_id {
+            Some(last_id) => request.header("Last-Event-ID", last_id),
+            None => request,
+        }
+    }
+
+    /// Get the implementation session's events URL for SSE subscription.
+    pub fn implementation_events_url(&self, id: &str) -> String {
+        format!("{}/api/v1/issues/{}/implementation/events", self.base_url, id)
+    }
+
+    /// Build the SSE request for implementation events, resuming after
+    /// `last_event_id` if given.
+    pub fn implementation_events_request(
+        &self,
+        id: &str,
+        last_event_id: Option<&str>,
+    ) -> reqwest::RequestBuilder {
+        let request = self.get(&self.implementation_events_url(id));
+        match last_event_id {
+            Some(last_id) => request.header("Last-Event-ID", last_id),
+            None => request,
+        }
+    }
+
+    /// Get the critique URL for an issue's proposal.
+    pub fn critique_url(&self, id: &str) -> String {
+        format!("{}/api/v1/issues/{}/critique", self.base_url, id)
+    }
+
+    /// Build the SSE request that starts a critic pass over the proposal.
+    pub fn critique_request(&self, id: &str) -> reqwest::RequestBuilder {
+        self.post(&self.critique_url(id))
+    }
+
+    /// Get the server-wide events URL for SSE subscription.
+    pub fn global_events_url(&self) -> String {
+        format!("{}/api/v1/events", self.base_url)
+    }
+
+    /// Build the SSE request for server-wide events.
+    pub fn global_events_request(&self) -> reqwest::RequestBuilder {
+        self.get(&self.global_events_url())
+    }
+
+    /// Read a response body, turning non-success statuses into [`ApiError`].
+    async fn read_body(response: reqwest::Response) -> Result<String> {
         let status = response.status();
+        let request_id = response
+            .headers()
+            .get("x-request-id")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
         let body = response.text().await?;
         debug!(%status, body_len = body.len(), "Response received");
 
         if !status.is_success() {
             error!(%status, %body, "Request failed");
-            anyhow::bail!("Request failed with status {}: {}", status, body);
+            return Err(ApiError::new(status.as_u16(), request_id, body).into());
         }
+        Ok(body)
+    }
 
-        serde_json::from_str(&body).with_context(|| {
-            error!(%body, "Failed to parse response");
-            format!("Failed to parse response from {}", url)
-        })
+    /// Helper to make a GET request and parse JSON response with logging.
+    async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T> {
+        debug!(%url, "GET request");
+        self.send_json(self.get(url), url, true).await
     }
 
     /// Helper to make a POST request and parse JSON response with logging.
     async fn post_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T> {
         debug!(%url, "POST request");
-        let response = self.client.post(url).send().await?;
-        let status = response.status();
-        let body = response.text().await?;
-        debug!(%status, body_len = body.len(), "Response received");
+        self.send_json(self.post(url), url, true).await
+    }
 
-        if !status.is_success() {
-            error!(%status, %body, "Request failed");
-            anyhow::bail!("Request failed with status {}: {}", status, body);
+    /// Helper to POST a JSON body and parse the JSON response.
+    async fn post_json_body<B: serde::Serialize, T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        body: &B,
+    ) -> Result<T> {
+        debug!(%url, "POST request");
+        self.send_json(self.post(url).json(body), url, true).await
+    }
+
+    /// Send a request and parse the JSON response, timing the round trip
+    /// if `timed` (error responses included, failed connections not).
+    async fn send_json<T: serde::de::DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+        url: &str,
+        timed: bool,
+    ) -> Result<T> {
+        let started = Instant::now();
+        let response = request.send().await?;
+        let body = Self::read_body(response).await;
+        if timed {
+            self.latency.record(started.elapsed());
         }
+        let body = body?;
 
         serde_json::from_str(&body).with_context(|| {
             error!(%body, "Failed to parse response");
@@ -75,7 +273,10 @@ impl ApiClient {
     /// Refresh issues from Sentry and return updated list.
     pub async fn refresh_issues(&self) -> Result<ListIssuesResponse> {
         let url = format!("{}/api/v1/issues/refresh", self.base_url);
-        self.post_json(&url).await
+        debug!(%url, "POST request");
+        // Left out of the latency figures: it waits on Sentry, which says
+        // nothing about how the server is doing
+        self.send_json(self.post(&url), &url, false).await
     }
 
     /// Get issue detail (returns cached data from DB).
@@ -84,34 +285,126 @@ impl ApiClient {
         self.get_json(&url).await
     }
 
+    /// Get issue detail as the server sends it, fields the TUI doesn't know
+    /// about included.
+    pub async fn get_issue_raw(&self, id: &str) -> Result<serde_json::Value> {
+        let url = format!("{}/api/v1/issues/{}", self.base_url, id);
+        self.get_json(&url).await
+    }
+
     /// Refresh a single issue from Sentry and return updated detail.
     pub async fn refresh_issue(&self, id: &str) -> Result<IssueDetail> {
         let url = format!("{}/api/v1/issues/{}/refresh", self.base_url, id);
         self.post_json(&url).await
     }
 
+    /// Add an issue from Sentry by URL or ID, even one the server's query
+    /// doesn't match, and return its detail.
+    pub async fn import_issue(&self, issue: &str) -> Result<IssueDetail> {
+        let url = format!("{}/api/v1/issues/import", self.base_url);
+        let body = ImportIssueRequest {
+            issue: issue.to_string(),
+        };
+        debug!(%url, "POST request");
+        // Waits on Sentry like a refresh, so it's left out of the latency
+        // figures too
+        self.send_json(self.post(&url).json(&body), &url, false).await
+    }
+
+    /// Create an issue that didn't come from Sentry and return its detail.
+    pub async fn create_issue(&self, request: &CreateIssueRequest) -> Result<IssueDetail> {
+        let url = format!("{}/api/v1/issues", self.base_url);
+        debug!(%url, "POST request");
+        self.send_json(self.post(&url).json(request), &url, true).await
+    }
+
     /// Get session info for an issue.
     pub async fn get_session(&self, id: &str) -> Result<SessionInfo> {
         let url = format!("{}/api/v1/issues/{}/session", self.base_url, id);
         self.get_json(&url).await
     }
 
-    /// Start analysis on an issue.
-    pub async fn analyze(&self, id: &str) -> Result<AnalyzeResponse> {
-        let url = format!("{}/api/v1/issues/{}/analyze", self.base_url, id);
+    /// Get the distribution of tag values across an issue's events.
+    pub async fn get_tag_stats(&self, id: &str) -> Result<TagStatsResponse> {
+        let url = format!("{}/api/v1/issues/{}/tags", self.base_url, id);
+        self.get_json(&url).await
+    }
+
+    /// Expand an issue's proposal into the files and tests it would touch.
+    pub async fn preview_plan(&self, id: &str) -> Result<PlanResponse> {
+        let url = format!("{}/api/v1/issues/{}/plan", self.base_url, id);
         self.post_json(&url).await
     }
 
-    /// Approve proposal.
-    pub async fn approve(&self, id: &str) -> Result<ApproveResponse> {
+    /// Get the optional features the server supports.
+    pub async fn capabilities(&self) -> Result<CapabilitiesResponse> {
+        let url = format!("{}/api/v1/capabilities", self.base_url);
+        self.get_json(&url).await
+    }
+
+    /// Get the analyses waiting for the server to start them.
+    pub async fn queue_status(&self) -> Result<QueueStatusResponse> {
+        let url = format!("{}/api/v1/queue", self.base_url);
+        self.get_json(&url).await
+    }
+
+    /// Get sampled events for an issue, for picking one to analyze.
+    pub async fn get_issue_events(&self, id: &str) -> Result<IssueEventsResponse> {
+        let url = format!("{}/api/v1/issues/{}/samples", self.base_url, id);
+        self.get_json(&url).await
+    }
+
+    /// Get a page of an issue's events, most recent first, starting after
+    /// `cursor` (or from the latest).
+    pub async fn list_occurrences(&self, id: &str, cursor: Option<&str>) -> Result<OccurrencesResponse> {
+        let url = format!("{}/api/v1/issues/{}/occurrences", self.base_url, id);
+        let mut request = self.get(&url);
+        if let Some(cursor) = cursor {
+            request = request.query(&[("cursor", cursor)]);
+        }
+        debug!(%url, ?cursor, "GET request");
+        self.send_json(request, &url, true).await
+    }
+
+    /// Get one event of an issue: what was thrown, the request, user,
+    /// context and breadcrumbs as they were for that occurrence.
+    pub async fn get_occurrence(&self, id: &str, event_id: &str) -> Result<IssueSource> {
+        let url = format!("{}/api/v1/issues/{}/occurrences/{}", self.base_url, id, event_id);
+        self.get_json(&url).await
+    }
+
+    /// Start analysis on an issue, optionally with guidance for the agent
+    /// and based on a particular event.
+    pub async fn analyze(
+        &self,
+        id: &str,
+        instructions: Option<&str>,
+        event_id: Option<&str>,
+    ) -> Result<AnalyzeResponse> {
+        let url = format!("{}/api/v1/issues/{}/analyze", self.base_url, id);
+        let body = AnalyzeRequest {
+            instructions: instructions.map(str::to_string),
+            event_id: event_id.map(str::to_string),
+        };
+        self.post_json_body(&url, &body).await
+    }
+
+    /// Approve proposal, optionally with guidance for the implementation.
+    pub async fn approve(&self, id: &str, instructions: Option<&str>) -> Result<ApproveResponse> {
         let url = format!("{}/api/v1/issues/{}/approve", self.base_url, id);
-        self.post_json(&url).await
+        let body = ApproveRequest {
+            instructions: instructions.map(str::to_string),
+        };
+        self.post_json_body(&url, &body).await
     }
 
-    /// Reject proposal.
-    pub async fn reject(&self, id: &str) -> Result<RejectResponse> {
+    /// Reject proposal, optionally saying why.
+    pub async fn reject(&self, id: &str, reason: Option<&str>) -> Result<RejectResponse> {
         let url = format!("{}/api/v1/issues/{}/reject", self.base_url, id);
-        self.post_json(&url).await
+        let body = RejectRequest {
+            reason: reason.map(str::to_string),
+        };
+        self.post_json_body(&url, &body).await
     }
 
     /// Complete review.
@@ -120,6 +413,16 @@ impl ApiClient {
         self.post_json(&url).await
     }
 
+    /// Merge duplicate issues into `id`, consolidating their analyses,
+    /// proposals and worktrees under it.
+    pub async fn merge(&self, id: &str, duplicate_ids: &[String]) -> Result<MergeResponse> {
+        let url = format!("{}/api/v1/issues/{}/merge", self.base_url, id);
+        let body = MergeRequest {
+            duplicate_ids: duplicate_ids.to_vec(),
+        };
+        self.post_json_body(&url, &body).await
+    }
+
     /// Retry after error.
     pub async fn retry(&self, id: &str) -> Result<RetryResponse> {
         let url = format!("{}/api/v1/issues/{}/retry", self.base_url, id);
@@ -1,7 +1,8 @@
 //! API types matching the server's REST contract.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
 
 // =============================================================================
 // List Issues
@@ -29,6 +30,37 @@ pub struct Issue {
     pub first_seen: String,
     pub last_seen: String,
     pub updated_at: String,
+    /// Culprit, environment and release of the latest event, from servers
+    /// that list them
+    pub culprit: Option<String>,
+    pub environment: Option<String>,
+    pub release: Option<String>,
+    /// Project the issue belongs to, set by the TUI when aggregating
+    /// several servers' lists
+    #[serde(skip)]
+    pub project: Option<String>,
+}
+
+/// A listed Sentry issue for tests, with `short_id` as its ID too and
+/// nothing else set.
+#[cfg(test)]
+pub fn test_issue(short_id: &str, status: &str) -> Issue {
+    Issue {
+        id: short_id.to_string(),
+        source_type: "sentry".to_string(),
+        title: String::new(),
+        short_id: short_id.to_string(),
+        status: status.to_string(),
+        event_count: 0,
+        user_count: 0,
+        first_seen: String::new(),
+        last_seen: String::new(),
+        updated_at: String::new(),
+        culprit: None,
+        environment: None,
+        release: None,
+        project: None,
+    }
 }
 
 // =============================================================================
@@ -45,6 +77,28 @@ pub struct IssueDetail {
     pub state: IssueState,
     pub created_at: String,
     pub updated_at: String,
+    /// Status changes, oldest first (absent from older servers)
+    #[serde(default)]
+    pub history: Vec<StatusChange>,
+    /// Past proposal rejections, oldest first
+    #[serde(default)]
+    pub rejections: Vec<Rejection>,
+}
+
+/// When an issue moved into a status.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusChange {
+    pub status: String,
+    pub at: String,
+}
+
+/// A rejected proposal and the reviewer's reason for it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rejection {
+    pub reason: Option<String>,
+    pub at: String,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -66,6 +120,200 @@ pub struct IssueSource {
     pub request: Option<RequestInfo>,
     pub user: Option<UserInfo>,
     pub contexts: Option<ContextInfo>,
+    /// Sentry's issue category ("error", "performance", "cron", ...)
+    #[serde(default)]
+    pub issue_category: Option<String>,
+    #[serde(default)]
+    pub level: Option<String>,
+    /// Formatted log message for message (non-exception) events
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub cron: Option<CronInfo>,
+    #[serde(default)]
+    pub transaction: Option<TransactionInfo>,
+    /// Session replay recorded around the event, if any
+    #[serde(default)]
+    pub replay: Option<ReplayInfo>,
+    /// What was reported, for issues created by hand
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Stack trace pasted into a manual issue, as text
+    #[serde(default)]
+    pub stacktrace: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayInfo {
+    pub id: String,
+    /// Link to the replay in Sentry
+    pub url: String,
+    /// When the recording started, for lining up breadcrumbs
+    pub started_at: Option<String>,
+}
+
+/// What kind of problem an issue is, which decides the detail sections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueCategory {
+    Error,
+    Message,
+    Cron,
+    Performance,
+}
+
+impl IssueSource {
+    /// This issue with the details of one of its events (what was thrown,
+    /// the request, user, context and breadcrumbs) in place of the event it
+    /// came with. Counts, title and first/last seen stay the issue's.
+    pub fn with_event(&self, event: &IssueSource) -> IssueSource {
+        IssueSource {
+            exceptions: event.exceptions.clone(),
+            breadcrumbs: event.breadcrumbs.clone(),
+            environment: event.environment.clone(),
+            release: event.release.clone(),
+            tags: event.tags.clone(),
+            request: event.request.clone(),
+            user: event.user.clone(),
+            contexts: event.contexts.clone(),
+            level: event.level.clone(),
+            message: event.message.clone(),
+            cron: event.cron.clone(),
+            transaction: event.transaction.clone(),
+            replay: event.replay.clone(),
+            ..self.clone()
+        }
+    }
+
+    /// Stack frames of all exceptions, in display order.
+    pub fn frames(&self) -> impl Iterator<Item = &StackFrame> {
+        self.exceptions
+            .iter()
+            .flatten()
+            .filter_map(|e| e.stacktrace.as_ref())
+            .flat_map(|s| &s.frames)
+    }
+
+    /// Runs of two or more library frames in a row within one stacktrace,
+    /// as ranges of indexes into `frames()`. Frames Sentry didn't classify
+    /// are library frames when `is_library` says so of their path.
+    pub fn library_runs(&self, is_library: impl Fn(&str) -> bool) -> Vec<Range<usize>> {
+        let mut runs = Vec::new();
+        let mut index = 0;
+        for stacktrace in self.exceptions.iter().flatten().filter_map(|e| e.stacktrace.as_ref()) {
+            let mut start = None;
+            for frame in &stacktrace.frames {
+                let library = match frame.in_app {
+                    Some(in_app) => !in_app,
+                    None => frame.filename.as_deref().is_some_and(&is_library),
+                };
+                match (library, start) {
+                    (true, None) => start = Some(index),
+                    (false, Some(s)) => {
+                        runs.push(s..index);
+                        start = None;
+                    }
+                    _ => {}
+                }
+                index += 1;
+            }
+            if let Some(s) = start {
+                runs.push(s..index);
+            }
+        }
+        runs.retain(|run| run.len() > 1);
+        runs
+    }
+
+    /// Breadcrumb categories, in the order they first appear.
+    pub fn breadcrumb_categories(&self) -> Vec<&str> {
+        let mut categories: Vec<&str> = Vec::new();
+        for crumb in self.breadcrumbs.iter().flatten() {
+            if !categories.contains(&crumb.category()) {
+                categories.push(crumb.category());
+            }
+        }
+        categories
+    }
+
+    /// The issue's category, from Sentry's own classification when present
+    /// and otherwise from which payload sections are filled in.
+    pub fn category(&self) -> IssueCategory {
+        match self.issue_category.as_deref() {
+            Some("cron") => return IssueCategory::Cron,
+            Some("performance") => return IssueCategory::Performance,
+            _ => {}
+        }
+
+        let has_exceptions = self.exceptions.as_ref().is_some_and(|e| !e.is_empty());
+        if has_exceptions {
+            IssueCategory::Error
+        } else if self.cron.is_some() {
+            IssueCategory::Cron
+        } else if self.transaction.is_some() {
+            IssueCategory::Performance
+        } else if self.message.is_some() {
+            IssueCategory::Message
+        } else {
+            IssueCategory::Error
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CronInfo {
+    pub monitor_slug: String,
+    /// Crontab expression or interval, e.g. "0 * * * *" or "every 5 minutes"
+    pub schedule: Option<String>,
+    pub timezone: Option<String>,
+    pub last_check_in: Option<String>,
+    /// Recent check-ins that were missed, timed out or failed
+    #[serde(default)]
+    pub missed_check_ins: Vec<CronCheckIn>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CronCheckIn {
+    pub expected_at: String,
+    /// "missed", "timeout" or "error"
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionInfo {
+    pub name: String,
+    pub op: Option<String>,
+    /// Start and end as Unix timestamps in seconds
+    pub start_timestamp: f64,
+    pub timestamp: f64,
+    #[serde(default)]
+    pub spans: Vec<SpanInfo>,
+}
+
+impl TransactionInfo {
+    pub fn duration_ms(&self) -> f64 {
+        (self.timestamp - self.start_timestamp) * 1000.0
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpanInfo {
+    pub span_id: String,
+    pub parent_span_id: Option<String>,
+    pub op: Option<String>,
+    pub description: Option<String>,
+    pub start_timestamp: f64,
+    pub timestamp: f64,
+}
+
+impl SpanInfo {
+    pub fn duration_ms(&self) -> f64 {
+        (self.timestamp - self.start_timestamp) * 1000.0
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -163,11 +411,69 @@ pub struct Stacktrace {
 pub struct StackFrame {
     pub filename: Option<String>,
     pub function: Option<String>,
+    /// The server passes Sentry's frames through as `lineNo`
+    #[serde(alias = "lineNo")]
     pub lineno: Option<u32>,
+    #[serde(alias = "colNo")]
     pub colno: Option<u32>,
     pub context: Option<Vec<ContextLine>>,
+    /// Whether Sentry counts the frame as the app's own code
+    #[serde(default)]
+    pub in_app: Option<bool>,
+    /// Minified location before sourcemap resolution (JS only)
+    #[serde(default)]
+    pub raw_function: Option<String>,
+    #[serde(default)]
+    pub raw_filename: Option<String>,
+    #[serde(default)]
+    pub raw_lineno: Option<u32>,
+    #[serde(default)]
+    pub raw_colno: Option<u32>,
+    /// Sourcemap processing info Sentry attaches to the frame
+    #[serde(default)]
+    pub data: Option<FrameData>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameData {
+    /// URL of the sourcemap that was applied
+    pub sourcemap: Option<String>,
+    /// Whether Sentry managed to resolve the frame
+    pub symbolicated: Option<bool>,
+}
+
+/// Whether a frame was translated from minified code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameResolution {
+    /// Not minified, or no sourcemap processing info
+    NotApplicable,
+    /// Resolved to original source via a sourcemap
+    Resolved,
+    /// A sourcemap should have applied but resolution failed
+    Failed,
+}
+
+impl StackFrame {
+    pub fn resolution(&self) -> FrameResolution {
+        let Some(data) = &self.data else {
+            return FrameResolution::NotApplicable;
+        };
+        match (data.symbolicated, &data.sourcemap) {
+            (Some(false), _) => FrameResolution::Failed,
+            (Some(true), _) | (None, Some(_)) => FrameResolution::Resolved,
+            (None, None) => FrameResolution::NotApplicable,
+        }
+    }
+
+    /// Whether a line of the frame's source context is the frame's own.
+    pub fn is_current(&self, context: &ContextLine) -> bool {
+        context.current || self.lineno == Some(context.line)
+    }
+}
+
+/// A line of source around a frame: `{line, code, current}`, or Sentry's
+/// `[line, code]` pair.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ContextLine {
@@ -188,6 +494,13 @@ pub struct Breadcrumb {
     pub data: Option<BreadcrumbData>,
 }
 
+impl Breadcrumb {
+    /// The crumb's category, or `?` when the SDK didn't give one.
+    pub fn category(&self) -> &str {
+        self.category.as_deref().unwrap_or("?")
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BreadcrumbData {
@@ -197,6 +510,39 @@ pub struct BreadcrumbData {
     #[serde(rename = "http.method")]
     pub http_method: Option<String>,
     pub reason: Option<String>,
+    /// Everything else the SDK attached
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+impl BreadcrumbData {
+    /// Every field as text, the well-known ones first. Objects and arrays
+    /// are pretty-printed over several lines.
+    pub fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = Vec::new();
+        let known = [
+            ("http.method", self.http_method.clone()),
+            ("url", self.url.clone()),
+            ("http.response.status_code", self.status_code.map(|s| s.to_string())),
+            ("reason", self.reason.clone()),
+        ];
+        for (key, value) in known {
+            if let Some(value) = value {
+                fields.push((key.to_string(), value));
+            }
+        }
+        for (key, value) in &self.other {
+            let text = match value {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
+                    serde_json::to_string_pretty(value).unwrap_or_default()
+                }
+                value => value.to_string(),
+            };
+            fields.push((key.clone(), text));
+        }
+        fields
+    }
 }
 
 // =============================================================================
@@ -229,6 +575,9 @@ pub enum IssueState {
         implementation_session_id: String,
         worktree_path: String,
         worktree_branch: String,
+        /// The approved proposal, from servers that keep it past approval
+        #[serde(default)]
+        proposal: Option<String>,
     },
     #[serde(rename_all = "camelCase")]
     Error {
@@ -238,6 +587,20 @@ pub enum IssueState {
     },
 }
 
+impl IssueState {
+    /// The status name the server uses for this state.
+    pub fn status(&self) -> &'static str {
+        match self {
+            IssueState::Pending => "pending",
+            IssueState::Analyzing { .. } => "analyzing",
+            IssueState::PendingApproval { .. } => "pending_approval",
+            IssueState::InProgress { .. } => "in_progress",
+            IssueState::PendingReview { .. } => "pending_review",
+            IssueState::Error { .. } => "error",
+        }
+    }
+}
+
 // =============================================================================
 // Session Info
 // =============================================================================
@@ -256,10 +619,202 @@ pub struct SessionRef {
     pub path: String,
 }
 
+// =============================================================================
+// Tag Stats
+// =============================================================================
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagStatsResponse {
+    pub tags: Vec<TagStats>,
+}
+
+/// How the values of one tag are distributed across an issue's events.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagStats {
+    pub key: String,
+    /// Events that carry this tag
+    pub total_values: u64,
+    /// Most common values, most frequent first
+    pub top_values: Vec<TagValueCount>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagValueCount {
+    pub value: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueEventsResponse {
+    /// Most recent first
+    pub events: Vec<IssueEvent>,
+}
+
+/// A page of an issue's events, from `GET /api/v1/issues/:id/occurrences`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OccurrencesResponse {
+    /// Most recent first
+    pub events: Vec<IssueEvent>,
+    /// Cursor for the next (older) page, absent on the last one
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+}
+
+/// One sampled occurrence of an issue.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueEvent {
+    pub id: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub environment: Option<String>,
+    #[serde(default)]
+    pub release: Option<String>,
+    /// Whether this is the event Sentry picked to represent the issue
+    #[serde(default)]
+    pub representative: bool,
+}
+
+/// Optional endpoints the server advertises, from `GET /api/v1/capabilities`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapabilitiesResponse {
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub capabilities: Vec<Capability>,
+}
+
+/// A feature beyond the core issue endpoints, which older servers lack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Capability {
+    /// Tag value distributions (`/issues/:id/tags`)
+    TagStats,
+    /// Sampled events to analyze from (`/issues/:id/samples`)
+    EventSamples,
+    /// Merging duplicate issues (`/issues/:id/merge`)
+    Merge,
+    /// Critic passes over proposals (`/issues/:id/critique`)
+    Critique,
+    /// File-level plan previews (`/issues/:id/plan`)
+    Plan,
+    /// Following implementation sessions (`/issues/:id/implementation/events`)
+    ImplementationEvents,
+    /// Analysis queue positions (`/queue`)
+    Queue,
+    /// Paging through every event of an issue (`/issues/:id/occurrences`)
+    EventBrowsing,
+    /// Writing issues by hand (`POST /issues`)
+    ManualIssues,
+    /// Reasons and notes sent with rejections and approvals
+    /// (`/issues/:id/reject` and `/approve` bodies)
+    ProposalNotes,
+    /// Something newer than this TUI
+    #[serde(other)]
+    Other,
+}
+
+/// Analyses the server has accepted but not started yet.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueStatusResponse {
+    /// Next to start first
+    #[serde(default)]
+    pub queued: Vec<QueuedAnalysis>,
+}
+
+/// Where one issue's analysis stands in the server's queue.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedAnalysis {
+    pub issue_id: String,
+    /// 1 for the next analysis to start
+    pub position: u32,
+    /// Rough seconds until it starts, if the server can tell
+    #[serde(default)]
+    pub eta_seconds: Option<u64>,
+}
+
+/// File-level plan for carrying out a proposal, worked out by the server
+/// without creating a worktree.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanResponse {
+    /// Files the implementation expects to touch
+    pub files: Vec<PlannedFile>,
+    /// Tests it expects to add or change
+    #[serde(default)]
+    pub tests: Vec<PlannedTest>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedFile {
+    pub path: String,
+    pub change: FileChange,
+    /// What changes in the file
+    #[serde(default)]
+    pub summary: Option<String>,
+}
+
+/// How a file changes, in a plan or a worktree diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChange {
+    Create,
+    Modify,
+    Delete,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedTest {
+    pub path: String,
+    /// What the test covers
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
 // =============================================================================
 // Action Responses
 // =============================================================================
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeRequest {
+    /// Issues to fold into the primary as linked duplicates
+    pub duplicate_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeResponse {
+    pub primary_id: String,
+    /// Duplicates now linked to the primary and hidden from the default list
+    pub merged_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzeRequest {
+    /// Extra guidance for the agent, added to its prompt
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// Sentry event to analyze instead of the issue's representative one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AnalyzeResponse {
@@ -269,6 +824,31 @@ pub struct AnalyzeResponse {
     pub session_path: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportIssueRequest {
+    /// Sentry issue URL, numeric ID or short ID
+    pub issue: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIssueRequest {
+    pub title: String,
+    pub description: String,
+    /// Stack trace as pasted, for bugs reported with one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stacktrace: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApproveRequest {
+    /// Guidance for the implementation session, e.g. "add a regression test"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApproveResponse {
@@ -279,6 +859,14 @@ pub struct ApproveResponse {
     pub implementation_session_path: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RejectRequest {
+    /// Why the proposal was rejected, passed on to the next analysis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RejectResponse {
@@ -311,7 +899,7 @@ pub struct RetryResponse {
 // =============================================================================
 
 /// Events streamed during analysis via SSE.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum AnalysisEvent {
     /// Backfill of all previous events (sent first on connect)
@@ -337,10 +925,95 @@ pub enum AnalysisEvent {
         #[serde(rename = "isError")]
         is_error: bool,
     },
-    /// Analysis complete with final proposal
+    /// Token usage for one model turn (accumulated by the client)
+    #[serde(rename_all = "camelCase")]
+    Usage {
+        input_tokens: u64,
+        output_tokens: u64,
+        /// Cost in USD, when the provider reports it
+        #[serde(default)]
+        cost: Option<f64>,
+    },
+    /// Session complete; for analyses, with the final proposal
+    #[serde(rename_all = "camelCase")]
+    Complete {
+        #[serde(default)]
+        proposal: String,
+    },
+    /// Error occurred
+    #[serde(rename_all = "camelCase")]
+    Error { message: String },
+}
+
+/// Events streamed by a critic pass (`POST /issues/:id/critique`), in which
+/// a second reviewer model comments on the proposal.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CritiqueEvent {
+    /// The reviewer the server picked, sent first
+    #[serde(rename_all = "camelCase")]
+    Started { reviewer: String },
+    /// Streaming critique text
     #[serde(rename_all = "camelCase")]
-    Complete { proposal: String },
+    TextDelta { delta: String },
+    /// Critique finished
+    Complete,
     /// Error occurred
     #[serde(rename_all = "camelCase")]
     Error { message: String },
+    /// Anything else the reviewer reports (thinking, tool use)
+    #[serde(other)]
+    Other,
+}
+
+// =============================================================================
+// SSE Server Events
+// =============================================================================
+
+/// Events on the server-wide stream (`GET /events`).
+///
+/// The SSE `event:` field carries the type; unknown types are ignored so
+/// older TUIs keep working against newer servers.
+#[derive(Debug, Clone)]
+pub enum ServerEvent {
+    IssueUpdated(IssueUpdatedEvent),
+}
+
+impl ServerEvent {
+    /// Parse an SSE message by event name. Returns `Ok(None)` for unknown events.
+    pub fn parse(event: &str, data: &str) -> serde_json::Result<Option<Self>> {
+        match event {
+            "issue_updated" => serde_json::from_str(data).map(|e| Some(Self::IssueUpdated(e))),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// An issue changed state on the server.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueUpdatedEvent {
+    pub id: String,
+    pub status: String,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+// =============================================================================
+// Error Responses
+// =============================================================================
+
+/// Error body returned by the server on non-success responses.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ErrorResponse {
+    pub error: ErrorBody,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorBody {
+    pub code: String,
+    pub message: String,
+    #[serde(default)]
+    pub request_id: Option<String>,
 }
@@ -0,0 +1,101 @@
+//! Scroll acceleration for held keys.
+//!
+//! Terminals only report key presses (repeats included), never releases,
+//! so a key counts as held while its repeats keep arriving within
+//! `REPEAT_WINDOW` of each other. The step grows with how long it's been
+//! held and halves for every window that passes without a repeat, going by
+//! when presses arrive rather than how often frames are drawn.
+
+use std::mem::Discriminant;
+use std::time::{Duration, Instant};
+
+use crate::screens::Action;
+
+/// Longest gap between repeats of a held key.
+pub const REPEAT_WINDOW: Duration = Duration::from_millis(150);
+/// Repeats at a single step before speeding up, so taps stay precise.
+const WARMUP: u32 = 6;
+/// Repeats per extra line of step after the warmup.
+const RAMP: u32 = 4;
+
+/// How far one press of a scroll key moves while it's held.
+#[derive(Debug, Default)]
+pub struct ScrollAccelerator {
+    /// The action and direction last accelerated, and when
+    last: Option<(Discriminant<Action>, i32, Instant)>,
+    /// Repeats counted towards the current speed
+    streak: u32,
+}
+
+impl ScrollAccelerator {
+    /// Lines to move for a press of the scroll key `action` in `direction`
+    /// (1 or -1) at `now`, at most `max`.
+    pub fn step(&mut self, action: Discriminant<Action>, direction: i32, now: Instant, max: i32) -> i32 {
+        self.streak = match self.last {
+            Some((last, last_direction, at)) if last == action && last_direction == direction => {
+                let windows = now.saturating_duration_since(at).as_millis() / REPEAT_WINDOW.as_millis();
+                match windows {
+                    0 => self.streak + 1,
+                    // Let go for a while: slow down rather than stop dead
+                    1..=31 => self.streak >> windows,
+                    _ => 0,
+                }
+            }
+            _ => 0,
+        };
+        self.last = Some((action, direction, now));
+
+        let extra = self.streak.saturating_sub(WARMUP) / RAMP;
+        direction * (1 + extra as i32).min(max.max(1))
+    }
+
+    /// Forget the held key, e.g. when something else was pressed.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.streak = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scroll() -> Discriminant<Action> {
+        std::mem::discriminant(&Action::ScrollDetail(1))
+    }
+
+    #[test]
+    fn test_holding_speeds_up_to_the_cap() {
+        let mut accel = ScrollAccelerator::default();
+        let start = Instant::now();
+        let repeat = Duration::from_millis(30);
+        let steps: Vec<i32> = (0..30)
+            .map(|i| accel.step(scroll(), 1, start + repeat * i, 4))
+            .collect();
+        assert!(steps[..=WARMUP as usize].iter().all(|&step| step == 1));
+        assert!(steps.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(steps.last(), Some(&4));
+    }
+
+    #[test]
+    fn test_pauses_and_other_keys_slow_it_down() {
+        let mut accel = ScrollAccelerator::default();
+        let start = Instant::now();
+        let repeat = Duration::from_millis(30);
+        for i in 0..30 {
+            accel.step(scroll(), 1, start + repeat * i, 10);
+        }
+        let held = start + repeat * 30;
+        // A short pause halves the speed rather than dropping it
+        let step = accel.step(scroll(), 1, held + REPEAT_WINDOW, 10);
+        assert!(step > 1 && step < 6);
+        // The other direction starts from scratch
+        assert_eq!(accel.step(scroll(), -1, held + REPEAT_WINDOW + repeat, 10), -1);
+
+        for i in 0..30 {
+            accel.step(scroll(), 1, held + repeat * (i + 10), 10);
+        }
+        accel.reset();
+        assert_eq!(accel.step(scroll(), 1, held + repeat * 41, 10), 1);
+    }
+}
@@ -0,0 +1,341 @@
+//! Analysis output kept as received, bounded in size, and wrapped on demand.
+
+use std::cell::{Ref, RefCell};
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use ratatui::text::Line;
+use tracing::warn;
+
+use super::state::{ActivityLine, ActivityStyle};
+use crate::util::word_wrap;
+
+/// Lines kept before the oldest are dropped.
+pub const DEFAULT_MAX_LINES: usize = 10_000;
+
+/// Activity lines as received, in a ring buffer capped at `max_lines`.
+///
+/// Dropped lines can be appended to a spill file so nothing is lost. A copy
+/// wrapped to the last rendered width is cached, so a resize reflows
+/// instead of keeping the old wrapping, and so are the styled lines last
+/// drawn, so code isn't highlighted again every frame.
+#[derive(Debug)]
+pub struct ActivityLog {
+    lines: VecDeque<ActivityLine>,
+    max_lines: usize,
+    /// Lines dropped from the front since the last clear
+    dropped: usize,
+    spill_path: Option<PathBuf>,
+    spill: Option<BufWriter<File>>,
+    /// Whether the text so far opened a code fence it hasn't closed
+    in_code: bool,
+    wrapped: RefCell<WrappedLines>,
+    styled: RefCell<Option<StyledLines>>,
+}
+
+#[derive(Debug)]
+struct StyledLines {
+    /// How the lines were shown (width, wrapping, theme)
+    key: u64,
+    /// Source lines covered, as `dropped + len`
+    end: usize,
+    lines: Vec<Line<'static>>,
+}
+
+#[derive(Debug, Default)]
+struct WrappedLines {
+    width: usize,
+    /// Source lines covered, as `dropped + index` positions: `first..end`
+    first: usize,
+    end: usize,
+    rows: VecDeque<ActivityLine>,
+    /// Wrapped rows per covered source line, to drop them with their source
+    row_counts: VecDeque<usize>,
+}
+
+impl Default for ActivityLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_LINES)
+    }
+}
+
+impl ActivityLog {
+    pub fn new(max_lines: usize) -> Self {
+        Self {
+            lines: VecDeque::new(),
+            max_lines: max_lines.max(1),
+            dropped: 0,
+            spill_path: None,
+            spill: None,
+            in_code: false,
+            wrapped: RefCell::default(),
+            styled: RefCell::default(),
+        }
+    }
+
+    /// Append lines dropped from now on to `path` (or stop spilling).
+    pub fn set_spill_path(&mut self, path: Option<PathBuf>) {
+        self.spill = None;
+        self.spill_path = path;
+    }
+
+    pub fn push(&mut self, line: ActivityLine) {
+        if self.lines.len() >= self.max_lines {
+            if let Some(oldest) = self.lines.pop_front() {
+                self.dropped += 1;
+                self.spill_line(&oldest);
+            }
+        }
+        self.lines.push_back(line);
+    }
+
+    /// Remove all lines. Stops spilling; the next analysis sets a new path.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.dropped = 0;
+        self.set_spill_path(None);
+        self.in_code = false;
+        *self.wrapped.get_mut() = WrappedLines::default();
+        *self.styled.get_mut() = None;
+    }
+
+    /// Whether text pushed so far left a code block open, so lines flushed
+    /// later are still code.
+    pub fn in_code_block(&self) -> bool {
+        self.in_code
+    }
+
+    pub fn set_in_code_block(&mut self, in_code: bool) {
+        self.in_code = in_code;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// How many older lines were dropped to stay under the cap.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Where dropped lines were written, once any were.
+    pub fn spill_path(&self) -> Option<&Path> {
+        self.spill.as_ref().and(self.spill_path.as_deref())
+    }
+
+    /// Lines as received, unwrapped.
+    pub fn iter(&self) -> impl Iterator<Item = &ActivityLine> {
+        self.lines.iter()
+    }
+
+    /// Lines word-wrapped so their text fits in `width` columns. Continuation
+    /// rows get a blank icon. Only lines added since the last call are
+    /// wrapped unless the width changed.
+    pub fn wrapped(&self, width: usize) -> Ref<'_, [ActivityLine]> {
+        let width = width.max(1);
+        {
+            let mut cache = self.wrapped.borrow_mut();
+            if cache.width != width || cache.first > self.dropped || cache.end < self.dropped {
+                *cache = WrappedLines {
+                    width,
+                    first: self.dropped,
+                    end: self.dropped,
+                    ..WrappedLines::default()
+                };
+            }
+
+            // Forget rows for lines that have since been dropped
+            while cache.first < self.dropped {
+                let count = cache.row_counts.pop_front().unwrap_or(0);
+                cache.rows.drain(..count);
+                cache.first += 1;
+            }
+
+            for line in self.lines.iter().skip(cache.end - self.dropped) {
+                // Word wrapping would collapse indentation; long code lines
+                // are left for the paragraph to wrap
+                let wrapped = if line.style == ActivityStyle::Code {
+                    vec![line.text.clone()]
+                } else {
+                    word_wrap(&line.text, width)
+                };
+                cache.row_counts.push_back(wrapped.len());
+                for (i, text) in wrapped.into_iter().enumerate() {
+                    cache.rows.push_back(ActivityLine {
+                        icon: if i == 0 { line.icon } else { "  " },
+                        text,
+                        style: line.style,
+                    });
+                }
+            }
+            cache.end = self.dropped + self.lines.len();
+            cache.rows.make_contiguous();
+        }
+        Ref::map(self.wrapped.borrow(), |cache| cache.rows.as_slices().0)
+    }
+
+    /// The lines as styled by `build` for display, built again only when
+    /// lines were added or dropped or `key` (how they're shown) changed.
+    pub fn styled(&self, key: u64, build: impl FnOnce() -> Vec<Line<'static>>) -> Vec<Line<'static>> {
+        let end = self.dropped + self.lines.len();
+        let mut styled = self.styled.borrow_mut();
+        match styled.as_ref() {
+            Some(cached) if cached.key == key && cached.end == end => {}
+            _ => {
+                *styled = Some(StyledLines {
+                    key,
+                    end,
+                    lines: build(),
+                })
+            }
+        }
+        styled.as_ref().map(|cached| cached.lines.clone()).unwrap_or_default()
+    }
+
+    fn spill_line(&mut self, line: &ActivityLine) {
+        let Some(path) = &self.spill_path else {
+            return;
+        };
+        if self.spill.is_none() {
+            let opened = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|()| File::options().create(true).append(true).open(path));
+            match opened {
+                Ok(file) => self.spill = Some(BufWriter::new(file)),
+                Err(e) => {
+                    warn!(path = %path.display(), error = %e, "Can't spill analysis output");
+                    self.spill_path = None;
+                    return;
+                }
+            }
+        }
+
+        if let Some(spill) = &mut self.spill {
+            if let Err(e) = writeln!(spill, "{} {}", line.icon, line.text) {
+                warn!(error = %e, "Failed to spill analysis output");
+                self.spill = None;
+                self.spill_path = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(icon: &'static str, text: &str) -> ActivityLine {
+        ActivityLine {
+            icon,
+            text: text.to_string(),
+            style: ActivityStyle::Normal,
+        }
+    }
+
+    fn texts(log: &ActivityLog, width: usize) -> Vec<String> {
+        log.wrapped(width).iter().map(|l| l.text.clone()).collect()
+    }
+
+    #[test]
+    fn test_rewraps_on_width_change() {
+        let mut log = ActivityLog::default();
+        log.push(line("🔧", "read path=src/handlers/user.ts"));
+
+        let narrow: Vec<_> = log.wrapped(12).iter().map(|l| (l.icon, l.text.clone())).collect();
+        assert_eq!(
+            narrow,
+            vec![("🔧", "read".to_string()), ("  ", "path=src/handlers/user.ts".to_string())]
+        );
+        assert_eq!(log.wrapped(80).len(), 1);
+
+        // Lines added later are wrapped incrementally at the cached width
+        log.push(line("  ", "one two three"));
+        assert_eq!(log.wrapped(80).len(), 2);
+        assert_eq!(log.wrapped(8).len(), 4);
+
+        log.clear();
+        assert!(log.wrapped(8).is_empty());
+    }
+
+    #[test]
+    fn test_code_keeps_indentation() {
+        let mut log = ActivityLog::default();
+        log.push(ActivityLine {
+            icon: "  ",
+            text: "    if (!user) return null;".to_string(),
+            style: ActivityStyle::Code,
+        });
+        assert_eq!(texts(&log, 8), vec!["    if (!user) return null;"]);
+    }
+
+    #[test]
+    fn test_drops_oldest_lines_past_the_cap() {
+        let mut log = ActivityLog::new(2);
+        log.push(line("  ", "one two"));
+        assert_eq!(texts(&log, 3), vec!["one", "two"]);
+
+        log.push(line("  ", "three"));
+        log.push(line("  ", "four"));
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.dropped(), 1);
+        // The cached rows for the dropped line go with it
+        assert_eq!(texts(&log, 3), vec!["three", "four"]);
+
+        log.push(line("  ", "five"));
+        log.push(line("  ", "six"));
+        assert_eq!(texts(&log, 3), vec!["five", "six"]);
+        assert_eq!(log.dropped(), 3);
+    }
+
+    #[test]
+    fn test_styles_again_only_on_change() {
+        let mut log = ActivityLog::default();
+        let builds = std::cell::Cell::new(0);
+        let build = || {
+            builds.set(builds.get() + 1);
+            vec![Line::from("styled")]
+        };
+        log.push(line("  ", "one"));
+        log.styled(1, build);
+        log.styled(1, build);
+        assert_eq!(builds.get(), 1);
+
+        log.styled(2, build);
+        log.push(line("  ", "two"));
+        log.styled(2, build);
+        assert_eq!(builds.get(), 3);
+
+        // Cleared and refilled to the same length is still new
+        log.clear();
+        log.push(line("  ", "three"));
+        log.push(line("  ", "four"));
+        log.styled(2, build);
+        assert_eq!(builds.get(), 4);
+    }
+
+    #[test]
+    fn test_spills_dropped_lines() {
+        let path = std::env::temp_dir().join(format!("glass-spill-{}.log", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut log = ActivityLog::new(1);
+        log.set_spill_path(Some(path.clone()));
+        assert_eq!(log.spill_path(), None);
+
+        log.push(line("🔧", "first"));
+        log.push(line("  ", "second"));
+        log.push(line("  ", "third"));
+        assert_eq!(log.spill_path(), Some(path.as_path()));
+
+        log.clear();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "🔧 first\n   second\n");
+        fs::remove_file(&path).unwrap();
+    }
+}
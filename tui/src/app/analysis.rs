@@ -1,38 +1,129 @@
 //! Analysis event handling - processes SSE events into display lines.
+//!
+//! Lines are stored unwrapped; the analysis screen wraps them to its width.
 
 use crate::api::{AnalysisEvent, IssueState};
-use crate::app::state::{ActivityLine, ActivityStyle, AppState, Screen};
-use crate::util::word_wrap;
+use std::time::Duration;
+
+use crate::app::state::{
+    ActivityLine, ActivityStyle, AnalysisProgress, AppState, RecordedEvent, Screen,
+};
+use crate::app::ActivityLog;
 
 /// Handle an analysis event from the SSE stream.
 pub fn handle_analysis_event(state: &mut AppState, event: AnalysisEvent) {
+    if !matches!(event, AnalysisEvent::Backfill { .. }) {
+        state.analysis_progress.event_count += 1;
+        let offset = state
+            .analysis_progress
+            .started_at
+            .map(|started| started.elapsed())
+            .unwrap_or_default();
+        state.analysis_events.push(RecordedEvent {
+            offset,
+            event: event.clone(),
+        });
+    }
+
     match event {
         AnalysisEvent::Backfill { events } => {
             for e in events {
                 handle_analysis_event(state, e);
             }
         }
-        AnalysisEvent::Thinking => {
+        AnalysisEvent::Complete { proposal } => {
+            flush_text_buffer(state);
+
+            state.is_streaming_analysis = false;
+            state.analysis_progress.finish();
+
+            let text = match state.analysis_progress.usage_summary() {
+                Some(usage) => format!("Analysis complete ({})", usage),
+                None => "Analysis complete".to_string(),
+            };
+            state.analysis_lines.push(ActivityLine {
+                icon: "✓",
+                text,
+                style: ActivityStyle::Success,
+            });
+
+            if let Some(id) = state.current_issue.as_ref().map(|i| i.id.clone()) {
+                let label = state.issue_label(&id);
+                state.toasts.success(format!("Analysis complete for {}", label));
+            }
+
+            // Update the issue state with the proposal
+            if let Some(ref mut issue) = state.current_issue {
+                if let IssueState::Analyzing { analysis_session_id } = &issue.state {
+                    issue.state = IssueState::PendingApproval {
+                        analysis_session_id: analysis_session_id.clone(),
+                        proposal,
+                    };
+                }
+            }
+
+            // Automatically transition to proposal screen
+            state.screen = Screen::Proposal;
+            state.proposal_scroll.reset();
+            if let Some(id) = state.open_issue_id().map(str::to_string) {
+                // A fresh proposal shouldn't reopen at the old one's offset
+                state.scroll_memory.remove(&(id, Screen::Proposal));
+            }
+        }
+        AnalysisEvent::Error { message } => {
+            flush_text_buffer(state);
+
             state.analysis_lines.push(ActivityLine {
+                icon: "✗",
+                text: message.clone(),
+                style: ActivityStyle::Error,
+            });
+
+            state.is_streaming_analysis = false;
+            state.analysis_progress.finish();
+
+            if let Some(id) = state.current_issue.as_ref().map(|i| i.id.clone()) {
+                let label = state.issue_label(&id);
+                state.toasts.error(format!("Analysis failed for {}: {}", label, message));
+            }
+        }
+        event => push_activity(
+            &mut state.analysis_lines,
+            &mut state.current_text_buffer,
+            &mut state.analysis_progress,
+            event,
+        ),
+    }
+}
+
+/// Add the lines for an agent event that doesn't end the session. Shared by
+/// the analysis and implementation panes; `Backfill`, `Complete` and
+/// `Error` are left to the caller.
+pub fn push_activity(
+    lines: &mut ActivityLog,
+    text_buffer: &mut String,
+    progress: &mut AnalysisProgress,
+    event: AnalysisEvent,
+) {
+    match event {
+        AnalysisEvent::Thinking => {
+            lines.push(ActivityLine {
                 icon: "◐",
                 text: "Thinking...".to_string(),
                 style: ActivityStyle::Thinking,
             });
         }
         AnalysisEvent::TextDelta { delta } => {
-            state.current_text_buffer.push_str(&delta);
+            text_buffer.push_str(&delta);
 
             // Flush periodically for real-time feel
-            if state.current_text_buffer.contains('\n')
-                || state.current_text_buffer.len() > 200
-            {
-                flush_text_buffer(state);
+            if text_buffer.contains('\n') || text_buffer.len() > 200 {
+                flush_text(lines, text_buffer);
             }
         }
         AnalysisEvent::ToolStart { tool, args } => {
-            flush_text_buffer(state);
-
-            let wrap_width = (state.terminal_width as usize).saturating_sub(6).max(40);
+            flush_text(lines, text_buffer);
+            progress.tool_calls += 1;
 
             let args_str = if let Some(obj) = args.as_object() {
                 obj.iter()
@@ -49,107 +140,124 @@ pub fn handle_analysis_event(state: &mut AppState, event: AnalysisEvent) {
                 String::new()
             };
 
-            let full_text = format!("{} {}", tool, args_str);
-
-            let wrapped = word_wrap(&full_text, wrap_width);
-            for (i, line) in wrapped.into_iter().enumerate() {
-                state.analysis_lines.push(ActivityLine {
-                    icon: if i == 0 { "🔧" } else { "  " },
-                    text: line,
-                    style: ActivityStyle::Tool,
-                });
-            }
+            lines.push(ActivityLine {
+                icon: "🔧",
+                text: format!("{} {}", tool, args_str),
+                style: ActivityStyle::Tool,
+            });
         }
         AnalysisEvent::ToolOutput { output } => {
-            let wrap_width = (state.terminal_width as usize).saturating_sub(6).max(40);
-            if !output.trim().is_empty() {
-                for line in output.lines().take(5) {
-                    let trimmed = line.trim();
-                    if trimmed.is_empty() {
-                        continue;
-                    }
-                    for wrapped in word_wrap(trimmed, wrap_width) {
-                        state.analysis_lines.push(ActivityLine {
-                            icon: "  ",
-                            text: wrapped,
-                            style: ActivityStyle::Dimmed,
-                        });
-                    }
+            for line in output.lines().take(5) {
+                let trimmed = line.trim();
+                if trimmed.is_empty() {
+                    continue;
                 }
+                lines.push(ActivityLine {
+                    icon: "  ",
+                    text: trimmed.to_string(),
+                    style: ActivityStyle::Dimmed,
+                });
             }
         }
         AnalysisEvent::ToolEnd { tool: _, is_error } => {
             if is_error {
-                state.analysis_lines.push(ActivityLine {
+                lines.push(ActivityLine {
                     icon: "  ",
                     text: "(error)".to_string(),
                     style: ActivityStyle::Error,
                 });
             }
         }
-        AnalysisEvent::Complete { proposal } => {
-            flush_text_buffer(state);
-
-            state.analysis_lines.push(ActivityLine {
-                icon: "✓",
-                text: "Analysis complete".to_string(),
-                style: ActivityStyle::Success,
-            });
-
-            state.is_streaming_analysis = false;
-
-            // Update the issue state with the proposal
-            if let Some(ref mut issue) = state.current_issue {
-                if let IssueState::Analyzing { analysis_session_id } = &issue.state {
-                    issue.state = IssueState::PendingApproval {
-                        analysis_session_id: analysis_session_id.clone(),
-                        proposal,
-                    };
-                }
-            }
-
-            // Automatically transition to proposal screen
-            state.screen = Screen::Proposal;
-            state.proposal_scroll = 0;
-        }
-        AnalysisEvent::Error { message } => {
-            flush_text_buffer(state);
-
-            state.analysis_lines.push(ActivityLine {
-                icon: "✗",
-                text: message,
-                style: ActivityStyle::Error,
-            });
-
-            state.is_streaming_analysis = false;
+        AnalysisEvent::Usage { input_tokens, output_tokens, cost } => {
+            progress.add_usage(input_tokens, output_tokens, cost);
         }
+        AnalysisEvent::Backfill { .. }
+        | AnalysisEvent::Complete { .. }
+        | AnalysisEvent::Error { .. } => {}
     }
 }
 
 /// Flush accumulated text buffer to analysis lines.
 pub fn flush_text_buffer(state: &mut AppState) {
-    if state.current_text_buffer.is_empty() {
+    flush_text(&mut state.analysis_lines, &mut state.current_text_buffer);
+}
+
+/// Move buffered text into activity lines.
+pub fn flush_text(lines: &mut ActivityLog, text_buffer: &mut String) {
+    if text_buffer.is_empty() {
         return;
     }
 
-    let text = state.current_text_buffer.trim();
-    if !text.is_empty() {
-        let wrap_width = (state.terminal_width as usize).saturating_sub(6).max(40);
+    // A code block can span several flushes
+    let mut in_code = lines.in_code_block();
+    for line in text_buffer.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+        let (text, style) = if in_code || is_fence {
+            // Keep indentation inside code blocks
+            (line.trim_end(), ActivityStyle::Code)
+        } else {
+            (line.trim(), ActivityStyle::Normal)
+        };
+        if is_fence {
+            in_code = !in_code;
+        }
+        if text.is_empty() {
+            continue;
+        }
+        lines.push(ActivityLine {
+            icon: "  ",
+            text: text.to_string(),
+            style,
+        });
+    }
+    lines.set_in_code_block(in_code);
+    text_buffer.clear();
+}
 
-        for line in text.lines() {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
+/// The lines each recorded event added to the activity pane, with the
+/// event's offset, by replaying the events into a scratch state.
+pub fn recorded_lines<'a>(events: impl Iterator<Item = &'a RecordedEvent>) -> Vec<(Duration, ActivityLine)> {
+    // The scratch pane only needs to hold one event's lines at a time
+    let mut scratch = AppState::default();
+    let pushed = |log: &ActivityLog| log.dropped() + log.len();
+    let mut lines = Vec::new();
+    for recorded in events {
+        let before = pushed(&scratch.analysis_lines);
+        handle_analysis_event(&mut scratch, recorded.event.clone());
+        let added = pushed(&scratch.analysis_lines) - before;
+        let log = &scratch.analysis_lines;
+        lines.extend(
+            log.iter()
+                .skip(log.len().saturating_sub(added))
+                .map(|line| (recorded.offset, line.clone())),
+        );
+        scratch.analysis_events.clear();
+    }
+    lines
+}
 
-            for wrapped in word_wrap(trimmed, wrap_width) {
-                state.analysis_lines.push(ActivityLine {
-                    icon: "  ",
-                    text: wrapped,
-                    style: ActivityStyle::Normal,
-                });
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_block_spans_flushes() {
+        let mut lines = ActivityLog::default();
+        let mut buffer = String::new();
+        for chunk in ["Try this:\n", "```rust\n", "    let x = 1;\n", "```\n", "  Done.\n"] {
+            buffer.push_str(chunk);
+            flush_text(&mut lines, &mut buffer);
         }
+        let styled: Vec<(&str, ActivityStyle)> = lines.iter().map(|l| (l.text.as_str(), l.style)).collect();
+        assert_eq!(
+            styled,
+            vec![
+                ("Try this:", ActivityStyle::Normal),
+                ("```rust", ActivityStyle::Code),
+                ("    let x = 1;", ActivityStyle::Code),
+                ("```", ActivityStyle::Code),
+                ("Done.", ActivityStyle::Normal),
+            ]
+        );
     }
-    state.current_text_buffer.clear();
 }
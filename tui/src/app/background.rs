@@ -1,154 +1,1816 @@
 //! Background task management - spawning async tasks and receiving results.
 
+use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Command;
+use tokio::sync::{mpsc, oneshot};
+use futures_util::future::join_all;
 use futures_util::StreamExt;
-use reqwest_eventsource::{Event, EventSource};
+use reqwest_eventsource::{retry::Never, Error as EventSourceError, Event, EventSource};
 use tracing::{debug, error, info, warn};
 
-use crate::api::{AnalysisEvent, ApiClient, IssueDetail, ListIssuesResponse};
+use crate::api::{
+    AnalysisEvent, ApiClient, ApiError, AuthToken, Capability, CreateIssueRequest, CritiqueEvent, Issue, IssueDetail, IssueEvent, IssueSource, ListIssuesResponse,
+    OccurrencesResponse, PlanResponse, QueueStatusResponse, ServerEvent, TagStats,
+};
+use crate::forge;
+use crate::git::{self, AtRisk, BlameInfo, BranchLog, FileDiff, Worktree};
+use crate::server::{ServerLaunch, ServerProcess, StartFailure};
+use super::state::ServerCapabilities;
 
 /// Messages from background tasks.
 pub enum BackgroundMessage {
+    /// Glass started its own server, which is coming up
+    ServerSpawned,
+    /// The server answered its health check (or never did), with the
+    /// process to keep alive if glass started it
+    ServerReady(Result<Option<ServerProcess>, StartFailure>),
+    /// The server stopped answering health checks mid-session
+    ServerLost(String),
+    /// A restart of the lost server came up (or didn't), with the process
+    /// to keep alive if glass started it
+    ServerRestarted(Result<Option<ServerProcess>, StartFailure>),
+    /// The cached issue list loaded at startup
+    CachedListLoaded(Result<ListIssuesResponse, String>),
     /// List refresh completed with result
     ListRefreshComplete(Result<ListIssuesResponse, String>),
+    /// One project's server couldn't be reached while aggregating the list
+    ProjectUnreachable { project: String, error: String },
     /// Detail refresh completed with result
-    DetailRefreshComplete(Result<IssueDetail, String>),
-    /// Analysis event received from SSE
-    AnalysisEvent(AnalysisEvent),
+    DetailRefreshComplete(Result<Box<IssueDetail>, String>),
+    /// An issue added by URL or ID was fetched from Sentry
+    IssueImported(Result<Box<IssueDetail>, String>),
+    /// An issue created by hand was saved
+    IssueCreated(Result<Box<IssueDetail>, String>),
+    /// Analysis events received from SSE, several at once when they arrive
+    /// faster than they're handled
+    AnalysisEvents(Vec<AnalysisEvent>),
+    /// Analysis SSE stream dropped and will reconnect after a backoff
+    AnalysisReconnecting { attempt: u32, error: String },
     /// Analysis SSE stream ended (connected or error)
     AnalysisStreamEnded(Option<String>),
+    /// Events received from an implementation session's SSE stream,
+    /// batched the same way
+    ImplementationEvents {
+        issue_id: String,
+        events: Vec<AnalysisEvent>,
+    },
+    /// Implementation SSE stream dropped and will reconnect after a backoff
+    ImplementationReconnecting {
+        issue_id: String,
+        attempt: u32,
+        error: String,
+    },
+    /// Implementation SSE stream ended, with an error if it ended early
+    ImplementationStreamEnded {
+        issue_id: String,
+        error: Option<String>,
+    },
+    /// Event received from the server-wide SSE stream
+    ServerEvent(ServerEvent),
+    /// Server-wide SSE stream stopped for good (e.g. unsupported by server)
+    ServerStreamEnded(String),
+    /// The selection has rested on an issue long enough to preview it
+    PreviewDue { issue_id: String },
+    /// Detail for the peek popup or preview pane loaded
+    PeekLoaded {
+        issue_id: String,
+        result: Result<Box<IssueDetail>, String>,
+    },
+    /// Issue JSON for the raw JSON viewer loaded
+    RawIssueLoaded {
+        issue_id: String,
+        result: Result<serde_json::Value, String>,
+    },
+    /// Tag value distribution for the tag breakdown loaded
+    TagStatsLoaded {
+        issue_id: String,
+        result: Result<Vec<TagStats>, String>,
+    },
+    /// Time to check the watched issues again
+    WatchCheckDue,
+    /// A watched issue's event count fetched from Sentry
+    WatchChecked {
+        issue_id: String,
+        result: Result<u64, String>,
+    },
+    /// Capability discovery finished
+    CapabilitiesLoaded(ServerCapabilities),
+    /// Server analysis queue loaded
+    QueueStatusLoaded(Result<QueueStatusResponse, String>),
+    /// Sampled events for the events browser loaded
+    IssueEventsLoaded {
+        issue_id: String,
+        result: Result<Vec<IssueEvent>, String>,
+    },
+    /// A page of the issue's events for stepping through them loaded
+    OccurrencesLoaded {
+        issue_id: String,
+        result: Result<OccurrencesResponse, String>,
+    },
+    /// One event's details loaded
+    OccurrenceLoaded {
+        issue_id: String,
+        event_id: String,
+        result: Result<Box<IssueSource>, String>,
+    },
+    /// File-level plan for the proposal loaded
+    PlanLoaded {
+        issue_id: String,
+        result: Result<PlanResponse, String>,
+    },
+    /// Event received from a critic pass over the proposal
+    CritiqueEvent { issue_id: String, event: CritiqueEvent },
+    /// Critic pass stream closed, with an error if it ended early
+    CritiqueEnded {
+        issue_id: String,
+        error: Option<String>,
+    },
+    /// Git blame lookup for a stack frame finished
+    BlameComplete {
+        issue_id: String,
+        frame: usize,
+        result: Result<BlameInfo, String>,
+    },
+    /// Diff of a fix's worktree loaded
+    ReviewDiffLoaded {
+        issue_id: String,
+        result: Result<Vec<FileDiff>, String>,
+    },
+    /// Commits on a fix's branch, and work its worktree would lose, loaded
+    /// for the completion dialog
+    BranchLogLoaded {
+        issue_id: String,
+        result: Result<BranchLog, String>,
+        at_risk: Result<AtRisk, String>,
+    },
+    /// Pushing a fix's branch and opening its pull request finished, with
+    /// the pull request's URL
+    PullRequestOpened {
+        issue_id: String,
+        result: Result<String, String>,
+    },
+    /// The project's worktrees were listed
+    WorktreesLoaded(Result<Vec<Worktree>, String>),
+    /// Removing or pruning worktrees finished, with what was done
+    WorktreesCleaned(Result<String, String>),
+    /// A line of output from a worktree test run
+    TestOutput { issue_id: String, line: String },
+    /// A worktree test run exited with this code (`None` if killed by a
+    /// signal), or couldn't be started
+    TestsFinished {
+        issue_id: String,
+        result: Result<Option<i32>, String>,
+    },
+}
+
+/// Capacity of the channel from background tasks. Streams send events in
+/// batches, so it only fills when the UI falls well behind; senders then
+/// wait for room.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Most events a stream forwards in one message.
+const MAX_BATCH: usize = 256;
+
+/// Tries at the cached issue list at startup, for requests that race a
+/// server still settling in.
+const INITIAL_FETCH_ATTEMPTS: u32 = 3;
+const INITIAL_FETCH_RETRY: Duration = Duration::from_millis(500);
+/// Lines of output kept from a server that failed to start.
+pub(super) const FAILURE_OUTPUT_LINES: usize = 8;
+/// How often a running server's health is checked.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// Health checks missed in a row before the server counts as lost, so one
+/// slow answer doesn't restart it.
+const MISSED_HEALTH_CHECKS: u32 = 2;
+
+/// How long the selection rests on an issue before the preview pane loads it.
+const PREVIEW_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// How backed up the channel from background tasks has been.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChannelStats {
+    /// Messages waiting to be handled
+    pub queued: usize,
+    /// Most messages seen waiting at once
+    pub peak: usize,
+    /// Sends from event streams that found the channel full and waited
+    pub stalls: usize,
+}
+
+impl BackgroundMessage {
+    /// Roughly how much work handling the message is, in events.
+    fn weight(&self) -> usize {
+        match self {
+            BackgroundMessage::AnalysisEvents(events)
+            | BackgroundMessage::ImplementationEvents { events, .. } => events.len().max(1),
+            _ => 1,
+        }
+    }
+}
+
+/// A Glass server and the project it serves.
+#[derive(Clone)]
+struct ProjectServer {
+    name: String,
+    client: Arc<ApiClient>,
 }
 
 /// Manages background task communication.
 pub struct BackgroundTasks {
-    /// API client for server communication
-    client: Arc<ApiClient>,
+    /// Servers whose issues are listed, the primary one first; the others
+    /// are only present in aggregated mode
+    servers: Vec<ProjectServer>,
+    /// Index into `servers` of each aggregated issue's owner (absent: the
+    /// primary)
+    owners: HashMap<String, usize>,
+    /// Index into `servers` of the project the list is narrowed to, which
+    /// new issues go to and refreshes ask Sentry for
+    scoped: Option<usize>,
     /// Channel receiver for background task results
     rx: mpsc::Receiver<BackgroundMessage>,
     /// Channel sender for background tasks (cloned into spawned tasks)
     tx: mpsc::Sender<BackgroundMessage>,
+    /// Most messages seen queued, for `ChannelStats`
+    peak: usize,
+    /// Shared with event streams, for `ChannelStats`
+    stalls: Arc<AtomicUsize>,
+    /// Stops the running test command, if there is one
+    cancel_tests: Option<oneshot::Sender<()>>,
 }
 
 impl BackgroundTasks {
-    pub fn new(server_url: String) -> Self {
-        let (tx, rx) = mpsc::channel(64);
+    pub fn new(server_url: String, token: Option<AuthToken>) -> Self {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
         Self {
-            client: Arc::new(ApiClient::new(server_url)),
+            servers: vec![ProjectServer {
+                name: String::new(),
+                client: Arc::new(ApiClient::new(server_url, token)),
+            }],
+            owners: HashMap::new(),
+            scoped: None,
             rx,
             tx,
+            peak: 0,
+            stalls: Arc::default(),
+            cancel_tests: None,
         }
     }
 
-    /// Get a reference to the API client.
+    /// Aggregate the issues of another project's server into the list,
+    /// naming the primary project `primary`.
+    pub fn add_server(&mut self, primary: &str, name: String, url: String, token: Option<AuthToken>) {
+        self.servers[0].name = primary.to_string();
+        self.servers.push(ProjectServer {
+            name,
+            client: Arc::new(ApiClient::new(url, token)),
+        });
+    }
+
+    /// Names of the projects whose issues are listed, the primary first;
+    /// empty unless aggregating.
+    pub fn projects(&self) -> Vec<String> {
+        if self.servers.len() == 1 {
+            return Vec::new();
+        }
+        self.servers.iter().map(|s| s.name.clone()).collect()
+    }
+
+    /// Send new issues and refreshes to `project`'s server only, or with
+    /// `None` to every server (new issues to the primary).
+    pub fn scope(&mut self, project: Option<&str>) {
+        self.scoped = project.and_then(|name| self.servers.iter().position(|s| s.name == name));
+    }
+
+    /// The API client of the project the list is narrowed to, or the
+    /// primary server's.
+    fn scoped_client(&self) -> Arc<ApiClient> {
+        Arc::clone(&self.servers[self.scoped.unwrap_or(0)].client)
+    }
+
+    /// Get a reference to the primary server's API client.
     pub fn client(&self) -> &ApiClient {
-        &self.client
+        &self.servers[0].client
+    }
+
+    /// The API client of the server an issue came from.
+    pub fn client_for(&self, issue_id: &str) -> Arc<ApiClient> {
+        let index = self.owners.get(issue_id).copied().unwrap_or(0);
+        Arc::clone(&self.servers[index].client)
+    }
+
+    /// Whether two issues came from the same server.
+    pub fn same_server(&self, a: &str, b: &str) -> bool {
+        self.owners.get(a) == self.owners.get(b)
+    }
+
+    /// Remember which server owns each issue of an aggregated list, so
+    /// actions go back to it.
+    pub fn route(&mut self, issues: &[Issue]) {
+        if self.servers.len() == 1 {
+            return;
+        }
+        self.owners = issues
+            .iter()
+            .filter_map(|issue| {
+                let project = issue.project.as_deref()?;
+                let index = self.servers.iter().position(|s| s.name == project)?;
+                (index > 0).then(|| (issue.id.clone(), index))
+            })
+            .collect();
+    }
+
+    /// Wait for the next background task message.
+    ///
+    /// Never returns `None` in practice since we hold a sender ourselves.
+    pub async fn recv(&mut self) -> Option<BackgroundMessage> {
+        self.rx.recv().await
     }
 
-    /// Poll for background task completions.
-    /// Returns an iterator of all pending messages.
-    pub fn poll(&mut self) -> Vec<BackgroundMessage> {
+    /// Poll for background task completions, taking messages until they
+    /// carry `budget` events between them (other messages count as one).
+    /// Anything left stays queued for the next call.
+    pub fn poll(&mut self, budget: usize) -> Vec<BackgroundMessage> {
+        self.peak = self.peak.max(self.rx.len());
         let mut messages = Vec::new();
-        while let Ok(msg) = self.rx.try_recv() {
+        let mut spent = 0;
+        while spent < budget {
+            let Ok(msg) = self.rx.try_recv() else {
+                break;
+            };
+            spent += msg.weight();
             messages.push(msg);
         }
         messages
     }
 
-    /// Spawn a background task to refresh the issue list from Sentry.
-    pub fn spawn_list_refresh(&self) {
-        let client = Arc::clone(&self.client);
+    pub fn channel_stats(&self) -> ChannelStats {
+        ChannelStats {
+            queued: self.rx.len(),
+            peak: self.peak.max(self.rx.len()),
+            stalls: self.stalls.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Spawn `task`, supervised: if it panics, the message `failed` builds
+    /// from the panic is sent in place of its result, so whatever was
+    /// waiting on it (a spinner, a loading popup) hears back.
+    fn supervise<F>(
+        &self,
+        what: &'static str,
+        failed: impl FnOnce(String) -> BackgroundMessage + Send + 'static,
+        task: F,
+    ) where
+        F: Future<Output = ()> + Send + 'static,
+    {
         let tx = self.tx.clone();
+        let handle = tokio::spawn(task);
 
         tokio::spawn(async move {
-            let result = client
-                .refresh_issues()
-                .await
-                .map_err(|e| format!("Failed to refresh issues: {}", e));
-
-            let _ = tx.send(BackgroundMessage::ListRefreshComplete(result)).await;
+            let Err(e) = handle.await else {
+                return;
+            };
+            if !e.is_panic() {
+                // Cancelled by the runtime shutting down
+                return;
+            }
+            let error = format!("{} crashed: {}", what, panic_message(e.into_panic()));
+            error!(%error, "Background task panicked");
+            let _ = tx.send(failed(error)).await;
         });
     }
 
+    /// Spawn a background task to refresh the issue list from Sentry.
+    pub fn spawn_list_refresh(&self) {
+        self.spawn_lists(true);
+    }
+
     /// Spawn a background task to refresh issue detail from Sentry.
     pub fn spawn_detail_refresh(&self, issue_id: String) {
-        let client = Arc::clone(&self.client);
+        let client = self.client_for(&issue_id);
         let tx = self.tx.clone();
 
-        tokio::spawn(async move {
-            let result = client
-                .refresh_issue(&issue_id)
-                .await
-                .map_err(|e| format!("Failed to refresh issue: {}", e));
+        self.supervise(
+            "Issue refresh",
+            |error| BackgroundMessage::DetailRefreshComplete(Err(error)),
+            async move {
+                let result = client
+                    .refresh_issue(&issue_id)
+                    .await
+                    .map(Box::new)
+                    .map_err(|e| format!("Failed to refresh issue: {}", e));
 
-            let _ = tx.send(BackgroundMessage::DetailRefreshComplete(result)).await;
-        });
+                let _ = tx.send(BackgroundMessage::DetailRefreshComplete(result)).await;
+            },
+        );
     }
 
-    /// Start the SSE stream for analysis events.
-    pub fn spawn_analysis_stream(&self, issue_id: &str) {
-        let url = self.client.events_url(issue_id);
+    /// Spawn a background task to add an issue by Sentry URL or ID on the
+    /// primary server.
+    pub fn spawn_issue_import(&self, reference: String) {
+        let client = self.scoped_client();
         let tx = self.tx.clone();
 
-        info!(%url, "Starting SSE stream for analysis events");
+        self.supervise(
+            "Issue import",
+            |error| BackgroundMessage::IssueImported(Err(error)),
+            async move {
+                let result = client
+                    .import_issue(&reference)
+                    .await
+                    .map(Box::new)
+                    .map_err(|e| format!("Failed to add issue: {}", e));
 
-        tokio::spawn(async move {
-            let mut es = EventSource::get(&url);
+                let _ = tx.send(BackgroundMessage::IssueImported(result)).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to create an issue that didn't come from
+    /// Sentry.
+    pub fn spawn_issue_create(&self, request: CreateIssueRequest) {
+        let client = self.scoped_client();
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Issue creation",
+            |error| BackgroundMessage::IssueCreated(Err(error)),
+            async move {
+                let result = client
+                    .create_issue(&request)
+                    .await
+                    .map(Box::new)
+                    .map_err(|e| format!("Failed to create issue: {}", e));
+
+                let _ = tx.send(BackgroundMessage::IssueCreated(result)).await;
+            },
+        );
+    }
+
+    /// Say when the preview pane may load `issue_id`, after a pause so
+    /// scrolling through the list doesn't fetch every issue passed.
+    pub fn spawn_preview_delay(&self, issue_id: String) {
+        let tx = self.tx.clone();
+
+        // A timer that dies still lets the preview load, just without the pause
+        let failed = issue_id.clone();
+        self.supervise(
+            "Preview delay",
+            move |_| BackgroundMessage::PreviewDue { issue_id: failed },
+            async move {
+                tokio::time::sleep(PREVIEW_DEBOUNCE).await;
+                let _ = tx.send(BackgroundMessage::PreviewDue { issue_id }).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to load cached issue detail for the peek popup.
+    pub fn spawn_peek(&self, issue_id: String) {
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Peek",
+            move |error| BackgroundMessage::PeekLoaded { issue_id: failed, result: Err(error) },
+            async move {
+                let result = client
+                    .get_issue(&issue_id)
+                    .await
+                    .map(Box::new)
+                    .map_err(|e| format!("Failed to fetch issue: {}", e));
+
+                let _ = tx.send(BackgroundMessage::PeekLoaded { issue_id, result }).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to load an issue's JSON as the server sends it.
+    pub fn spawn_raw_issue(&self, issue_id: String) {
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Raw issue",
+            move |error| BackgroundMessage::RawIssueLoaded { issue_id: failed, result: Err(error) },
+            async move {
+                let result = client
+                    .get_issue_raw(&issue_id)
+                    .await
+                    .map_err(|e| format!("Failed to fetch issue: {}", e));
+
+                let _ = tx.send(BackgroundMessage::RawIssueLoaded { issue_id, result }).await;
+            },
+        );
+    }
 
-            while let Some(event) = es.next().await {
-                match event {
-                    Ok(Event::Open) => {
-                        info!("SSE connection opened");
+    /// Say when the watched issues are due a check, after `delay`, so an
+    /// idle TUI needn't tick to notice.
+    pub fn spawn_watch_wakeup(&self, delay: Duration) {
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Fix watch timer",
+            |_| BackgroundMessage::WatchCheckDue,
+            async move {
+                tokio::time::sleep(delay).await;
+                let _ = tx.send(BackgroundMessage::WatchCheckDue).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to fetch a watched issue's event count from
+    /// Sentry.
+    pub fn spawn_watch_check(&self, issue_id: String) {
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Fix watch",
+            move |error| BackgroundMessage::WatchChecked { issue_id: failed, result: Err(error) },
+            async move {
+                let result = client
+                    .refresh_issue(&issue_id)
+                    .await
+                    .map(|detail| detail.source.event_count.unwrap_or(0))
+                    .map_err(|e| format!("Failed to check issue: {}", e));
+
+                let _ = tx.send(BackgroundMessage::WatchChecked { issue_id, result }).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to load tag value counts for an issue.
+    pub fn spawn_tag_stats(&self, issue_id: String) {
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Tag stats",
+            move |error| BackgroundMessage::TagStatsLoaded { issue_id: failed, result: Err(error) },
+            async move {
+                let result = client
+                    .get_tag_stats(&issue_id)
+                    .await
+                    .map(|response| response.tags)
+                    .map_err(|e| format!("Failed to fetch tag stats: {}", e));
+
+                let _ = tx.send(BackgroundMessage::TagStatsLoaded { issue_id, result }).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to preview the plan for an issue's proposal.
+    pub fn spawn_plan_preview(&self, issue_id: String) {
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Plan preview",
+            move |error| BackgroundMessage::PlanLoaded { issue_id: failed, result: Err(error) },
+            async move {
+                let result = client
+                    .preview_plan(&issue_id)
+                    .await
+                    .map_err(|e| format!("Failed to preview plan: {}", e));
+
+                let _ = tx.send(BackgroundMessage::PlanLoaded { issue_id, result }).await;
+            },
+        );
+    }
+
+    /// Start a critic pass over the issue's proposal and stream it back.
+    ///
+    /// Each request runs a fresh critique, so a dropped stream is reported
+    /// rather than retried.
+    pub fn spawn_critique(&self, issue_id: String) {
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        info!(url = %client.critique_url(&issue_id), "Starting critic pass");
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Critic pass",
+            move |error| BackgroundMessage::CritiqueEnded { issue_id: failed, error: Some(error) },
+            async move {
+                let error = match EventSource::new(client.critique_request(&issue_id)) {
+                    Ok(mut es) => {
+                        es.set_retry_policy(Box::new(Never));
+                        stream_critique(&mut es, &issue_id, &tx).await
                     }
-                    Ok(Event::Message(message)) => {
-                        debug!(data_len = message.data.len(), "Received SSE message");
-                        if message.data.len() > 500 {
-                            debug!(data_preview = %&message.data[..500], "SSE data preview");
-                        } else {
-                            debug!(data = %message.data, "SSE data");
+                    Err(e) => Some(e.to_string()),
+                };
+                let _ = tx.send(BackgroundMessage::CritiqueEnded { issue_id, error }).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to fetch an issue's sampled events.
+    pub fn spawn_issue_events(&self, issue_id: String) {
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Events fetch",
+            move |error| BackgroundMessage::IssueEventsLoaded { issue_id: failed, result: Err(error) },
+            async move {
+                let result = client
+                    .get_issue_events(&issue_id)
+                    .await
+                    .map(|response| response.events)
+                    .map_err(|e| format!("Failed to fetch events: {}", e));
+
+                let _ = tx.send(BackgroundMessage::IssueEventsLoaded { issue_id, result }).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to fetch a page of an issue's events.
+    pub fn spawn_occurrences(&self, issue_id: String, cursor: Option<String>) {
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Events page fetch",
+            move |error| BackgroundMessage::OccurrencesLoaded { issue_id: failed, result: Err(error) },
+            async move {
+                let result = client
+                    .list_occurrences(&issue_id, cursor.as_deref())
+                    .await
+                    .map_err(|e| format!("Failed to fetch events: {}", e));
+
+                let _ = tx.send(BackgroundMessage::OccurrencesLoaded { issue_id, result }).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to fetch one event of an issue.
+    pub fn spawn_occurrence(&self, issue_id: String, event_id: String) {
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        let (failed_issue, failed_event) = (issue_id.clone(), event_id.clone());
+        self.supervise(
+            "Event fetch",
+            move |error| BackgroundMessage::OccurrenceLoaded {
+                issue_id: failed_issue,
+                event_id: failed_event,
+                result: Err(error),
+            },
+            async move {
+                let result = client
+                    .get_occurrence(&issue_id, &event_id)
+                    .await
+                    .map(Box::new)
+                    .map_err(|e| format!("Failed to fetch event: {}", e));
+
+                let _ = tx
+                    .send(BackgroundMessage::OccurrenceLoaded { issue_id, event_id, result })
+                    .await;
+            },
+        );
+    }
+
+    /// Spawn a background task to blame one line of a file in the project.
+    pub fn spawn_blame(
+        &self,
+        issue_id: String,
+        frame: usize,
+        repo: PathBuf,
+        file: PathBuf,
+        line: u32,
+    ) {
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Blame",
+            move |error| BackgroundMessage::BlameComplete { issue_id: failed, frame, result: Err(error) },
+            async move {
+                let result = git::blame_line(&repo, &file, line).await;
+                let _ = tx
+                    .send(BackgroundMessage::BlameComplete { issue_id, frame, result })
+                    .await;
+            },
+        );
+    }
+
+    /// Spawn a background task to diff a fix's worktree against the project.
+    pub fn spawn_review_diff(&self, issue_id: String, project: PathBuf, worktree: PathBuf) {
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Review diff",
+            move |error| BackgroundMessage::ReviewDiffLoaded { issue_id: failed, result: Err(error) },
+            async move {
+                let result = git::worktree_diff(&project, &worktree).await;
+                let _ = tx.send(BackgroundMessage::ReviewDiffLoaded { issue_id, result }).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to fetch the primary server's analysis queue.
+    /// Analyses queued on aggregated servers aren't shown.
+    pub fn spawn_queue_status(&self) {
+        let client = Arc::clone(&self.servers[0].client);
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Queue fetch",
+            |error| BackgroundMessage::QueueStatusLoaded(Err(error)),
+            async move {
+                let result = client
+                    .queue_status()
+                    .await
+                    .map_err(|e| format!("Failed to fetch queue: {}", e));
+
+                let _ = tx.send(BackgroundMessage::QueueStatusLoaded(result)).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to list the commits on a fix's branch and
+    /// check its worktree for uncommitted or unpushed work.
+    pub fn spawn_branch_log(&self, issue_id: String, project: PathBuf, worktree: PathBuf) {
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Branch log",
+            move |error| BackgroundMessage::BranchLogLoaded {
+                issue_id: failed,
+                result: Err(error.clone()),
+                at_risk: Err(error),
+            },
+            async move {
+                let result = git::branch_log(&project, &worktree).await;
+                let at_risk = git::at_risk(&worktree).await;
+                let _ = tx
+                    .send(BackgroundMessage::BranchLogLoaded { issue_id, result, at_risk })
+                    .await;
+            },
+        );
+    }
+
+    /// Spawn a background task to push a fix's branch and open a pull
+    /// request for it.
+    pub fn spawn_pull_request(
+        &self,
+        issue_id: String,
+        worktree: PathBuf,
+        branch: String,
+        title: String,
+        body: String,
+    ) {
+        let tx = self.tx.clone();
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Pull request",
+            move |error| BackgroundMessage::PullRequestOpened { issue_id: failed, result: Err(error) },
+            async move {
+                let result = forge::open_pull_request(&worktree, &branch, &title, &body).await;
+                let _ = tx.send(BackgroundMessage::PullRequestOpened { issue_id, result }).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to list the project's worktrees.
+    pub fn spawn_worktrees(&self, project: PathBuf) {
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Worktree listing",
+            |error| BackgroundMessage::WorktreesLoaded(Err(error)),
+            async move {
+                let result = git::list_worktrees(&project).await;
+                let _ = tx.send(BackgroundMessage::WorktreesLoaded(result)).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to remove a worktree, or with `None` to
+    /// prune the records of worktrees whose directories are gone.
+    pub fn spawn_worktree_cleanup(&self, project: PathBuf, worktree: Option<Worktree>) {
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Worktree cleanup",
+            |error| BackgroundMessage::WorktreesCleaned(Err(error)),
+            async move {
+                let result = match worktree {
+                    Some(worktree) => git::remove_worktree(&project, &worktree).await,
+                    None => git::prune_worktrees(&project).await,
+                };
+                let _ = tx.send(BackgroundMessage::WorktreesCleaned(result)).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to run `command` in a fix's worktree,
+    /// forwarding its output line by line.
+    pub fn spawn_worktree_tests(&mut self, issue_id: String, command: String, worktree: PathBuf) {
+        let tx = self.tx.clone();
+        let (cancel_tx, cancel) = oneshot::channel();
+        self.cancel_tests = Some(cancel_tx);
+
+        let failed = issue_id.clone();
+        self.supervise(
+            "Test run",
+            move |error| BackgroundMessage::TestsFinished { issue_id: failed, result: Err(error) },
+            async move {
+                let result = run_tests(&issue_id, &command, &worktree, cancel, &tx).await;
+                let _ = tx.send(BackgroundMessage::TestsFinished { issue_id, result }).await;
+            },
+        );
+    }
+
+    /// Kill the running test command. Its run finishes without a result.
+    pub fn cancel_worktree_tests(&mut self) {
+        if let Some(cancel) = self.cancel_tests.take() {
+            let _ = cancel.send(());
+        }
+    }
+
+    /// Spawn the startup load while the UI draws: start the server `launch`
+    /// describes unless one is running (or glass doesn't manage it), wait
+    /// for it to come up, then fetch the cached issue list alongside what the
+    /// server supports (and its analysis queue, if it has one). Each is sent
+    /// as it arrives, so the list shows without waiting on the others.
+    pub fn spawn_initial_load(&self, launch: Option<ServerLaunch>) {
+        let servers = self.servers.clone();
+        let client = Arc::clone(&self.servers[0].client);
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Initial load",
+            |error| BackgroundMessage::CachedListLoaded(Err(error)),
+            async move {
+                let server = match launch {
+                    Some(launch) => start_server(&launch, &tx).await,
+                    None => Ok(None),
+                };
+                let failed = server.is_err();
+                let _ = tx.send(BackgroundMessage::ServerReady(server)).await;
+                if failed {
+                    return;
+                }
+
+                let lists = async {
+                    let mut attempt = 1;
+                    let (result, unreachable) = loop {
+                        let (result, unreachable) = fetch_lists(servers.clone(), vec![false; servers.len()]).await;
+                        if result.is_ok() || attempt == INITIAL_FETCH_ATTEMPTS {
+                            break (result, unreachable);
                         }
+                        attempt += 1;
+                        tokio::time::sleep(INITIAL_FETCH_RETRY).await;
+                    };
+                    for (project, error) in unreachable {
+                        let _ = tx.send(BackgroundMessage::ProjectUnreachable { project, error }).await;
+                    }
+                    let _ = tx.send(BackgroundMessage::CachedListLoaded(result)).await;
+                };
+                tokio::join!(lists, sync_capabilities(&client, &tx));
+            },
+        );
+    }
+
+    /// Spawn a background task to rediscover what the server supports, and
+    /// fetch its analysis queue if it has one, as after a restart.
+    pub fn spawn_capabilities(&self) {
+        let client = Arc::clone(&self.servers[0].client);
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Capability discovery",
+            |_| BackgroundMessage::CapabilitiesLoaded(ServerCapabilities::Unknown),
+            async move { sync_capabilities(&client, &tx).await },
+        );
+    }
+
+    /// Watch the server on `port` from the background, saying it's lost
+    /// once it misses a few health checks in a row. Ends there; a restarted
+    /// server gets a new watch.
+    pub fn spawn_server_monitor(&self, port: u16) {
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Server monitor",
+            BackgroundMessage::ServerLost,
+            async move {
+                let mut missed = 0;
+                while missed < MISSED_HEALTH_CHECKS {
+                    tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+                    if tx.is_closed() {
+                        return;
+                    }
+                    if ServerProcess::is_running(port).await {
+                        missed = 0;
+                    } else {
+                        missed += 1;
+                        debug!(port, missed, "Server missed a health check");
+                    }
+                }
+                warn!(port, "Server stopped answering health checks");
+                let _ = tx
+                    .send(BackgroundMessage::ServerLost(format!(
+                        "no answer on port {} for {} seconds",
+                        port,
+                        (HEALTH_CHECK_INTERVAL * MISSED_HEALTH_CHECKS).as_secs()
+                    )))
+                    .await;
+            },
+        );
+    }
+
+    /// Spawn restart attempt `attempt` of the lost server `launch`
+    /// describes, after a backoff. A server that came back on its own (or
+    /// was started by hand) meanwhile is used as is.
+    pub fn spawn_server_restart(&self, launch: ServerLaunch, attempt: u32) {
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Server restart",
+            |error| BackgroundMessage::ServerRestarted(Err(StartFailure { error, output: Vec::new() })),
+            async move {
+                tokio::time::sleep(restart_delay(attempt)).await;
+                info!(attempt, port = launch.port, "Restarting lost server");
+                let server = start_server(&launch, &tx).await;
+                let _ = tx.send(BackgroundMessage::ServerRestarted(server)).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to reload the cached issue list.
+    pub fn spawn_list_reload(&self) {
+        self.spawn_lists(false);
+    }
+
+    fn spawn_lists(&self, refresh: bool) {
+        let servers = self.servers.clone();
+        // Narrowed to one project, only it is refreshed from Sentry
+        let refresh: Vec<bool> = (0..servers.len())
+            .map(|index| refresh && self.scoped.is_none_or(|scoped| scoped == index))
+            .collect();
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Issue list fetch",
+            |error| BackgroundMessage::ListRefreshComplete(Err(error)),
+            async move {
+                let (result, unreachable) = fetch_lists(servers, refresh).await;
+                for (project, error) in unreachable {
+                    let _ = tx.send(BackgroundMessage::ProjectUnreachable { project, error }).await;
+                }
+                let _ = tx.send(BackgroundMessage::ListRefreshComplete(result)).await;
+            },
+        );
+    }
+
+    /// Spawn a background task to reload cached issue detail.
+    pub fn spawn_detail_reload(&self, issue_id: String) {
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        self.supervise(
+            "Issue fetch",
+            |error| BackgroundMessage::DetailRefreshComplete(Err(error)),
+            async move {
+                let result = client
+                    .get_issue(&issue_id)
+                    .await
+                    .map(Box::new)
+                    .map_err(|e| format!("Failed to fetch issue: {}", e));
+
+                let _ = tx.send(BackgroundMessage::DetailRefreshComplete(result)).await;
+            },
+        );
+    }
+
+    /// Start the server-wide SSE stream for live issue updates, from every
+    /// server when aggregating.
+    pub fn spawn_server_events(&self) {
+        for server in &self.servers {
+            self.spawn_global_stream(&server.client);
+        }
+    }
+
+    /// Transport errors are retried with the event source's backoff; a
+    /// server that doesn't offer the stream ends it permanently.
+    fn spawn_global_stream(&self, client: &ApiClient) {
+        let tx = self.tx.clone();
+        let request = client.global_events_request();
+
+        info!(url = %client.global_events_url(), "Starting server-wide SSE stream");
 
-                        match serde_json::from_str::<AnalysisEvent>(&message.data) {
-                            Ok(event) => {
-                                debug!(?event, "Parsed analysis event");
-                                if tx.send(BackgroundMessage::AnalysisEvent(event)).await.is_err() {
-                                    warn!("Failed to send event to channel, receiver dropped");
-                                    break;
+        self.supervise(
+            "Server event stream",
+            BackgroundMessage::ServerStreamEnded,
+            async move {
+                let mut es = match EventSource::new(request) {
+                    Ok(es) => es,
+                    Err(e) => {
+                        let _ = tx.send(BackgroundMessage::ServerStreamEnded(e.to_string())).await;
+                        return;
+                    }
+                };
+
+                while let Some(event) = es.next().await {
+                    match event {
+                        Ok(Event::Open) => {
+                            info!("Server SSE connection opened");
+                        }
+                        Ok(Event::Message(message)) => {
+                            match ServerEvent::parse(&message.event, &message.data) {
+                                Ok(Some(event)) => {
+                                    debug!(?event, "Parsed server event");
+                                    if tx.send(BackgroundMessage::ServerEvent(event)).await.is_err() {
+                                        break;
+                                    }
+                                }
+                                Ok(None) => {
+                                    debug!(event = %message.event, "Ignoring unknown server event");
+                                }
+                                Err(e) => {
+                                    warn!(%e, data = %message.data, "Failed to parse server event");
                                 }
-                            }
-                            Err(e) => {
-                                error!(%e, data = %message.data, "Failed to parse SSE event");
-                                let _ = tx
-                                    .send(BackgroundMessage::AnalysisStreamEnded(Some(format!(
-                                        "Parse error: {}",
-                                        e
-                                    ))))
-                                    .await;
-                                break;
                             }
                         }
-                    }
-                    Err(e) => {
-                        let err_str = e.to_string();
-                        let is_normal_end = err_str.contains("end of stream")
-                            || err_str.contains("Stream ended")
-                            || err_str.contains("EOF");
-                        if !is_normal_end {
-                            error!(%err_str, "SSE stream error");
+                        Err(
+                            e @ (EventSourceError::InvalidStatusCode(..)
+                            | EventSourceError::InvalidContentType(..)),
+                        ) => {
+                            warn!(%e, "Server events unavailable");
+                            es.close();
                             let _ = tx
-                                .send(BackgroundMessage::AnalysisStreamEnded(Some(err_str)))
+                                .send(BackgroundMessage::ServerStreamEnded(e.to_string()))
                                 .await;
-                        } else {
-                            info!("SSE stream ended normally");
-                            let _ = tx.send(BackgroundMessage::AnalysisStreamEnded(None)).await;
+                            break;
+                        }
+                        Err(e) => {
+                            // The event source reconnects on its own
+                            debug!(%e, "Server SSE stream error, reconnecting");
+                        }
+                    }
+                }
+
+                info!("Server SSE stream task completed");
+            },
+        );
+    }
+
+    /// Start the SSE stream for analysis events.
+    ///
+    /// Reconnects with exponential backoff if the stream drops before the
+    /// analysis finishes. See [`AnalysisStream`] for how replays are deduped.
+    pub fn spawn_analysis_stream(&self, issue_id: &str) {
+        self.spawn_session_stream(Session::Analysis, issue_id);
+    }
+
+    /// Start the SSE stream for an implementation session's events, which
+    /// are shaped like analysis events and resumed the same way.
+    pub fn spawn_implementation_stream(&self, issue_id: &str) {
+        self.spawn_session_stream(Session::Implementation, issue_id);
+    }
+
+    fn spawn_session_stream(&self, session: Session, issue_id: &str) {
+        let issue_id = issue_id.to_string();
+        let client = self.client_for(&issue_id);
+        let tx = self.tx.clone();
+
+        let url = session.url(&client, &issue_id);
+        info!(?session, %url, "Starting SSE stream for session events");
+
+        let stalls = Arc::clone(&self.stalls);
+        let failed = issue_id.clone();
+        self.supervise(
+            "Event stream",
+            move |error| session.ended(failed, Some(error)),
+            async move {
+                let mut stream = AnalysisStream {
+                    session,
+                    stalls,
+                    ..AnalysisStream::default()
+                };
+                let mut attempt = 0;
+
+                let ended = loop {
+                    match stream.run(&client, &issue_id, &tx).await {
+                        StreamOutcome::Finished => break None,
+                        StreamOutcome::Fatal(err) => break Some(err),
+                        StreamOutcome::Dropped { err, opened } => {
+                            // A successful connection resets the backoff
+                            if opened {
+                                attempt = 0;
+                            }
+                            attempt += 1;
+                            if attempt > MAX_RECONNECT_ATTEMPTS {
+                                break Some(format!(
+                                    "{} (gave up after {} retries)",
+                                    err, MAX_RECONNECT_ATTEMPTS
+                                ));
+                            }
+
+                            let delay = reconnect_delay(attempt);
+                            warn!(%err, attempt, ?delay, ?session, "Stream dropped, reconnecting");
+                            let msg = session.reconnecting(&issue_id, attempt, err);
+                            if tx.send(msg).await.is_err() {
+                                return;
+                            }
+                            tokio::time::sleep(delay).await;
                         }
+                    }
+                };
+
+                info!("SSE stream task completed");
+                let _ = tx.send(session.ended(issue_id, ended)).await;
+            },
+        );
+    }
+}
+
+/// The text a panic was raised with.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or_else(|| "unknown panic".to_string(), |message| message.to_string()),
+    }
+}
+
+/// Start the server `launch` describes unless one is already running on
+/// its port, and wait for it to answer health checks. If it doesn't, the
+/// failure carries the last of what it printed.
+async fn start_server(
+    launch: &ServerLaunch,
+    tx: &mpsc::Sender<BackgroundMessage>,
+) -> Result<Option<ServerProcess>, StartFailure> {
+    if ServerProcess::is_running(launch.port).await {
+        return Ok(None);
+    }
+    let mut server = ServerProcess::spawn(launch).map_err(|e| StartFailure {
+        error: e.to_string(),
+        output: Vec::new(),
+    })?;
+    let _ = tx.send(BackgroundMessage::ServerSpawned).await;
+    server.wait_for_ready().await.map_err(|e| StartFailure {
+        error: e.to_string(),
+        output: server.output_tail(FAILURE_OUTPUT_LINES),
+    })?;
+    Ok(Some(server))
+}
+
+/// Find out what the server supports.
+/// Discover what the server supports, then fetch its analysis queue unless
+/// it says it has none. Only asked of the primary server when aggregating.
+async fn sync_capabilities(client: &ApiClient, tx: &mpsc::Sender<BackgroundMessage>) {
+    let capabilities = discover_capabilities(client).await;
+    let queue = capabilities.supports(Capability::Queue);
+    let _ = tx.send(BackgroundMessage::CapabilitiesLoaded(capabilities)).await;
+    if queue {
+        let result = client
+            .queue_status()
+            .await
+            .map_err(|e| format!("Failed to fetch queue: {}", e));
+        let _ = tx.send(BackgroundMessage::QueueStatusLoaded(result)).await;
+    }
+}
+
+async fn discover_capabilities(client: &ApiClient) -> ServerCapabilities {
+    match client.capabilities().await {
+        Ok(response) => {
+            info!(
+                version = ?response.version,
+                capabilities = ?response.capabilities,
+                "Server capabilities"
+            );
+            ServerCapabilities::Advertised(response.capabilities.into_iter().collect())
+        }
+        // Servers from before discovery only have the core endpoints
+        Err(e) if e.downcast_ref::<ApiError>().is_some_and(|e| e.status == 404) => {
+            info!("Server predates capability discovery");
+            ServerCapabilities::Advertised(Default::default())
+        }
+        Err(e) => {
+            warn!(error = %e, "Capability discovery failed");
+            ServerCapabilities::Unknown
+        }
+    }
+}
+
+/// Fetch (or where `refresh` says so, re-fetch from Sentry) every server's
+/// issue list, merged newest activity first.
+///
+/// With more than one server each issue is tagged with its project, and a
+/// project that can't be reached is reported and left out, unless none can.
+async fn fetch_lists(
+    servers: Vec<ProjectServer>,
+    refresh: Vec<bool>,
+) -> (Result<ListIssuesResponse, String>, Vec<(String, String)>) {
+    let what = if refresh.contains(&true) { "refresh" } else { "fetch" };
+    let results = join_all(servers.iter().zip(refresh).map(|(server, refresh)| async move {
+        if refresh {
+            server.client.refresh_issues().await
+        } else {
+            server.client.list_issues().await
+        }
+    }))
+    .await;
+
+    if servers.len() == 1 {
+        let result = results.into_iter().next().expect("one server");
+        return (result.map_err(|e| format!("Failed to {} issues: {}", what, e)), Vec::new());
+    }
+
+    let mut lists = Vec::new();
+    let mut unreachable = Vec::new();
+    for (server, result) in servers.iter().zip(results) {
+        match result {
+            Ok(list) => lists.push((server.name.clone(), list)),
+            Err(e) => {
+                warn!(project = %server.name, error = %e, "Project server unreachable");
+                unreachable.push((server.name.clone(), format!("Failed to {} issues: {}", what, e)));
+            }
+        }
+    }
+    if lists.is_empty() {
+        let (_, error) = unreachable.remove(0);
+        return (Err(error), unreachable);
+    }
+    (Ok(merge_lists(lists)), unreachable)
+}
+
+/// Combine per-project lists into one, tagging each issue with its project.
+fn merge_lists(lists: Vec<(String, ListIssuesResponse)>) -> ListIssuesResponse {
+    let mut merged = ListIssuesResponse {
+        issues: Vec::new(),
+        total: 0,
+        limit: 0,
+        offset: 0,
+    };
+    for (project, list) in lists {
+        merged.total += list.total;
+        merged.limit += list.limit;
+        merged.issues.extend(list.issues.into_iter().map(|mut issue| {
+            issue.project = Some(project.clone());
+            issue
+        }));
+    }
+    // ISO timestamps sort chronologically as strings
+    merged.issues.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+    merged
+}
+
+/// Forward critique events until the reviewer finishes, returning an error
+/// if the stream ends any other way.
+async fn stream_critique(
+    es: &mut EventSource,
+    issue_id: &str,
+    tx: &mpsc::Sender<BackgroundMessage>,
+) -> Option<String> {
+    while let Some(event) = es.next().await {
+        match event {
+            Ok(Event::Open) => debug!("Critique stream opened"),
+            Ok(Event::Message(message)) => {
+                let event = match serde_json::from_str::<CritiqueEvent>(&message.data) {
+                    Ok(event) => event,
+                    Err(e) => {
+                        error!(%e, data = %message.data, "Failed to parse critique event");
+                        es.close();
+                        return Some(format!("Parse error: {}", e));
+                    }
+                };
+                let finished = matches!(event, CritiqueEvent::Complete | CritiqueEvent::Error { .. });
+                let msg = BackgroundMessage::CritiqueEvent {
+                    issue_id: issue_id.to_string(),
+                    event,
+                };
+                if tx.send(msg).await.is_err() || finished {
+                    es.close();
+                    return None;
+                }
+            }
+            Err(e) => {
+                warn!(%e, "Critique stream error");
+                es.close();
+                return Some(e.to_string());
+            }
+        }
+    }
+    Some("Stream closed".to_string())
+}
+
+/// Run a test command through the shell, forwarding stdout and stderr as
+/// they arrive, and return its exit code.
+async fn run_tests(
+    issue_id: &str,
+    command: &str,
+    worktree: &Path,
+    mut cancel: oneshot::Receiver<()>,
+    tx: &mpsc::Sender<BackgroundMessage>,
+) -> Result<Option<i32>, String> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .current_dir(worktree)
+        // Escape codes would show up as garbage in the pane
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        // Its own process group, so cancelling reaches what the shell starts
+        .process_group(0)
+        .spawn()
+        .map_err(|e| format!("Failed to start tests: {}", e))?;
+
+    let (stdout, stderr) = (child.stdout.take(), child.stderr.take());
+    let status = tokio::select! {
+        status = async {
+            tokio::join!(forward_lines(stdout, issue_id, tx), forward_lines(stderr, issue_id, tx));
+            child.wait().await
+        } => Some(status),
+        Ok(()) = &mut cancel => None,
+    };
+    let Some(status) = status else {
+        info!(command, "Killing cancelled test run");
+        if let Some(pid) = child.id() {
+            let group = format!("-{}", pid);
+            let _ = Command::new("kill").args(["-KILL", "--", &group]).status().await;
+        }
+        let _ = child.kill().await;
+        return Ok(None);
+    };
+    let status = status.map_err(|e| format!("Failed to run tests: {}", e))?;
+    Ok(status.code())
+}
+
+/// Send each line read from `output` as test output until it closes.
+async fn forward_lines(
+    output: Option<impl AsyncRead + Unpin>,
+    issue_id: &str,
+    tx: &mpsc::Sender<BackgroundMessage>,
+) {
+    let Some(output) = output else {
+        return;
+    };
+    let mut lines = BufReader::new(output).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let msg = BackgroundMessage::TestOutput {
+            issue_id: issue_id.to_string(),
+            line,
+        };
+        if tx.send(msg).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Give up reconnecting after this many consecutive failures.
+const MAX_RECONNECT_ATTEMPTS: u32 = 6;
+
+/// Backoff before reconnect attempt `attempt` (1-based): 0.5s doubling to 10s.
+fn reconnect_delay(attempt: u32) -> Duration {
+    let millis = 500u64.saturating_mul(1 << attempt.saturating_sub(1).min(5));
+    Duration::from_millis(millis).min(Duration::from_secs(10))
+}
+
+/// Backoff before restart attempt `attempt` (1-based) of a lost server:
+/// 1s doubling to 30s.
+fn restart_delay(attempt: u32) -> Duration {
+    let secs = 1u64 << attempt.saturating_sub(1).min(5);
+    Duration::from_secs(secs).min(Duration::from_secs(30))
+}
+
+/// How a single analysis stream connection ended.
+enum StreamOutcome {
+    /// The analysis reached a terminal event
+    Finished,
+    /// Unrecoverable; don't reconnect
+    Fatal(String),
+    /// Connection lost mid-analysis; worth reconnecting
+    Dropped { err: String, opened: bool },
+}
+
+/// Which agent session a stream follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Session {
+    #[default]
+    Analysis,
+    Implementation,
+}
+
+impl Session {
+    fn url(self, client: &ApiClient, issue_id: &str) -> String {
+        match self {
+            Session::Analysis => client.events_url(issue_id),
+            Session::Implementation => client.implementation_events_url(issue_id),
+        }
+    }
+
+    fn request(
+        self,
+        client: &ApiClient,
+        issue_id: &str,
+        last_event_id: Option<&str>,
+    ) -> reqwest::RequestBuilder {
+        match self {
+            Session::Analysis => client.events_request(issue_id, last_event_id),
+            Session::Implementation => {
+                client.implementation_events_request(issue_id, last_event_id)
+            }
+        }
+    }
+
+    fn events(self, issue_id: &str, events: Vec<AnalysisEvent>) -> BackgroundMessage {
+        match self {
+            Session::Analysis => BackgroundMessage::AnalysisEvents(events),
+            Session::Implementation => BackgroundMessage::ImplementationEvents {
+                issue_id: issue_id.to_string(),
+                events,
+            },
+        }
+    }
+
+    fn reconnecting(self, issue_id: &str, attempt: u32, error: String) -> BackgroundMessage {
+        match self {
+            Session::Analysis => BackgroundMessage::AnalysisReconnecting { attempt, error },
+            Session::Implementation => BackgroundMessage::ImplementationReconnecting {
+                issue_id: issue_id.to_string(),
+                attempt,
+                error,
+            },
+        }
+    }
+
+    fn ended(self, issue_id: String, error: Option<String>) -> BackgroundMessage {
+        match self {
+            Session::Analysis => BackgroundMessage::AnalysisStreamEnded(error),
+            Session::Implementation => {
+                BackgroundMessage::ImplementationStreamEnded { issue_id, error }
+            }
+        }
+    }
+}
+
+/// Resumable analysis stream state, kept across reconnects.
+///
+/// The server replays every buffered event as a backfill on connect. When
+/// it assigns event ids we send `Last-Event-ID` and trust it to resume from
+/// there; otherwise we skip the part of the backfill already delivered.
+#[derive(Default)]
+struct AnalysisStream {
+    /// Session whose events are followed
+    session: Session,
+    /// Number of events forwarded to the app so far
+    delivered: usize,
+    /// Id of the last message received, if the server sends ids
+    last_event_id: Option<String>,
+    /// Whether a terminal (complete/error) event has been seen
+    finished: bool,
+    /// Shared count of sends that found the channel full
+    stalls: Arc<AtomicUsize>,
+}
+
+impl AnalysisStream {
+    async fn run(
+        &mut self,
+        client: &ApiClient,
+        issue_id: &str,
+        tx: &mpsc::Sender<BackgroundMessage>,
+    ) -> StreamOutcome {
+        let request = self.session.request(client, issue_id, self.last_event_id.as_deref());
+        let mut es = match EventSource::new(request) {
+            Ok(es) => es,
+            Err(e) => return StreamOutcome::Fatal(e.to_string()),
+        };
+        // We handle reconnection ourselves so replays can be deduped
+        es.set_retry_policy(Box::new(Never));
+
+        // Whatever has already arrived is read at once and forwarded as one
+        // message, so a flood of events doesn't become a flood of messages
+        let mut chunks = es.ready_chunks(MAX_BATCH);
+        let mut opened = false;
+        while let Some(chunk) = chunks.next().await {
+            let mut batch = Vec::new();
+            let mut outcome = None;
+            for event in chunk {
+                match self.read(event, &mut opened) {
+                    Ok(Some(event)) => batch.push(event),
+                    Ok(None) => {}
+                    Err(ended) => {
+                        outcome = Some(ended);
                         break;
                     }
                 }
             }
 
-            info!("SSE stream task completed");
-            let _ = tx.send(BackgroundMessage::AnalysisStreamEnded(None)).await;
+            if !batch.is_empty() && !self.forward(issue_id, batch, tx).await {
+                warn!("Failed to send events to channel, receiver dropped");
+                return StreamOutcome::Finished;
+            }
+            if let Some(outcome) = outcome {
+                return outcome;
+            }
+        }
+
+        if self.finished {
+            StreamOutcome::Finished
+        } else {
+            StreamOutcome::Dropped { err: "Stream closed".to_string(), opened }
+        }
+    }
+
+    /// Handle one item from the event source: an event to forward, nothing,
+    /// or how the stream ended.
+    fn read(
+        &mut self,
+        event: Result<Event, EventSourceError>,
+        opened: &mut bool,
+    ) -> Result<Option<AnalysisEvent>, StreamOutcome> {
+        match event {
+            Ok(Event::Open) => {
+                info!("SSE connection opened");
+                *opened = true;
+                Ok(None)
+            }
+            Ok(Event::Message(message)) => {
+                debug!(data_len = message.data.len(), "Received SSE message");
+                if message.data.len() > 500 {
+                    debug!(data_preview = %&message.data[..500], "SSE data preview");
+                } else {
+                    debug!(data = %message.data, "SSE data");
+                }
+
+                if !message.id.is_empty() {
+                    self.last_event_id = Some(message.id.clone());
+                }
+
+                let event = match serde_json::from_str::<AnalysisEvent>(&message.data) {
+                    Ok(event) => event,
+                    Err(e) => {
+                        error!(%e, data = %message.data, "Failed to parse SSE event");
+                        return Err(StreamOutcome::Fatal(format!("Parse error: {}", e)));
+                    }
+                };
+                debug!(?event, "Parsed analysis event");
+                Ok(self.accept(event))
+            }
+            Err(EventSourceError::StreamEnded) if self.finished => {
+                info!("SSE stream ended normally");
+                Err(StreamOutcome::Finished)
+            }
+            Err(e @ EventSourceError::InvalidStatusCode(..)) => {
+                // e.g. 404 once the session buffer is gone
+                Err(StreamOutcome::Fatal(e.to_string()))
+            }
+            Err(e) => {
+                error!(%e, "SSE stream error");
+                Err(StreamOutcome::Dropped { err: e.to_string(), opened: *opened })
+            }
+        }
+    }
+
+    /// Send a batch of events to the app, waiting if the channel is full.
+    /// Returns false once the app is gone.
+    async fn forward(
+        &self,
+        issue_id: &str,
+        events: Vec<AnalysisEvent>,
+        tx: &mpsc::Sender<BackgroundMessage>,
+    ) -> bool {
+        if tx.capacity() == 0 {
+            let stalls = self.stalls.fetch_add(1, Ordering::Relaxed) + 1;
+            debug!(stalls, "Background channel full, stream waiting");
+        }
+        tx.send(self.session.events(issue_id, events)).await.is_ok()
+    }
+
+    /// Track an incoming event, trimming already-delivered backfill.
+    fn accept(&mut self, event: AnalysisEvent) -> Option<AnalysisEvent> {
+        match event {
+            AnalysisEvent::Backfill { events } => {
+                let events: Vec<_> = if self.last_event_id.is_some() {
+                    events
+                } else {
+                    events.into_iter().skip(self.delivered).collect()
+                };
+                if events.is_empty() {
+                    return None;
+                }
+                self.delivered += events.len();
+                self.finished |= events.iter().any(is_terminal);
+                Some(AnalysisEvent::Backfill { events })
+            }
+            event => {
+                self.delivered += 1;
+                self.finished |= is_terminal(&event);
+                Some(event)
+            }
+        }
+    }
+}
+
+fn is_terminal(event: &AnalysisEvent) -> bool {
+    matches!(event, AnalysisEvent::Complete { .. } | AnalysisEvent::Error { .. })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(delta: &str) -> AnalysisEvent {
+        AnalysisEvent::TextDelta {
+            delta: delta.to_string(),
+        }
+    }
+
+    fn deltas(event: &AnalysisEvent) -> Vec<String> {
+        match event {
+            AnalysisEvent::Backfill { events } => events.iter().flat_map(deltas).collect(),
+            AnalysisEvent::TextDelta { delta } => vec![delta.clone()],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_replayed_backfill_is_trimmed() {
+        let mut stream = AnalysisStream::default();
+
+        // First connection: backfill of two, then one live event
+        let first = stream.accept(AnalysisEvent::Backfill {
+            events: vec![text("a"), text("b")],
+        });
+        assert_eq!(deltas(&first.unwrap()), vec!["a", "b"]);
+        stream.accept(text("c"));
+
+        // Reconnect: server replays everything plus what we missed
+        let replay = stream.accept(AnalysisEvent::Backfill {
+            events: vec![text("a"), text("b"), text("c"), text("d")],
         });
+        assert_eq!(deltas(&replay.unwrap()), vec!["d"]);
+
+        // Nothing new
+        let empty = stream.accept(AnalysisEvent::Backfill {
+            events: vec![text("a"), text("b"), text("c"), text("d")],
+        });
+        assert!(empty.is_none());
+    }
+
+    #[test]
+    fn test_terminal_event_in_backfill_finishes() {
+        let mut stream = AnalysisStream::default();
+        stream.accept(AnalysisEvent::Backfill {
+            events: vec![text("a"), AnalysisEvent::Complete { proposal: String::new() }],
+        });
+        assert!(stream.finished);
+    }
+
+    #[test]
+    fn test_reconnect_delay_backs_off() {
+        assert_eq!(reconnect_delay(1), Duration::from_millis(500));
+        assert_eq!(reconnect_delay(2), Duration::from_secs(1));
+        assert_eq!(reconnect_delay(5), Duration::from_secs(8));
+        assert_eq!(reconnect_delay(6), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_restart_delay_backs_off() {
+        assert_eq!(restart_delay(1), Duration::from_secs(1));
+        assert_eq!(restart_delay(3), Duration::from_secs(4));
+        assert_eq!(restart_delay(6), Duration::from_secs(30));
+        assert_eq!(restart_delay(40), Duration::from_secs(30));
+    }
+
+    fn list(ids: &[(&str, &str)]) -> ListIssuesResponse {
+        let issues = ids
+            .iter()
+            .map(|(id, last_seen)| {
+                serde_json::from_value(serde_json::json!({
+                    "id": id, "sourceType": "sentry", "title": "", "shortId": "",
+                    "status": "pending", "eventCount": 0, "userCount": 0,
+                    "firstSeen": "", "lastSeen": last_seen, "updatedAt": "",
+                }))
+                .unwrap()
+            })
+            .collect();
+        ListIssuesResponse {
+            issues,
+            total: ids.len(),
+            limit: 50,
+            offset: 0,
+        }
+    }
+
+    #[test]
+    fn test_aggregated_issues_route_to_their_server() {
+        let merged = merge_lists(vec![
+            ("web".to_string(), list(&[("a", "2026-02-01T10:00:00Z")])),
+            ("billing".to_string(), list(&[("b", "2026-02-01T12:00:00Z")])),
+        ]);
+        let order: Vec<_> = merged
+            .issues
+            .iter()
+            .map(|i| (i.id.as_str(), i.project.as_deref()))
+            .collect();
+        assert_eq!(order, vec![("b", Some("billing")), ("a", Some("web"))]);
+        assert_eq!(merged.total, 2);
+
+        let mut bg = BackgroundTasks::new("http://web".to_string(), None);
+        bg.add_server("web", "billing".to_string(), "http://billing".to_string(), None);
+        bg.route(&merged.issues);
+        assert_eq!(bg.client_for("a").global_events_url(), bg.client().global_events_url());
+        assert_ne!(bg.client_for("b").global_events_url(), bg.client().global_events_url());
+        assert!(!bg.same_server("a", "b"));
+    }
+
+    #[test]
+    fn test_scoped_project_gets_new_issues() {
+        let mut bg = BackgroundTasks::new("http://web".to_string(), None);
+        assert!(bg.projects().is_empty());
+        bg.add_server("web", "billing".to_string(), "http://billing".to_string(), None);
+        assert_eq!(bg.projects(), vec!["web", "billing"]);
+
+        bg.scope(Some("billing"));
+        assert!(bg.scoped_client().global_events_url().starts_with("http://billing/"));
+        bg.scope(None);
+        assert_eq!(bg.scoped_client().global_events_url(), bg.client().global_events_url());
+    }
+
+    #[tokio::test]
+    async fn test_panicking_task_reports_failure() {
+        let mut bg = BackgroundTasks::new("http://localhost:1".to_string(), None);
+        bg.supervise(
+            "Issue list fetch",
+            |error| BackgroundMessage::ListRefreshComplete(Err(error)),
+            async { panic!("boom") },
+        );
+        match bg.recv().await {
+            Some(BackgroundMessage::ListRefreshComplete(Err(error))) => {
+                assert_eq!(error, "Issue list fetch crashed: boom");
+            }
+            _ => panic!("expected the list refresh to fail"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_test_run_is_killed() {
+        let mut bg = BackgroundTasks::new("http://localhost:1".to_string(), None);
+        let pid_file = std::env::temp_dir().join(format!("glass-test-run-{}", std::process::id()));
+        let command = format!("sleep 30 & echo $! > {}; echo started; wait", pid_file.display());
+        bg.spawn_worktree_tests("a".to_string(), command, std::env::temp_dir());
+        match bg.recv().await {
+            Some(BackgroundMessage::TestOutput { line, .. }) => assert_eq!(line, "started"),
+            _ => panic!("expected test output"),
+        }
+
+        bg.cancel_worktree_tests();
+        let finished = tokio::time::timeout(Duration::from_secs(5), bg.recv()).await;
+        assert!(matches!(
+            finished,
+            Ok(Some(BackgroundMessage::TestsFinished { result: Ok(None), .. }))
+        ));
+        // What the shell started is gone too
+        let pid = std::fs::read_to_string(&pid_file).unwrap();
+        let _ = std::fs::remove_file(&pid_file);
+        // (left a zombie if nothing reaps orphans)
+        let ps = std::process::Command::new("ps").args(["-o", "stat=", "-p", pid.trim()]).output().unwrap();
+        let state = String::from_utf8_lossy(&ps.stdout);
+        assert!(state.trim().is_empty() || state.starts_with('Z'), "still running: {}", state);
+    }
+
+    #[test]
+    fn test_poll_stops_at_the_event_budget() {
+        let mut bg = BackgroundTasks::new("http://localhost:1".to_string(), None);
+        for _ in 0..3 {
+            let events = vec![text("a"), text("b"), text("c")];
+            bg.tx.try_send(BackgroundMessage::AnalysisEvents(events)).unwrap();
+        }
+        bg.tx.try_send(BackgroundMessage::ServerStreamEnded("gone".to_string())).unwrap();
+
+        assert_eq!(bg.poll(5).len(), 2);
+        assert_eq!(
+            bg.channel_stats(),
+            ChannelStats { queued: 2, peak: 4, stalls: 0 }
+        );
+        assert_eq!(bg.poll(5).len(), 2);
+        assert_eq!(bg.channel_stats().queued, 0);
     }
 }
@@ -0,0 +1,157 @@
+//! What changed about an issue between two loads of its detail, so looking
+//! at an issue again after a deploy shows whether it's still happening.
+
+use std::collections::BTreeSet;
+
+use crate::api::IssueDetail;
+
+/// A tag that's new, or whose value changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagChange {
+    pub key: String,
+    /// `None` for a tag the earlier load didn't have
+    pub old: Option<String>,
+    pub new: String,
+}
+
+/// How an issue differs from the last time its detail was loaded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssueChanges {
+    pub issue_id: String,
+    /// Events and users since, negative if Sentry dropped some
+    pub new_events: i64,
+    pub new_users: i64,
+    /// Old and new status
+    pub status: Option<(String, String)>,
+    /// Old and new release of the latest event
+    pub release: Option<(Option<String>, Option<String>)>,
+    /// The new last seen, if it moved
+    pub last_seen: Option<String>,
+    /// Sorted by key
+    pub tags: Vec<TagChange>,
+    /// Breadcrumb categories the earlier event didn't have, in order
+    pub breadcrumb_categories: Vec<String>,
+}
+
+impl IssueChanges {
+    /// The changes from `old` to `new`, two loads of the same issue.
+    pub fn between(old: &IssueDetail, new: &IssueDetail) -> Self {
+        let (before, after) = (&old.source, &new.source);
+        let count = |n: Option<u64>| n.unwrap_or(0) as i64;
+
+        let old_tags = before.tags.clone().unwrap_or_default();
+        let mut tags: Vec<TagChange> = after
+            .tags
+            .iter()
+            .flatten()
+            .filter(|(key, value)| old_tags.get(*key) != Some(*value))
+            .map(|(key, value)| TagChange {
+                key: key.clone(),
+                old: old_tags.get(key).cloned(),
+                new: value.clone(),
+            })
+            .collect();
+        tags.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let old_categories: BTreeSet<&str> = before.breadcrumb_categories().into_iter().collect();
+        let breadcrumb_categories = after
+            .breadcrumb_categories()
+            .into_iter()
+            .filter(|category| !old_categories.contains(category))
+            .map(str::to_string)
+            .collect();
+
+        Self {
+            issue_id: new.id.clone(),
+            new_events: count(after.event_count) - count(before.event_count),
+            new_users: count(after.user_count) - count(before.user_count),
+            status: (old.status != new.status).then(|| (old.status.clone(), new.status.clone())),
+            release: (before.release != after.release).then(|| (before.release.clone(), after.release.clone())),
+            last_seen: after.last_seen.clone().filter(|_| before.last_seen != after.last_seen),
+            tags,
+            breadcrumb_categories,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        Self {
+            issue_id: self.issue_id.clone(),
+            ..Self::default()
+        } == *self
+    }
+
+    /// One line for a toast: the event count first, since it's what says
+    /// whether a fix worked.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        match self.new_events {
+            0 => parts.push("no new events".to_string()),
+            1 => parts.push("1 new event".to_string()),
+            n => parts.push(format!("{} new events", n)),
+        }
+        if let Some((_, new)) = &self.release {
+            parts.push(format!("release now {}", new.as_deref().unwrap_or("unset")));
+        }
+        if let Some((_, new)) = &self.status {
+            parts.push(format!("now {}", new));
+        }
+        if !self.tags.is_empty() {
+            parts.push(format!("{} tag{} changed", self.tags.len(), if self.tags.len() == 1 { "" } else { "s" }));
+        }
+        if !self.breadcrumb_categories.is_empty() {
+            parts.push(format!("new breadcrumbs: {}", self.breadcrumb_categories.join(", ")));
+        }
+        parts.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(status: &str, source: &str) -> IssueDetail {
+        serde_json::from_str(&format!(
+            r#"{{"id":"i","sourceType":"sentry","status":"{}","source":{},"state":{{"status":"pending"}},
+                "createdAt":"2026-02-01T10:00:00Z","updatedAt":"2026-02-01T12:00:00Z"}}"#,
+            status, source
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_between() {
+        let old = issue(
+            "pending",
+            r#"{"eventCount":10,"userCount":2,"release":"web@1.0","lastSeen":"2026-02-01T12:00:00Z",
+                "tags":{"browser":"Firefox","os":"Linux"},"breadcrumbs":[{"category":"http"}]}"#,
+        );
+        let new = issue(
+            "pending",
+            r#"{"eventCount":13,"userCount":2,"release":"web@1.1","lastSeen":"2026-02-02T09:00:00Z",
+                "tags":{"browser":"Chrome","os":"Linux","device":"Desktop"},
+                "breadcrumbs":[{"category":"ui.click"},{"category":"http"},{"category":"console"}]}"#,
+        );
+        let changes = IssueChanges::between(&old, &new);
+        assert_eq!(changes.new_events, 3);
+        assert_eq!(changes.new_users, 0);
+        assert_eq!(changes.status, None);
+        assert_eq!(changes.release, Some((Some("web@1.0".into()), Some("web@1.1".into()))));
+        assert_eq!(changes.last_seen.as_deref(), Some("2026-02-02T09:00:00Z"));
+        assert_eq!(
+            changes.tags,
+            vec![
+                TagChange { key: "browser".into(), old: Some("Firefox".into()), new: "Chrome".into() },
+                TagChange { key: "device".into(), old: None, new: "Desktop".into() },
+            ]
+        );
+        assert_eq!(changes.breadcrumb_categories, vec!["ui.click", "console"]);
+        assert_eq!(
+            changes.summary(),
+            "3 new events, release now web@1.1, 2 tags changed, new breadcrumbs: ui.click, console"
+        );
+
+        let unchanged = IssueChanges::between(&new, &new);
+        assert!(unchanged.is_empty());
+        assert_eq!(unchanged.summary(), "no new events");
+    }
+}
@@ -0,0 +1,161 @@
+//! Raw analysis events kept for transcripts and recordings, bounded like the
+//! activity log they produce.
+
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use tracing::warn;
+
+use super::state::RecordedEvent;
+
+/// Events kept before the oldest are dropped. Text arrives a few tokens per
+/// event, so this is well above the activity log's line cap.
+pub const DEFAULT_MAX_EVENTS: usize = 50_000;
+
+/// Recorded events in a ring buffer capped at `max_events`.
+///
+/// Dropped events can be appended to a spill file as JSONL, the same format
+/// transcripts use for the events they still have.
+#[derive(Debug)]
+pub struct EventLog {
+    events: VecDeque<RecordedEvent>,
+    max_events: usize,
+    /// Events dropped from the front since the last clear
+    dropped: usize,
+    spill_path: Option<PathBuf>,
+    spill: Option<BufWriter<File>>,
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_EVENTS)
+    }
+}
+
+impl EventLog {
+    pub fn new(max_events: usize) -> Self {
+        Self {
+            events: VecDeque::new(),
+            max_events: max_events.max(1),
+            dropped: 0,
+            spill_path: None,
+            spill: None,
+        }
+    }
+
+    /// Append events dropped from now on to `path` (or stop spilling).
+    pub fn set_spill_path(&mut self, path: Option<PathBuf>) {
+        self.spill = None;
+        self.spill_path = path;
+    }
+
+    pub fn push(&mut self, event: RecordedEvent) {
+        if self.events.len() >= self.max_events {
+            if let Some(oldest) = self.events.pop_front() {
+                self.dropped += 1;
+                self.spill_event(&oldest);
+            }
+        }
+        self.events.push_back(event);
+    }
+
+    /// Remove all events. Stops spilling; the next analysis sets a new path.
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.dropped = 0;
+        self.set_spill_path(None);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// How many older events were dropped to stay under the cap.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Where dropped events were written, once any were.
+    pub fn spill_path(&self) -> Option<&Path> {
+        self.spill.as_ref().and(self.spill_path.as_deref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RecordedEvent> {
+        self.events.iter()
+    }
+
+    fn spill_event(&mut self, recorded: &RecordedEvent) {
+        let Some(path) = &self.spill_path else {
+            return;
+        };
+        if self.spill.is_none() {
+            let opened = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|()| File::options().create(true).append(true).open(path));
+            match opened {
+                Ok(file) => self.spill = Some(BufWriter::new(file)),
+                Err(e) => {
+                    warn!(path = %path.display(), error = %e, "Can't spill analysis events");
+                    self.spill_path = None;
+                    return;
+                }
+            }
+        }
+
+        let Ok(json) = serde_json::to_string(&recorded.event) else {
+            return;
+        };
+        if let Some(spill) = &mut self.spill {
+            if let Err(e) = writeln!(spill, "{}", json) {
+                warn!(error = %e, "Failed to spill analysis events");
+                self.spill = None;
+                self.spill_path = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use crate::api::AnalysisEvent;
+
+    fn delta(text: &str) -> RecordedEvent {
+        RecordedEvent {
+            offset: Duration::ZERO,
+            event: AnalysisEvent::TextDelta { delta: text.to_string() },
+        }
+    }
+
+    #[test]
+    fn test_drops_and_spills_oldest_events() {
+        let path = std::env::temp_dir().join(format!("glass-events-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut log = EventLog::new(2);
+        log.set_spill_path(Some(path.clone()));
+        for text in ["a", "b", "c"] {
+            log.push(delta(text));
+        }
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.dropped(), 1);
+        assert_eq!(log.spill_path(), Some(path.as_path()));
+
+        log.clear();
+        assert!(log.is_empty());
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "{\"type\":\"text_delta\",\"delta\":\"a\"}\n"
+        );
+        fs::remove_file(&path).unwrap();
+    }
+}
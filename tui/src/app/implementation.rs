@@ -0,0 +1,85 @@
+//! Implementation event handling - follows an approved fix being carried
+//! out, with the same activity lines as the analysis screen.
+
+use crate::api::AnalysisEvent;
+
+use crate::app::analysis::{flush_text, push_activity};
+use crate::app::state::{ActivityLine, ActivityStyle, AppState};
+
+/// Handle an event from the implementation session's SSE stream.
+pub fn handle_implementation_event(state: &mut AppState, event: AnalysisEvent) {
+    if !matches!(event, AnalysisEvent::Backfill { .. }) {
+        state.implementation_progress.event_count += 1;
+    }
+
+    match event {
+        AnalysisEvent::Backfill { events } => {
+            for e in events {
+                handle_implementation_event(state, e);
+            }
+        }
+        AnalysisEvent::Complete { .. } => {
+            flush_text(&mut state.implementation_lines, &mut state.implementation_text_buffer);
+
+            state.is_streaming_implementation = false;
+            state.implementation_progress.finish();
+
+            let text = match state.implementation_progress.usage_summary() {
+                Some(usage) => format!("Implementation complete ({})", usage),
+                None => "Implementation complete".to_string(),
+            };
+            state.implementation_lines.push(ActivityLine {
+                icon: "✓",
+                text,
+                style: ActivityStyle::Success,
+            });
+        }
+        AnalysisEvent::Error { message } => {
+            flush_text(&mut state.implementation_lines, &mut state.implementation_text_buffer);
+
+            state.implementation_lines.push(ActivityLine {
+                icon: "✗",
+                text: message,
+                style: ActivityStyle::Error,
+            });
+
+            state.is_streaming_implementation = false;
+            state.implementation_progress.finish();
+        }
+        event => push_activity(
+            &mut state.implementation_lines,
+            &mut state.implementation_text_buffer,
+            &mut state.implementation_progress,
+            event,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_fill_the_implementation_pane() {
+        let mut state = AppState {
+            is_streaming_implementation: true,
+            ..AppState::default()
+        };
+        let events: Vec<AnalysisEvent> = serde_json::from_str(
+            r#"[{"type":"tool_start","tool":"edit","args":{"path":"src/auth.ts"}},
+                {"type":"text_delta","delta":"Guarded the user lookup.\n"},
+                {"type":"complete"}]"#,
+        )
+        .unwrap();
+        handle_implementation_event(&mut state, AnalysisEvent::Backfill { events });
+
+        let texts: Vec<&str> = state.implementation_lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec!["edit path=src/auth.ts", "Guarded the user lookup.", "Implementation complete"]
+        );
+        assert!(!state.is_streaming_implementation);
+        assert_eq!(state.implementation_progress.tool_calls, 1);
+        assert!(state.analysis_lines.is_empty());
+    }
+}
@@ -0,0 +1,176 @@
+//! Text input state for prompts (feedback, notes, commands).
+//!
+//! Cursor movement and deletion operate on grapheme clusters, so composed
+//! characters (accents from dead keys, Hangul syllables, ZWJ emoji) behave
+//! as a single unit. IME composition happens in the terminal; we only see
+//! the committed text.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// An editable text buffer with a cursor.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextInput {
+    text: String,
+    /// Cursor position as a byte offset (always on a grapheme boundary)
+    cursor: usize,
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current contents.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Cursor position as a byte offset into `text()`.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Display column of the cursor within its line, for placing the
+    /// terminal cursor (wide CJK characters take two columns).
+    pub fn cursor_column(&self) -> usize {
+        let before = &self.text[..self.cursor];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        before[line_start..].width()
+    }
+
+    /// Line index of the cursor (0-based).
+    pub fn cursor_line(&self) -> usize {
+        self.text[..self.cursor].matches('\n').count()
+    }
+
+    /// Insert a single character at the cursor.
+    ///
+    /// A combining mark typed after a base character joins its cluster;
+    /// the cursor stays after the combined grapheme.
+    pub fn insert_char(&mut self, c: char) {
+        self.text.insert(self.cursor, c);
+        self.cursor += c.len_utf8();
+    }
+
+    /// Insert a string at the cursor as one edit (used for pastes).
+    ///
+    /// Line endings are normalized to `\n`.
+    pub fn insert_str(&mut self, s: &str) {
+        let normalized = s.replace("\r\n", "\n").replace('\r', "\n");
+        self.text.insert_str(self.cursor, &normalized);
+        self.cursor += normalized.len();
+    }
+
+    /// Delete the grapheme before the cursor.
+    pub fn backspace(&mut self) {
+        let start = self.prev_boundary();
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    /// Delete the grapheme under the cursor.
+    pub fn delete(&mut self) {
+        let end = self.next_boundary();
+        self.text.replace_range(self.cursor..end, "");
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.prev_boundary();
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = self.next_boundary();
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.text.len();
+    }
+
+    fn prev_boundary(&self) -> usize {
+        self.text[..self.cursor]
+            .grapheme_indices(true)
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    fn next_boundary(&self) -> usize {
+        self.text[self.cursor..]
+            .graphemes(true)
+            .next()
+            .map(|g| self.cursor + g.len())
+            .unwrap_or(self.cursor)
+    }
+
+    /// Remove all text.
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+
+    /// Apply an editing key. Returns false if the key isn't an edit.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('a') if ctrl => self.move_home(),
+            KeyCode::Char('e') if ctrl => self.move_end(),
+            KeyCode::Char('u') if ctrl => self.clear(),
+            KeyCode::Char(c) if !ctrl => self.insert_char(c),
+            KeyCode::Backspace => self.backspace(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Left => self.move_left(),
+            KeyCode::Right => self.move_right(),
+            KeyCode::Home => self.move_home(),
+            KeyCode::End => self.move_end(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_backspace() {
+        let mut input = TextInput::new();
+        input.insert_char('h');
+        input.insert_char('i');
+        input.backspace();
+        assert_eq!(input.text(), "h");
+        assert_eq!(input.cursor(), 1);
+    }
+
+    #[test]
+    fn test_paste_is_single_insertion() {
+        let mut input = TextInput::new();
+        input.insert_str("ab");
+        input.move_left();
+        input.insert_str("Error: boom\r\n  at main (app.ts:1)\r\nq");
+        assert_eq!(input.text(), "aError: boom\n  at main (app.ts:1)\nqb");
+        assert_eq!(input.cursor(), input.text().len() - 1);
+    }
+
+    #[test]
+    fn test_multibyte_cursor_movement() {
+        let mut input = TextInput::new();
+        input.insert_str("héllo");
+        input.move_home();
+        input.move_right();
+        input.move_right();
+        assert_eq!(input.cursor(), 3);
+        input.backspace();
+        assert_eq!(input.text(), "hllo");
+    }
+}
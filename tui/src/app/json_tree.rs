@@ -0,0 +1,172 @@
+//! The raw JSON viewer's tree: a JSON value laid out one row per scalar
+//! and per bracket, with folded objects and arrays kept to a single row.
+//!
+//! Nodes are named by their path from the root (`$.source.tags[0]`), which
+//! is what the set of folded nodes holds.
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+/// How deep the viewer starts unfolded; containers below are folded.
+pub const UNFOLDED_DEPTH: usize = 2;
+
+/// What a row of the tree shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonRowKind {
+    /// A scalar, or an empty object or array
+    Value,
+    /// The opening bracket of an object or array, with its entry count
+    Open { len: usize, folded: bool },
+    /// The closing bracket of an unfolded object or array
+    Close,
+}
+
+/// One row of the tree.
+#[derive(Debug, Clone)]
+pub struct JsonRow<'a> {
+    /// Path of the node the row belongs to
+    pub path: String,
+    pub depth: usize,
+    /// Object key or array index, `None` for the root and closing brackets
+    pub key: Option<String>,
+    pub value: &'a Value,
+    pub kind: JsonRowKind,
+}
+
+/// The rows of `value` with the containers in `folded` shut.
+pub fn flatten<'a>(value: &'a Value, folded: &HashSet<String>) -> Vec<JsonRow<'a>> {
+    let mut rows = Vec::new();
+    push_rows(&mut rows, value, "$".to_string(), None, 0, folded);
+    rows
+}
+
+fn push_rows<'a>(
+    rows: &mut Vec<JsonRow<'a>>,
+    value: &'a Value,
+    path: String,
+    key: Option<String>,
+    depth: usize,
+    folded: &HashSet<String>,
+) {
+    let children = children(value, &path);
+    if children.is_empty() {
+        rows.push(JsonRow { path, depth, key, value, kind: JsonRowKind::Value });
+        return;
+    }
+    let is_folded = folded.contains(&path);
+    rows.push(JsonRow {
+        path: path.clone(),
+        depth,
+        key,
+        value,
+        kind: JsonRowKind::Open { len: children.len(), folded: is_folded },
+    });
+    if is_folded {
+        return;
+    }
+    for (child_path, child_key, child) in children {
+        push_rows(rows, child, child_path, Some(child_key), depth + 1, folded);
+    }
+    rows.push(JsonRow { path, depth, key: None, value, kind: JsonRowKind::Close });
+}
+
+/// Each entry of an object or array as (path, key, value).
+fn children<'a>(value: &'a Value, path: &str) -> Vec<(String, String, &'a Value)> {
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, child)| (format!("{}.{}", path, key), key.clone(), child))
+            .collect(),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, child)| (format!("{}[{}]", path, i), i.to_string(), child))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Paths of the non-empty containers at `depth` or deeper, for folding
+/// everything below the first few levels.
+pub fn containers_from(value: &Value, depth: usize) -> HashSet<String> {
+    let mut paths = HashSet::new();
+    collect_containers(value, "$".to_string(), 0, depth, &mut paths);
+    paths
+}
+
+fn collect_containers(value: &Value, path: String, depth: usize, from: usize, paths: &mut HashSet<String>) {
+    let children = children(value, &path);
+    if children.is_empty() {
+        return;
+    }
+    for (child_path, _, child) in children {
+        collect_containers(child, child_path, depth + 1, from, paths);
+    }
+    if depth >= from {
+        paths.insert(path);
+    }
+}
+
+/// The row of the container `path` opens, searching back from `before`.
+pub fn opening_row(rows: &[JsonRow], path: &str, before: usize) -> Option<usize> {
+    rows[..=before.min(rows.len().saturating_sub(1))]
+        .iter()
+        .rposition(|row| row.path == path && matches!(row.kind, JsonRowKind::Open { .. }))
+}
+
+/// Path of the container holding `path`, or `None` for the root.
+pub fn parent_path(path: &str) -> Option<&str> {
+    let end = path.rfind(['.', '['])?;
+    Some(&path[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Value {
+        serde_json::json!({
+            "id": "sentry:42",
+            "source": {"tags": [{"key": "browser"}, {"key": "os"}], "extra": {}},
+        })
+    }
+
+    fn shape(rows: &[JsonRow]) -> Vec<(String, JsonRowKind)> {
+        rows.iter().map(|row| (row.path.clone(), row.kind)).collect()
+    }
+
+    #[test]
+    fn test_flatten() {
+        let value = sample();
+        let folded = containers_from(&value, UNFOLDED_DEPTH);
+        let rows = flatten(&value, &folded);
+        assert_eq!(
+            shape(&rows),
+            vec![
+                ("$".to_string(), JsonRowKind::Open { len: 2, folded: false }),
+                ("$.id".to_string(), JsonRowKind::Value),
+                ("$.source".to_string(), JsonRowKind::Open { len: 2, folded: false }),
+                // Empty containers have nothing to fold
+                ("$.source.extra".to_string(), JsonRowKind::Value),
+                ("$.source.tags".to_string(), JsonRowKind::Open { len: 2, folded: true }),
+                ("$.source".to_string(), JsonRowKind::Close),
+                ("$".to_string(), JsonRowKind::Close),
+            ]
+        );
+        assert_eq!(rows[4].key.as_deref(), Some("tags"));
+        assert_eq!(rows[4].depth, 2);
+        assert_eq!(opening_row(&rows, "$.source", 5), Some(2));
+
+        let rows = flatten(&value, &HashSet::new());
+        assert_eq!(rows.len(), 14);
+        assert_eq!(rows[6].path, "$.source.tags[0].key");
+    }
+
+    #[test]
+    fn test_parent_path() {
+        assert_eq!(parent_path("$.source.tags[0].key"), Some("$.source.tags[0]"));
+        assert_eq!(parent_path("$.source.tags[0]"), Some("$.source.tags"));
+        assert_eq!(parent_path("$"), None);
+    }
+}
@@ -0,0 +1,145 @@
+//! Styled lines kept between frames, so long text is laid out once and a
+//! frame only copies the rows it shows.
+
+use std::cell::RefCell;
+
+use ratatui::text::Line;
+use ratatui::widgets::{Paragraph, Wrap};
+
+/// Lines built for a key and wrapped to a width (or left unwrapped),
+/// cached until either changes.
+///
+/// Like `ScrollView`, this is filled in while rendering, which only has
+/// shared access to state.
+#[derive(Debug, Default)]
+pub struct LineCache {
+    cached: RefCell<Option<LaidOut>>,
+}
+
+#[derive(Debug)]
+struct LaidOut {
+    key: u64,
+    /// `None` when lines aren't wrapped
+    width: Option<u16>,
+    lines: Vec<Line<'static>>,
+    /// Columns taken by the widest line
+    widest: usize,
+    /// Wrapped row each line starts on, then the total row count
+    row_starts: Vec<usize>,
+}
+
+impl LaidOut {
+    fn wrap(&mut self, width: Option<u16>) {
+        self.width = width;
+        self.row_starts.clear();
+        let mut rows = 0;
+        for line in &self.lines {
+            self.row_starts.push(rows);
+            rows += match width {
+                Some(width) => Paragraph::new(line.clone()).wrap(Wrap { trim: false }).line_count(width).max(1),
+                None => 1,
+            };
+        }
+        self.row_starts.push(rows);
+    }
+
+    fn total_rows(&self) -> usize {
+        self.row_starts.last().copied().unwrap_or(0)
+    }
+}
+
+impl LineCache {
+    /// Lay out the lines for `key` wrapped to `width` columns (or one row
+    /// each with no width), and return how many rows they take. `build`
+    /// only runs when the key changed; a new width just rewraps.
+    pub fn lay_out(&self, key: u64, width: Option<u16>, build: impl FnOnce() -> Vec<Line<'static>>) -> usize {
+        let mut cached = self.cached.borrow_mut();
+        match cached.as_mut() {
+            Some(laid_out) if laid_out.key == key => {
+                if laid_out.width != width {
+                    laid_out.wrap(width);
+                }
+            }
+            _ => {
+                let lines = build();
+                let mut laid_out = LaidOut {
+                    key,
+                    width,
+                    widest: lines.iter().map(Line::width).max().unwrap_or(0),
+                    lines,
+                    row_starts: Vec::new(),
+                };
+                laid_out.wrap(width);
+                *cached = Some(laid_out);
+            }
+        }
+        cached.as_ref().map_or(0, LaidOut::total_rows)
+    }
+
+    /// Columns taken by the widest line laid out.
+    pub fn widest(&self) -> usize {
+        self.cached.borrow().as_ref().map_or(0, |laid_out| laid_out.widest)
+    }
+
+    /// Drop the lines, so the next `lay_out` builds them whatever the key.
+    pub fn invalidate(&self) {
+        self.cached.borrow_mut().take();
+    }
+
+    /// The lines covering `height` rows from row `offset`, and how many rows
+    /// of the first one are above the window (to scroll the paragraph by).
+    pub fn rows(&self, offset: usize, height: usize) -> (Vec<Line<'static>>, u16) {
+        let cached = self.cached.borrow();
+        let Some(laid_out) = cached.as_ref() else {
+            return (Vec::new(), 0);
+        };
+        let starts = &laid_out.row_starts[..laid_out.lines.len()];
+        let first = starts.partition_point(|&start| start <= offset).saturating_sub(1);
+        let end = starts.partition_point(|&start| start < offset + height).max(first);
+        let skip = offset.saturating_sub(laid_out.row_starts[first]);
+        (laid_out.lines[first..end].to_vec(), skip as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_builds_once_and_slices_visible_rows() {
+        let cache = LineCache::default();
+        let builds = std::cell::Cell::new(0);
+        let build = || {
+            builds.set(builds.get() + 1);
+            vec![Line::from("one"), Line::from("two three four"), Line::from("five")]
+        };
+
+        assert_eq!(cache.lay_out(1, Some(80), build), 3);
+        assert_eq!(cache.lay_out(1, Some(80), build), 3);
+        // Rewrapped, not rebuilt: "two three four" takes three rows
+        assert_eq!(cache.lay_out(1, Some(5), build), 5);
+        assert_eq!(builds.get(), 1);
+
+        let (lines, skip) = cache.rows(2, 2);
+        assert_eq!(text(&lines), vec!["two three four"]);
+        assert_eq!(skip, 1);
+        let (lines, skip) = cache.rows(3, 5);
+        assert_eq!(text(&lines), vec!["two three four", "five"]);
+        assert_eq!(skip, 2);
+
+        assert_eq!(cache.lay_out(2, Some(5), build), 5);
+        assert_eq!(builds.get(), 2);
+        cache.invalidate();
+        assert_eq!(cache.lay_out(2, Some(5), build), 5);
+        assert_eq!(builds.get(), 3);
+
+        // Unwrapped, each line is a row
+        assert_eq!(cache.lay_out(2, None, build), 3);
+        assert_eq!(builds.get(), 3);
+        assert_eq!(cache.widest(), 14);
+    }
+}
@@ -4,16 +4,93 @@
 //! - `state`: Pure data structures
 //! - `background`: Async task management
 //! - `analysis`: Analysis event processing
+//! - `input`: Text input editing
+//! - `json_tree`: The raw JSON viewer's foldable tree
+//! - `changes`: What a detail refresh changed about an issue
+//! - `toast`: Transient notifications
+//! - `activity_log`: Bounded, rewrappable analysis output
+//! - `event_log`: Bounded raw analysis events behind that output
+//! - `line_cache`: Styled lines laid out once for long views
+//! - `transitions`: The issue state machine
+//! - `tutorial`: Guided tutorial on demo data
+//! - `view`: Filtering, sorting and grouping of the issue list
+//! - `watch`: Completed issues watched for new events
+//! - `yank`: What the yank menu can copy
 
+mod accel;
+mod activity_log;
 mod analysis;
 mod background;
+mod changes;
+mod event_log;
+mod implementation;
+mod input;
+mod json_tree;
+mod line_cache;
+mod new_issue;
 mod state;
+mod toast;
+mod transitions;
+mod tutorial;
+mod view;
+mod watch;
+mod yank;
 
-pub use state::{ActivityLine, ActivityStyle, AppState, Screen};
-pub use background::{BackgroundMessage, BackgroundTasks};
+pub use state::{
+    ActivityLine, ActivityStyle, AnalysisProgress, AppState, BellMode, BreadcrumbView, CommandLine, Critique, CritiqueStatus,
+    DetailSection, EventBrowser, EventPicker, EventTarget, FailedAction, FinishReview, IssueAction, JsonView, MergeDialog, Peek, PlanPreview, Playback, Prompt, PullRequest, RecordedEvent,
+    Screen, ScrollView, ServerCapabilities, ServerStatus, SessionKind, SessionPicker, TagBreakdown, TestRun, TestStatus, WorktreeUse, Worktrees,
+    DEFAULT_TIMELINE_ZOOM, MIN_TERMINAL_HEIGHT, MIN_TERMINAL_WIDTH, SPLIT_MIN_WIDTH, TIMELINE_ZOOM_LEVELS,
+};
+pub use accel::ScrollAccelerator;
+pub use activity_log::{ActivityLog, DEFAULT_MAX_LINES};
+pub use background::{BackgroundMessage, BackgroundTasks, ChannelStats};
+pub use changes::{IssueChanges, TagChange};
+pub use event_log::EventLog;
+pub use input::TextInput;
+pub use json_tree::{JsonRow, JsonRowKind};
+pub use line_cache::LineCache;
+pub use new_issue::{NewIssueField, NewIssueForm};
+pub use toast::{Severity, Toast, Toasts};
+pub use transitions::{allowed_actions, allows, transitions_from, Transition, Trigger, TRANSITIONS};
+pub use tutorial::{Tutorial, TutorialData, TutorialStep, TUTORIAL_STEPS};
+pub use view::{FilterField, GroupBy, IssueFilter, IssueSort, ListView, SortKey, STATUSES};
+pub use watch::{FixWatch, WATCH_CHECK_INTERVAL};
+pub use yank::{YankItem, YankMenu};
 
-use crate::api::IssueState;
-use tracing::debug;
+use std::collections::HashSet;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::api::{
+    AnalysisEvent, ApiError, AuthToken, Capability, CritiqueEvent, Issue, IssueDetail, IssueEvent, IssueState, IssueUpdatedEvent, LatencyStats, Rejection, ServerEvent,
+    SessionRef, StackFrame,
+};
+use crate::config::{IconSet, OpenIn, Palette, ServerConfig};
+use crate::recording::{LoadedRecording, Recording};
+use crate::transcript::Transcript;
+use crate::server::{ServerLaunch, ServerProcess, StartFailure};
+use crate::workspace::Workspace;
+use crate::escape::{self, CommitEdit, Placement};
+use crate::locale::NumberStyle;
+use crate::{forge, git, logging, notify};
+use crate::screens::{self, Action, ERROR_ACTIONS};
+use tracing::{debug, warn};
+
+/// How long the status bar stays inverted for a visual bell.
+const BELL_FLASH_DURATION: Duration = Duration::from_secs(1);
+/// How often queue positions are checked while analyses are waiting.
+const QUEUE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// How long a second quit has to come after a held-back one to go through
+/// (as long as the warning stays up).
+const QUIT_CONFIRM_WINDOW: Duration = Duration::from_secs(6);
+/// Stream events handled between redraws at most.
+const FRAME_EVENT_BUDGET: usize = 2048;
 
 /// Main application coordinator.
 ///
@@ -23,273 +100,3322 @@ pub struct App {
     pub state: AppState,
     /// Background task manager
     bg: BackgroundTasks,
+    /// The server glass started, kept so it stops when glass does
+    server: Option<ServerProcess>,
+    /// How to start the server again if it's lost, when it's glass's to start
+    launch: Option<ServerLaunch>,
 }
 
-impl App {
-    pub fn new(server_url: String) -> Self {
-        Self {
-            state: AppState::default(),
-            bg: BackgroundTasks::new(server_url),
-        }
+impl App {
+    pub fn new(server_url: String, token: Option<AuthToken>) -> Self {
+        Self {
+            state: AppState::default(),
+            bg: BackgroundTasks::new(server_url, token),
+            server: None,
+            launch: None,
+        }
+    }
+
+    // === Convenience accessors (delegate to state) ===
+
+    pub fn screen(&self) -> &Screen {
+        &self.state.screen
+    }
+
+    pub fn half_page(&self) -> i32 {
+        self.state.half_page()
+    }
+
+    /// Move further per press while a scroll key is held, so long lists
+    /// and transcripts keep up with key repeat. Single steps only: page
+    /// scrolling is left alone, and any other key lets go of the held one.
+    pub fn accelerate(&mut self, action: Action) -> Action {
+        // Lists move a selection you need to be able to stop on; text
+        // only needs to go by fast
+        const MAX_SELECTION_STEP: i32 = 4;
+        const MAX_SCROLL_STEP: i32 = 12;
+
+        let now = Instant::now();
+        let kind = std::mem::discriminant(&action);
+        let half_page = self.half_page();
+        let accel = &mut self.state.scroll_accel;
+        let mut step = |delta: i32, max: i32| accel.step(kind, delta, now, max.min(half_page));
+        match action {
+            Action::MoveSelection(d) if d.abs() == 1 => Action::MoveSelection(step(d, MAX_SELECTION_STEP)),
+            Action::MoveBreadcrumbSelection(d) if d.abs() == 1 => {
+                Action::MoveBreadcrumbSelection(step(d, MAX_SELECTION_STEP))
+            }
+            Action::MoveJsonSelection(d) if d.abs() == 1 => Action::MoveJsonSelection(step(d, MAX_SELECTION_STEP)),
+            Action::ScrollDetail(d) if d.abs() == 1 => Action::ScrollDetail(step(d, MAX_SCROLL_STEP)),
+            Action::ScrollAnalysis(d) if d.abs() == 1 => Action::ScrollAnalysis(step(d, MAX_SCROLL_STEP)),
+            Action::ScrollImplementation(d) if d.abs() == 1 => {
+                Action::ScrollImplementation(step(d, MAX_SCROLL_STEP))
+            }
+            Action::ScrollReview(d) if d.abs() == 1 => Action::ScrollReview(step(d, MAX_SCROLL_STEP)),
+            Action::ScrollTestOutput(d) if d.abs() == 1 => Action::ScrollTestOutput(step(d, MAX_SCROLL_STEP)),
+            Action::ScrollProposal(d) if d.abs() == 1 => Action::ScrollProposal(step(d, MAX_SCROLL_STEP)),
+            Action::ScrollPlanPreview(d) if d.abs() == 1 => Action::ScrollPlanPreview(step(d, MAX_SCROLL_STEP)),
+            Action::ScrollCritique(d) if d.abs() == 1 => Action::ScrollCritique(step(d, MAX_SCROLL_STEP)),
+            action => {
+                accel.reset();
+                action
+            }
+        }
+    }
+
+    /// Response times of the glass server, for the status bar.
+    pub fn latency(&self) -> Option<LatencyStats> {
+        self.bg.client().latency()
+    }
+
+    pub fn set_terminal_size(&mut self, width: u16, height: u16) {
+        self.state.set_terminal_size(width, height);
+        self.follow_preview();
+    }
+
+    /// Enable periodic background refreshes.
+    pub fn set_auto_refresh(&mut self, interval: Duration) {
+        self.state.auto_refresh_interval = Some(interval);
+        self.state.next_auto_refresh = Some(Instant::now() + interval);
+    }
+
+    /// Start an automatic refresh if one is due.
+    ///
+    /// Refreshes the list, plus the open issue when on the detail screen.
+    pub fn tick_auto_refresh(&mut self) {
+        let (Some(interval), Some(due)) =
+            (self.state.auto_refresh_interval, self.state.next_auto_refresh)
+        else {
+            return;
+        };
+        if Instant::now() < due {
+            return;
+        }
+
+        // Reschedule even if a refresh is already running
+        self.state.next_auto_refresh = Some(Instant::now() + interval);
+        self.start_refresh();
+        if self.state.screen == Screen::Detail {
+            self.start_detail_refresh();
+        }
+    }
+
+    /// Periodic housekeeping: expire toasts and the bell flash, and run any
+    /// due auto-refresh.
+    pub fn tick(&mut self) {
+        // Keep the toast behind an open details modal alive
+        if !self.state.show_error_details {
+            self.state.toasts.expire(Instant::now());
+        }
+        if self.state.bell_flash_until.is_some_and(|until| until <= Instant::now()) {
+            self.state.bell_flash_until = None;
+        }
+        self.tick_auto_refresh();
+        if self.state.next_queue_poll.is_some_and(|due| due <= Instant::now()) {
+            self.state.next_queue_poll = None;
+            self.sync_queue();
+        }
+        self.tick_tutorial();
+        self.tick_playback();
+    }
+
+    /// Insert pasted text into the focused input, if any.
+    ///
+    /// Pastes outside an input are dropped rather than replayed as keys.
+    pub fn paste(&mut self, text: &str) {
+        if let Some(form) = self.state.new_issue.as_mut().filter(|f| !f.submitting) {
+            form.focused_input().insert_str(text);
+        } else if let Some(input) = self.state.input.as_mut() {
+            input.insert_str(text);
+        }
+    }
+
+    // === Background task polling ===
+
+    /// Handle background task results that have arrived, up to a frame's
+    /// worth of events; the rest wait for the next frame so input and
+    /// redraws keep up during a flood.
+    pub fn poll_background(&mut self) {
+        for msg in self.bg.poll(FRAME_EVENT_BUDGET) {
+            self.handle_background(msg);
+        }
+        let stats = self.bg.channel_stats();
+        if stats.queued > 0 {
+            debug!(?stats, "Background messages left for the next frame");
+        }
+    }
+
+    /// Wait for the next background task message.
+    pub async fn next_background(&mut self) -> Option<BackgroundMessage> {
+        self.bg.recv().await
+    }
+
+    /// Apply a background task message to state.
+    pub fn handle_background(&mut self, msg: BackgroundMessage) {
+        match msg {
+            // Real data would replace the demo issues mid-tutorial
+            BackgroundMessage::ListRefreshComplete(_) | BackgroundMessage::DetailRefreshComplete(_)
+                if self.state.tutorial.is_some() =>
+            {
+                self.state.is_refreshing = false;
+                self.state.is_refreshing_detail = false;
+            }
+            BackgroundMessage::ServerSpawned => {
+                self.state.startup.record("server spawn");
+            }
+            BackgroundMessage::ServerReady(Ok(server)) => {
+                if server.is_some() {
+                    self.server = server;
+                }
+                self.state.server_status = ServerStatus::Ready;
+                self.state.startup.record("health wait");
+                self.monitor_server();
+                self.start_server_events();
+                // Watches restored from last time are checked right away
+                if !self.state.watches.is_empty() {
+                    self.check_watches();
+                }
+            }
+            BackgroundMessage::ServerReady(Err(e)) => {
+                self.state.is_loading = false;
+                self.state.server_status = ServerStatus::Failed(e);
+            }
+            BackgroundMessage::ServerLost(error) => self.restart_server(error),
+            BackgroundMessage::ServerRestarted(Ok(server)) => {
+                if server.is_some() {
+                    self.server = server;
+                }
+                self.state.server_status = ServerStatus::Ready;
+                self.state.toasts.success("Server is back");
+                self.monitor_server();
+                // Whatever changed while it was down, on a server that may
+                // have been upgraded
+                self.bg.spawn_list_reload();
+                self.bg.spawn_capabilities();
+                if let Some(id) = self.current_issue_id() {
+                    self.bg.spawn_detail_reload(id);
+                }
+            }
+            BackgroundMessage::ServerRestarted(Err(failure)) => {
+                if let (ServerStatus::Restarting { attempt, .. }, Some(launch)) =
+                    (&self.state.server_status, &self.launch)
+                {
+                    let attempt = attempt + 1;
+                    warn!(attempt, error = %failure.error, "Server restart failed, retrying");
+                    self.bg.spawn_server_restart(launch.clone(), attempt);
+                    self.state.server_status = ServerStatus::Restarting { attempt, cause: failure };
+                }
+            }
+            BackgroundMessage::CachedListLoaded(result) => {
+                self.state.is_loading = false;
+                self.state.startup.record("cached list");
+                match result {
+                    Ok(response) => {
+                        self.bg.route(&response.issues);
+                        self.state.set_issues(response.issues);
+                        self.state.clamp_selection();
+                        self.follow_preview();
+                    }
+                    Err(e) => {
+                        self.state.toasts.error(e);
+                    }
+                }
+                // Then refresh from Sentry
+                self.start_refresh();
+            }
+            BackgroundMessage::ListRefreshComplete(result) => {
+                self.state.is_refreshing = false;
+                self.state.startup.record("first refresh");
+                match result {
+                    Ok(response) => {
+                        self.bg.route(&response.issues);
+                        self.state.set_issues(response.issues);
+                        self.state.clamp_selection();
+                        self.prune_merge_marks();
+                        self.follow_peek();
+                        self.sync_queue();
+                        self.select_added_issue();
+                        self.follow_preview();
+                    }
+                    Err(e) => {
+                        self.state.toasts.error(e);
+                    }
+                }
+            }
+            BackgroundMessage::IssueImported(result) => match result {
+                Ok(detail) => {
+                    let name = detail.source.short_id.clone().unwrap_or_else(|| detail.id.clone());
+                    self.state.toasts.success(format!("Added {}", name));
+                    self.state.select_after_reload = Some(detail.id);
+                    self.state.is_refreshing = true;
+                    self.bg.spawn_list_reload();
+                }
+                Err(e) => {
+                    self.state.toasts.error(e);
+                }
+            },
+            BackgroundMessage::IssueCreated(result) => match result {
+                Ok(detail) => {
+                    self.state.new_issue = None;
+                    let name = detail.source.short_id.clone().unwrap_or_else(|| detail.id.clone());
+                    self.state.toasts.success(format!("Created {}", name));
+                    self.state.select_after_reload = Some(detail.id);
+                    self.state.is_refreshing = true;
+                    self.bg.spawn_list_reload();
+                }
+                Err(e) => {
+                    if let Some(form) = &mut self.state.new_issue {
+                        form.submitting = false;
+                    }
+                    self.state.toasts.error(e);
+                }
+            },
+            BackgroundMessage::ProjectUnreachable { project, error } => {
+                self.state.toasts.warning(format!("{}: {}", project, error));
+            }
+            BackgroundMessage::DetailRefreshComplete(result) => {
+                self.state.is_refreshing_detail = false;
+                match result {
+                    Ok(detail) => {
+                        // Ignore results for an issue we've navigated away from
+                        let still_open = self.state.screen != Screen::List
+                            && self.state.current_issue.as_ref().is_some_and(|issue| issue.id == detail.id);
+                        if still_open {
+                            let previous = self.state.current_issue.replace(*detail);
+                            self.state.detail_lines.invalidate();
+                            self.apply_shown_event();
+                            self.note_issue_changes(previous);
+                        }
+                    }
+                    Err(e) => {
+                        self.state.toasts.error(e);
+                    }
+                }
+            }
+            BackgroundMessage::AnalysisEvents(events) => {
+                for event in events {
+                    let finished = match &event {
+                        AnalysisEvent::Complete { .. } => Some("pending_approval"),
+                        AnalysisEvent::Error { .. } => Some("error"),
+                        _ => None,
+                    };
+                    // Check before handling: completion switches to the proposal
+                    if let (Some(status), Some(id)) = (finished, self.current_issue_id()) {
+                        self.announce_transition(&id, "analyzing", status);
+                    }
+                    analysis::handle_analysis_event(&mut self.state, event);
+                }
+            }
+            BackgroundMessage::ImplementationEvents { issue_id, events } => {
+                if self.state.implementation_issue.as_deref() != Some(issue_id.as_str()) {
+                    return;
+                }
+                let finished = events
+                    .iter()
+                    .any(|event| matches!(event, AnalysisEvent::Complete { .. } | AnalysisEvent::Error { .. }));
+                for event in events {
+                    implementation::handle_implementation_event(&mut self.state, event);
+                }
+                // Pick up the review state the finished session moved to
+                if finished && self.current_issue_id().as_deref() == Some(issue_id.as_str()) {
+                    self.bg.spawn_detail_reload(issue_id);
+                }
+            }
+            BackgroundMessage::ServerEvent(ServerEvent::IssueUpdated(event)) => {
+                self.apply_issue_update(event);
+            }
+            BackgroundMessage::PreviewDue { issue_id } => {
+                // Still selected once the selection settled
+                let due = self
+                    .state
+                    .preview
+                    .as_ref()
+                    .is_some_and(|p| p.issue_id == issue_id && p.detail.is_none());
+                if due {
+                    self.bg.spawn_peek(issue_id);
+                }
+            }
+            BackgroundMessage::PeekLoaded { issue_id, result } => {
+                if let Some(preview) = &mut self.state.preview {
+                    if preview.issue_id == issue_id {
+                        preview.detail = Some(result.clone());
+                    }
+                }
+                if let Some(peek) = &mut self.state.peek {
+                    if peek.issue_id == issue_id {
+                        peek.detail = Some(result);
+                    }
+                }
+            }
+            BackgroundMessage::RawIssueLoaded { issue_id, result } => {
+                if let Some(view) = &mut self.state.json_view {
+                    if view.issue_id == issue_id {
+                        if let Ok(value) = &result {
+                            view.folded = json_tree::containers_from(value, json_tree::UNFOLDED_DEPTH);
+                        }
+                        view.value = Some(result);
+                    }
+                }
+            }
+            BackgroundMessage::TagStatsLoaded { issue_id, result } => {
+                if let Some(breakdown) = &mut self.state.tag_breakdown {
+                    if breakdown.issue_id == issue_id {
+                        breakdown.tags = Some(result);
+                    }
+                }
+            }
+            BackgroundMessage::WatchCheckDue => {
+                self.state.watch_check_scheduled = false;
+                self.check_watches();
+            }
+            BackgroundMessage::WatchChecked { issue_id, result } => {
+                let events = match result {
+                    Ok(events) => events,
+                    Err(e) => {
+                        // Tried again at the next check
+                        debug!(issue_id, error = %e, "Fix watch check failed");
+                        return;
+                    }
+                };
+                let Some(watch) = self.state.watches.iter_mut().find(|w| w.issue_id == issue_id) else {
+                    return;
+                };
+                if watch.check(events) {
+                    let new = watch.new_events.unwrap_or_default();
+                    let message = format!(
+                        "{} has {} new event{} since its fix",
+                        watch.short_id,
+                        new,
+                        if new == 1 { "" } else { "s" }
+                    );
+                    self.state.toasts.warning(message);
+                    self.save_workspace();
+                }
+            }
+            BackgroundMessage::CapabilitiesLoaded(capabilities) => {
+                self.state.capabilities = capabilities;
+                self.state.startup.record("capabilities");
+                // A server without a queue has nothing to poll
+                if !self.state.capabilities.supports(Capability::Queue) {
+                    self.state.analysis_queue.clear();
+                    self.state.next_queue_poll = None;
+                }
+            }
+            BackgroundMessage::QueueStatusLoaded(result) => {
+                let queued = result.map(|response| response.queued).unwrap_or_else(|e| {
+                    // Older servers don't queue, so have no endpoint for it
+                    debug!(error = %e, "No analysis queue");
+                    Vec::new()
+                });
+                self.state.next_queue_poll =
+                    (!queued.is_empty()).then(|| Instant::now() + QUEUE_POLL_INTERVAL);
+                self.state.analysis_queue = queued
+                    .into_iter()
+                    .map(|entry| (entry.issue_id.clone(), entry))
+                    .collect();
+            }
+            BackgroundMessage::IssueEventsLoaded { issue_id, result } => {
+                if let Some(picker) = &mut self.state.event_picker {
+                    if picker.issue_id == issue_id {
+                        picker.events = Some(result);
+                    }
+                }
+            }
+            BackgroundMessage::OccurrencesLoaded { issue_id, result } => {
+                let Some(browser) = self.state.event_browser.as_mut().filter(|b| b.issue_id == issue_id) else {
+                    return;
+                };
+                browser.loading_page = false;
+                match result {
+                    Ok(page) => {
+                        browser.events.extend(page.events);
+                        browser.complete = page.next_cursor.is_none();
+                        browser.next_cursor = page.next_cursor;
+                        if let Some(target) = browser.pending.take() {
+                            self.browse_events(target);
+                        }
+                    }
+                    Err(e) => {
+                        browser.pending = None;
+                        self.state.toasts.error(e);
+                    }
+                }
+            }
+            BackgroundMessage::OccurrenceLoaded { issue_id, event_id, result } => {
+                let Some(browser) = self.state.event_browser.as_mut().filter(|b| b.issue_id == issue_id) else {
+                    return;
+                };
+                match result {
+                    Ok(source) => {
+                        browser.details.insert(event_id, *source);
+                        self.apply_shown_event();
+                    }
+                    Err(e) => {
+                        // Back to the event still on screen
+                        if browser.shown.as_ref().is_some_and(|shown| shown.id == event_id) {
+                            browser.shown = browser.applied.clone();
+                        }
+                        self.state.toasts.error(e);
+                    }
+                }
+            }
+            BackgroundMessage::PlanLoaded { issue_id, result } => {
+                if let Some(preview) = &mut self.state.plan_preview {
+                    if preview.issue_id == issue_id {
+                        preview.plan = Some(result);
+                    }
+                }
+            }
+            BackgroundMessage::CritiqueEvent { issue_id, event } => {
+                let Some(critique) = &mut self.state.critique else {
+                    return;
+                };
+                if critique.issue_id != issue_id {
+                    return;
+                }
+                match event {
+                    CritiqueEvent::Started { reviewer } => critique.reviewer = Some(reviewer),
+                    CritiqueEvent::TextDelta { delta } => critique.text.push_str(&delta),
+                    CritiqueEvent::Complete => critique.status = CritiqueStatus::Done,
+                    CritiqueEvent::Error { message } => {
+                        critique.status = CritiqueStatus::Failed(message)
+                    }
+                    CritiqueEvent::Other => {}
+                }
+            }
+            BackgroundMessage::CritiqueEnded { issue_id, error } => {
+                if let Some(critique) = &mut self.state.critique {
+                    if critique.issue_id == issue_id && critique.status == CritiqueStatus::Streaming {
+                        critique.status = match error {
+                            Some(e) => CritiqueStatus::Failed(e),
+                            None => CritiqueStatus::Done,
+                        };
+                    }
+                }
+            }
+            BackgroundMessage::BlameComplete { issue_id, frame, result } => {
+                if self.current_issue_id().as_deref() != Some(issue_id.as_str()) {
+                    return;
+                }
+                if let Err(e) = &result {
+                    self.state.toasts.warning(format!("git blame: {}", e));
+                }
+                self.state.frame_blame.insert(frame, result);
+            }
+            BackgroundMessage::TestOutput { issue_id, line } => {
+                if let Some(run) = &mut self.state.test_run {
+                    if run.issue_id == issue_id {
+                        run.lines.push(ActivityLine {
+                            icon: "  ",
+                            text: line,
+                            style: ActivityStyle::Normal,
+                        });
+                    }
+                }
+            }
+            BackgroundMessage::TestsFinished { issue_id, result } => {
+                let Some(run) = &mut self.state.test_run else {
+                    return;
+                };
+                // A cancelled run has already said so
+                if run.issue_id != issue_id || run.status != TestStatus::Running {
+                    return;
+                }
+                let duration = run.started.elapsed();
+                run.duration = Some(duration);
+                run.status = match result {
+                    Ok(Some(0)) => TestStatus::Passed,
+                    Ok(Some(code)) => TestStatus::Failed(format!("exit code {}", code)),
+                    Ok(None) => TestStatus::Failed("killed by a signal".to_string()),
+                    Err(e) => TestStatus::Failed(e),
+                };
+                let (icon, style, summary) = match &run.status {
+                    TestStatus::Passed => ("✓", ActivityStyle::Success, "Tests passed".to_string()),
+                    TestStatus::Failed(reason) => {
+                        ("✗", ActivityStyle::Error, format!("Tests failed: {}", reason))
+                    }
+                    TestStatus::Running | TestStatus::Cancelled => unreachable!(),
+                };
+                let secs = duration.as_secs();
+                let text = format!("{} in {}:{:02}", summary, secs / 60, secs % 60);
+                run.lines.push(ActivityLine {
+                    icon,
+                    text: text.clone(),
+                    style,
+                });
+                match run.status {
+                    TestStatus::Passed => self.state.toasts.success(text),
+                    _ => self.state.toasts.error(text),
+                }
+            }
+            BackgroundMessage::BranchLogLoaded { issue_id, result, at_risk } => {
+                if let Some(dialog) = &mut self.state.finish_review {
+                    if dialog.issue_id == issue_id {
+                        dialog.log = Some(result);
+                        dialog.at_risk = Some(at_risk);
+                    }
+                }
+            }
+            BackgroundMessage::PullRequestOpened { issue_id, result } => match result {
+                Ok(url) => {
+                    self.state.toasts.success(format!("Opened {} (URL copied)", url));
+                    self.state.pending_clipboard = Some(url.clone());
+                    self.state.pull_requests.insert(issue_id, PullRequest::Opened(url));
+                }
+                Err(e) => {
+                    self.state.pull_requests.remove(&issue_id);
+                    self.state.toasts.error(format!("Failed to open pull request: {}", e));
+                }
+            },
+            BackgroundMessage::WorktreesLoaded(result) => {
+                let worktrees = &mut self.state.worktrees;
+                if let Ok(list) = &result {
+                    worktrees.selected = worktrees.selected.min(list.len().saturating_sub(1));
+                }
+                worktrees.list = Some(result);
+            }
+            BackgroundMessage::WorktreesCleaned(result) => {
+                match result {
+                    Ok(done) => self.state.toasts.success(done),
+                    Err(e) => self.state.toasts.error(format!("Cleanup failed: {}", e)),
+                }
+                self.reload_worktrees();
+            }
+            BackgroundMessage::ReviewDiffLoaded { issue_id, result } => {
+                if self.state.review_issue.as_deref() != Some(issue_id.as_str()) {
+                    return;
+                }
+                let files = result.as_ref().map_or(0, |files| files.len());
+                self.state.review_file = self.state.review_file.min(files.saturating_sub(1));
+                self.state.review_diff = Some(result);
+            }
+            BackgroundMessage::ServerStreamEnded(reason) => {
+                debug!(%reason, "Live updates disabled");
+            }
+            BackgroundMessage::AnalysisReconnecting { attempt, error } => {
+                self.state.analysis_lines.push(ActivityLine {
+                    icon: "↻",
+                    text: format!("Connection lost ({}), reconnecting (attempt {})...", error, attempt),
+                    style: ActivityStyle::Dimmed,
+                });
+            }
+            BackgroundMessage::ImplementationReconnecting { issue_id, attempt, error } => {
+                if self.state.implementation_issue.as_deref() != Some(issue_id.as_str()) {
+                    return;
+                }
+                self.state.implementation_lines.push(ActivityLine {
+                    icon: "↻",
+                    text: format!("Connection lost ({}), reconnecting (attempt {})...", error, attempt),
+                    style: ActivityStyle::Dimmed,
+                });
+            }
+            BackgroundMessage::ImplementationStreamEnded { issue_id, error } => {
+                if self.state.implementation_issue.as_deref() != Some(issue_id.as_str()) {
+                    return;
+                }
+                self.state.is_streaming_implementation = false;
+                self.state.implementation_progress.finish();
+                if let Some(err) = error {
+                    self.state.implementation_lines.push(ActivityLine {
+                        icon: "✗",
+                        text: format!("Stream error: {}", err),
+                        style: ActivityStyle::Error,
+                    });
+                }
+            }
+            BackgroundMessage::AnalysisStreamEnded(error) => {
+                self.state.is_streaming_analysis = false;
+                self.state.analysis_progress.finish();
+                if let Some(err) = error {
+                    self.state.analysis_lines.push(ActivityLine {
+                        icon: "✗",
+                        text: format!("Stream error: {}", err),
+                        style: ActivityStyle::Error,
+                    });
+
+                    // We may have missed the final events; pick up the end state
+                    if let Some(id) = self.state.current_issue.as_ref().map(|i| i.id.clone()) {
+                        self.bg.spawn_detail_reload(id);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Patch the list (and open issue) with a live state change.
+    fn apply_issue_update(&mut self, event: IssueUpdatedEvent) {
+        let Some(issue) = self.state.issues.iter_mut().find(|i| i.id == event.id) else {
+            // Not in our list yet; reload to pick it up
+            self.bg.spawn_list_reload();
+            return;
+        };
+
+        let previous = std::mem::replace(&mut issue.status, event.status.clone());
+        if let Some(updated_at) = event.updated_at {
+            issue.updated_at = updated_at;
+        }
+
+        // The list only carries a status string; fetch the full state
+        // (proposal, worktree) for the open issue.
+        let is_current = self
+            .state
+            .current_issue
+            .as_ref()
+            .is_some_and(|d| d.id == event.id && d.status != event.status);
+        let queue_changed = previous != event.status
+            && (previous == "analyzing" || event.status == "analyzing");
+        self.announce_transition(&event.id, &previous, &event.status);
+        if is_current {
+            self.bg.spawn_detail_reload(event.id);
+        }
+        if queue_changed {
+            self.sync_queue();
+        }
+        // A new status can move the issue in or out of a filtered list
+        self.state.apply_view();
+    }
+
+    /// Fetch queue positions if any issue is waiting on an analysis,
+    /// or forget them if none is.
+    fn sync_queue(&mut self) {
+        let waiting = self.state.issues.iter().any(|i| i.status == "analyzing");
+        if waiting && self.state.capabilities.supports(Capability::Queue) {
+            self.bg.spawn_queue_status();
+        } else {
+            self.state.analysis_queue.clear();
+            self.state.next_queue_poll = None;
+        }
+    }
+
+    fn current_issue_id(&self) -> Option<String> {
+        self.state.current_issue.as_ref().map(|i| i.id.clone())
+    }
+
+    /// Announce that `from -> to` ended long-running agent work: ring the
+    /// bell for analyses, and send a desktop notification if the user isn't
+    /// watching the issue.
+    fn announce_transition(&mut self, id: &str, from: &str, to: &str) {
+        let Some(title) = notify::transition_title(from, to) else {
+            return;
+        };
+
+        let key = (id.to_string(), to.to_string());
+        if self.state.last_announced.as_ref() == Some(&key) {
+            // Already announced from the analysis stream or the events stream
+            return;
+        }
+        self.state.last_announced = Some(key);
+
+        if from == "analyzing" {
+            self.ring_bell(title);
+        }
+
+        if !self.state.desktop_notifications {
+            return;
+        }
+        let watching = self.state.terminal_focused
+            && matches!(self.state.screen, Screen::Detail | Screen::Analysis)
+            && self.state.current_issue.as_ref().is_some_and(|i| i.id == id);
+        if watching {
+            return;
+        }
+
+        let body = match self.state.issues.iter().find(|i| i.id == id) {
+            Some(issue) => format!("{}: {}", issue.short_id, issue.title),
+            None => id.to_string(),
+        };
+        notify::send(title, &body);
+    }
+
+    /// Ring the terminal bell or flash the status bar, per the bell mode.
+    /// With reduced motion the visual bell is a toast saying what happened.
+    fn ring_bell(&mut self, what: &str) {
+        match self.state.bell_mode {
+            BellMode::Off => {}
+            BellMode::Audible => self.state.bell_pending = true,
+            BellMode::Visual if self.state.theme.reduced_motion => self.state.toasts.info(what),
+            BellMode::Visual => {
+                self.state.bell_flash_until = Some(Instant::now() + BELL_FLASH_DURATION);
+            }
+        }
+    }
+
+    /// Take a pending audible bell, for the main loop to write out.
+    pub fn take_bell(&mut self) -> bool {
+        std::mem::take(&mut self.state.bell_pending)
+    }
+
+    // === Data loading ===
+
+    /// Subscribe to server-wide events for live list updates.
+    pub fn start_server_events(&mut self) {
+        self.bg.spawn_server_events();
+    }
+
+    /// List the issues of other projects' servers alongside this one's,
+    /// with actions on them sent to the server they came from.
+    pub fn aggregate(&mut self, primary: &str, servers: Vec<ServerConfig>) {
+        for server in servers {
+            self.bg.add_server(primary, server.name, server.url, server.token);
+        }
+        self.state.projects = self.bg.projects();
+    }
+
+    /// Load cached issues in the background, then refresh them. With a
+    /// `launch`, glass starts a server first unless one is running on its
+    /// port, and the list says it's connecting meanwhile.
+    pub fn start_initial_load(&mut self, launch: Option<ServerLaunch>) {
+        self.state.is_loading = true;
+        if let Some(launch) = &launch {
+            self.state.server_status = ServerStatus::Connecting;
+            self.state.server_port = launch.port;
+        }
+        self.launch = launch.clone();
+        self.bg.spawn_initial_load(launch);
+    }
+
+    /// Watch a server glass is in charge of, to restart it if it's lost.
+    fn monitor_server(&self) {
+        if let Some(launch) = &self.launch {
+            self.bg.spawn_server_monitor(launch.port);
+        }
+    }
+
+    /// The server stopped answering: let go of it (stopping it if glass
+    /// started it and it's hung rather than gone) and start it again.
+    fn restart_server(&mut self, error: String) {
+        let Some(launch) = self.launch.clone() else {
+            return;
+        };
+        let cause = match self.server.take() {
+            Some(mut server) => match server.exit_status() {
+                Some(status) => StartFailure {
+                    error: format!("Server exited with {}", status),
+                    output: server.output_tail(background::FAILURE_OUTPUT_LINES),
+                },
+                None => StartFailure { error: format!("Server hung: {}", error), output: Vec::new() },
+            },
+            None => StartFailure { error: format!("Server lost: {}", error), output: Vec::new() },
+        };
+        warn!(error = %cause.error, "Server lost, restarting it");
+        self.state.toasts.warning("Server lost, restarting it");
+        self.state.server_status = ServerStatus::Restarting { attempt: 1, cause };
+        self.bg.spawn_server_restart(launch, 1);
+    }
+
+    /// Start a background refresh from Sentry.
+    pub fn start_refresh(&mut self) {
+        if self.state.is_refreshing {
+            return;
+        }
+
+        self.state.is_refreshing = true;
+        self.bg.spawn_list_refresh();
+
+        // Any refresh restarts the auto-refresh countdown
+        if let Some(interval) = self.state.auto_refresh_interval {
+            self.state.next_auto_refresh = Some(Instant::now() + interval);
+        }
+    }
+
+    /// Load cached issue detail from server (fast).
+    pub async fn load_cached_detail(&mut self) {
+        let Some(issue_id) = self.state.selected_issue_id().map(|s| s.to_string()) else {
+            return;
+        };
+
+        match self.bg.client_for(&issue_id).get_issue(&issue_id).await {
+            Ok(detail) => {
+                // If issue is in Analyzing state, connect to SSE stream
+                if matches!(detail.state, IssueState::Analyzing { .. }) {
+                    self.start_analysis_stream(&detail.id);
+                }
+                self.state.current_issue = Some(detail);
+                self.state.detail_lines.invalidate();
+                self.apply_shown_event();
+            }
+            Err(e) => {
+                self.state.toasts.error(format!("Failed to fetch issue: {}", e));
+            }
+        }
+    }
+
+    /// Start a background refresh for the current issue from Sentry.
+    pub fn start_detail_refresh(&mut self) {
+        if self.state.is_refreshing_detail {
+            return;
+        }
+
+        let Some(issue_id) = self.state.selected_issue_id().map(|s| s.to_string()) else {
+            return;
+        };
+
+        self.state.is_refreshing_detail = true;
+        self.bg.spawn_detail_refresh(issue_id);
+    }
+
+    /// Refresh current issue detail from server (sync, for use after actions).
+    pub async fn refresh_current_issue(&mut self) {
+        let Some(issue_id) = self.state.selected_issue_id().map(|s| s.to_string()) else {
+            return;
+        };
+
+        self.state.is_loading = true;
+
+        match self.bg.client_for(&issue_id).get_issue(&issue_id).await {
+            Ok(detail) => {
+                self.state.current_issue = Some(detail);
+                self.state.detail_lines.invalidate();
+                self.apply_shown_event();
+            }
+            Err(e) => {
+                self.state.toasts.error(format!("Failed to fetch issue: {}", e));
+            }
+        }
+
+        self.state.is_loading = false;
+    }
+
+    // === Navigation ===
+
+    /// Move selection by delta (positive = down, negative = up), stepping
+    /// over issues in folded groups.
+    pub fn move_selection(&mut self, delta: i32) {
+        if self.state.rows.is_empty() {
+            return;
+        }
+
+        let last = self.state.rows.len() - 1;
+        let mut index = self.state.selected_index;
+        for _ in 0..delta.unsigned_abs() {
+            let mut next = index;
+            loop {
+                next = if delta > 0 { next + 1 } else { next.wrapping_sub(1) };
+                if next > last || !self.state.is_folded(next) {
+                    break;
+                }
+            }
+            if next > last {
+                break;
+            }
+            index = next;
+        }
+        self.state.selected_index = index;
+        self.follow_peek();
+    }
+
+    /// Jump to top of list.
+    pub fn jump_to_top(&mut self) {
+        self.state.selected_index = 0;
+        self.follow_peek();
+    }
+
+    /// Jump to bottom of list.
+    pub fn jump_to_bottom(&mut self) {
+        if !self.state.rows.is_empty() {
+            self.state.selected_index = self.state.rows.len() - 1;
+            self.state.clamp_selection();
+        }
+        self.follow_peek();
+    }
+
+    /// Fold or unfold the selected issue's group.
+    pub fn toggle_group(&mut self) {
+        let Some(group) = self.state.view.group else {
+            return;
+        };
+        let Some(issue) = self.state.selected_issue() else {
+            return;
+        };
+        let key = group.key(issue);
+        if !self.state.collapsed_groups.remove(&key) {
+            self.state.collapsed_groups.insert(key);
+            self.state.clamp_selection();
+        }
+    }
+
+    /// Fold every group, or unfold them all if they already are.
+    pub fn toggle_all_groups(&mut self) {
+        let Some(group) = self.state.view.group else {
+            return;
+        };
+        let keys: HashSet<String> = self.state.listed().map(|issue| group.key(issue)).collect();
+        if keys.is_subset(&self.state.collapsed_groups) {
+            self.state.collapsed_groups.clear();
+        } else {
+            self.state.collapsed_groups = keys;
+            self.state.clamp_selection();
+        }
+    }
+
+    /// Open or close the peek popup for the selected issue.
+    pub fn toggle_peek(&mut self) {
+        if self.state.peek.take().is_none() {
+            self.peek_selected();
+        }
+    }
+
+    /// Point an open peek at the newly selected issue.
+    fn follow_peek(&mut self) {
+        let moved = self
+            .state
+            .peek
+            .as_ref()
+            .is_some_and(|p| Some(p.issue_id.as_str()) != self.state.selected_issue_id());
+        if moved {
+            self.peek_selected();
+        }
+    }
+
+    fn peek_selected(&mut self) {
+        let Some(issue_id) = self.state.selected_issue_id().map(str::to_string) else {
+            self.state.peek = None;
+            return;
+        };
+        self.state.peek = Some(Peek {
+            issue_id: issue_id.clone(),
+            detail: None,
+        });
+        self.bg.spawn_peek(issue_id);
+    }
+
+    /// Point the preview pane at the selected issue, loading it once the
+    /// selection has settled; drop it when the list isn't split.
+    pub fn follow_preview(&mut self) {
+        if !self.state.shows_preview() {
+            self.state.preview = None;
+            return;
+        }
+        let selected = self.state.selected_issue_id().map(str::to_string);
+        if self.state.preview.as_ref().map(|p| &p.issue_id) == selected.as_ref() {
+            return;
+        }
+        self.state.preview = selected.map(|issue_id| {
+            self.bg.spawn_preview_delay(issue_id.clone());
+            Peek { issue_id, detail: None }
+        });
+    }
+
+    // === Prompts ===
+
+    /// Open a prompt with an empty input.
+    pub fn open_prompt(&mut self, prompt: Prompt) {
+        self.state.prompt = Some(prompt);
+        self.state.input = Some(TextInput::new());
+    }
+
+    /// Ask for notes to pass on with the approval.
+    pub fn open_approve_prompt(&mut self) {
+        if self.awaiting_approval() {
+            self.open_prompt(Prompt::ApproveInstructions);
+        }
+    }
+
+    /// Ask why the proposal is being rejected.
+    pub fn open_reject_prompt(&mut self) {
+        if self.awaiting_approval() {
+            self.open_prompt(Prompt::RejectReason);
+        }
+    }
+
+    /// Ask for notes if the server takes them, or approve straight away.
+    pub async fn start_approval(&mut self) {
+        if self.state.capabilities.supports(Capability::ProposalNotes) {
+            self.open_approve_prompt();
+        } else if self.awaiting_approval() {
+            self.approve_proposal(None).await;
+            self.back_from_proposal();
+        }
+    }
+
+    /// Ask for a reason if the server takes one, or reject straight away.
+    pub async fn start_rejection(&mut self) {
+        if self.state.capabilities.supports(Capability::ProposalNotes) {
+            self.open_reject_prompt();
+        } else if self.awaiting_approval() {
+            self.reject_proposal(None).await;
+            self.back_from_proposal();
+        }
+    }
+
+    fn awaiting_approval(&self) -> bool {
+        matches!(
+            self.state.current_issue.as_ref().map(|i| &i.state),
+            Some(IssueState::PendingApproval { .. })
+        )
+    }
+
+    /// Ask for a Sentry issue to add to the list.
+    pub fn open_add_issue_prompt(&mut self) {
+        self.open_prompt(Prompt::AddIssue);
+    }
+
+    /// Open the form for creating an issue by hand.
+    pub fn open_new_issue(&mut self) {
+        self.state.new_issue = Some(NewIssueForm::default());
+    }
+
+    /// Apply an editing key to the focused field.
+    pub fn edit_new_issue(&mut self, key: KeyEvent) {
+        let Some(form) = self.state.new_issue.as_mut().filter(|f| !f.submitting) else {
+            return;
+        };
+        if key.code == KeyCode::Enter {
+            form.focused_input().insert_char('\n');
+        } else {
+            form.focused_input().handle_key(key);
+        }
+    }
+
+    pub fn move_new_issue_focus(&mut self, delta: isize) {
+        if let Some(form) = &mut self.state.new_issue {
+            form.focus.traverse(delta);
+        }
+    }
+
+    /// Send the form to the server; it stays open until the issue is saved
+    /// so nothing typed is lost if that fails.
+    pub fn submit_new_issue(&mut self) {
+        let Some(form) = self.state.new_issue.as_mut().filter(|f| !f.submitting) else {
+            return;
+        };
+        match form.request() {
+            Ok(request) => {
+                form.submitting = true;
+                self.bg.spawn_issue_create(request);
+            }
+            Err(reason) => {
+                form.focus.focus(NewIssueField::Title);
+                self.state.toasts.warning(reason);
+            }
+        }
+    }
+
+    pub fn cancel_new_issue(&mut self) {
+        self.state.new_issue = None;
+    }
+
+    /// Ask for guidance to re-run the analysis with.
+    pub fn open_analyze_prompt(&mut self) {
+        if self.state.current_issue.is_none() || self.state.is_refreshing_detail {
+            self.state.toasts.warning("Please wait for issue details to load");
+            return;
+        }
+        self.open_prompt(Prompt::AnalyzeInstructions);
+    }
+
+    /// Apply an editing key to the open prompt.
+    pub fn edit_prompt(&mut self, key: KeyEvent) {
+        let multiline = self.state.prompt.is_some_and(|p| p.multiline());
+        if let Some(input) = self.state.input.as_mut() {
+            if key.code == KeyCode::Enter && multiline {
+                input.insert_char('\n');
+            } else {
+                input.handle_key(key);
+            }
+        }
+    }
+
+    /// Close the prompt, discarding what was typed.
+    pub fn cancel_prompt(&mut self) {
+        self.state.prompt = None;
+        self.state.input = None;
+    }
+
+    // === Command line ===
+
+    pub fn open_command_line(&mut self) {
+        self.state.command_line = Some(CommandLine::default());
+        self.state.input = Some(TextInput::new());
+    }
+
+    pub fn cancel_command(&mut self) {
+        self.state.command_line = None;
+        self.state.input = None;
+    }
+
+    /// Edit the command; backspace on an empty one closes it, as in vim.
+    pub fn edit_command(&mut self, key: KeyEvent) {
+        let Some(input) = self.state.input.as_mut() else {
+            return;
+        };
+        if key.code == KeyCode::Backspace && input.is_empty() {
+            self.cancel_command();
+            return;
+        }
+        if input.handle_key(key) {
+            if let Some(line) = &mut self.state.command_line {
+                line.completions = None;
+            }
+        }
+    }
+
+    /// Complete the command's last word, cycling through the candidates on
+    /// repeated presses.
+    pub fn complete_command(&mut self) {
+        let (Some(line), Some(input)) = (&mut self.state.command_line, &mut self.state.input) else {
+            return;
+        };
+        let completion = match &mut line.completions {
+            Some((candidates, index)) => {
+                *index = (*index + 1) % candidates.len();
+                candidates[*index].clone()
+            }
+            None => {
+                let candidates = screens::complete_command(input.text(), &self.state.issues, &self.state.projects);
+                let Some(first) = candidates.first().cloned() else {
+                    return;
+                };
+                if candidates.len() > 1 {
+                    line.completions = Some((candidates, 0));
+                }
+                first
+            }
+        };
+        input.clear();
+        input.insert_str(&completion);
+    }
+
+    /// Step through earlier commands; past the newest is an empty line.
+    pub fn command_history(&mut self, delta: i32) {
+        let history = &self.state.command_history;
+        let (Some(line), Some(input)) = (&mut self.state.command_line, &mut self.state.input) else {
+            return;
+        };
+        if history.is_empty() {
+            return;
+        }
+        let current = line.history_index.unwrap_or(history.len()) as i32;
+        let index = (current + delta).clamp(0, history.len() as i32) as usize;
+        line.history_index = (index < history.len()).then_some(index);
+        line.completions = None;
+        input.clear();
+        if let Some(command) = history.get(index) {
+            input.insert_str(command);
+        }
+    }
+
+    /// Close the command line and parse the command into its action.
+    pub fn submit_command(&mut self) -> Action {
+        self.state.command_line = None;
+        let Some(input) = self.state.input.take() else {
+            return Action::None;
+        };
+        let command = input.text().trim().to_string();
+        if command.is_empty() {
+            return Action::None;
+        }
+        if self.state.command_history.last() != Some(&command) {
+            self.state.command_history.push(command.clone());
+        }
+        match screens::parse_command(&command) {
+            Ok(action) if screens::supported(self, &action) => action,
+            Ok(_) => {
+                self.state.toasts.warning("The server doesn't support that");
+                Action::None
+            }
+            Err(e) => {
+                self.state.toasts.error(e);
+                Action::None
+            }
+        }
+    }
+
+    /// Add an issue by Sentry URL or ID, even one the server's query
+    /// doesn't match; it's selected once it's in the list.
+    pub fn add_issue(&mut self, reference: String) {
+        self.state.toasts.info(format!("Adding {}…", reference));
+        self.bg.spawn_issue_import(reference);
+    }
+
+    /// Select the issue just added, once a reload has brought it in.
+    fn select_added_issue(&mut self) {
+        let Some(id) = self.state.select_after_reload.as_deref() else {
+            return;
+        };
+        if !self.state.issues.iter().any(|issue| issue.id == id) {
+            // An older refresh finishing; the reload is still to come
+            return;
+        }
+        // Only on the list; elsewhere it'd pull someone off what they're doing
+        if let Some(id) = self.state.select_after_reload.take() {
+            if self.state.screen == Screen::List {
+                self.select_issue(&id);
+            }
+        }
+    }
+
+    /// Show only issues matching `filters`; none shows everything.
+    pub fn filter_issues(&mut self, filters: Vec<IssueFilter>) {
+        self.state.view.filters = filters;
+        self.state.apply_view();
+        self.save_workspace();
+        if !self.state.view.filters.is_empty() {
+            let shown = self.state.rows.len();
+            let total = self.state.issues.len();
+            self.state.toasts.info(format!("Showing {} of {} issues", shown, total));
+        }
+    }
+
+    pub fn sort_issues(&mut self, sort: Option<IssueSort>) {
+        self.state.view.sort = sort;
+        self.state.apply_view();
+        self.save_workspace();
+    }
+
+    /// List the issues of a group together; `None` lists them one by one.
+    pub fn group_issues(&mut self, group: Option<GroupBy>) {
+        self.state.view.group = group;
+        self.state.collapsed_groups.clear();
+        self.state.apply_view();
+        self.save_workspace();
+    }
+
+    /// Show or hide the issues the ignore rules match.
+    pub fn toggle_ignored(&mut self) {
+        self.state.show_ignored = !self.state.show_ignored;
+        self.state.apply_view();
+        let count = self.state.ignored_count();
+        let verb = if self.state.show_ignored { "Showing" } else { "Hiding" };
+        let noun = if count == 1 { "issue" } else { "issues" };
+        self.state.toasts.info(format!("{} {} ignored {}", verb, count, noun));
+    }
+
+    /// Select an issue by short ID (or ID), clearing the filter (and
+    /// showing ignored issues) if it hides it. Returns whether it was found.
+    pub fn select_issue(&mut self, id: &str) -> bool {
+        let matches = |issue: &Issue| issue.short_id.eq_ignore_ascii_case(id) || issue.id == id;
+        let Some(issue) = self.state.issues.iter().find(|issue| matches(issue)) else {
+            self.state.toasts.warning(format!("No issue {} in the list", id));
+            return false;
+        };
+        let id = issue.id.clone();
+        if self.state.row_of(&id).is_none() {
+            if self.state.ignore.matches(issue) {
+                self.state.show_ignored = true;
+            }
+            self.filter_issues(Vec::new());
+        }
+        let Some(index) = self.state.row_of(&id) else {
+            self.state.toasts.warning(format!("No issue {} in the list", id));
+            return false;
+        };
+        if self.state.screen != Screen::List {
+            self.back_to_list();
+        }
+        self.state.selected_index = index;
+        true
+    }
+
+    /// Change a display option from `:set`.
+    pub fn set_option(&mut self, name: &str, value: &str) {
+        let on = value == "on";
+        let theme = &mut self.state.theme;
+        match name {
+            "theme" | "palette" if value == "light" => theme.palette = Palette::Light,
+            "theme" => theme.palette = Palette::Default,
+            "palette" if value == "color-blind" => theme.palette = Palette::ColorBlind,
+            "palette" => theme.palette = Palette::Default,
+            "icons" if value == "ascii" => theme.icons = IconSet::Ascii,
+            "icons" => theme.icons = IconSet::Unicode,
+            "badges" => theme.status_badges = on,
+            "summary" => theme.summary_line = on,
+            "motion" => {
+                theme.reduced_motion = value == "reduced";
+                self.state.apply_motion();
+            }
+            "linenumbers" => self.state.diff_line_numbers = on,
+            "times" => theme.absolute_times = value == "absolute",
+            "numbers" => {
+                theme.numbers = match value {
+                    "grouped" => NumberStyle::Grouped,
+                    "compact" => NumberStyle::Compact,
+                    _ => NumberStyle::Plain,
+                }
+            }
+            "preview" => theme.list_only = !on,
+            _ => return,
+        }
+        self.state.toasts.info(format!("{}={}", name, value));
+    }
+
+    /// Close the prompt, returning it with the trimmed text (if any).
+    fn take_prompt(&mut self) -> Option<(Prompt, Option<String>)> {
+        let prompt = self.state.prompt.take()?;
+        let text = self
+            .state
+            .input
+            .take()
+            .map(|input| input.text().trim().to_string())
+            .filter(|text| !text.is_empty());
+        Some((prompt, text))
+    }
+
+    /// Close the prompt and run its action with the trimmed text.
+    pub async fn submit_prompt(&mut self) {
+        let Some((prompt, text)) = self.take_prompt() else {
+            return;
+        };
+
+        match prompt {
+            Prompt::RejectReason => {
+                self.reject_proposal(text).await;
+                self.back_from_proposal();
+            }
+            Prompt::AnalyzeInstructions => self.analyze_issue(text, None).await,
+            Prompt::AddIssue => {
+                if let Some(reference) = text {
+                    self.add_issue(reference);
+                }
+            }
+            Prompt::ApproveInstructions => {
+                self.approve_proposal(text).await;
+                self.back_from_proposal();
+            }
+        }
+    }
+
+    // === Merging ===
+
+    /// Mark or unmark the selected issue for merging.
+    pub fn toggle_merge_mark(&mut self) {
+        let Some(issue_id) = self.state.selected_issue_id().map(str::to_string) else {
+            return;
+        };
+        match self.state.merge_marks.iter().position(|id| *id == issue_id) {
+            Some(index) => {
+                self.state.merge_marks.remove(index);
+            }
+            None => self.state.merge_marks.push(issue_id),
+        }
+    }
+
+    /// Pin the selected issue to the summary line, or unpin it.
+    pub fn toggle_pin(&mut self) {
+        if !self.state.theme.summary_line {
+            self.state
+                .toasts
+                .warning("Pins show in the summary line; set summary_line under [tui.theme] in glass.toml");
+            return;
+        }
+        let Some(issue_id) = self.state.open_issue_id().map(str::to_string) else {
+            return;
+        };
+        let label = self.state.issue_label(&issue_id);
+        if self.state.pinned_issue.as_deref() == Some(issue_id.as_str()) {
+            self.state.pinned_issue = None;
+            self.state.toasts.info(format!("Unpinned {}", label));
+        } else {
+            self.state.pinned_issue = Some(issue_id);
+            self.state.toasts.info(format!("Pinned {}", label));
+        }
+        self.save_workspace();
+    }
+
+    /// Restore the project's saved workspace, and keep saving it from now on.
+    pub fn restore_workspace(&mut self) {
+        match Workspace::load(&self.state.project_path) {
+            Ok(workspace) => workspace.restore(&mut self.state),
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load workspace");
+                self.state.toasts.warning(format!("Ignoring saved workspace: {}", e));
+            }
+        }
+        // A project narrowed to last time may no longer be registered
+        if self.state.view.project.as_ref().is_some_and(|p| !self.state.projects.contains(p)) {
+            self.state.view.project = None;
+        }
+        self.bg.scope(self.state.view.project.as_deref());
+        self.state.persist_workspace = true;
+    }
+
+    // === Projects ===
+
+    /// Open the project switcher on the project the list is narrowed to.
+    pub fn open_project_switcher(&mut self) {
+        if self.state.projects.is_empty() {
+            self.state
+                .toasts
+                .info("Only one project is registered; add more under [[tui.servers]] or with --project-server");
+            return;
+        }
+        let current = self.state.view.project.as_ref();
+        let row = current
+            .and_then(|project| self.state.projects.iter().position(|p| p == project))
+            .map_or(0, |index| index + 1);
+        self.state.project_switcher = Some(row);
+    }
+
+    pub fn move_project_selection(&mut self, delta: isize) {
+        let last = self.state.projects.len() as isize;
+        if let Some(row) = &mut self.state.project_switcher {
+            *row = (*row as isize + delta).clamp(0, last) as usize;
+        }
+    }
+
+    pub fn close_project_switcher(&mut self) {
+        self.state.project_switcher = None;
+    }
+
+    /// Narrow the list to one project, sending new issues and refreshes to
+    /// its server, or with `None` go back to every project.
+    pub fn switch_project(&mut self, project: Option<String>) {
+        self.state.project_switcher = None;
+        let project = match project {
+            Some(name) => match self.state.projects.iter().find(|p| p.eq_ignore_ascii_case(&name)) {
+                Some(project) => Some(project.clone()),
+                None => {
+                    self.state.toasts.error(format!("No project named {}", name));
+                    return;
+                }
+            },
+            None => None,
+        };
+        if project == self.state.view.project {
+            return;
+        }
+
+        self.bg.scope(project.as_deref());
+        self.state.toasts.info(match &project {
+            Some(project) => format!("Showing {}", project),
+            None => "Showing every project".to_string(),
+        });
+        self.state.view.project = project;
+        self.state.apply_view();
+        self.save_workspace();
+    }
+
+    // === Fix watches ===
+
+    /// Start watching a just-completed issue for new events, if watches
+    /// are on. Returns how many hours it's watched for.
+    async fn watch_fix(&mut self, issue_id: &str) -> Option<u64> {
+        let hours = self.state.watch_hours?;
+        // The open issue's count may be hours old; new events are counted
+        // from what Sentry has now
+        let issue = match self.bg.client_for(issue_id).refresh_issue(issue_id).await {
+            Ok(issue) => issue,
+            Err(e) => {
+                self.state.toasts.warning(format!("Not watching for new events: {}", e));
+                return None;
+            }
+        };
+        let short_id = issue.source.short_id.clone().unwrap_or_else(|| issue_id.to_string());
+        let baseline = issue.source.event_count.unwrap_or(0);
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+
+        self.state.watches.retain(|w| w.issue_id != issue_id);
+        self.state.watches.push(FixWatch::new(issue_id.to_string(), short_id, baseline, hours, now));
+        self.schedule_watch_check();
+        self.save_workspace();
+        Some(hours)
+    }
+
+    /// Check the watches after the usual interval, unless a check is
+    /// already on its way.
+    fn schedule_watch_check(&mut self) {
+        if !self.state.watch_check_scheduled {
+            self.state.watch_check_scheduled = true;
+            self.bg.spawn_watch_wakeup(WATCH_CHECK_INTERVAL);
+        }
+    }
+
+    /// Check each running watch against Sentry. Watches that ran out
+    /// without new events are dropped with the good news; ones that saw
+    /// some stay until the issue is looked at.
+    fn check_watches(&mut self) {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let (held, kept): (Vec<FixWatch>, Vec<FixWatch>) = std::mem::take(&mut self.state.watches)
+            .into_iter()
+            .partition(|w| w.is_expired(now) && w.new_events.is_none());
+        self.state.watches = kept;
+        for watch in &held {
+            self.state.toasts.success(format!("{}: no new events since its fix", watch.short_id));
+        }
+
+        let running: Vec<String> = self
+            .state
+            .watches
+            .iter()
+            .filter(|w| !w.is_expired(now))
+            .map(|w| w.issue_id.clone())
+            .collect();
+        if !running.is_empty() {
+            self.schedule_watch_check();
+        }
+        for issue_id in running {
+            self.bg.spawn_watch_check(issue_id);
+        }
+        if !held.is_empty() {
+            self.save_workspace();
+        }
+    }
+
+    /// Stop watching the selected issue once someone opens it after it saw
+    /// new events.
+    fn acknowledge_watch(&mut self) {
+        let Some(issue_id) = self.state.selected_issue_id().map(|s| s.to_string()) else {
+            return;
+        };
+        let before = self.state.watches.len();
+        self.state
+            .watches
+            .retain(|w| w.issue_id != issue_id || w.new_events.is_none());
+        if self.state.watches.len() != before {
+            self.save_workspace();
+        }
+    }
+
+    /// Save the filters, pin and session choices for next time.
+    fn save_workspace(&self) {
+        if !self.state.persist_workspace || self.state.tutorial.is_some() {
+            return;
+        }
+        if let Err(e) = Workspace::capture(&self.state).save(&self.state.project_path) {
+            tracing::warn!(error = %e, "Failed to save workspace");
+        }
+    }
+
+    /// Forget marks for issues no longer in the list.
+    fn prune_merge_marks(&mut self) {
+        let issues = &self.state.issues;
+        self.state.merge_marks.retain(|id| issues.iter().any(|i| i.id == *id));
+    }
+
+    /// Ask which of the marked issues (plus the selected one) to keep.
+    pub fn open_merge_dialog(&mut self) {
+        let Some(selected) = self.state.selected_issue_id().map(str::to_string) else {
+            return;
+        };
+        if !self.state.merge_marks.contains(&selected) {
+            self.state.merge_marks.push(selected.clone());
+        }
+        if self.state.merge_marks.len() < 2 {
+            self.state.toasts.warning("Mark the duplicates with m first");
+            return;
+        }
+
+        let issue_ids: Vec<String> = self
+            .state
+            .issues
+            .iter()
+            .filter(|i| self.state.merge_marks.contains(&i.id))
+            .map(|i| i.id.clone())
+            .collect();
+        let primary = issue_ids.iter().position(|id| *id == selected).unwrap_or(0);
+        self.state.merge_dialog = Some(MergeDialog { issue_ids, primary });
+    }
+
+    /// Pick a different primary in the merge dialog.
+    pub fn move_merge_primary(&mut self, delta: i32) {
+        if let Some(dialog) = &mut self.state.merge_dialog {
+            let last = dialog.issue_ids.len() as i32 - 1;
+            dialog.primary = (dialog.primary as i32 + delta).clamp(0, last) as usize;
+        }
+    }
+
+    pub fn cancel_merge(&mut self) {
+        self.state.merge_dialog = None;
+    }
+
+    /// Merge with the primary picked in the dialog.
+    pub async fn confirm_merge(&mut self) {
+        let Some(dialog) = self.state.merge_dialog.take() else {
+            return;
+        };
+        // Select the primary so the cursor stays on the surviving issue
+        let primary = &dialog.issue_ids[dialog.primary];
+        if let Some(index) = self.state.row_of(primary) {
+            self.state.selected_index = index;
+        }
+        self.merge_marked().await;
+    }
+
+    /// Merge the marked issues into the selected one.
+    pub async fn merge_marked(&mut self) {
+        let Some(primary) = self.state.selected_issue_id().map(str::to_string) else {
+            return;
+        };
+        let duplicates: Vec<String> = self
+            .state
+            .merge_marks
+            .iter()
+            .filter(|id| **id != primary)
+            .cloned()
+            .collect();
+        if duplicates.is_empty() {
+            return;
+        }
+        if !duplicates.iter().all(|id| self.bg.same_server(id, &primary)) {
+            self.state.toasts.warning("Only issues from the same project can be merged");
+            return;
+        }
+
+        self.state.is_loading = true;
+        match self.bg.client_for(&primary).merge(&primary, &duplicates).await {
+            Ok(response) => {
+                // Merged issues are hidden from the default list
+                self.state.issues.retain(|i| !response.merged_ids.contains(&i.id));
+                self.state.apply_view();
+                if let Some(index) = self.state.row_of(&primary) {
+                    self.state.selected_index = index;
+                }
+                self.state.merge_marks.clear();
+                let label = self.state.issue_label(&primary);
+                self.state.toasts.success(format!(
+                    "Merged {} issue{} into {}",
+                    response.merged_ids.len(),
+                    if response.merged_ids.len() == 1 { "" } else { "s" },
+                    label
+                ));
+            }
+            Err(e) => self.fail_action(IssueAction::Merge, &primary, "Failed to merge", e),
+        }
+        self.state.is_loading = false;
+    }
+
+    /// Open the selected issue in detail view.
+    pub fn open_selected(&mut self) {
+        if self.state.selected_issue().is_none() {
+            return;
+        }
+        self.state.peek = None;
+
+        self.state.screen = Screen::Detail;
+        self.acknowledge_watch();
+        self.state.current_issue = None;
+        self.state.restore_scroll(Screen::Detail);
+        self.state.frame_focus.blur();
+        self.state.frame_blame.clear();
+        self.state.expanded_frames.clear();
+        self.state.unfolded_runs.clear();
+        self.state.section_focus.blur();
+        self.state.event_browser = None;
+        self.state.reset_analysis();
+    }
+
+    /// Go back to list view.
+    pub fn back_to_list(&mut self) {
+        self.state.remember_scroll(Screen::Detail);
+        self.state.screen = Screen::List;
+        self.state.current_issue = None;
+        self.state.tag_breakdown = None;
+        self.state.issue_changes = None;
+        self.state.show_issue_changes = false;
+        self.state.event_picker = None;
+        self.state.event_browser = None;
+        self.state.plan_preview = None;
+        self.state.critique = None;
+        self.state.detail_scroll.reset();
+        self.state.analysis_lines.clear();
+    }
+
+    /// Go back from analysis to detail view.
+    pub fn back_to_detail(&mut self) {
+        self.state.screen = Screen::Detail;
+        self.state.json_view = None;
+    }
+
+    /// Open the implementation screen, following the issue's implementation
+    /// session unless it's already being followed.
+    pub fn open_implementation(&mut self) {
+        let Some(issue) = &self.state.current_issue else {
+            return;
+        };
+        if !matches!(issue.state, IssueState::InProgress { .. }) {
+            return;
+        }
+        let issue_id = issue.id.clone();
+        self.state.screen = Screen::Implementation;
+
+        let following = self.state.is_streaming_implementation
+            && self.state.implementation_issue.as_deref() == Some(issue_id.as_str());
+        if following {
+            return;
+        }
+        self.state.reset_implementation(&issue_id);
+        self.state.is_streaming_implementation = true;
+        self.state.implementation_progress.start();
+        self.bg.spawn_implementation_stream(&issue_id);
+    }
+
+    /// Open the review screen with the diff of the issue's worktree.
+    pub fn open_review(&mut self) {
+        let Some(issue) = &self.state.current_issue else {
+            return;
+        };
+        if !matches!(issue.state, IssueState::PendingReview { .. }) {
+            return;
+        }
+        let issue_id = issue.id.clone();
+        self.state.screen = Screen::Review;
+
+        if self.state.review_issue.as_deref() != Some(issue_id.as_str()) {
+            self.state.review_issue = Some(issue_id);
+            self.state.review_file = 0;
+            self.state.review_scroll.reset();
+        }
+        self.reload_review();
+    }
+
+    /// Diff the worktree again, keeping the shown file.
+    pub fn reload_review(&mut self) {
+        let Some(issue) = &self.state.current_issue else {
+            return;
+        };
+        let IssueState::PendingReview { worktree_path, .. } = &issue.state else {
+            return;
+        };
+        // Relative worktree paths are relative to the project checkout
+        let project = self.state.project_path.clone();
+        let worktree = project.join(worktree_path);
+
+        self.state.review_diff = None;
+        self.bg.spawn_review_diff(issue.id.clone(), project, worktree);
+    }
+
+    /// Ask before completing the current issue's review, showing the
+    /// commits its branch keeps.
+    pub fn finish_review(&mut self) {
+        let Some(issue) = &self.state.current_issue else {
+            return;
+        };
+        let IssueState::PendingReview { worktree_path, .. } = &issue.state else {
+            return;
+        };
+        let worktree = self.state.project_path.join(worktree_path);
+        self.state.finish_review = Some(FinishReview {
+            issue_id: issue.id.clone(),
+            worktree: worktree.clone(),
+            log: None,
+            at_risk: None,
+        });
+        self.bg
+            .spawn_branch_log(issue.id.clone(), self.state.project_path.clone(), worktree);
+    }
+
+    pub fn cancel_finish_review(&mut self) {
+        self.state.finish_review = None;
+    }
+
+    /// Complete the review from the completion dialog. Unless `discard` is
+    /// set, a worktree with uncommitted or unpushed work (or one still being
+    /// checked) is kept.
+    pub async fn confirm_finish_review(&mut self, discard: bool) {
+        let Some(dialog) = &self.state.finish_review else {
+            return;
+        };
+        if !discard {
+            match &dialog.at_risk {
+                None => {
+                    self.state.toasts.info("Still checking the worktree for changes");
+                    return;
+                }
+                Some(Ok(at_risk)) if !at_risk.is_empty() => {
+                    self.state.toasts.warning("Completing would lose work in the worktree; D completes anyway");
+                    return;
+                }
+                // A worktree that can't be checked has nothing git can lose
+                _ => {}
+            }
+        }
+        self.state.finish_review = None;
+        self.complete_review().await;
+    }
+
+    /// The commit edit to hand the terminal over for, if the branch has
+    /// commits to edit.
+    pub fn commit_edit(&mut self, squash: bool) -> Option<(PathBuf, CommitEdit)> {
+        let dialog = self.state.finish_review.as_ref()?;
+        let Some(Ok(log)) = &dialog.log else {
+            return None;
+        };
+        let edit = match log.commits.len() {
+            0 => {
+                self.state.toasts.warning("The branch has no commits yet");
+                return None;
+            }
+            1 if squash => {
+                self.state.toasts.info("Only one commit, nothing to squash");
+                return None;
+            }
+            _ if squash => CommitEdit::Squash {
+                base: log.base.clone(),
+            },
+            _ => CommitEdit::Amend,
+        };
+        Some((dialog.worktree.clone(), edit))
+    }
+
+    /// Report a commit edit and list the branch's commits again.
+    pub fn commits_edited(&mut self, result: anyhow::Result<bool>) {
+        match result {
+            Ok(true) => self.state.toasts.success("Commits updated"),
+            Ok(false) => self.state.toasts.info("Commits left as they were"),
+            Err(e) => self.state.toasts.error(format!("Failed to edit commits: {}", e)),
+        }
+        if let Some(dialog) = &mut self.state.finish_review {
+            dialog.log = None;
+            dialog.at_risk = None;
+            self.bg.spawn_branch_log(
+                dialog.issue_id.clone(),
+                self.state.project_path.clone(),
+                dialog.worktree.clone(),
+            );
+        }
+    }
+
+    /// Push the fix's branch and open a pull request for it. Once one is
+    /// open, copy its URL instead.
+    pub fn open_pull_request(&mut self) {
+        let Some(issue) = &self.state.current_issue else {
+            return;
+        };
+        let IssueState::PendingReview { worktree_path, worktree_branch, .. } = &issue.state else {
+            return;
+        };
+        match self.state.pull_requests.get(&issue.id) {
+            Some(PullRequest::Opening) => {
+                self.state.toasts.info("Pull request still opening");
+                return;
+            }
+            Some(PullRequest::Opened(_)) => {
+                self.copy_pull_request_url();
+                return;
+            }
+            None => {}
+        }
+
+        let worktree = self.state.project_path.join(worktree_path);
+        self.state.toasts.info(format!("Pushing {}…", worktree_branch));
+        self.state.pull_requests.insert(issue.id.clone(), PullRequest::Opening);
+        self.bg.spawn_pull_request(
+            issue.id.clone(),
+            worktree,
+            worktree_branch.clone(),
+            forge::title(issue),
+            forge::body(issue),
+        );
+    }
+
+    /// Copy the URL of the current issue's pull request.
+    pub fn copy_pull_request_url(&mut self) {
+        let Some(issue) = &self.state.current_issue else {
+            return;
+        };
+        if let Some(PullRequest::Opened(url)) = self.state.pull_requests.get(&issue.id) {
+            self.state.pending_clipboard = Some(url.clone());
+            self.state.toasts.info("Copied pull request URL");
+        }
+    }
+
+    /// Run the configured test command in the issue's worktree.
+    pub fn run_worktree_tests(&mut self) {
+        let Some(issue) = &self.state.current_issue else {
+            return;
+        };
+        let IssueState::PendingReview { worktree_path, .. } = &issue.state else {
+            return;
+        };
+        let Some(command) = self.state.test_command.clone() else {
+            self.state
+                .toasts
+                .warning("No test command; set test_command under [tui.review] in glass.toml");
+            return;
+        };
+        if self.state.test_run.as_ref().is_some_and(|r| r.status == TestStatus::Running) {
+            self.state.toasts.info("Tests still running; T stops them");
+            return;
+        }
+
+        let worktree = self.state.project_path.join(worktree_path);
+        let mut lines = ActivityLog::default();
+        lines.push(ActivityLine {
+            icon: "▶",
+            text: format!("$ {}", command),
+            style: ActivityStyle::Dimmed,
+        });
+        self.state.test_run = Some(TestRun {
+            issue_id: issue.id.clone(),
+            command: command.clone(),
+            lines,
+            status: TestStatus::Running,
+            scroll: self.state.output_view(),
+            started: Instant::now(),
+            duration: None,
+        });
+        self.bg.spawn_worktree_tests(issue.id.clone(), command, worktree);
+    }
+
+    /// Stop the running test command, killing it.
+    pub fn cancel_worktree_tests(&mut self) {
+        let Some(run) = self.state.test_run.as_mut().filter(|r| r.status == TestStatus::Running) else {
+            return;
+        };
+        self.bg.cancel_worktree_tests();
+        run.status = TestStatus::Cancelled;
+        run.duration = Some(run.started.elapsed());
+        run.lines.push(ActivityLine {
+            icon: "■",
+            text: "Tests cancelled".to_string(),
+            style: ActivityStyle::Dimmed,
+        });
+        self.state.toasts.info("Tests cancelled");
+    }
+
+    /// Show the next (or previous) changed file.
+    pub fn select_review_file(&mut self, delta: i32) {
+        let Some(Ok(files)) = &self.state.review_diff else {
+            return;
+        };
+        if files.is_empty() {
+            return;
+        }
+        let index = (self.state.review_file as i32 + delta).rem_euclid(files.len() as i32);
+        self.state.review_file = index as usize;
+        self.state.review_scroll.reset();
+    }
+
+    /// Open proposal screen.
+    pub fn open_proposal(&mut self) {
+        self.state.screen = Screen::Proposal;
+        self.state.restore_scroll(Screen::Proposal);
+    }
+
+    /// Go back from proposal to detail view.
+    pub fn back_from_proposal(&mut self) {
+        self.state.remember_scroll(Screen::Proposal);
+        self.state.screen = Screen::Detail;
+    }
+
+    /// Open the error screen for the current issue.
+    pub fn open_error(&mut self) {
+        let Some(issue) = &self.state.current_issue else {
+            return;
+        };
+        let IssueState::Error { session_id, .. } = &issue.state else {
+            return;
+        };
+
+        let prefixed = format!("sentry:{}", issue.id);
+        let needles = [issue.id.as_str(), prefixed.as_str(), session_id.as_str()];
+        self.state.error_log_excerpt = logging::recent_server_log_lines(&needles, 20);
+
+        self.state.screen = Screen::Error;
+        self.state.error_scroll.reset();
+        self.state.error_action_index = 0;
+    }
+
+    /// Go back from the error screen to detail view.
+    pub fn back_from_error(&mut self) {
+        self.state.screen = Screen::Detail;
+    }
+
+    /// Move the selected remediation button on the error screen.
+    pub fn select_error_action(&mut self, delta: i32) {
+        let count = ERROR_ACTIONS.len() as i32;
+        let index = (self.state.error_action_index as i32 + delta).rem_euclid(count);
+        self.state.error_action_index = index as usize;
+    }
+
+    // === Worktrees ===
+
+    /// Open the worktree management screen from the list.
+    pub fn open_worktrees(&mut self) {
+        self.state.screen = Screen::Worktrees;
+        self.state.worktrees = Worktrees::default();
+        self.bg.spawn_worktrees(self.state.project_path.clone());
+    }
+
+    pub fn back_from_worktrees(&mut self) {
+        self.state.screen = Screen::List;
+    }
+
+    pub fn reload_worktrees(&mut self) {
+        self.state.worktrees.list = None;
+        self.state.worktrees.confirm_remove = None;
+        self.bg.spawn_worktrees(self.state.project_path.clone());
+    }
+
+    pub fn move_worktree_selection(&mut self, delta: i32) {
+        let worktrees = &mut self.state.worktrees;
+        let Some(Ok(list)) = &worktrees.list else {
+            return;
+        };
+        let last = list.len().saturating_sub(1) as i32;
+        worktrees.selected = (worktrees.selected as i32 + delta).clamp(0, last) as usize;
+        worktrees.confirm_remove = None;
+    }
+
+    /// Remove the selected worktree, once confirmed with a second press.
+    /// Worktrees of issues still being fixed or reviewed are kept.
+    pub fn remove_worktree(&mut self) {
+        let Some(Ok(list)) = &self.state.worktrees.list else {
+            return;
+        };
+        let Some(worktree) = list.get(self.state.worktrees.selected) else {
+            return;
+        };
+        if let WorktreeUse::Active(issue) = self.state.worktree_use(worktree) {
+            let message = format!("{} is still {}", issue.short_id, issue.status.replace('_', " "));
+            self.state.toasts.warning(message);
+            return;
+        }
+        if self.state.worktrees.confirm_remove.as_ref() != Some(&worktree.path) {
+            let message = format!("Press d again to remove {}", worktree.path.display());
+            self.state.worktrees.confirm_remove = Some(worktree.path.clone());
+            self.state.toasts.info(message);
+            return;
+        }
+        let worktree = Some(worktree.clone());
+        self.state.worktrees.confirm_remove = None;
+        self.bg.spawn_worktree_cleanup(self.state.project_path.clone(), worktree);
+    }
+
+    /// Drop git's records of worktrees whose directories are gone.
+    pub fn prune_worktrees(&mut self) {
+        self.bg.spawn_worktree_cleanup(self.state.project_path.clone(), None);
+    }
+
+    // === Breadcrumbs ===
+
+    /// Open the current issue's breadcrumbs in full, newest selected.
+    pub fn open_breadcrumbs(&mut self) {
+        let count = self
+            .state
+            .current_issue
+            .as_ref()
+            .and_then(|issue| issue.source.breadcrumbs.as_ref())
+            .map_or(0, Vec::len);
+        if count == 0 {
+            self.state.toasts.info("No breadcrumbs on this issue");
+            return;
+        }
+        self.state.breadcrumb_view = BreadcrumbView {
+            selected: count - 1,
+            ..BreadcrumbView::default()
+        };
+        // Clamped to the end on the first render
+        self.state.breadcrumb_view.scroll.restore(usize::MAX);
+        self.state.screen = Screen::Breadcrumbs;
+    }
+
+    /// Indexes of the breadcrumbs the category filter shows.
+    pub fn shown_breadcrumbs(&self) -> Vec<usize> {
+        let Some(breadcrumbs) = self.state.current_issue.as_ref().and_then(|i| i.source.breadcrumbs.as_ref()) else {
+            return Vec::new();
+        };
+        let category = self.state.breadcrumb_view.category.as_deref();
+        (0..breadcrumbs.len())
+            .filter(|&i| category.is_none_or(|c| breadcrumbs[i].category() == c))
+            .collect()
+    }
+
+    pub fn move_breadcrumb_selection(&mut self, delta: i32) {
+        let count = self.shown_breadcrumbs().len();
+        if count == 0 {
+            return;
+        }
+        let view = &mut self.state.breadcrumb_view;
+        view.selected = (view.selected as i64 + delta as i64).clamp(0, count as i64 - 1) as usize;
+        view.reveal_selected();
+    }
+
+    /// Show or hide the selected breadcrumb's data.
+    pub fn toggle_breadcrumb_data(&mut self) {
+        let Some(&index) = self.shown_breadcrumbs().get(self.state.breadcrumb_view.selected) else {
+            return;
+        };
+        let has_data = self
+            .state
+            .current_issue
+            .as_ref()
+            .and_then(|i| i.source.breadcrumbs.as_ref()?.get(index)?.data.as_ref())
+            .is_some_and(|data| !data.fields().is_empty());
+        if !has_data {
+            self.state.toasts.info("No data on this breadcrumb");
+            return;
+        }
+        let expanded = &mut self.state.breadcrumb_view.expanded;
+        if !expanded.remove(&index) {
+            expanded.insert(index);
+        }
+    }
+
+    /// Show only the next (or previous) category, cycling through all of
+    /// them and back to every breadcrumb.
+    pub fn cycle_breadcrumb_category(&mut self, delta: i32) {
+        let Some(issue) = &self.state.current_issue else {
+            return;
+        };
+        let mut options: Vec<Option<String>> = vec![None];
+        options.extend(issue.source.breadcrumb_categories().into_iter().map(|c| Some(c.to_string())));
+        let view = &mut self.state.breadcrumb_view;
+        let current = options.iter().position(|c| *c == view.category).unwrap_or(0);
+        let next = (current as i64 + delta as i64).rem_euclid(options.len() as i64) as usize;
+        view.category = options.swap_remove(next);
+
+        let count = self.shown_breadcrumbs().len();
+        let view = &mut self.state.breadcrumb_view;
+        view.selected = count.saturating_sub(1);
+        view.rows.borrow_mut().clear();
+        view.scroll.restore(usize::MAX);
+    }
+
+    // === Raw JSON ===
+
+    /// Open the raw JSON viewer on the current issue.
+    pub fn open_raw_json(&mut self) {
+        let Some(issue_id) = self.current_issue_id() else {
+            return;
+        };
+        self.state.json_view = Some(JsonView::new(issue_id.clone()));
+        self.state.screen = Screen::RawJson;
+        self.bg.spawn_raw_issue(issue_id);
+    }
+
+    /// The raw JSON viewer's rows, folded containers kept to one row.
+    pub fn json_rows(&self) -> Vec<JsonRow<'_>> {
+        match &self.state.json_view {
+            Some(JsonView { value: Some(Ok(value)), folded, .. }) => json_tree::flatten(value, folded),
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn move_json_selection(&mut self, delta: i32) {
+        let count = self.json_rows().len();
+        let Some(view) = &mut self.state.json_view else {
+            return;
+        };
+        if count == 0 {
+            return;
+        }
+        view.selected = (view.selected as i64 + delta as i64).clamp(0, count as i64 - 1) as usize;
+        view.reveal_selected();
+    }
+
+    /// Fold or unfold the object or array at the selection. `None` toggles
+    /// it; folding from inside a container folds the container.
+    pub fn fold_json(&mut self, fold: Option<bool>) {
+        let rows = self.json_rows();
+        let Some(view) = &self.state.json_view else {
+            return;
+        };
+        let Some(row) = rows.get(view.selected) else {
+            return;
+        };
+        let (path, folded) = match row.kind {
+            JsonRowKind::Open { folded, .. } => (row.path.as_str(), folded),
+            JsonRowKind::Close => (row.path.as_str(), false),
+            JsonRowKind::Value if fold != Some(false) => match json_tree::parent_path(&row.path) {
+                Some(parent) => (parent, false),
+                None => return,
+            },
+            JsonRowKind::Value => return,
+        };
+        let fold = fold.unwrap_or(!folded);
+        if fold == folded {
+            return;
+        }
+        let path = path.to_string();
+        // Folding moves the selection up to the row that stays
+        let selected = if fold {
+            json_tree::opening_row(&rows, &path, view.selected).unwrap_or(view.selected)
+        } else {
+            view.selected
+        };
+        drop(rows);
+        let Some(view) = &mut self.state.json_view else {
+            return;
+        };
+        if fold {
+            view.folded.insert(path);
+        } else {
+            view.folded.remove(&path);
+        }
+        view.selected = selected;
+        view.rows.borrow_mut().clear();
+        view.reveal_selected();
+    }
+
+    // === Event browsing ===
+
+    /// Show another of the current issue's events, loading pages of them
+    /// until the one asked for is in.
+    pub fn browse_events(&mut self, target: EventTarget) {
+        let Some(issue_id) = self.current_issue_id() else {
+            return;
+        };
+        if self.state.event_browser.as_ref().is_none_or(|b| b.issue_id != issue_id) {
+            self.state.event_browser = Some(EventBrowser::new(issue_id.clone()));
+        }
+        let Some(browser) = &mut self.state.event_browser else {
+            return;
+        };
+
+        let loaded = browser.events.len();
+        let position = browser.position();
+        let index = match target {
+            _ if loaded == 0 && !browser.complete => None,
+            EventTarget::Latest => Some(0),
+            EventTarget::Oldest => browser.complete.then(|| loaded.saturating_sub(1)),
+            EventTarget::Older(steps) => {
+                let index = (position as i64 + steps as i64).max(0) as usize;
+                (index < loaded || browser.complete).then(|| index.min(loaded.saturating_sub(1)))
+            }
+        };
+        let Some(index) = index else {
+            browser.pending = Some(target);
+            if !browser.loading_page {
+                browser.loading_page = true;
+                self.bg.spawn_occurrences(issue_id, browser.next_cursor.clone());
+            }
+            return;
+        };
+        // A later key wins over one still waiting on a page
+        browser.pending = None;
+
+        let Some(event) = browser.events.get(index).cloned() else {
+            self.state.toasts.info("No events to show");
+            return;
+        };
+        if browser.shown.is_some() && index == position {
+            let edge = if index == 0 { "latest" } else { "oldest" };
+            self.state.toasts.info(format!("Already at the {} event", edge));
+            return;
+        }
+        self.show_event(event);
+    }
+
+    /// Show the event picked in the events popup.
+    pub fn view_picked_event(&mut self) {
+        let Some(picker) = self.state.event_picker.take() else {
+            return;
+        };
+        let Some(Ok(events)) = picker.events else {
+            return;
+        };
+        let Some(event) = events.into_iter().nth(picker.selected) else {
+            return;
+        };
+        if self.state.event_browser.as_ref().is_none_or(|b| b.issue_id != picker.issue_id) {
+            self.state.event_browser = Some(EventBrowser::new(picker.issue_id));
+        }
+        self.show_event(event);
+    }
+
+    /// Switch the detail screen to `event`, fetching its details unless
+    /// they were fetched before.
+    fn show_event(&mut self, event: IssueEvent) {
+        let Some(browser) = &mut self.state.event_browser else {
+            return;
+        };
+        let fetched = browser.details.contains_key(&event.id);
+        let (issue_id, event_id) = (browser.issue_id.clone(), event.id.clone());
+        browser.shown = Some(event);
+        if fetched {
+            self.apply_shown_event();
+        } else {
+            self.bg.spawn_occurrence(issue_id, event_id);
+        }
+    }
+
+    /// Put the shown event's details in place of the current issue's own,
+    /// once they've loaded. Refreshes replace the issue, so they call this
+    /// again.
+    fn apply_shown_event(&mut self) {
+        let (Some(browser), Some(issue)) = (&mut self.state.event_browser, &mut self.state.current_issue) else {
+            return;
+        };
+        if browser.issue_id != issue.id {
+            return;
+        }
+        let Some(shown) = &browser.shown else {
+            return;
+        };
+        let Some(source) = browser.details.get(&shown.id) else {
+            return;
+        };
+        issue.source = issue.source.with_event(source);
+        if browser.applied.as_ref().is_none_or(|applied| applied.id != shown.id) {
+            browser.applied = Some(shown.clone());
+            // Frame indexes belong to the last event's stack trace
+            self.state.frame_focus.blur();
+            self.state.frame_blame.clear();
+            self.state.expanded_frames.clear();
+            self.state.unfolded_runs.clear();
+        }
+    }
+
+    // === Scrolling ===
+
+    pub fn scroll_detail(&mut self, delta: i32) {
+        self.state.detail_scroll.scroll_by(delta);
+    }
+
+    pub fn scroll_tag_breakdown(&mut self, delta: i32) {
+        if let Some(breakdown) = &mut self.state.tag_breakdown {
+            breakdown.scroll.scroll_by(delta);
+        }
+    }
+
+    pub fn scroll_analysis(&mut self, delta: i32) {
+        self.state.analysis_scroll.scroll_by(delta);
+    }
+
+    pub fn scroll_implementation(&mut self, delta: i32) {
+        self.state.implementation_scroll.scroll_by(delta);
+    }
+
+    pub fn scroll_review(&mut self, delta: i32) {
+        self.state.review_scroll.scroll_by(delta);
+    }
+
+    pub fn scroll_test_output(&mut self, delta: i32) {
+        if let Some(run) = &mut self.state.test_run {
+            run.scroll.scroll_by(delta);
+        }
+    }
+
+    pub fn scroll_proposal(&mut self, delta: i32) {
+        self.state.proposal_scroll.scroll_by(delta);
+    }
+
+    pub fn scroll_plan_preview(&mut self, delta: i32) {
+        if let Some(preview) = &mut self.state.plan_preview {
+            preview.scroll.scroll_by(delta);
+        }
+    }
+
+    pub fn scroll_critique(&mut self, delta: i32) {
+        if let Some(critique) = &mut self.state.critique {
+            critique.scroll.scroll_by(delta);
+        }
+    }
+
+    pub fn scroll_error(&mut self, delta: i32) {
+        self.state.error_scroll.scroll_by(delta);
+    }
+
+    /// Move stack frame focus on the detail screen, starting from the first
+    /// (or last) frame when nothing is focused.
+    pub fn focus_frame(&mut self, delta: i32) {
+        let Some(issue) = &self.state.current_issue else {
+            return;
+        };
+        // A folded run is a single stop, at its first frame
+        let folded = self.folded_runs();
+        let stops: Vec<usize> = (0..issue.source.frames().count())
+            .filter(|i| !folded.iter().any(|run| run.contains(i) && *i != run.start))
+            .collect();
+        if stops.is_empty() {
+            return;
+        }
+
+        self.state.frame_focus.set_widgets(stops);
+        self.state.frame_focus.step(delta as isize);
+        self.state.section_focus.blur();
+    }
+
+    /// Move the focus to the next (or previous) section header, wrapping
+    /// around, and scroll it to the top.
+    pub fn focus_section(&mut self, delta: i32) {
+        let row = {
+            // Sections come and go with the issue, so the ring is refilled
+            // from the last render
+            let rows = self.state.section_rows.borrow();
+            self.state.section_focus.set_widgets(rows.iter().map(|(section, _)| *section));
+            let Some(section) = self.state.section_focus.traverse(delta as isize) else {
+                return;
+            };
+            rows.iter().find(|(s, _)| *s == section).map_or(0, |(_, row)| *row)
+        };
+        self.state.frame_focus.blur();
+        self.state.detail_scroll.restore(row);
+    }
+
+    /// Fold the focused section down to its header, or open it back up.
+    pub fn toggle_section(&mut self) {
+        let (Some(section), Some(issue)) = (self.state.section_focus.focused(), &self.state.current_issue) else {
+            return;
+        };
+        let collapsed = self.state.collapsed_sections.entry(issue.id.clone()).or_default();
+        if !collapsed.remove(&section) {
+            collapsed.insert(section);
+        }
+        self.state.collapsed_sections.retain(|_, sections| !sections.is_empty());
+        self.save_workspace();
+    }
+
+    /// Whether `section` is folded on the open issue.
+    pub fn is_collapsed(&self, section: DetailSection) -> bool {
+        self.state
+            .current_issue
+            .as_ref()
+            .and_then(|issue| self.state.collapsed_sections.get(&issue.id))
+            .is_some_and(|sections| sections.contains(&section))
+    }
+
+    pub fn unfocus_section(&mut self) {
+        self.state.section_focus.blur();
+    }
+
+    /// Show or hide the source lines around the focused frame.
+    pub fn toggle_frame_context(&mut self) {
+        let (Some(index), Some(frame)) = (self.state.frame_focus.focused(), self.focused_frame()) else {
+            return;
+        };
+        if frame.context.as_ref().is_none_or(|c| c.is_empty()) {
+            self.state.toasts.info("No source context for this frame");
+            return;
+        }
+        if !self.state.expanded_frames.remove(&index) {
+            self.state.expanded_frames.insert(index);
+        }
+    }
+
+    /// Runs of library frames still folded, as ranges of frame indexes.
+    pub fn folded_runs(&self) -> Vec<Range<usize>> {
+        let Some(issue) = &self.state.current_issue else {
+            return Vec::new();
+        };
+        let paths = &self.state.path_config;
+        issue
+            .source
+            .library_runs(|path| paths.is_library(path))
+            .into_iter()
+            .filter(|run| !self.state.unfolded_runs.contains(&run.start))
+            .collect()
+    }
+
+    /// The folded run the focused frame stands in for, if any.
+    pub fn focused_fold(&self) -> Option<Range<usize>> {
+        let index = self.state.frame_focus.focused()?;
+        self.folded_runs().into_iter().find(|run| run.start == index)
+    }
+
+    /// Show the library frames folded at the focused frame.
+    pub fn unfold_frames(&mut self) {
+        if let Some(run) = self.focused_fold() {
+            self.state.unfolded_runs.insert(run.start);
+        }
+    }
+
+    /// Stop focusing a frame, giving Enter and `e` back to the screen.
+    pub fn unfocus_frame(&mut self) {
+        self.state.frame_focus.blur();
+    }
+
+    /// The focused frame's file in the project, and its line, for opening
+    /// in the editor.
+    pub fn frame_to_edit(&mut self) -> Option<(PathBuf, Option<u32>)> {
+        let frame = self.focused_frame()?;
+        let line = frame.lineno;
+        let Some(filename) = frame.filename.clone() else {
+            self.state.toasts.warning("Frame has no file to open");
+            return None;
+        };
+        let repo = &self.state.project_path;
+        let Some(file) = git::resolve_in_repo(repo, &filename) else {
+            self.state.toasts.warning(format!("{} not found in the project", filename));
+            return None;
+        };
+        Some((repo.join(file), line))
+    }
+
+    /// Report the editor failing to open a frame.
+    pub fn editor_closed(&mut self, result: anyhow::Result<()>) {
+        if let Err(e) = result {
+            self.state.toasts.error(format!("Failed to open the editor: {}", e));
+        }
+    }
+
+    /// Switch the breadcrumbs between the plain list and the timeline.
+    pub fn toggle_timeline(&mut self) {
+        self.state.breadcrumb_zoom = match self.state.breadcrumb_zoom {
+            Some(_) => None,
+            None => Some(DEFAULT_TIMELINE_ZOOM),
+        };
+    }
+
+    /// Open or close the tag breakdown for the current issue.
+    pub fn toggle_tag_breakdown(&mut self) {
+        if self.state.tag_breakdown.take().is_some() {
+            return;
+        }
+        let Some(issue_id) = self.current_issue_id() else {
+            return;
+        };
+        self.state.tag_breakdown = Some(TagBreakdown {
+            issue_id: issue_id.clone(),
+            tags: None,
+            scroll: ScrollView::default(),
+        });
+        self.bg.spawn_tag_stats(issue_id);
+    }
+
+    /// Compare a refreshed issue with what was shown before, keeping the
+    /// changes for the "what changed" popup and summing them up in a toast.
+    fn note_issue_changes(&mut self, previous: Option<IssueDetail>) {
+        let (Some(previous), Some(current)) = (previous, &self.state.current_issue) else {
+            return;
+        };
+        if previous.id != current.id {
+            return;
+        }
+        let changes = IssueChanges::between(&previous, current);
+        if !changes.is_empty() {
+            self.state.toasts.info(format!("Since the last load: {} (D for details)", changes.summary()));
+        }
+        self.state.issue_changes = Some(changes);
+    }
+
+    /// Open or close the "what changed" popup.
+    pub fn toggle_issue_changes(&mut self) {
+        if self.state.show_issue_changes {
+            self.state.show_issue_changes = false;
+        } else if self.state.issue_changes.is_some() {
+            self.state.show_issue_changes = true;
+        } else {
+            self.state.toasts.info("Nothing to compare yet: refresh with r first");
+        }
+    }
+
+    /// Open or close the file-level plan for the proposal.
+    pub fn toggle_plan_preview(&mut self) {
+        if self.state.plan_preview.take().is_some() {
+            return;
+        }
+        let Some(issue_id) = self.current_issue_id() else {
+            return;
+        };
+        self.state.plan_preview = Some(PlanPreview {
+            issue_id: issue_id.clone(),
+            plan: None,
+            scroll: ScrollView::default(),
+        });
+        self.bg.spawn_plan_preview(issue_id);
+    }
+
+    /// Start a critic pass over the proposal, or close its pane once the
+    /// critique has finished.
+    pub fn toggle_critique(&mut self) {
+        match &self.state.critique {
+            Some(critique) if critique.status == CritiqueStatus::Streaming => {
+                self.state.toasts.info("Critic pass still running");
+                return;
+            }
+            Some(_) => {
+                self.state.critique = None;
+                return;
+            }
+            None => {}
+        }
+        let Some(issue_id) = self.current_issue_id() else {
+            return;
+        };
+        self.state.critique = Some(Critique {
+            issue_id: issue_id.clone(),
+            reviewer: None,
+            text: String::new(),
+            status: CritiqueStatus::Streaming,
+            scroll: self.state.output_view(),
+        });
+        self.bg.spawn_critique(issue_id);
+    }
+
+    /// Open or close the events browser for the current issue.
+    pub fn toggle_event_picker(&mut self) {
+        if self.state.event_picker.take().is_some() {
+            return;
+        }
+        let Some(issue_id) = self.current_issue_id() else {
+            return;
+        };
+        self.state.event_picker = Some(EventPicker {
+            issue_id: issue_id.clone(),
+            events: None,
+            selected: 0,
+        });
+        self.bg.spawn_issue_events(issue_id);
+    }
+
+    /// Move the selection in the events browser.
+    pub fn move_event_selection(&mut self, delta: i32) {
+        let Some(picker) = &mut self.state.event_picker else {
+            return;
+        };
+        let Some(Ok(events)) = &picker.events else {
+            return;
+        };
+        let max = events.len().saturating_sub(1) as i64;
+        picker.selected = (picker.selected as i64 + delta as i64).clamp(0, max) as usize;
     }
 
-    // === Convenience accessors (delegate to state) ===
+    /// Show or hide line numbers beside diffs in the proposal.
+    pub fn toggle_diff_line_numbers(&mut self) {
+        self.state.diff_line_numbers = !self.state.diff_line_numbers;
+    }
 
-    pub fn screen(&self) -> &Screen {
-        &self.state.screen
+    /// The current screen's scroll view, if its long lines can be left
+    /// unwrapped.
+    fn wrappable_view(&mut self) -> Option<&mut ScrollView> {
+        match self.state.screen {
+            Screen::Proposal => Some(&mut self.state.proposal_scroll),
+            Screen::Analysis => Some(&mut self.state.analysis_scroll),
+            Screen::Implementation => Some(&mut self.state.implementation_scroll),
+            _ => None,
+        }
     }
 
-    pub fn half_page(&self) -> i32 {
-        self.state.half_page()
+    /// Switch the current screen between wrapping long lines and scrolling
+    /// them sideways. Each screen keeps its own choice.
+    pub fn toggle_wrap(&mut self) {
+        if let Some(view) = self.wrappable_view() {
+            view.toggle_wrap();
+        }
     }
 
-    pub fn set_terminal_size(&mut self, width: u16, height: u16) {
-        self.state.set_terminal_size(width, height);
+    /// Scroll the current screen sideways while its lines are unwrapped.
+    pub fn scroll_columns(&mut self, delta: i32) {
+        if let Some(view) = self.wrappable_view() {
+            view.scroll_columns(delta);
+        }
     }
 
-    // === Background task polling ===
+    /// Zoom the breadcrumb timeline in (negative) or out (positive).
+    pub fn zoom_timeline(&mut self, delta: i32) {
+        if let Some(zoom) = self.state.breadcrumb_zoom {
+            let max = TIMELINE_ZOOM_LEVELS.len() as i32 - 1;
+            self.state.breadcrumb_zoom = Some((zoom as i32 + delta).clamp(0, max) as usize);
+        }
+    }
 
-    /// Poll for background task completions and update state.
-    pub fn poll_background(&mut self) {
-        for msg in self.bg.poll() {
-            match msg {
-                BackgroundMessage::ListRefreshComplete(result) => {
-                    self.state.is_refreshing = false;
-                    match result {
-                        Ok(response) => {
-                            self.state.issues = response.issues;
-                            self.state.clamp_selection();
-                        }
-                        Err(e) => {
-                            self.state.error = Some(e);
-                        }
-                    }
-                }
-                BackgroundMessage::DetailRefreshComplete(result) => {
-                    self.state.is_refreshing_detail = false;
-                    match result {
-                        Ok(detail) => {
-                            self.state.current_issue = Some(detail);
-                        }
-                        Err(e) => {
-                            self.state.error = Some(e);
-                        }
-                    }
-                }
-                BackgroundMessage::AnalysisEvent(event) => {
-                    analysis::handle_analysis_event(&mut self.state, event);
+    /// The focused stack frame, if any.
+    pub fn focused_frame(&self) -> Option<&StackFrame> {
+        let index = self.state.frame_focus.focused()?;
+        self.state.current_issue.as_ref()?.source.frames().nth(index)
+    }
+
+    /// Open the yank menu for the selected issue on the list, or the open
+    /// issue elsewhere (with its focused frame first).
+    pub fn open_yank_menu(&mut self) {
+        let sentry = &self.state.sentry;
+        let items = if self.state.screen == Screen::List {
+            let selected = self.state.selected_issue();
+            match (selected, &self.state.current_issue) {
+                // Loaded before, so the stack trace and proposal are known
+                (Some(issue), Some(detail)) if detail.id == issue.id => {
+                    yank::detail_items(detail, None, None, sentry)
                 }
-                BackgroundMessage::AnalysisStreamEnded(error) => {
-                    self.state.is_streaming_analysis = false;
-                    if let Some(err) = error {
-                        self.state.analysis_lines.push(ActivityLine {
-                            icon: "✗",
-                            text: format!("Stream error: {}", err),
-                            style: ActivityStyle::Error,
-                        });
-                    }
+                (Some(issue), _) => yank::issue_items(issue, sentry),
+                (None, _) => Vec::new(),
+            }
+        } else {
+            match &self.state.current_issue {
+                Some(issue) => {
+                    let blame = self.state.frame_focus.focused().and_then(|i| self.state.frame_blame.get(&i));
+                    yank::detail_items(issue, self.focused_frame(), blame.and_then(|b| b.as_ref().ok()), sentry)
                 }
+                None => Vec::new(),
             }
+        };
+        if !items.is_empty() {
+            self.state.yank_menu = Some(YankMenu::new(items));
         }
     }
 
-    // === Data loading ===
-
-    /// Load cached issues from server (fast).
-    pub async fn load_cached(&mut self) {
-        self.state.error = None;
-
-        match self.bg.client().list_issues().await {
-            Ok(response) => {
-                self.state.issues = response.issues;
-                self.state.clamp_selection();
-            }
-            Err(e) => {
-                self.state.error = Some(format!("Failed to fetch issues: {}", e));
-            }
+    pub fn move_yank_selection(&mut self, delta: i32) {
+        if let Some(menu) = &mut self.state.yank_menu {
+            let last = menu.items.len().saturating_sub(1) as i32;
+            menu.selected = (menu.selected as i32 + delta).clamp(0, last) as usize;
         }
     }
 
-    /// Start a background refresh from Sentry.
-    pub fn start_refresh(&mut self) {
-        if self.state.is_refreshing {
+    /// Copy an item from the yank menu (`None` for the selected one) and
+    /// close it.
+    pub fn yank(&mut self, item: Option<YankItem>) {
+        let Some(menu) = &self.state.yank_menu else {
             return;
-        }
+        };
+        let item = item.or_else(|| menu.items.get(menu.selected).map(|(item, _)| *item));
+        let Some((item, text)) = item.and_then(|item| Some((item, menu.text(item)?.to_string()))) else {
+            // A key for something this issue doesn't have
+            return;
+        };
+        self.state.yank_menu = None;
+        self.state.toasts.info(format!("Copied {}", item.label()));
+        self.state.pending_clipboard = Some(text);
+    }
 
-        self.state.is_refreshing = true;
-        self.state.error = None;
-        self.bg.spawn_list_refresh();
+    pub fn close_yank_menu(&mut self) {
+        self.state.yank_menu = None;
     }
 
-    /// Load cached issue detail from server (fast).
-    pub async fn load_cached_detail(&mut self) {
-        let Some(issue_id) = self.state.selected_issue_id().map(|s| s.to_string()) else {
+    /// Look up the last commit touching the focused frame's line. The result
+    /// is shown under the frame, and `y` can copy it.
+    pub fn blame_frame(&mut self) {
+        let (Some(index), Some(frame)) = (self.state.frame_focus.focused(), self.focused_frame()) else {
+            return;
+        };
+        let (Some(filename), Some(line)) = (frame.filename.clone(), frame.lineno) else {
+            self.state.toasts.warning("Frame has no file and line to blame");
+            return;
+        };
+        let Some(issue_id) = self.current_issue_id() else {
             return;
         };
 
-        self.state.error = None;
+        let repo = self.state.project_path.clone();
+        let Some(file) = git::resolve_in_repo(&repo, &filename) else {
+            self.state.toasts.warning(format!("{} not found in the project", filename));
+            return;
+        };
+        self.bg.spawn_blame(issue_id, index, repo, file, line);
+    }
 
-        match self.bg.client().get_issue(&issue_id).await {
-            Ok(detail) => {
-                // If issue is in Analyzing state, connect to SSE stream
-                if matches!(detail.state, IssueState::Analyzing { .. }) {
-                    self.start_analysis_stream(&detail.id);
-                }
-                self.state.current_issue = Some(detail);
-            }
-            Err(e) => {
-                self.state.error = Some(format!("Failed to fetch issue: {}", e));
-            }
-        }
+    /// Take text waiting to be copied, for the main loop to write out.
+    pub fn take_clipboard(&mut self) -> Option<String> {
+        self.state.pending_clipboard.take()
     }
 
-    /// Start a background refresh for the current issue from Sentry.
-    pub fn start_detail_refresh(&mut self) {
-        if self.state.is_refreshing_detail {
+    /// Save the analysis transcript under the project and report where.
+    pub fn export_transcript(&mut self) {
+        if self.state.analysis_lines.is_empty() && self.state.analysis_events.is_empty() {
+            self.state.toasts.warning("Nothing to export yet");
             return;
         }
 
-        let Some(issue_id) = self.state.selected_issue_id().map(|s| s.to_string()) else {
-            return;
+        let (issue, title) = self.export_names();
+        let transcript = Transcript {
+            issue: &issue,
+            title: &title,
+            lines: &self.state.analysis_lines,
+            events: &self.state.analysis_events,
+            progress: &self.state.analysis_progress,
         };
 
-        self.state.is_refreshing_detail = true;
-        self.state.error = None;
-        self.bg.spawn_detail_refresh(issue_id);
+        match transcript.write(&self.state.project_path) {
+            Ok(path) => {
+                let shown = path
+                    .strip_prefix(&self.state.project_path)
+                    .unwrap_or(&path)
+                    .display()
+                    .to_string();
+                self.state.toasts.success(format!("Saved transcript to {}", shown));
+            }
+            Err(e) => self.state.toasts.error(format!("Failed to save transcript: {}", e)),
+        }
     }
 
-    /// Refresh current issue detail from server (sync, for use after actions).
-    pub async fn refresh_current_issue(&mut self) {
-        let Some(issue_id) = self.state.selected_issue_id().map(|s| s.to_string()) else {
+    /// Export the analysis as an asciicast recording with its timing.
+    pub fn export_recording(&mut self) {
+        if self.state.analysis_events.is_empty() {
+            self.state.toasts.warning("Nothing to export yet");
             return;
-        };
+        }
 
-        self.state.is_loading = true;
+        let (issue, title) = self.export_names();
+        let frames = analysis::recorded_lines(self.state.analysis_events.iter());
+        let recording = Recording {
+            issue: &issue,
+            title: &title,
+            frames: &frames,
+            width: self.state.terminal_width,
+            height: self.state.terminal_height,
+        };
 
-        match self.bg.client().get_issue(&issue_id).await {
-            Ok(detail) => {
-                self.state.current_issue = Some(detail);
-            }
-            Err(e) => {
-                self.state.error = Some(format!("Failed to fetch issue: {}", e));
+        match recording.write(&self.state.project_path) {
+            Ok(path) => {
+                let shown = path
+                    .strip_prefix(&self.state.project_path)
+                    .unwrap_or(&path)
+                    .display()
+                    .to_string();
+                self.state.toasts.success(format!("Saved recording to {}", shown));
             }
+            Err(e) => self.state.toasts.error(format!("Failed to save recording: {}", e)),
         }
+    }
 
-        self.state.is_loading = false;
+    /// Issue label and title to name exports after.
+    fn export_names(&self) -> (String, String) {
+        match &self.state.current_issue {
+            Some(issue) => (
+                issue
+                    .source
+                    .short_id
+                    .clone()
+                    .unwrap_or_else(|| self.state.issue_label(&issue.id)),
+                issue.source.title.clone().unwrap_or_default(),
+            ),
+            None => ("analysis".to_string(), String::new()),
+        }
     }
 
-    // === Navigation ===
+    // === Quitting ===
 
-    /// Move selection by delta (positive = down, negative = up).
-    pub fn move_selection(&mut self, delta: i32) {
-        if self.state.issues.is_empty() {
-            return;
+    /// Quit, unless that would cut short work still in flight, in which
+    /// case say what and quit on a second request.
+    pub fn request_quit(&mut self) {
+        let repeated = self
+            .state
+            .quit_requested_at
+            .is_some_and(|at| at.elapsed() < QUIT_CONFIRM_WINDOW);
+        match self.work_in_flight() {
+            Some(work) if !repeated => {
+                self.state.quit_requested_at = Some(Instant::now());
+                self.state.toasts.warning(format!("{}; quit again to stop anyway", work));
+            }
+            _ => self.state.should_quit = true,
         }
+    }
 
-        let new_index = self.state.selected_index as i32 + delta;
-        self.state.selected_index = new_index.clamp(0, self.state.issues.len() as i32 - 1) as usize;
+    /// What quitting now would cut short: local tests and pushes, and the
+    /// agents on a server glass started (which stops with it).
+    fn work_in_flight(&self) -> Option<String> {
+        if self.state.test_run.as_ref().is_some_and(|r| r.status == TestStatus::Running) {
+            return Some("Tests are still running".to_string());
+        }
+        if self.state.pull_requests.values().any(|pr| *pr == PullRequest::Opening) {
+            return Some("A pull request is still being opened".to_string());
+        }
+        if self.server.is_some() {
+            let running = self
+                .state
+                .issues
+                .iter()
+                .filter(|issue| matches!(issue.status.as_str(), "analyzing" | "in_progress"))
+                .count();
+            match running {
+                0 => {}
+                1 => return Some("An agent is still working on the server glass started".to_string()),
+                n => return Some(format!("{} agents are still working on the server glass started", n)),
+            }
+        }
+        None
     }
 
-    /// Jump to top of list.
-    pub fn jump_to_top(&mut self) {
-        self.state.selected_index = 0;
+    // === Playback ===
+
+    /// Play back a loaded recording on the analysis screen.
+    pub fn start_playback(&mut self, loaded: LoadedRecording, quit_on_exit: bool) {
+        self.state.screen = Screen::Analysis;
+        self.state.current_issue = None;
+        self.state.show_help = false;
+        self.state.reset_analysis();
+        self.state.playback = Some(Playback {
+            title: loaded.title,
+            pending: loaded.frames.into(),
+            started_at: Instant::now(),
+            quit_on_exit,
+        });
+        // Lines at the very start show up right away
+        self.tick_playback();
     }
 
-    /// Jump to bottom of list.
-    pub fn jump_to_bottom(&mut self) {
-        if !self.state.issues.is_empty() {
-            self.state.selected_index = self.state.issues.len() - 1;
+    /// Stop playing back a recording.
+    pub fn end_playback(&mut self) {
+        let Some(playback) = self.state.playback.take() else {
+            return;
+        };
+        if playback.quit_on_exit {
+            self.state.should_quit = true;
+            return;
         }
+        self.state.reset_analysis();
+        self.state.screen = Screen::List;
     }
 
-    /// Open the selected issue in detail view.
-    pub fn open_selected(&mut self) {
-        if self.state.issues.is_empty() {
+    /// Show the recorded lines whose time has come.
+    fn tick_playback(&mut self) {
+        let Some(playback) = self.state.playback.as_mut() else {
             return;
+        };
+        let elapsed = playback.started_at.elapsed();
+        while playback.pending.front().is_some_and(|(offset, _)| *offset <= elapsed) {
+            if let Some((_, line)) = playback.pending.pop_front() {
+                self.state.analysis_lines.push(line);
+            }
         }
+    }
 
-        self.state.screen = Screen::Detail;
-        self.state.detail_scroll = 0;
+    // === Tutorial ===
+
+    /// Swap in the demo issues and start the guided tutorial.
+    pub fn start_tutorial(&mut self, quit_on_exit: bool) {
+        if self.state.tutorial.is_some() {
+            return;
+        }
+        let saved_issues = std::mem::take(&mut self.state.issues);
+        let tutorial = Tutorial::new(saved_issues, self.state.selected_index, quit_on_exit);
+
+        self.state.issues = tutorial.data.issues.clone();
+        // The demo issues are all listed, whatever the view
+        self.state.rows = (0..self.state.issues.len()).collect();
+        self.state.selected_index = 0;
+        self.state.screen = Screen::List;
         self.state.current_issue = None;
+        self.state.peek = None;
+        self.state.show_help = false;
         self.state.reset_analysis();
+        self.state.tutorial = Some(tutorial);
     }
 
-    /// Go back to list view.
-    pub fn back_to_list(&mut self) {
+    /// Leave the tutorial, restoring the real issue list.
+    pub fn end_tutorial(&mut self) {
+        let Some(tutorial) = self.state.tutorial.take() else {
+            return;
+        };
+        if tutorial.quit_on_exit {
+            self.state.should_quit = true;
+            return;
+        }
+
+        self.state.selected_index = tutorial.saved_selected;
+        self.state.set_issues(tutorial.saved_issues);
         self.state.screen = Screen::List;
         self.state.current_issue = None;
-        self.state.detail_scroll = 0;
-        self.state.analysis_lines.clear();
+        self.state.show_help = false;
+        self.state.reset_analysis();
+        self.start_refresh();
     }
 
-    /// Go back from analysis to detail view.
-    pub fn back_to_detail(&mut self) {
-        self.state.screen = Screen::Detail;
+    /// Check an action that moves an issue along the workflow against the
+    /// transition table, and say why when it can't be taken.
+    pub fn transition_allowed(&mut self, action: &Action) -> bool {
+        let Some((issue_action, status)) = self.blocked_transition(action) else {
+            return true;
+        };
+        let message = format!(
+            "{} isn't possible while {}",
+            issue_action.label(),
+            status.replace('_', " ")
+        );
+        self.state.toasts.warning(message);
+        false
     }
 
-    /// Open proposal screen.
-    pub fn open_proposal(&mut self) {
-        self.state.screen = Screen::Proposal;
-        self.state.proposal_scroll = 0;
+    /// The workflow step `action` asks for and the issue's status, when the
+    /// transition table doesn't allow it. Issues that haven't loaded yet
+    /// aren't held back; the actions wait for them.
+    pub fn blocked_transition(&self, action: &Action) -> Option<(IssueAction, &str)> {
+        let issue_action = screens::requested_transition(action)?;
+        let (allowed, status) = match issue_action {
+            IssueAction::AnalyzeFromList => {
+                let issue = self.state.selected_issue()?;
+                (transitions::allows(&issue.status, issue_action), issue.status.as_str())
+            }
+            _ => {
+                let issue = self.state.current_issue.as_ref()?;
+                let allowed = transitions::allowed_actions(&issue.state).contains(&issue_action);
+                (allowed, issue.state.status())
+            }
+        };
+        (!allowed).then_some((issue_action, status))
     }
 
-    /// Go back from proposal to detail view.
-    pub fn back_from_proposal(&mut self) {
-        self.state.screen = Screen::Detail;
-    }
+    /// Handle an action locally while the tutorial runs.
+    ///
+    /// Returns `false` for purely local actions (navigation, scrolling) that
+    /// should run as usual; anything that would reach the server is simulated
+    /// against the demo data or refused.
+    pub fn tutorial_action(&mut self, action: &Action) -> bool {
+        let Some(tutorial) = &self.state.tutorial else {
+            return false;
+        };
+        let demo = tutorial.data.detail.clone();
 
-    // === Scrolling ===
+        match action {
+            Action::Quit | Action::EndTutorial => self.end_tutorial(),
+            Action::OpenSelected => {
+                if self.state.selected_issue_id() == Some(demo.id.as_str()) {
+                    self.open_selected();
+                    self.state.current_issue = Some(demo);
+                } else {
+                    self.state.toasts.info("This tutorial follows DEMO-2");
+                }
+            }
+            Action::BackToDetail => self.back_to_detail(),
+            Action::AnalyzeFromDetail if self.state.current_issue.is_some() => {
+                self.start_demo_analysis();
+            }
+            // The demo takes notes whatever the server supports
+            Action::ApproveProposal => self.open_approve_prompt(),
+            Action::RejectProposal => self.open_reject_prompt(),
+            Action::SubmitPrompt => match self.take_prompt() {
+                Some((Prompt::ApproveInstructions, _)) => {
+                    if let Some(issue) = &mut self.state.current_issue {
+                        issue.state = IssueState::InProgress {
+                            analysis_session_id: "demo".to_string(),
+                            implementation_session_id: "demo".to_string(),
+                            worktree_path: "../demo-worktree".to_string(),
+                            worktree_branch: "glass/demo-2".to_string(),
+                        };
+                    }
+                    self.state.toasts.success("Proposal approved");
+                    self.back_from_proposal();
+                }
+                Some((Prompt::RejectReason, reason)) => {
+                    if let Some(issue) = &mut self.state.current_issue {
+                        issue.state = IssueState::Pending;
+                        issue.rejections.push(Rejection {
+                            reason,
+                            at: OffsetDateTime::now_utc().format(&Rfc3339).unwrap_or_default(),
+                        });
+                    }
+                    self.state.toasts.info("Proposal rejected");
+                    self.back_from_proposal();
+                }
+                Some((Prompt::AnalyzeInstructions, _)) => self.start_demo_analysis(),
+                Some((Prompt::AddIssue, _)) | None => {}
+            },
+            Action::None
+            | Action::ToggleHelp
+            | Action::MoveSelection(_)
+            | Action::JumpToTop
+            | Action::JumpToBottom
+            | Action::ScrollDetail(_)
+            | Action::ScrollAnalysis(_)
+            | Action::ScrollProposal(_)
+            | Action::ScrollError(_)
+            | Action::FocusFrame(_)
+            | Action::ZoomTimeline(_)
+            | Action::ToggleTimeline
+            | Action::ToggleDiffLineNumbers
+            | Action::ToggleWrap
+            | Action::ScrollColumns(_)
+            | Action::SelectErrorAction(_)
+            | Action::BackToList
+            | Action::BackFromProposal
+            | Action::OpenProposal
+            | Action::OpenAnalysis
+            | Action::AnalyzeWithInstructions
+            | Action::PromptInput(_)
+            | Action::CancelPrompt
+            | Action::OpenCommandLine
+            | Action::CommandInput(_)
+            | Action::CompleteCommand
+            | Action::CommandHistory(_)
+            | Action::CancelCommand
+            | Action::SetOption(_, _)
+            | Action::ToggleIgnored
+            | Action::GroupIssues(_)
+            | Action::ToggleGroup
+            | Action::ToggleAllGroups
+            | Action::UnfocusFrame
+            | Action::UnfoldFrames
+            | Action::OpenBreadcrumbs
+            | Action::MoveBreadcrumbSelection(_)
+            | Action::ToggleBreadcrumbData
+            | Action::CycleBreadcrumbCategory(_)
+            | Action::FocusSection(_)
+            | Action::ToggleSection
+            | Action::UnfocusSection
+            | Action::ToggleFrameContext
+            | Action::ToggleIssueChanges
+            | Action::OpenYankMenu
+            | Action::MoveYankSelection(_)
+            | Action::Yank(_)
+            | Action::CloseYankMenu => return false,
+            _ => self.state.toasts.info("Not available in the tutorial"),
+        }
+        true
+    }
 
-    pub fn scroll_detail(&mut self, delta: i32) {
-        let new_scroll = self.state.detail_scroll as i32 + delta;
-        self.state.detail_scroll = new_scroll.max(0) as usize;
+    /// Stream the demo analysis as if the server had started one.
+    fn start_demo_analysis(&mut self) {
+        if let Some(tutorial) = &mut self.state.tutorial {
+            tutorial.pending_events = tutorial.data.analysis.iter().cloned().collect();
+        }
+        self.state.screen = Screen::Analysis;
+        self.state.reset_analysis();
+        self.state.analysis_progress.start();
+        self.state.is_streaming_analysis = true;
+        if let Some(issue) = &mut self.state.current_issue {
+            issue.state = IssueState::Analyzing {
+                analysis_session_id: "demo".to_string(),
+            };
+        }
     }
 
-    pub fn scroll_analysis(&mut self, delta: i32) {
-        let new_scroll = self.state.analysis_scroll as i32 + delta;
-        self.state.analysis_scroll = new_scroll.max(0) as usize;
+    /// Move the tutorial on once the current step is done.
+    pub fn advance_tutorial(&mut self) {
+        if let Some(mut tutorial) = self.state.tutorial.take() {
+            tutorial.advance(&self.state);
+            self.state.tutorial = Some(tutorial);
+        }
     }
 
-    pub fn scroll_proposal(&mut self, delta: i32) {
-        let new_scroll = self.state.proposal_scroll as i32 + delta;
-        self.state.proposal_scroll = new_scroll.max(0) as usize;
+    /// Play back the next demo analysis event.
+    fn tick_tutorial(&mut self) {
+        let Some(event) = self
+            .state
+            .tutorial
+            .as_mut()
+            .and_then(|t| t.pending_events.pop_front())
+        else {
+            return;
+        };
+        analysis::handle_analysis_event(&mut self.state, event);
+        self.advance_tutorial();
     }
 
     // === Actions ===
 
-    /// Get session path for interactive pi (escape hatch).
-    pub async fn get_session_path(&self) -> Option<String> {
-        let issue_id = self.state.selected_issue_id()?;
+    /// Open the current issue's session replay in the browser.
+    pub fn open_replay(&mut self) {
+        let Some(replay) = self.state.current_issue.as_ref().and_then(|i| i.source.replay.as_ref())
+        else {
+            return;
+        };
+
+        match open::that_detached(&replay.url) {
+            Ok(()) => self.state.toasts.info("Opened replay in browser"),
+            Err(e) => self.state.toasts.error(format!("Failed to open replay: {}", e)),
+        }
+    }
+
+    /// Get the session to resume interactively (escape hatch).
+    ///
+    /// Issues with both an analysis and an implementation session get the
+    /// session picker instead (and `None`), starting on the one last opened.
+    pub async fn get_session(&mut self) -> Option<SessionRef> {
+        let issue_id = self.state.open_issue_id()?.to_string();
+
+        let session = self.bg.client_for(&issue_id).get_session(&issue_id).await.ok()?;
+        match (session.analysis_session, session.implementation_session) {
+            (Some(analysis), Some(implementation)) => {
+                let selected = self
+                    .state
+                    .session_choices
+                    .get(&issue_id)
+                    .copied()
+                    .unwrap_or(SessionKind::Implementation);
+                self.state.session_picker = Some(SessionPicker {
+                    issue_id,
+                    analysis,
+                    implementation,
+                    selected,
+                });
+                None
+            }
+            (analysis, implementation) => analysis.or(implementation),
+        }
+    }
+
+    pub fn toggle_session_selection(&mut self) {
+        if let Some(picker) = &mut self.state.session_picker {
+            picker.selected = match picker.selected {
+                SessionKind::Analysis => SessionKind::Implementation,
+                SessionKind::Implementation => SessionKind::Analysis,
+            };
+        }
+    }
+
+    pub fn cancel_session_picker(&mut self) {
+        self.state.session_picker = None;
+    }
+
+    /// Open `session` in a new pane or window when glass runs in tmux or
+    /// zellij and the config says to, or ask where to open it. Returns the
+    /// session if it should take over this terminal instead.
+    pub fn resume_session(&mut self, session: SessionRef) -> Option<SessionRef> {
+        if self.state.multiplexer.is_none() {
+            return Some(session);
+        }
+        let placement = match self.state.escape_open_in {
+            OpenIn::Ask => {
+                self.state.placement_prompt = Some(session);
+                return None;
+            }
+            OpenIn::Terminal => return Some(session),
+            OpenIn::Pane => Placement::Pane,
+            OpenIn::Window => Placement::Window,
+        };
+        self.open_session_in(&session, placement);
+        None
+    }
+
+    /// Answer the placement prompt: a new pane or window, or (`None`) this
+    /// terminal, in which case the session is returned to run here.
+    pub fn resume_in(&mut self, placement: Option<Placement>) -> Option<SessionRef> {
+        let session = self.state.placement_prompt.take()?;
+        match placement {
+            Some(placement) => {
+                self.open_session_in(&session, placement);
+                None
+            }
+            None => Some(session),
+        }
+    }
+
+    pub fn cancel_placement_prompt(&mut self) {
+        self.state.placement_prompt = None;
+    }
 
-        match self.bg.client().get_session(issue_id).await {
-            Ok(session) => session
-                .analysis_session
-                .map(|s| s.path)
-                .or(session.implementation_session.map(|s| s.path)),
-            Err(_) => None,
+    fn open_session_in(&mut self, session: &SessionRef, placement: Placement) {
+        let Some(multiplexer) = self.state.multiplexer else {
+            return;
+        };
+        let program = escape::program(&self.state.escape_command).to_string();
+        let place = format!("{} {}", multiplexer.name(), multiplexer.describe(placement));
+        match escape::open_agent_in(multiplexer, placement, &self.state.escape_command, session) {
+            Ok(()) => self.state.toasts.info(format!("Opened {} in a new {}", program, place)),
+            Err(e) => self.state.toasts.error(format!("Couldn't open a {}: {}", place, e)),
         }
     }
 
+    /// Close the session picker on `kind` (or the selected session),
+    /// remembering the choice for the issue, and return it.
+    pub fn pick_session(&mut self, kind: Option<SessionKind>) -> Option<SessionRef> {
+        let picker = self.state.session_picker.take()?;
+        let kind = kind.unwrap_or(picker.selected);
+        self.state.session_choices.insert(picker.issue_id, kind);
+        self.save_workspace();
+        Some(match kind {
+            SessionKind::Analysis => picker.analysis,
+            SessionKind::Implementation => picker.implementation,
+        })
+    }
+
     /// Start analysis on current issue from list view (headless).
     pub async fn analyze_issue_from_list(&mut self) {
         let Some(issue_id) = self.state.selected_issue_id().map(|s| s.to_string()) else {
             return;
         };
 
-        match self.bg.client().analyze(&issue_id).await {
+        match self.bg.client_for(&issue_id).analyze(&issue_id, None, None).await {
             Ok(_) => {
+                let label = self.state.issue_label(&issue_id);
+                self.state.toasts.info(format!("Analysis started for {}", label));
                 self.start_refresh();
             }
             Err(e) => {
-                self.state.error = Some(format!("Failed to start analysis: {}", e));
+                self.fail_action(IssueAction::AnalyzeFromList, &issue_id, "Failed to start analysis", e);
             }
         }
     }
 
+    /// Re-analyze the current issue from the event selected in the events
+    /// browser.
+    pub async fn analyze_from_event(&mut self) {
+        let Some(picker) = self.state.event_picker.take() else {
+            return;
+        };
+        let event_id = match picker.events {
+            Some(Ok(events)) => events.into_iter().nth(picker.selected).map(|e| e.id),
+            _ => None,
+        };
+        if event_id.is_some() {
+            self.analyze_issue(None, event_id).await;
+        }
+    }
+
     /// Start analysis on current issue (from detail view).
-    pub async fn analyze_issue(&mut self) {
+    pub async fn analyze_issue(&mut self, instructions: Option<String>, event_id: Option<String>) {
         if self.state.current_issue.is_none() || self.state.is_refreshing_detail {
-            self.state.error = Some("Please wait for issue details to load".to_string());
+            self.state.toasts.warning("Please wait for issue details to load");
             return;
         }
 
@@ -306,20 +3432,38 @@ impl App {
             text: "Starting analysis...".to_string(),
             style: ActivityStyle::Normal,
         });
+        if let Some(event_id) = &event_id {
+            self.state.analysis_lines.push(ActivityLine {
+                icon: "  ",
+                text: format!("Based on event {}", event_id),
+                style: ActivityStyle::Dimmed,
+            });
+        }
+        for line in instructions.iter().flat_map(|text| text.lines()) {
+            self.state.analysis_lines.push(ActivityLine {
+                icon: "  ",
+                text: line.to_string(),
+                style: ActivityStyle::Dimmed,
+            });
+        }
 
         self.state.is_loading = true;
-        match self.bg.client().analyze(&issue_id).await {
+        self.state.analyze_instructions = instructions;
+        self.state.analyze_event_id = event_id;
+        let instructions = self.state.analyze_instructions.as_deref();
+        let event_id = self.state.analyze_event_id.as_deref();
+        match self.bg.client_for(&issue_id).analyze(&issue_id, instructions, event_id).await {
             Ok(_) => {
                 self.start_analysis_stream(&issue_id);
                 self.refresh_current_issue().await;
             }
             Err(e) => {
-                self.state.error = Some(format!("Failed to start analysis: {}", e));
                 self.state.analysis_lines.push(ActivityLine {
                     icon: "✗",
                     text: format!("Failed: {}", e),
                     style: ActivityStyle::Error,
                 });
+                self.fail_action(IssueAction::Analyze, &issue_id, "Failed to start analysis", e);
             }
         }
         self.state.is_loading = false;
@@ -333,32 +3477,46 @@ impl App {
         }
 
         self.state.is_streaming_analysis = true;
+        self.state.analysis_progress.start();
+        if self.state.spill_analysis_overflow {
+            let label = self.state.issue_label(issue_id);
+            let path = logging::analysis_spill_path(&label);
+            let events = path.as_ref().map(|path| path.with_extension("events.jsonl"));
+            self.state.analysis_lines.set_spill_path(path);
+            self.state.analysis_events.set_spill_path(events);
+        }
         self.bg.spawn_analysis_stream(issue_id);
     }
 
     /// Approve proposal on current issue.
-    pub async fn approve_proposal(&mut self) {
+    pub async fn approve_proposal(&mut self, instructions: Option<String>) {
         let Some(issue_id) = self.state.selected_issue_id().map(|s| s.to_string()) else {
             return;
         };
 
         self.state.is_loading = true;
-        if let Err(e) = self.bg.client().approve(&issue_id).await {
-            self.state.error = Some(format!("Failed to approve: {}", e));
+        self.state.approve_instructions = instructions;
+        let instructions = self.state.approve_instructions.as_deref();
+        match self.bg.client_for(&issue_id).approve(&issue_id, instructions).await {
+            Ok(_) => self.state.toasts.success("Proposal approved"),
+            Err(e) => self.fail_action(IssueAction::Approve, &issue_id, "Failed to approve", e),
         }
         self.refresh_current_issue().await;
         self.state.is_loading = false;
     }
 
-    /// Reject proposal on current issue.
-    pub async fn reject_proposal(&mut self) {
+    /// Reject proposal on current issue, with the reviewer's reason.
+    pub async fn reject_proposal(&mut self, reason: Option<String>) {
         let Some(issue_id) = self.state.selected_issue_id().map(|s| s.to_string()) else {
             return;
         };
 
         self.state.is_loading = true;
-        if let Err(e) = self.bg.client().reject(&issue_id).await {
-            self.state.error = Some(format!("Failed to reject: {}", e));
+        self.state.reject_reason = reason;
+        let reason = self.state.reject_reason.as_deref();
+        match self.bg.client_for(&issue_id).reject(&issue_id, reason).await {
+            Ok(_) => self.state.toasts.info("Proposal rejected"),
+            Err(e) => self.fail_action(IssueAction::Reject, &issue_id, "Failed to reject", e),
         }
         self.refresh_current_issue().await;
         self.state.is_loading = false;
@@ -371,8 +3529,21 @@ impl App {
         };
 
         self.state.is_loading = true;
-        if let Err(e) = self.bg.client().complete(&issue_id).await {
-            self.state.error = Some(format!("Failed to complete: {}", e));
+        match self.bg.client_for(&issue_id).complete(&issue_id).await {
+            Ok(_) => {
+                match self.watch_fix(&issue_id).await {
+                    Some(hours) => self
+                        .state
+                        .toasts
+                        .success(format!("Review completed; watching for new events for {}h", hours)),
+                    None => self.state.toasts.success("Review completed"),
+                }
+                // The diff is done with once the fix is accepted
+                if self.state.screen == Screen::Review {
+                    self.state.screen = Screen::Detail;
+                }
+            }
+            Err(e) => self.fail_action(IssueAction::Complete, &issue_id, "Failed to complete", e),
         }
         self.refresh_current_issue().await;
         self.state.is_loading = false;
@@ -385,10 +3556,147 @@ impl App {
         };
 
         self.state.is_loading = true;
-        if let Err(e) = self.bg.client().retry(&issue_id).await {
-            self.state.error = Some(format!("Failed to retry: {}", e));
+        match self.bg.client_for(&issue_id).retry(&issue_id).await {
+            Ok(_) => self.state.toasts.info("Retrying"),
+            Err(e) => self.fail_action(IssueAction::Retry, &issue_id, "Failed to retry", e),
         }
         self.refresh_current_issue().await;
         self.state.is_loading = false;
     }
+
+    /// Record a failed issue action, keeping the server response for the
+    /// error details modal.
+    fn fail_action(&mut self, action: IssueAction, issue_id: &str, context: &str, err: anyhow::Error) {
+        let toast = Toast::new(Severity::Error, format!("{}: {}", context, err));
+        let toast = match err.downcast_ref::<ApiError>() {
+            Some(error) => toast.with_details(FailedAction {
+                action,
+                issue_id: issue_id.to_string(),
+                error: error.clone(),
+            }),
+            None => toast,
+        };
+        self.state.toasts.push(toast);
+    }
+
+    /// Open or close the error details modal.
+    pub fn toggle_error_details(&mut self) {
+        self.state.show_error_details =
+            !self.state.show_error_details && self.state.error_details().is_some();
+    }
+
+    /// Re-run the failed action shown in the error details modal.
+    pub async fn retry_failed_action(&mut self) {
+        self.state.show_error_details = false;
+        let Some(failed) = self.state.error_details().cloned() else {
+            return;
+        };
+        if self.state.selected_issue_id() != Some(failed.issue_id.as_str()) {
+            self.state.toasts.warning("Issue is no longer selected");
+            return;
+        }
+
+        // The retry reports its own outcome
+        self.state.toasts.dismiss_details();
+        match failed.action {
+            IssueAction::AnalyzeFromList => self.analyze_issue_from_list().await,
+            IssueAction::Analyze => {
+                let instructions = self.state.analyze_instructions.clone();
+                let event_id = self.state.analyze_event_id.clone();
+                self.analyze_issue(instructions, event_id).await
+            }
+            IssueAction::Approve => self.approve_proposal(self.state.approve_instructions.clone()).await,
+            IssueAction::Reject => self.reject_proposal(self.state.reject_reason.clone()).await,
+            IssueAction::Complete => self.complete_review().await,
+            IssueAction::Retry => self.retry_error().await,
+            IssueAction::Merge => self.merge_marked().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app_with_sections(sections: &[DetailSection]) -> App {
+        let mut app = App::new("http://localhost:1".into(), None);
+        app.state.screen = Screen::Detail;
+        app.state.current_issue = Some(
+            serde_json::from_value(serde_json::json!({
+                "id": "a", "sourceType": "sentry", "status": "pending", "source": {},
+                "state": {"status": "pending"}, "createdAt": "", "updatedAt": "",
+            }))
+            .unwrap(),
+        );
+        app.state.detail_scroll.set_rendered(200, 10);
+        *app.state.section_rows.borrow_mut() = sections.iter().enumerate().map(|(i, s)| (*s, i * 10)).collect();
+        app
+    }
+
+    #[test]
+    fn test_focus_section_wraps_around() {
+        use DetailSection::{Breadcrumbs, Exception, Tags};
+        let mut app = app_with_sections(&[Exception, Breadcrumbs, Tags]);
+
+        app.focus_section(-1);
+        assert_eq!(app.state.section_focus.focused(), Some(Tags));
+        assert_eq!(app.state.detail_scroll.offset(), 20);
+        app.focus_section(1);
+        assert_eq!(app.state.section_focus.focused(), Some(Exception));
+        assert_eq!(app.state.detail_scroll.offset(), 0);
+
+        // A section that's gone from the last render loses the focus
+        *app.state.section_rows.borrow_mut() = vec![(Breadcrumbs, 0), (Tags, 10)];
+        app.focus_section(1);
+        assert_eq!(app.state.section_focus.focused(), Some(Breadcrumbs));
+    }
+
+    #[test]
+    fn test_toggle_section_folds_per_issue() {
+        let mut app = app_with_sections(&[DetailSection::Exception, DetailSection::Tags]);
+        app.toggle_section();
+        assert!(app.state.collapsed_sections.is_empty(), "nothing focused to fold");
+
+        app.focus_section(1);
+        app.toggle_section();
+        assert!(app.is_collapsed(DetailSection::Exception));
+        assert!(!app.is_collapsed(DetailSection::Tags));
+
+        app.toggle_section();
+        assert!(!app.is_collapsed(DetailSection::Exception));
+        // Issues with nothing folded aren't kept around
+        assert!(app.state.collapsed_sections.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rejection_asks_for_a_reason_only_if_the_server_takes_one() {
+        let mut app = App::new("http://localhost:1".into(), None);
+        app.state.current_issue = Some(
+            serde_json::from_value(serde_json::json!({
+                "id": "a", "sourceType": "sentry", "status": "pending_approval", "source": {},
+                "state": {"status": "pending_approval", "analysisSessionId": "s", "proposal": ""},
+                "createdAt": "", "updatedAt": "",
+            }))
+            .unwrap(),
+        );
+
+        app.start_rejection().await;
+        assert_eq!(app.state.prompt, Some(Prompt::RejectReason));
+
+        app.cancel_prompt();
+        app.state.capabilities = ServerCapabilities::Advertised(Default::default());
+        app.start_rejection().await;
+        assert_eq!(app.state.prompt, None);
+    }
+
+    #[test]
+    fn test_set_theme_picks_the_palette() {
+        let mut app = App::new("http://localhost:1".into(), None);
+        app.set_option("theme", "light");
+        assert_eq!(app.state.theme.palette, Palette::Light);
+        app.set_option("palette", "color-blind");
+        assert_eq!(app.state.theme.palette, Palette::ColorBlind);
+        app.set_option("theme", "dark");
+        assert_eq!(app.state.theme.palette, Palette::Default);
+    }
 }
@@ -0,0 +1,143 @@
+//! The form for creating an issue by hand, for bugs that never reached
+//! Sentry (reported on Slack, in a support ticket, ...).
+
+use crate::api::CreateIssueRequest;
+use crate::ui::focus::FocusRing;
+use super::input::TextInput;
+
+/// A field of the new issue form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewIssueField {
+    Title,
+    Description,
+    StackTrace,
+}
+
+impl NewIssueField {
+    pub const ALL: [NewIssueField; 3] = [
+        NewIssueField::Title,
+        NewIssueField::Description,
+        NewIssueField::StackTrace,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            NewIssueField::Title => "Title",
+            NewIssueField::Description => "Description",
+            NewIssueField::StackTrace => "Stack trace (optional)",
+        }
+    }
+
+    /// Placeholder shown while the field is empty.
+    pub fn placeholder(self) -> &'static str {
+        match self {
+            NewIssueField::Title => "e.g. Checkout button does nothing on Safari",
+            NewIssueField::Description => "What was reported, and how to reproduce it",
+            NewIssueField::StackTrace => "Paste one if the report came with it",
+        }
+    }
+
+    /// Whether Enter starts a new line rather than moving to the next field.
+    pub fn multiline(self) -> bool {
+        self != NewIssueField::Title
+    }
+}
+
+/// The open new issue form.
+#[derive(Debug)]
+pub struct NewIssueForm {
+    pub title: TextInput,
+    pub description: TextInput,
+    pub stacktrace: TextInput,
+    pub focus: FocusRing<NewIssueField>,
+    /// Sent to the server and waiting on its answer
+    pub submitting: bool,
+}
+
+impl Default for NewIssueForm {
+    fn default() -> Self {
+        Self {
+            title: TextInput::default(),
+            description: TextInput::default(),
+            stacktrace: TextInput::default(),
+            focus: FocusRing::new(NewIssueField::ALL),
+            submitting: false,
+        }
+    }
+}
+
+impl NewIssueForm {
+    /// The field being typed into.
+    pub fn focused(&self) -> NewIssueField {
+        self.focus.focused().unwrap_or(NewIssueField::Title)
+    }
+
+    pub fn input(&self, field: NewIssueField) -> &TextInput {
+        match field {
+            NewIssueField::Title => &self.title,
+            NewIssueField::Description => &self.description,
+            NewIssueField::StackTrace => &self.stacktrace,
+        }
+    }
+
+    /// The input of the field being typed into.
+    pub fn focused_input(&mut self) -> &mut TextInput {
+        match self.focused() {
+            NewIssueField::Title => &mut self.title,
+            NewIssueField::Description => &mut self.description,
+            NewIssueField::StackTrace => &mut self.stacktrace,
+        }
+    }
+
+    /// What to send to the server, or why it can't be sent yet.
+    pub fn request(&self) -> Result<CreateIssueRequest, &'static str> {
+        let title = self.title.text().trim();
+        if title.is_empty() {
+            return Err("Give the issue a title");
+        }
+        let stacktrace = self.stacktrace.text().trim_end();
+        Ok(CreateIssueRequest {
+            title: title.to_string(),
+            description: self.description.text().trim().to_string(),
+            stacktrace: (!stacktrace.trim().is_empty()).then(|| stacktrace.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typing_goes_to_the_focused_field() {
+        let mut form = NewIssueForm::default();
+        form.focused_input().insert_str("Checkout hangs");
+        form.focus.traverse(-1);
+        assert_eq!(form.focused(), NewIssueField::StackTrace);
+        form.focused_input().insert_str("TypeError: boom");
+        assert_eq!(form.title.text(), "Checkout hangs");
+        assert_eq!(form.stacktrace.text(), "TypeError: boom");
+    }
+
+    #[test]
+    fn test_request_needs_a_title() {
+        let mut form = NewIssueForm::default();
+        form.description.insert_str("Spinner never stops");
+        assert!(form.request().is_err());
+
+        form.title.insert_str("  Checkout hangs ");
+        form.stacktrace.insert_str("   \n");
+        let request = form.request().unwrap();
+        assert_eq!(request.title, "Checkout hangs");
+        assert_eq!(request.description, "Spinner never stops");
+        assert_eq!(request.stacktrace, None);
+
+        // Indentation of a pasted trace is kept
+        form.stacktrace.clear();
+        form.stacktrace.insert_str("TypeError: boom\n    at pay (checkout.ts:42)\n");
+        assert_eq!(
+            form.request().unwrap().stacktrace.as_deref(),
+            Some("TypeError: boom\n    at pay (checkout.ts:42)")
+        );
+    }
+}
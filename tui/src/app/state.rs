@@ -1,14 +1,72 @@
 //! Pure application state - data only, no logic.
 
-use crate::api::{Issue, IssueDetail};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    AnalysisEvent, ApiError, Capability, Issue, IssueDetail, IssueEvent, IssueSource, PlanResponse, QueuedAnalysis,
+    SessionRef, TagStats,
+};
+use crate::config::{IgnoreConfig, OpenIn, PathConfig, SentryConfig, ThemeConfig};
+use crate::escape::{Multiplexer, DEFAULT_ESCAPE_COMMAND};
+use crate::server::{StartFailure, DEFAULT_PORT};
+use crate::startup::StartupProfile;
+use crate::git::{AtRisk, BlameInfo, BranchLog, FileDiff, Worktree};
+use super::accel::ScrollAccelerator;
+use super::activity_log::ActivityLog;
+use super::changes::IssueChanges;
+use super::event_log::EventLog;
+use crate::ui::focus::FocusRing;
+use super::input::TextInput;
+use super::line_cache::LineCache;
+use super::new_issue::NewIssueForm;
+use super::toast::Toasts;
+use super::view::ListView;
+use super::tutorial::Tutorial;
+use super::watch::FixWatch;
+use super::yank::YankMenu;
+
+/// Smallest terminal width the layouts are designed for.
+pub const MIN_TERMINAL_WIDTH: u16 = 80;
+/// Smallest terminal height the layouts are designed for.
+pub const MIN_TERMINAL_HEIGHT: u16 = 24;
+/// Terminal width from which the list shares the screen with a preview of
+/// the selected issue.
+pub const SPLIT_MIN_WIDTH: u16 = 160;
+
+/// Breadcrumb timeline zoom levels as (seconds per row, seconds between
+/// ruler ticks).
+pub const TIMELINE_ZOOM_LEVELS: [(f64, f64); 7] = [
+    (0.1, 1.0),
+    (0.5, 5.0),
+    (1.0, 5.0),
+    (5.0, 30.0),
+    (15.0, 60.0),
+    (60.0, 300.0),
+    (300.0, 1800.0),
+];
+
+/// Zoom level used when the timeline is first opened.
+pub const DEFAULT_TIMELINE_ZOOM: usize = 2;
 
 /// Current screen being displayed.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Screen {
     List,
     Detail,
     Analysis,
+    Implementation,
+    Review,
     Proposal,
+    Error,
+    Worktrees,
+    Breadcrumbs,
+    RawJson,
 }
 
 /// A line in the analysis activity pane.
@@ -27,6 +85,681 @@ pub enum ActivityStyle {
     Thinking,
     Error,
     Success,
+    /// Inside a fenced code block, fences included; kept unwrapped
+    Code,
+}
+
+/// The list's peek popup: a summary of the selected issue.
+#[derive(Debug, Clone)]
+pub struct Peek {
+    pub issue_id: String,
+    /// `None` while the detail is loading
+    pub detail: Option<Result<Box<IssueDetail>, String>>,
+}
+
+/// Confirmation for merging the marked issues, with the primary picked.
+#[derive(Debug, Clone)]
+pub struct MergeDialog {
+    /// Issues being merged, in list order
+    pub issue_ids: Vec<String>,
+    /// Index into `issue_ids` of the issue the others merge into
+    pub primary: usize,
+}
+
+/// The detail screen's tag breakdown popup.
+#[derive(Debug)]
+pub struct TagBreakdown {
+    pub issue_id: String,
+    /// `None` while the stats are loading
+    pub tags: Option<Result<Vec<TagStats>, String>>,
+    pub scroll: ScrollView,
+}
+
+/// The detail screen's events browser, for picking the event a
+/// re-analysis starts from.
+#[derive(Debug)]
+pub struct EventPicker {
+    pub issue_id: String,
+    /// `None` while the events are loading
+    pub events: Option<Result<Vec<IssueEvent>, String>>,
+    pub selected: usize,
+}
+
+/// Which of an issue's events the detail screen shows, when stepping
+/// through them rather than looking at the one the issue came with.
+#[derive(Debug)]
+pub struct EventBrowser {
+    pub issue_id: String,
+    /// Most recent first, as many pages as have loaded
+    pub events: Vec<IssueEvent>,
+    /// Cursor for the next (older) page
+    pub next_cursor: Option<String>,
+    /// Whether the oldest page has loaded
+    pub complete: bool,
+    pub loading_page: bool,
+    /// Where to go once the page being loaded arrives
+    pub pending: Option<EventTarget>,
+    /// The event asked for, `None` for the one the issue came with
+    pub shown: Option<IssueEvent>,
+    /// The event whose details are on screen, which lags `shown` while
+    /// they load
+    pub applied: Option<IssueEvent>,
+    /// Details of the events fetched so far, by event ID
+    pub details: HashMap<String, IssueSource>,
+}
+
+impl EventBrowser {
+    pub fn new(issue_id: String) -> Self {
+        Self {
+            issue_id,
+            events: Vec::new(),
+            next_cursor: None,
+            complete: false,
+            loading_page: false,
+            pending: None,
+            shown: None,
+            applied: None,
+            details: HashMap::new(),
+        }
+    }
+
+    /// Index of the shown event among those loaded (the latest until one
+    /// is picked).
+    pub fn position(&self) -> usize {
+        self.shown
+            .as_ref()
+            .and_then(|shown| self.events.iter().position(|e| e.id == shown.id))
+            .unwrap_or(0)
+    }
+}
+
+/// An event to move the detail screen to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventTarget {
+    /// This many events back in time (negative for newer)
+    Older(i32),
+    Oldest,
+    Latest,
+}
+
+/// One of an issue's agent sessions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionKind {
+    Analysis,
+    Implementation,
+}
+
+impl SessionKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            SessionKind::Analysis => "Analysis",
+            SessionKind::Implementation => "Implementation",
+        }
+    }
+}
+
+/// A collapsible section of the detail screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DetailSection {
+    Source,
+    Description,
+    Workflow,
+    Rejections,
+    Request,
+    User,
+    Context,
+    Exception,
+    Message,
+    Cron,
+    Transaction,
+    Replay,
+    Breadcrumbs,
+    Tags,
+    Error,
+    Worktree,
+}
+
+/// The choice of session to resume, for issues that have both.
+#[derive(Debug)]
+pub struct SessionPicker {
+    pub issue_id: String,
+    pub analysis: SessionRef,
+    pub implementation: SessionRef,
+    pub selected: SessionKind,
+}
+
+/// The proposal screen's plan preview popup.
+#[derive(Debug)]
+pub struct PlanPreview {
+    pub issue_id: String,
+    /// `None` while the server works out the plan
+    pub plan: Option<Result<PlanResponse, String>>,
+    pub scroll: ScrollView,
+}
+
+/// A critic pass over the proposal, shown in a pane beside it.
+#[derive(Debug)]
+pub struct Critique {
+    pub issue_id: String,
+    /// Reviewer model, once the server names it
+    pub reviewer: Option<String>,
+    pub text: String,
+    pub status: CritiqueStatus,
+    pub scroll: ScrollView,
+}
+
+/// Where a critic pass is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CritiqueStatus {
+    Streaming,
+    Done,
+    Failed(String),
+}
+
+/// A test command run in a fix's worktree, shown under its diff.
+#[derive(Debug)]
+pub struct TestRun {
+    pub issue_id: String,
+    pub command: String,
+    /// Output lines, stdout and stderr interleaved
+    pub lines: ActivityLog,
+    pub status: TestStatus,
+    /// Follows new output
+    pub scroll: ScrollView,
+    pub started: Instant,
+    /// How long the run took, once it finished
+    pub duration: Option<Duration>,
+}
+
+/// Where a test run is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestStatus {
+    Running,
+    Passed,
+    Failed(String),
+    /// Stopped before it finished
+    Cancelled,
+}
+
+/// The confirmation before completing a review, listing the commits the
+/// branch keeps so they can be reworded or squashed first, and any work
+/// removing the worktree would lose.
+#[derive(Debug)]
+pub struct FinishReview {
+    pub issue_id: String,
+    pub worktree: PathBuf,
+    /// `None` while loading
+    pub log: Option<Result<BranchLog, String>>,
+    /// `None` while checking
+    pub at_risk: Option<Result<AtRisk, String>>,
+}
+
+/// A pull request for a reviewed fix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PullRequest {
+    /// Pushing the branch and creating the pull request
+    Opening,
+    /// Created, at this URL
+    Opened(String),
+}
+
+/// The worktree management screen.
+#[derive(Debug, Default)]
+pub struct Worktrees {
+    /// Glass's worktrees (`None` while loading)
+    pub list: Option<Result<Vec<Worktree>, String>>,
+    pub selected: usize,
+    /// The worktree a second `d` removes
+    pub confirm_remove: Option<PathBuf>,
+}
+
+/// The breadcrumbs screen's filter, selection and expanded payloads.
+#[derive(Debug, Default)]
+pub struct BreadcrumbView {
+    /// Category shown, or every one
+    pub category: Option<String>,
+    /// Index into the breadcrumbs the filter shows
+    pub selected: usize,
+    /// Breadcrumbs showing their data, by index into the issue's breadcrumbs
+    pub expanded: HashSet<usize>,
+    pub scroll: ScrollView,
+    /// Rows each shown breadcrumb took up in the last render
+    pub rows: RefCell<Vec<Range<usize>>>,
+}
+
+impl BreadcrumbView {
+    /// Scroll just far enough to show the whole selected breadcrumb.
+    pub fn reveal_selected(&mut self) {
+        if let Some(rows) = self.rows.borrow().get(self.selected).cloned() {
+            self.scroll.reveal(rows);
+        }
+    }
+}
+
+/// The raw JSON viewer: the issue as the server sent it, as a foldable tree.
+#[derive(Debug)]
+pub struct JsonView {
+    pub issue_id: String,
+    /// `None` while the issue is loading
+    pub value: Option<Result<serde_json::Value, String>>,
+    /// Index into the tree's rows
+    pub selected: usize,
+    /// Paths of the folded objects and arrays
+    pub folded: HashSet<String>,
+    pub scroll: ScrollView,
+    /// Rows each tree row took up in the last render
+    pub rows: RefCell<Vec<Range<usize>>>,
+}
+
+impl JsonView {
+    pub fn new(issue_id: String) -> Self {
+        Self {
+            issue_id,
+            value: None,
+            selected: 0,
+            folded: HashSet::new(),
+            scroll: ScrollView::default(),
+            rows: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Scroll just far enough to show the whole selected row.
+    pub fn reveal_selected(&mut self) {
+        if let Some(rows) = self.rows.borrow().get(self.selected).cloned() {
+            self.scroll.reveal(rows);
+        }
+    }
+}
+
+/// What a worktree left in the project is still good for.
+#[derive(Debug, Clone, Copy)]
+pub enum WorktreeUse<'a> {
+    /// Its issue is still being fixed or reviewed
+    Active(&'a Issue),
+    /// Its issue has moved on (completed, rejected or failed), or no listed
+    /// issue claims it
+    Orphaned(Option<&'a Issue>),
+    /// Its directory is gone; only git's record is left
+    Stale,
+}
+
+/// Which optional features the connected server supports.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ServerCapabilities {
+    /// Not discovered, either not yet or because the lookup failed; nothing
+    /// is hidden
+    #[default]
+    Unknown,
+    /// What the server advertised; servers from before discovery advertise
+    /// nothing
+    Advertised(HashSet<Capability>),
+}
+
+impl ServerCapabilities {
+    /// Whether actions needing `capability` should be offered.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match self {
+            ServerCapabilities::Unknown => true,
+            ServerCapabilities::Advertised(set) => set.contains(&capability),
+        }
+    }
+}
+
+/// Whether the server glass manages is up yet.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ServerStatus {
+    /// Up, already running, or not glass's to start
+    #[default]
+    Ready,
+    /// Starting behind the UI; the first load waits for it
+    Connecting,
+    /// It never answered a health check
+    Failed(StartFailure),
+    /// It was lost mid-session and glass is starting it again, on restart
+    /// attempt `attempt`
+    Restarting { attempt: u32, cause: StartFailure },
+}
+
+/// How to signal that an analysis completed or failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BellMode {
+    #[default]
+    Off,
+    /// Ring the terminal bell
+    Audible,
+    /// Flash the status bar
+    Visual,
+}
+
+/// A server action on an issue that can be re-run after it fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueAction {
+    /// Headless analysis started from the list
+    AnalyzeFromList,
+    Analyze,
+    Approve,
+    Reject,
+    Complete,
+    Retry,
+    Merge,
+}
+
+impl IssueAction {
+    /// Short description for the error details modal.
+    pub fn label(self) -> &'static str {
+        match self {
+            IssueAction::AnalyzeFromList | IssueAction::Analyze => "Start analysis",
+            IssueAction::Approve => "Approve proposal",
+            IssueAction::Reject => "Reject proposal",
+            IssueAction::Complete => "Complete review",
+            IssueAction::Retry => "Retry after error",
+            IssueAction::Merge => "Merge issues",
+        }
+    }
+}
+
+/// A modal prompt collecting text for an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prompt {
+    /// Optional reason for rejecting a proposal
+    RejectReason,
+    /// Guidance for re-running the analysis
+    AnalyzeInstructions,
+    /// Optional notes for the implementation of an approved proposal
+    ApproveInstructions,
+    /// Sentry URL or ID of an issue to add to the list
+    AddIssue,
+}
+
+impl Prompt {
+    pub fn title(self) -> &'static str {
+        match self {
+            Prompt::RejectReason => "Reject proposal",
+            Prompt::AnalyzeInstructions => "Re-analyze with instructions",
+            Prompt::ApproveInstructions => "Approve proposal",
+            Prompt::AddIssue => "Add issue",
+        }
+    }
+
+    /// Placeholder shown while the input is empty.
+    pub fn placeholder(self) -> &'static str {
+        match self {
+            Prompt::RejectReason => "Why? (optional, passed on to the next analysis)",
+            Prompt::AnalyzeInstructions => "e.g. focus on the retry logic in payments.rs",
+            Prompt::ApproveInstructions => "Notes for the implementation (optional), e.g. add a regression test",
+            Prompt::AddIssue => "Sentry issue URL or ID, e.g. https://acme.sentry.io/issues/12345/",
+        }
+    }
+
+    /// What submitting does.
+    pub fn submit_label(self) -> &'static str {
+        match self {
+            Prompt::RejectReason => "reject",
+            Prompt::AnalyzeInstructions => "analyze",
+            Prompt::ApproveInstructions => "approve",
+            Prompt::AddIssue => "add",
+        }
+    }
+
+    /// Whether the prompt takes newlines, submitting on Ctrl+S (or Enter,
+    /// when Shift+Enter can be told apart).
+    pub fn multiline(self) -> bool {
+        matches!(self, Prompt::AnalyzeInstructions)
+    }
+}
+
+/// State of the open `:` command line besides its text.
+#[derive(Debug, Clone, Default)]
+pub struct CommandLine {
+    /// Position in the command history while browsing it
+    pub history_index: Option<usize>,
+    /// Completions being cycled through with Tab, and the one shown
+    pub completions: Option<(Vec<String>, usize)>,
+}
+
+/// A failed issue action together with the server's response.
+#[derive(Debug, Clone)]
+pub struct FailedAction {
+    pub action: IssueAction,
+    pub issue_id: String,
+    pub error: ApiError,
+}
+
+/// An analysis event and when it arrived, relative to the analysis start.
+#[derive(Debug, Clone)]
+pub struct RecordedEvent {
+    pub offset: Duration,
+    pub event: AnalysisEvent,
+}
+
+/// A recorded analysis being played back on the analysis screen.
+#[derive(Debug)]
+pub struct Playback {
+    pub title: String,
+    /// Lines not shown yet, with when to show them
+    pub pending: VecDeque<(Duration, ActivityLine)>,
+    pub started_at: Instant,
+    /// Loaded with `--replay`, so ending it quits
+    pub quit_on_exit: bool,
+}
+
+/// Live totals for the analysis being streamed.
+#[derive(Debug, Clone, Default)]
+pub struct AnalysisProgress {
+    pub started_at: Option<Instant>,
+    /// Set when the analysis completes or fails, freezing the timer
+    pub finished_at: Option<Instant>,
+    pub event_count: usize,
+    pub tool_calls: usize,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// Total cost in USD, if any usage event reported one
+    pub cost: Option<f64>,
+}
+
+impl AnalysisProgress {
+    /// Start counting from now.
+    pub fn start(&mut self) {
+        *self = Self {
+            started_at: Some(Instant::now()),
+            ..Self::default()
+        };
+    }
+
+    /// Stop the timer; later calls keep the first finish time.
+    pub fn finish(&mut self) {
+        if self.started_at.is_some() && self.finished_at.is_none() {
+            self.finished_at = Some(Instant::now());
+        }
+    }
+
+    /// Add one turn's token usage to the totals.
+    pub fn add_usage(&mut self, input_tokens: u64, output_tokens: u64, cost: Option<f64>) {
+        self.input_tokens += input_tokens;
+        self.output_tokens += output_tokens;
+        if let Some(cost) = cost {
+            self.cost = Some(self.cost.unwrap_or(0.0) + cost);
+        }
+    }
+
+    /// "12.3k in / 2.1k out · $0.42", or `None` before any usage arrived.
+    pub fn usage_summary(&self) -> Option<String> {
+        if self.input_tokens == 0 && self.output_tokens == 0 {
+            return None;
+        }
+        let mut summary = format!(
+            "{} in / {} out",
+            format_tokens(self.input_tokens),
+            format_tokens(self.output_tokens)
+        );
+        if let Some(cost) = self.cost {
+            summary.push_str(&format!(" · ${:.2}", cost));
+        }
+        Some(summary)
+    }
+
+    /// Time since the analysis started, up to when it finished.
+    pub fn elapsed(&self) -> Option<Duration> {
+        let started = self.started_at?;
+        let end = self.finished_at.unwrap_or_else(Instant::now);
+        Some(end.saturating_duration_since(started))
+    }
+}
+
+/// Compact token count: 950, 12.3k, 1.2M.
+fn format_tokens(n: u64) -> String {
+    match n {
+        0..=999 => n.to_string(),
+        1_000..=999_999 => format!("{:.1}k", n as f64 / 1_000.0),
+        _ => format!("{:.1}M", n as f64 / 1_000_000.0),
+    }
+}
+
+/// Scroll position of a scrollable view, clamped to its rendered content.
+///
+/// Rendering only has shared access to state, so the content and viewport
+/// heights from the last frame are recorded through `Cell`s.
+#[derive(Debug, Default)]
+pub struct ScrollView {
+    offset: usize,
+    content_height: Cell<usize>,
+    viewport_height: Cell<usize>,
+    /// Whether this view sticks to the bottom when scrolled to the end
+    sticky: bool,
+    /// Whether the view is currently following the end of the content
+    following: bool,
+    /// Whether long lines run off the right edge, to scroll sideways to,
+    /// rather than wrapping
+    unwrapped: bool,
+    /// Columns scrolled right while unwrapped
+    column: usize,
+    content_width: Cell<usize>,
+    viewport_width: Cell<usize>,
+}
+
+impl ScrollView {
+    /// A view that follows the end of the content as it grows.
+    pub fn sticky() -> Self {
+        Self {
+            sticky: true,
+            following: true,
+            ..Self::default()
+        }
+    }
+
+    /// Effective scroll offset, clamped to the last rendered content.
+    pub fn offset(&self) -> usize {
+        if self.following {
+            self.max_offset()
+        } else {
+            self.offset.min(self.max_offset())
+        }
+    }
+
+    /// Largest offset that still fills the viewport.
+    pub fn max_offset(&self) -> usize {
+        self.content_height
+            .get()
+            .saturating_sub(self.viewport_height.get())
+    }
+
+    /// Total content height from the last render.
+    pub fn content_height(&self) -> usize {
+        self.content_height.get()
+    }
+
+    /// Visible height from the last render.
+    pub fn viewport_height(&self) -> usize {
+        self.viewport_height.get()
+    }
+
+    /// Record the dimensions of the last render.
+    pub fn set_rendered(&self, content_height: usize, viewport_height: usize) {
+        self.content_height.set(content_height);
+        self.viewport_height.set(viewport_height);
+    }
+
+    /// Scroll by delta lines (positive = down), clamped to the content.
+    pub fn scroll_by(&mut self, delta: i32) {
+        let new_offset = (self.offset() as i64 + delta as i64).max(0) as usize;
+        self.offset = new_offset.min(self.max_offset());
+        if self.sticky {
+            self.following = self.offset >= self.max_offset();
+        }
+    }
+
+    /// Start or stop following the end of the content, keeping the current
+    /// position either way.
+    pub fn set_sticky(&mut self, sticky: bool) {
+        self.offset = self.offset();
+        self.sticky = sticky;
+        self.following = sticky && self.offset >= self.max_offset();
+    }
+
+    /// Jump to a remembered offset (clamped on the next render).
+    pub fn restore(&mut self, offset: usize) {
+        self.offset = offset;
+        self.following = false;
+    }
+
+    /// Return to the top (or to following the end, for sticky views).
+    pub fn reset(&mut self) {
+        self.offset = 0;
+        self.following = self.sticky;
+    }
+
+    /// Whether long lines wrap, rather than scroll sideways.
+    pub fn wraps(&self) -> bool {
+        !self.unwrapped
+    }
+
+    /// Switch between wrapping long lines and scrolling sideways.
+    pub fn toggle_wrap(&mut self) {
+        self.unwrapped = !self.unwrapped;
+        self.column = 0;
+    }
+
+    /// Columns scrolled right, clamped to the widest line of the last
+    /// render; always 0 while wrapping.
+    pub fn column(&self) -> usize {
+        if self.unwrapped {
+            self.column.min(self.max_column())
+        } else {
+            0
+        }
+    }
+
+    fn max_column(&self) -> usize {
+        self.content_width.get().saturating_sub(self.viewport_width.get())
+    }
+
+    /// Record the widest line and the width it's shown in, without borders.
+    pub fn set_rendered_width(&self, content_width: usize, viewport_width: usize) {
+        self.content_width.set(content_width);
+        self.viewport_width.set(viewport_width);
+    }
+
+    /// Scroll sideways by `delta` columns (positive = right) while unwrapped.
+    pub fn scroll_columns(&mut self, delta: i32) {
+        if self.unwrapped {
+            let column = (self.column() as i64 + delta as i64).max(0) as usize;
+            self.column = column.min(self.max_column());
+        }
+    }
+
+    /// Scroll just far enough to show `rows`, or their top if they don't
+    /// fit. The viewport height includes the borders.
+    pub fn reveal(&mut self, rows: Range<usize>) {
+        let top = self.offset();
+        let height = self.viewport_height().saturating_sub(2);
+        if rows.start < top {
+            self.restore(rows.start);
+        } else if rows.end > top + height {
+            self.restore(rows.end.saturating_sub(height).min(rows.start));
+        }
+    }
 }
 
 /// Pure application state container.
@@ -37,52 +770,279 @@ pub struct AppState {
     pub screen: Screen,
 
     // === List screen state ===
-    /// List of issues
+    /// Every issue, as the server lists them, whatever the view shows
     pub issues: Vec<Issue>,
+    /// The list's rows: indexes into `issues` of the ones the view shows,
+    /// in the order it lists them
+    pub rows: Vec<usize>,
+    /// Filters and sort order set from the command line
+    pub view: ListView,
+    /// Known-noisy issues the list leaves out, from the project config
+    pub ignore: IgnoreConfig,
+    /// Whether ignored issues are shown anyway
+    pub show_ignored: bool,
+    /// Groups folded down to their header, by key, while the list is grouped
+    pub collapsed_groups: HashSet<String>,
+    /// Whether the view, pin and session choices are saved for next time
+    pub persist_workspace: bool,
     /// Currently selected index in list
     pub selected_index: usize,
+    /// Summary popup for the selected issue, while open
+    pub peek: Option<Peek>,
+    /// Selected issue in the preview pane beside a wide list
+    pub preview: Option<Peek>,
+    /// Issues marked for merging
+    pub merge_marks: Vec<String>,
+    /// Issue kept in the summary line wherever you go
+    pub pinned_issue: Option<String>,
+    /// Merge confirmation, while open
+    pub merge_dialog: Option<MergeDialog>,
+    /// Projects whose issues are listed, the primary first; empty unless
+    /// aggregating several servers
+    pub projects: Vec<String>,
+    /// Selected row of the project switcher while it's open, where row 0
+    /// is every project
+    pub project_switcher: Option<usize>,
+    /// Queue positions of analyses the server hasn't started, by issue ID
+    pub analysis_queue: HashMap<String, QueuedAnalysis>,
+    /// When to check the queue again, while anything is in it
+    pub next_queue_poll: Option<Instant>,
 
     // === Detail screen state ===
     /// Currently viewed issue detail
     pub current_issue: Option<IssueDetail>,
-    /// Scroll offset for detail view
-    pub detail_scroll: usize,
+    /// Scroll position for detail view
+    pub detail_scroll: ScrollView,
+    /// Stack frames that can take focus in the exception section (indexes
+    /// across all frames, a folded run standing in at its first)
+    pub frame_focus: FocusRing<usize>,
+    /// Git blame results for stack frames, by frame index
+    pub frame_blame: HashMap<usize, Result<BlameInfo, String>>,
+    /// Stack frames showing their surrounding source lines, by frame index
+    pub expanded_frames: HashSet<usize>,
+    /// Runs of library frames shown in full, by their first frame's index
+    pub unfolded_runs: HashSet<usize>,
+    /// Section headers focused with Tab (never at the same time as a frame)
+    pub section_focus: FocusRing<DetailSection>,
+    /// Sections folded down to their header, by issue ID
+    pub collapsed_sections: HashMap<String, HashSet<DetailSection>>,
+    /// Section headers from the last render and the rows they start on
+    pub section_rows: RefCell<Vec<(DetailSection, usize)>>,
+    /// Zoom level of the time-scaled breadcrumb timeline (`None` shows the
+    /// plain list)
+    pub breadcrumb_zoom: Option<usize>,
+    /// The breadcrumbs screen
+    pub breadcrumb_view: BreadcrumbView,
+    /// The raw JSON viewer, while open
+    pub json_view: Option<JsonView>,
+    /// Tag value distribution popup, while open
+    pub tag_breakdown: Option<TagBreakdown>,
+    /// What the last detail refresh changed about the current issue
+    pub issue_changes: Option<IssueChanges>,
+    /// Whether the "what changed" popup is open
+    pub show_issue_changes: bool,
+    /// Events browser popup, while open
+    pub event_picker: Option<EventPicker>,
+    /// Which event the detail screen shows, once stepped through
+    pub event_browser: Option<EventBrowser>,
+    /// How stack frame paths are shortened
+    pub path_config: PathConfig,
+    /// Palette and status markers
+    pub theme: ThemeConfig,
+    /// Project checkout the server is running against
+    pub project_path: PathBuf,
 
     // === Analysis screen state ===
     /// Lines to display in the analysis screen
-    pub analysis_lines: Vec<ActivityLine>,
-    /// Scroll offset for the analysis pane
-    pub analysis_scroll: usize,
+    pub analysis_lines: ActivityLog,
+    /// Whether lines dropped from `analysis_lines` are written to a file
+    pub spill_analysis_overflow: bool,
+    /// Raw events behind the lines, kept for transcript export (spilled
+    /// with the lines)
+    pub analysis_events: EventLog,
+    /// Recorded analysis being played back, if any
+    pub playback: Option<Playback>,
+    /// Scroll position for the analysis pane (follows new output)
+    pub analysis_scroll: ScrollView,
     /// Whether we're currently streaming analysis events
     pub is_streaming_analysis: bool,
+    /// Elapsed time and counts for the analysis header
+    pub analysis_progress: AnalysisProgress,
     /// Current text accumulator for streaming text deltas
     pub current_text_buffer: String,
 
+    // === Implementation screen state ===
+    /// Issue whose implementation session the pane shows
+    pub implementation_issue: Option<String>,
+    /// Lines to display in the implementation screen
+    pub implementation_lines: ActivityLog,
+    /// Scroll position for the implementation pane (follows new output)
+    pub implementation_scroll: ScrollView,
+    /// Whether we're currently streaming implementation events
+    pub is_streaming_implementation: bool,
+    /// Elapsed time and counts for the implementation header
+    pub implementation_progress: AnalysisProgress,
+    /// Text accumulator for the implementation's streaming text deltas
+    pub implementation_text_buffer: String,
+
+    // === Review screen state ===
+    /// Issue whose worktree diff is shown
+    pub review_issue: Option<String>,
+    /// The worktree's changes by file (`None` while loading)
+    pub review_diff: Option<Result<Vec<FileDiff>, String>>,
+    /// Index of the file being shown
+    pub review_file: usize,
+    /// Scroll position within the shown file
+    pub review_scroll: ScrollView,
+    /// Command that runs the project's tests, from the config
+    pub test_command: Option<String>,
+    /// How long completed issues are watched for new events, from the config
+    pub watch_hours: Option<u64>,
+    /// Completed issues being watched, oldest first
+    pub watches: Vec<FixWatch>,
+    /// Whether a wakeup to check watched issues is on its way
+    pub watch_check_scheduled: bool,
+    /// Latest test run in a worktree
+    pub test_run: Option<TestRun>,
+    /// Completion dialog, while open (on the detail or review screen)
+    pub finish_review: Option<FinishReview>,
+    /// Pull requests opened for fixes this session, by issue ID
+    pub pull_requests: HashMap<String, PullRequest>,
+
+    // === Worktrees screen state ===
+    pub worktrees: Worktrees,
+
     // === Proposal screen state ===
-    /// Scroll offset for the proposal view
-    pub proposal_scroll: usize,
+    /// Scroll position for the proposal view
+    pub proposal_scroll: ScrollView,
+    /// The proposal rendered to styled lines
+    pub proposal_lines: LineCache,
+    /// The detail screen's content, laid out
+    pub detail_lines: LineCache,
+    /// Show old/new line numbers beside diff blocks
+    pub diff_line_numbers: bool,
+    /// File-level plan popup, while open
+    pub plan_preview: Option<PlanPreview>,
+    /// Second-opinion critique of the proposal, while shown
+    pub critique: Option<Critique>,
+
+    // === Error screen state ===
+    /// Scroll position for the error screen
+    pub error_scroll: ScrollView,
+    /// Selected remediation button on the error screen
+    pub error_action_index: usize,
+    /// Recent server log lines related to the failing issue
+    pub error_log_excerpt: Vec<String>,
+
+    /// Scroll offsets remembered per issue and screen for this session
+    pub scroll_memory: HashMap<(String, Screen), usize>,
+
+    // === Server ===
+    /// Optional features the primary server supports; aggregated servers
+    /// are assumed to match it
+    pub capabilities: ServerCapabilities,
 
     // === Loading state ===
     /// Loading state (for synchronous operations)
     pub is_loading: bool,
     /// Whether a background list refresh is in progress
     pub is_refreshing: bool,
+    /// Issue to select once the list reloads, after adding it
+    pub select_after_reload: Option<String>,
     /// Whether a background detail refresh is in progress
     pub is_refreshing_detail: bool,
+    /// Interval between automatic refreshes (None = disabled)
+    pub auto_refresh_interval: Option<Duration>,
+    /// When the next automatic refresh is due
+    pub next_auto_refresh: Option<Instant>,
+    /// When each startup phase finished, with `--profile-startup`
+    pub startup: StartupProfile,
+    /// Whether the server glass started has come up
+    pub server_status: ServerStatus,
+    /// Port glass starts its server on
+    pub server_port: u16,
 
-    // === Error state ===
-    /// Error message to display
-    pub error: Option<String>,
+    // === Notifications ===
+    /// Transient notifications (errors, completions, confirmations)
+    pub toasts: Toasts,
+    /// Whether to send desktop notifications when agent work finishes
+    pub desktop_notifications: bool,
+    /// Last (issue id, status) announced with a bell or desktop notification
+    pub last_announced: Option<(String, String)>,
+    /// How to signal that an analysis finished
+    pub bell_mode: BellMode,
+    /// Whether the main loop should write an audible bell
+    pub bell_pending: bool,
+    /// Text for the main loop to put on the clipboard
+    pub pending_clipboard: Option<String>,
+    /// What can be copied, while the yank menu is open
+    pub yank_menu: Option<YankMenu>,
+    /// Organization and region for links to issues on Sentry
+    pub sentry: SentryConfig,
+    /// Until when the status bar is flashed for a visual bell
+    pub bell_flash_until: Option<Instant>,
 
     // === Terminal info ===
+    /// Whether the terminal window has focus (assumed until told otherwise)
+    pub terminal_focused: bool,
+    /// Whether keys arrive with the kitty protocol, which tells Shift+Enter
+    /// from Enter
+    pub keyboard_enhancement: bool,
     /// Terminal width for text wrapping
     pub terminal_width: u16,
     /// Terminal height for page scrolling
     pub terminal_height: u16,
+    /// Speeds up scrolling while a scroll key is held
+    pub scroll_accel: ScrollAccelerator,
+
+    // === Overlays ===
+    /// Whether the help overlay is open
+    pub show_help: bool,
+    /// Whether the error details modal is open
+    pub show_error_details: bool,
+    /// Text input that currently has focus, if any
+    pub input: Option<TextInput>,
+    /// What the focused input is asking for, while a prompt is open
+    pub prompt: Option<Prompt>,
+    /// Form for creating an issue by hand, while open
+    pub new_issue: Option<NewIssueForm>,
+    /// Session picker for the escape hatch, while open
+    pub session_picker: Option<SessionPicker>,
+    /// Session last resumed, by issue ID
+    pub session_choices: HashMap<String, SessionKind>,
+    /// Shell command template the escape hatch runs
+    pub escape_command: String,
+    /// Where the escape hatch opens sessions inside a multiplexer
+    pub escape_open_in: OpenIn,
+    /// tmux or zellij, when glass runs inside one
+    pub multiplexer: Option<Multiplexer>,
+    /// Session waiting on where to open it, while that's being asked
+    pub placement_prompt: Option<SessionRef>,
+    /// The `:` command line, while open (its text is the focused input)
+    pub command_line: Option<CommandLine>,
+    /// Commands run this session, oldest first
+    pub command_history: Vec<String>,
+    /// Word list prompts are spell checked against
+    #[cfg(feature = "spellcheck")]
+    pub dictionary: Option<crate::spell::Dictionary>,
+    /// Reason sent with the last reject, kept for retrying it
+    pub reject_reason: Option<String>,
+    /// Instructions sent with the last analyze, kept for retrying it
+    pub analyze_instructions: Option<String>,
+    /// Event the last analyze was based on, kept for retrying it
+    pub analyze_event_id: Option<String>,
+    /// Instructions sent with the last approve, kept for retrying it
+    pub approve_instructions: Option<String>,
+
+    /// Guided tutorial on demo data, while one runs
+    pub tutorial: Option<Tutorial>,
 
     // === Control ===
     /// Flag to quit the app
     pub should_quit: bool,
+    /// When quitting was held back for work still in flight; asking again
+    /// soon after quits anyway
+    pub quit_requested_at: Option<Instant>,
 }
 
 impl Default for AppState {
@@ -90,21 +1050,124 @@ impl Default for AppState {
         Self {
             screen: Screen::List,
             issues: Vec::new(),
+            rows: Vec::new(),
+            view: ListView::default(),
+            ignore: IgnoreConfig::default(),
+            show_ignored: false,
+            collapsed_groups: HashSet::new(),
+            persist_workspace: false,
             selected_index: 0,
+            peek: None,
+            preview: None,
+            merge_marks: Vec::new(),
+            pinned_issue: None,
+            merge_dialog: None,
+            projects: Vec::new(),
+            project_switcher: None,
+            analysis_queue: HashMap::new(),
+            next_queue_poll: None,
             current_issue: None,
-            detail_scroll: 0,
-            analysis_lines: Vec::new(),
-            analysis_scroll: 0,
+            detail_scroll: ScrollView::default(),
+            frame_focus: FocusRing::unfocused([]),
+            frame_blame: HashMap::new(),
+            expanded_frames: HashSet::new(),
+            unfolded_runs: HashSet::new(),
+            section_focus: FocusRing::unfocused([]),
+            collapsed_sections: HashMap::new(),
+            section_rows: RefCell::default(),
+            breadcrumb_zoom: None,
+            breadcrumb_view: BreadcrumbView::default(),
+            json_view: None,
+            tag_breakdown: None,
+            issue_changes: None,
+            show_issue_changes: false,
+            event_picker: None,
+            event_browser: None,
+            path_config: PathConfig::default(),
+            theme: ThemeConfig::default(),
+            project_path: PathBuf::from("."),
+            analysis_lines: ActivityLog::default(),
+            spill_analysis_overflow: false,
+            analysis_events: EventLog::default(),
+            playback: None,
+            analysis_scroll: ScrollView::sticky(),
             is_streaming_analysis: false,
+            analysis_progress: AnalysisProgress::default(),
             current_text_buffer: String::new(),
-            proposal_scroll: 0,
+            implementation_issue: None,
+            implementation_lines: ActivityLog::default(),
+            implementation_scroll: ScrollView::sticky(),
+            is_streaming_implementation: false,
+            implementation_progress: AnalysisProgress::default(),
+            implementation_text_buffer: String::new(),
+            review_issue: None,
+            review_diff: None,
+            review_file: 0,
+            review_scroll: ScrollView::default(),
+            test_command: None,
+            watch_hours: None,
+            watches: Vec::new(),
+            watch_check_scheduled: false,
+            test_run: None,
+            finish_review: None,
+            pull_requests: HashMap::new(),
+            worktrees: Worktrees::default(),
+            proposal_scroll: ScrollView::default(),
+            proposal_lines: LineCache::default(),
+            detail_lines: LineCache::default(),
+            diff_line_numbers: false,
+            plan_preview: None,
+            critique: None,
+            error_scroll: ScrollView::default(),
+            error_action_index: 0,
+            error_log_excerpt: Vec::new(),
+            scroll_memory: HashMap::new(),
+            capabilities: ServerCapabilities::default(),
             is_loading: false,
             is_refreshing: false,
+            select_after_reload: None,
             is_refreshing_detail: false,
-            error: None,
+            auto_refresh_interval: None,
+            next_auto_refresh: None,
+            startup: StartupProfile::default(),
+            server_status: ServerStatus::default(),
+            server_port: DEFAULT_PORT,
+            toasts: Toasts::default(),
+            desktop_notifications: false,
+            last_announced: None,
+            bell_mode: BellMode::Off,
+            bell_pending: false,
+            pending_clipboard: None,
+            yank_menu: None,
+            sentry: SentryConfig::default(),
+            bell_flash_until: None,
+            terminal_focused: true,
+            keyboard_enhancement: false,
             terminal_width: 80,
             terminal_height: 24,
+            scroll_accel: ScrollAccelerator::default(),
+            show_help: false,
+            show_error_details: false,
+            input: None,
+            prompt: None,
+            new_issue: None,
+            session_picker: None,
+            session_choices: HashMap::new(),
+            escape_command: DEFAULT_ESCAPE_COMMAND.to_string(),
+            escape_open_in: OpenIn::default(),
+            multiplexer: None,
+            placement_prompt: None,
+            command_line: None,
+            command_history: Vec::new(),
+            #[cfg(feature = "spellcheck")]
+            dictionary: None,
+            reject_reason: None,
+            analyze_instructions: None,
+            analyze_event_id: None,
+            approve_instructions: None,
+            tutorial: None,
             should_quit: false,
+            quit_requested_at: None,
         }
     }
 }
@@ -116,6 +1179,81 @@ impl AppState {
         self.terminal_height = height;
     }
 
+    /// Seconds until the next automatic refresh, if enabled.
+    pub fn auto_refresh_remaining(&self) -> Option<u64> {
+        let due = self.next_auto_refresh?;
+        Some(due.saturating_duration_since(Instant::now()).as_secs())
+    }
+
+    /// Whether anything time-based (countdown, toasts, bell flash, analysis
+    /// timer, queue polling, test run timer) needs periodic ticks. With
+    /// reduced motion the clocks only move when output arrives.
+    pub fn needs_tick(&self) -> bool {
+        let clock = !self.theme.reduced_motion
+            && ((self.is_streaming_analysis && self.screen == Screen::Analysis)
+                || (self.is_streaming_implementation && self.screen == Screen::Implementation)
+                || (self.screen == Screen::Review
+                    && self.test_run.as_ref().is_some_and(|r| r.status == TestStatus::Running)));
+        self.auto_refresh_interval.is_some()
+            || self.next_queue_poll.is_some()
+            || !self.toasts.is_empty()
+            || clock
+            || self.bell_flash_until.is_some()
+            || self.tutorial.as_ref().is_some_and(|t| !t.pending_events.is_empty())
+            || self.playback.as_ref().is_some_and(|p| !p.pending.is_empty())
+    }
+
+    /// A view for streaming output, which follows the end unless motion is
+    /// reduced.
+    pub fn output_view(&self) -> ScrollView {
+        if self.theme.reduced_motion {
+            ScrollView::default()
+        } else {
+            ScrollView::sticky()
+        }
+    }
+
+    /// Make the streaming views follow their output, or stop, per the
+    /// reduced motion setting.
+    pub fn apply_motion(&mut self) {
+        let sticky = !self.theme.reduced_motion;
+        self.analysis_scroll.set_sticky(sticky);
+        self.implementation_scroll.set_sticky(sticky);
+        if let Some(run) = &mut self.test_run {
+            run.scroll.set_sticky(sticky);
+        }
+        if let Some(critique) = &mut self.critique {
+            critique.scroll.set_sticky(sticky);
+        }
+    }
+
+    /// Whether the list is drawn beside a preview of the selected issue.
+    pub fn shows_preview(&self) -> bool {
+        self.screen == Screen::List
+            && self.terminal_width >= SPLIT_MIN_WIDTH
+            && !self.theme.list_only
+            && self.tutorial.is_none()
+    }
+
+    /// Whether the terminal is below the minimum supported size.
+    pub fn terminal_too_small(&self) -> bool {
+        self.terminal_width < MIN_TERMINAL_WIDTH || self.terminal_height < MIN_TERMINAL_HEIGHT
+    }
+
+    /// Details behind the newest visible toast for a failed issue action.
+    pub fn error_details(&self) -> Option<&FailedAction> {
+        self.toasts.details()
+    }
+
+    /// Short label for an issue in notifications (e.g. "PROJ-123").
+    pub fn issue_label(&self, id: &str) -> String {
+        self.issues
+            .iter()
+            .find(|i| i.id == id)
+            .map(|i| i.short_id.clone())
+            .unwrap_or_else(|| id.to_string())
+    }
+
     /// Get half-page scroll amount (for Ctrl+D/U).
     pub fn half_page(&self) -> i32 {
         (self.terminal_height.saturating_sub(6) / 2).max(1) as i32
@@ -123,20 +1261,438 @@ impl AppState {
 
     /// Clamp selected index to valid range.
     pub fn clamp_selection(&mut self) {
-        if !self.issues.is_empty() && self.selected_index >= self.issues.len() {
-            self.selected_index = self.issues.len() - 1;
+        if !self.rows.is_empty() && self.selected_index >= self.rows.len() {
+            self.selected_index = self.rows.len() - 1;
+        }
+        // A folded group is selected by its header
+        if self.is_folded(self.selected_index) {
+            self.selected_index = self.group_start(self.selected_index);
+        }
+    }
+
+    /// Index of the first issue in the group of the issue at `index`, which
+    /// is where its header goes. Groups are contiguous once the view is
+    /// applied.
+    pub fn group_start(&self, index: usize) -> usize {
+        let Some(group) = self.view.group else {
+            return index;
+        };
+        let Some(issue) = self.issues.get(index) else {
+            return index;
+        };
+        let key = group.key(issue);
+        let mut start = index;
+        while start > 0 && self.listed_issue(start - 1).is_some_and(|above| group.key(above) == key) {
+            start -= 1;
         }
+        start
+    }
+
+    /// Whether the issue at `index` is tucked away in a folded group (the
+    /// first issue stands in for the group's header, so isn't).
+    pub fn is_folded(&self, index: usize) -> bool {
+        let (Some(group), Some(issue)) = (self.view.group, self.listed_issue(index)) else {
+            return false;
+        };
+        self.collapsed_groups.contains(&group.key(issue)) && self.group_start(index) != index
+    }
+
+    /// Replace the list, filtering and sorting it by the current view.
+    pub fn set_issues(&mut self, issues: Vec<Issue>) {
+        self.issues = issues;
+        self.apply_view();
+    }
+
+    /// Work out the list's rows again after the view or the issues changed,
+    /// keeping the selected issue selected if it's still shown.
+    pub fn apply_view(&mut self) {
+        let selected = self.selected_issue_id().map(str::to_string);
+        let ignore = (!self.show_ignored).then_some(&self.ignore);
+        self.rows = self.view.rows(&self.issues, ignore);
+        if let Some(index) = selected.and_then(|id| self.row_of(&id)) {
+            self.selected_index = index;
+        }
+        self.clamp_selection();
+    }
+
+    /// The issues the list shows, in the order it shows them.
+    pub fn listed(&self) -> impl Iterator<Item = &Issue> + '_ {
+        self.rows.iter().map(|&row| &self.issues[row])
+    }
+
+    /// The issue on row `row` of the list.
+    pub fn listed_issue(&self, row: usize) -> Option<&Issue> {
+        self.rows.get(row).map(|&index| &self.issues[index])
+    }
+
+    /// The row of the list showing issue `id`, if it's shown.
+    pub fn row_of(&self, id: &str) -> Option<usize> {
+        self.rows.iter().position(|&index| self.issues[index].id == id)
+    }
+
+    /// Issues the view leaves out.
+    pub fn hidden_count(&self) -> usize {
+        self.issues.len() - self.rows.len()
+    }
+
+    /// How many issues the ignore rules match, shown or not.
+    pub fn ignored_count(&self) -> usize {
+        self.issues.iter().filter(|issue| self.ignore.matches(issue)).count()
+    }
+
+    /// The issue selected in the list, if any.
+    pub fn selected_issue(&self) -> Option<&Issue> {
+        self.listed_issue(self.selected_index)
     }
 
     /// Get currently selected issue ID, if any.
     pub fn selected_issue_id(&self) -> Option<&str> {
-        self.issues.get(self.selected_index).map(|i| i.id.as_str())
+        self.selected_issue().map(|i| i.id.as_str())
+    }
+
+    /// The issue the detail screens show: the open one, or the list
+    /// selection while its details are still loading.
+    pub fn open_issue_id(&self) -> Option<&str> {
+        match &self.current_issue {
+            Some(issue) => Some(issue.id.as_str()),
+            None => self.selected_issue_id(),
+        }
+    }
+
+    /// The issue a worktree was created for, recognised by the issue's short
+    /// ID (or ID) in its branch or directory name.
+    pub fn worktree_owner(&self, worktree: &Worktree) -> Option<&Issue> {
+        let dir = worktree.path.file_name().map(|name| name.to_string_lossy().to_lowercase());
+        let branch = worktree.branch.as_deref().map(str::to_lowercase);
+        self.issues.iter().find(|issue| {
+            [issue.short_id.to_lowercase(), issue.id.to_lowercase()]
+                .iter()
+                .filter(|needle| !needle.is_empty())
+                .any(|needle| {
+                    [&dir, &branch]
+                        .into_iter()
+                        .flatten()
+                        .any(|name| contains_word(name, needle))
+                })
+        })
+    }
+
+    /// Whether a worktree is still in use.
+    pub fn worktree_use(&self, worktree: &Worktree) -> WorktreeUse<'_> {
+        if worktree.prunable {
+            return WorktreeUse::Stale;
+        }
+        match self.worktree_owner(worktree) {
+            Some(issue) if matches!(issue.status.as_str(), "in_progress" | "pending_review") => {
+                WorktreeUse::Active(issue)
+            }
+            issue => WorktreeUse::Orphaned(issue),
+        }
+    }
+
+    /// Remember the scroll offset of `screen` for the open issue.
+    pub fn remember_scroll(&mut self, screen: Screen) {
+        let offset = match screen {
+            Screen::Detail => self.detail_scroll.offset(),
+            Screen::Proposal => self.proposal_scroll.offset(),
+            Screen::List
+            | Screen::Analysis
+            | Screen::Implementation
+            | Screen::Review
+            | Screen::Error
+            | Screen::Worktrees
+            | Screen::Breadcrumbs
+            | Screen::RawJson => return,
+        };
+        if let Some(id) = self.open_issue_id() {
+            self.scroll_memory.insert((id.to_string(), screen), offset);
+        }
+    }
+
+    /// Restore the remembered scroll offset of `screen` for the open issue,
+    /// or scroll to the top if there is none.
+    pub fn restore_scroll(&mut self, screen: Screen) {
+        let offset = self
+            .open_issue_id()
+            .and_then(|id| self.scroll_memory.get(&(id.to_string(), screen.clone())))
+            .copied();
+        let view = match screen {
+            Screen::Detail => &mut self.detail_scroll,
+            Screen::Proposal => &mut self.proposal_scroll,
+            Screen::List
+            | Screen::Analysis
+            | Screen::Implementation
+            | Screen::Review
+            | Screen::Error
+            | Screen::Worktrees
+            | Screen::Breadcrumbs
+            | Screen::RawJson => return,
+        };
+        match offset {
+            Some(offset) => view.restore(offset),
+            None => view.reset(),
+        }
     }
 
     /// Clear analysis state for a fresh analysis.
     pub fn reset_analysis(&mut self) {
         self.analysis_lines.clear();
-        self.analysis_scroll = 0;
+        self.analysis_events.clear();
+        self.analysis_scroll.reset();
         self.current_text_buffer.clear();
+        self.analysis_progress = AnalysisProgress::default();
+    }
+
+    /// Clear implementation state before following `issue_id`'s session.
+    pub fn reset_implementation(&mut self, issue_id: &str) {
+        self.implementation_issue = Some(issue_id.to_string());
+        self.implementation_lines.clear();
+        self.implementation_scroll.reset();
+        self.implementation_text_buffer.clear();
+        self.implementation_progress = AnalysisProgress::default();
+    }
+}
+
+/// Whether `needle` appears in `haystack` without letters or digits
+/// running on either side, so "proj-1" isn't found in "proj-12".
+fn contains_word(haystack: &str, needle: &str) -> bool {
+    haystack.match_indices(needle).any(|(start, _)| {
+        let before = haystack[..start].chars().next_back();
+        let after = haystack[start + needle.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_issue;
+    use crate::app::view::{FilterField, IssueFilter};
+
+    fn worktree(path: &str, branch: Option<&str>) -> Worktree {
+        Worktree {
+            path: PathBuf::from(path),
+            branch: branch.map(str::to_string),
+            locked: false,
+            prunable: false,
+        }
+    }
+
+    #[test]
+    fn test_worktree_owner_by_short_id() {
+        let issues = [("a", "PROJ-12", "pending_review"), ("b", "PROJ-1", "pending")]
+            .into_iter()
+            .map(|(id, short_id, status)| {
+                serde_json::from_value(serde_json::json!({
+                    "id": id, "sourceType": "sentry", "title": "", "shortId": short_id,
+                    "status": status, "eventCount": 0, "userCount": 0,
+                    "firstSeen": "", "lastSeen": "", "updatedAt": "",
+                }))
+                .unwrap()
+            })
+            .collect();
+        let state = AppState { issues, ..AppState::default() };
+
+        let active = worktree("/src/worktrees/fix-proj-12", None);
+        assert!(matches!(state.worktree_use(&active), WorktreeUse::Active(i) if i.id == "a"));
+        let orphaned = worktree("/src/worktrees/x", Some("glass/proj-1"));
+        assert!(matches!(state.worktree_use(&orphaned), WorktreeUse::Orphaned(Some(i)) if i.id == "b"));
+        let unowned = worktree("/src/feature", Some("proj-123"));
+        assert!(matches!(state.worktree_use(&unowned), WorktreeUse::Orphaned(None)));
+    }
+
+    #[test]
+    fn test_filtered_out_issues_stay_in_the_list() {
+        let mut state = AppState::default();
+        state.set_issues(
+            ["pending_review", "pending"]
+                .into_iter()
+                .enumerate()
+                .map(|(i, status)| {
+                    serde_json::from_value(serde_json::json!({
+                        "id": i.to_string(), "sourceType": "sentry", "title": "", "shortId": format!("PROJ-{}", i),
+                        "status": status, "eventCount": 0, "userCount": 0,
+                        "firstSeen": "", "lastSeen": "", "updatedAt": "",
+                    }))
+                    .unwrap()
+                })
+                .collect(),
+        );
+        state.selected_index = 1;
+        state.view.filters = vec![IssueFilter { field: FilterField::Status, value: "pending".to_string() }];
+        state.apply_view();
+
+        // Only the rows change; the selection follows its issue
+        assert_eq!(state.issues.len(), 2);
+        assert_eq!(state.hidden_count(), 1);
+        assert_eq!(state.selected_issue_id(), Some("1"));
+        // A fix's worktree is still its issue's while the issue is hidden
+        let worktree = worktree("/src/worktrees/fix-proj-0", None);
+        assert!(matches!(state.worktree_use(&worktree), WorktreeUse::Active(i) if i.id == "0"));
+    }
+
+    #[test]
+    fn test_capabilities_hide_only_once_known() {
+        assert!(ServerCapabilities::Unknown.supports(Capability::Critique));
+
+        let advertised = ServerCapabilities::Advertised([Capability::Plan].into_iter().collect());
+        assert!(advertised.supports(Capability::Plan));
+        assert!(!advertised.supports(Capability::Critique));
+        // Servers from before discovery
+        assert!(!ServerCapabilities::Advertised(HashSet::new()).supports(Capability::Plan));
+    }
+
+    #[test]
+    fn test_scroll_clamps_to_content() {
+        let mut view = ScrollView::default();
+        view.set_rendered(30, 10);
+
+        view.scroll_by(100);
+        assert_eq!(view.offset(), 20);
+
+        view.scroll_by(-100);
+        assert_eq!(view.offset(), 0);
+    }
+
+    #[test]
+    fn test_scroll_clamps_when_content_shrinks() {
+        let mut view = ScrollView::default();
+        view.set_rendered(30, 10);
+        view.scroll_by(15);
+
+        view.set_rendered(12, 10);
+        assert_eq!(view.offset(), 2);
+
+        // Scrolling continues from the clamped position
+        view.scroll_by(-1);
+        assert_eq!(view.offset(), 1);
+    }
+
+    #[test]
+    fn test_sideways_scroll_only_while_unwrapped() {
+        let mut view = ScrollView::default();
+        view.set_rendered_width(120, 80);
+        view.scroll_columns(8);
+        assert_eq!(view.column(), 0);
+
+        view.toggle_wrap();
+        view.scroll_columns(100);
+        assert_eq!(view.column(), 40);
+        view.scroll_columns(-8);
+        assert_eq!(view.column(), 32);
+
+        // Wrapping again starts from the left edge next time
+        view.toggle_wrap();
+        assert_eq!(view.column(), 0);
+        view.toggle_wrap();
+        assert_eq!(view.column(), 0);
+    }
+
+    fn issue(id: &str) -> Issue {
+        test_issue(id, "pending")
+    }
+
+    #[test]
+    fn test_scroll_memory_per_issue() {
+        let mut state = AppState::default();
+        state.set_issues(vec![issue("a"), issue("b")]);
+        state.detail_scroll.set_rendered(100, 10);
+        state.detail_scroll.scroll_by(42);
+        state.remember_scroll(Screen::Detail);
+
+        state.selected_index = 1;
+        state.restore_scroll(Screen::Detail);
+        assert_eq!(state.detail_scroll.offset(), 0);
+
+        state.selected_index = 0;
+        state.restore_scroll(Screen::Detail);
+        assert_eq!(state.detail_scroll.offset(), 42);
+    }
+
+    fn detail(id: &str) -> IssueDetail {
+        serde_json::from_value(serde_json::json!({
+            "id": id, "sourceType": "sentry", "status": "pending", "source": {},
+            "state": {"status": "pending"}, "createdAt": "", "updatedAt": "",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_scroll_memory_follows_open_issue() {
+        let mut state = AppState::default();
+        state.set_issues(vec![issue("a"), issue("b")]);
+        state.current_issue = Some(detail("b"));
+        state.detail_scroll.set_rendered(100, 10);
+        state.detail_scroll.scroll_by(7);
+        // The list moving underneath (a refresh, a new issue) mustn't
+        // file the offset under another issue
+        state.remember_scroll(Screen::Detail);
+
+        state.current_issue = None;
+        state.selected_index = 1;
+        state.restore_scroll(Screen::Detail);
+        assert_eq!(state.detail_scroll.offset(), 7);
+    }
+
+    #[test]
+    fn test_sticky_view_follows_end() {
+        let mut view = ScrollView::sticky();
+        view.set_rendered(30, 10);
+        assert_eq!(view.offset(), 20);
+
+        view.scroll_by(-5);
+        view.set_rendered(40, 10);
+        assert_eq!(view.offset(), 15);
+
+        view.scroll_by(100);
+        view.set_rendered(50, 10);
+        assert_eq!(view.offset(), 40);
+    }
+
+    #[test]
+    fn test_analysis_progress_freezes_on_finish() {
+        let mut progress = AnalysisProgress::default();
+        assert!(progress.elapsed().is_none());
+        progress.finish();
+        assert!(progress.finished_at.is_none());
+
+        progress.start();
+        progress.finish();
+        let frozen = progress.elapsed().unwrap();
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(progress.elapsed().unwrap(), frozen);
+    }
+
+    #[test]
+    fn test_analysis_usage_accumulates() {
+        let mut progress = AnalysisProgress::default();
+        assert_eq!(progress.usage_summary(), None);
+
+        progress.add_usage(12_000, 800, None);
+        assert_eq!(progress.usage_summary().as_deref(), Some("12.0k in / 800 out"));
+
+        progress.add_usage(300, 1_400, Some(0.25));
+        progress.add_usage(0, 0, Some(0.17));
+        assert_eq!(
+            progress.usage_summary().as_deref(),
+            Some("12.3k in / 2.2k out · $0.42")
+        );
+    }
+
+    #[test]
+    fn test_preview_on_wide_list_only() {
+        let mut state = AppState::default();
+        state.set_terminal_size(SPLIT_MIN_WIDTH - 1, 40);
+        assert!(!state.shows_preview());
+
+        state.set_terminal_size(SPLIT_MIN_WIDTH, 40);
+        assert!(state.shows_preview());
+
+        state.screen = Screen::Detail;
+        assert!(!state.shows_preview());
+
+        state.screen = Screen::List;
+        state.theme.list_only = true;
+        assert!(!state.shows_preview());
     }
 }
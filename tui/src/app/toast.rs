@@ -0,0 +1,163 @@
+//! Transient notifications with severity levels and auto-expiry.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use super::state::FailedAction;
+
+/// Most toasts shown at once; older ones are dropped first.
+pub const MAX_TOASTS: usize = 4;
+
+/// How important a toast is, which sets its color and lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// How long a toast of this severity stays up.
+    pub fn ttl(self) -> Duration {
+        match self {
+            Severity::Info | Severity::Success => Duration::from_secs(4),
+            Severity::Warning => Duration::from_secs(6),
+            Severity::Error => Duration::from_secs(10),
+        }
+    }
+}
+
+/// A single notification.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub severity: Severity,
+    pub message: String,
+    /// Server response behind a failed action, viewable in a modal
+    pub details: Option<Box<FailedAction>>,
+    pub expires_at: Instant,
+}
+
+impl Toast {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            details: None,
+            expires_at: Instant::now() + severity.ttl(),
+        }
+    }
+
+    pub fn with_details(mut self, details: FailedAction) -> Self {
+        self.details = Some(Box::new(details));
+        self
+    }
+}
+
+/// The stack of visible toasts, oldest first.
+#[derive(Debug, Default)]
+pub struct Toasts {
+    items: VecDeque<Toast>,
+}
+
+impl Toasts {
+    pub fn info(&mut self, message: impl Into<String>) {
+        self.push(Toast::new(Severity::Info, message));
+    }
+
+    pub fn success(&mut self, message: impl Into<String>) {
+        self.push(Toast::new(Severity::Success, message));
+    }
+
+    pub fn warning(&mut self, message: impl Into<String>) {
+        self.push(Toast::new(Severity::Warning, message));
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.push(Toast::new(Severity::Error, message));
+    }
+
+    /// Show a toast. Repeating the newest toast just extends its lifetime.
+    pub fn push(&mut self, toast: Toast) {
+        if let Some(last) = self.items.back_mut() {
+            if last.severity == toast.severity && last.message == toast.message {
+                *last = toast;
+                return;
+            }
+        }
+
+        self.items.push_back(toast);
+        while self.items.len() > MAX_TOASTS {
+            self.items.pop_front();
+        }
+    }
+
+    /// Drop toasts that have expired by `now`. Returns whether any were.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.items.len();
+        self.items.retain(|t| t.expires_at > now);
+        self.items.len() != before
+    }
+
+    /// Dismiss the toast that [`Toasts::details`] currently returns.
+    pub fn dismiss_details(&mut self) {
+        if let Some(index) = self.items.iter().rposition(|t| t.details.is_some()) {
+            self.items.remove(index);
+        }
+    }
+
+    /// Visible toasts, newest first.
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.items.iter().rev()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Failure details from the newest toast that has them.
+    pub fn details(&self) -> Option<&FailedAction> {
+        self.iter().find_map(|t| t.details.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(toasts: &Toasts) -> Vec<&str> {
+        toasts.iter().map(|t| t.message.as_str()).collect()
+    }
+
+    #[test]
+    fn test_keeps_only_the_newest() {
+        let mut toasts = Toasts::default();
+        for i in 0..MAX_TOASTS + 2 {
+            toasts.info(format!("toast {}", i));
+        }
+        assert_eq!(messages(&toasts), vec!["toast 5", "toast 4", "toast 3", "toast 2"]);
+    }
+
+    #[test]
+    fn test_repeated_toast_is_not_stacked() {
+        let mut toasts = Toasts::default();
+        toasts.error("Failed to fetch issues");
+        toasts.error("Failed to fetch issues");
+        toasts.info("Failed to fetch issues");
+        assert_eq!(toasts.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_expires_by_severity() {
+        let mut toasts = Toasts::default();
+        toasts.info("done");
+        toasts.error("broken");
+
+        let now = Instant::now();
+        assert!(!toasts.expire(now));
+        assert!(toasts.expire(now + Duration::from_secs(5)));
+        assert_eq!(messages(&toasts), vec!["broken"]);
+        assert!(toasts.expire(now + Duration::from_secs(11)));
+        assert!(toasts.is_empty());
+    }
+}
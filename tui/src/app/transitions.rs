@@ -0,0 +1,140 @@
+//! The issue state machine as the TUI drives it: where each status can go
+//! and what takes it there.
+//!
+//! The help overlay describes the workflow from this table, the action bar
+//! leaves out keys it doesn't allow, and actions that ask the server to
+//! move an issue are checked against it first, so none of them can
+//! disagree.
+
+use crate::api::IssueState;
+use super::state::{IssueAction, Screen};
+
+/// What moves an issue from one status to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Trigger {
+    /// A key on one of the issue's screens
+    Key {
+        key: char,
+        screen: Screen,
+        action: IssueAction,
+    },
+    /// The agent finishing (or giving up on) its work, described briefly
+    Agent(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transition {
+    pub from: &'static str,
+    pub trigger: Trigger,
+    pub to: &'static str,
+}
+
+const fn key(from: &'static str, key: char, screen: Screen, action: IssueAction, to: &'static str) -> Transition {
+    Transition {
+        from,
+        trigger: Trigger::Key { key, screen, action },
+        to,
+    }
+}
+
+const fn agent(from: &'static str, what: &'static str, to: &'static str) -> Transition {
+    Transition {
+        from,
+        trigger: Trigger::Agent(what),
+        to,
+    }
+}
+
+/// Every transition, grouped by the status it leaves in pipeline order.
+pub const TRANSITIONS: [Transition; 12] = [
+    key("pending", 'a', Screen::Detail, IssueAction::Analyze, "analyzing"),
+    agent("analyzing", "proposes", "pending_approval"),
+    agent("analyzing", "fails", "error"),
+    key("analyzing", 'a', Screen::Detail, IssueAction::Analyze, "analyzing"),
+    key("pending_approval", 'A', Screen::Proposal, IssueAction::Approve, "in_progress"),
+    key("pending_approval", 'x', Screen::Proposal, IssueAction::Reject, "pending"),
+    key("pending_approval", 'a', Screen::Detail, IssueAction::Analyze, "analyzing"),
+    agent("in_progress", "finishes", "pending_review"),
+    agent("in_progress", "fails", "error"),
+    key("pending_review", 'd', Screen::Detail, IssueAction::Complete, "pending"),
+    key("error", 'R', Screen::Detail, IssueAction::Retry, "analyzing"),
+    key("error", 'a', Screen::Detail, IssueAction::Analyze, "analyzing"),
+];
+
+/// Transitions out of `status`, in table order.
+pub fn transitions_from(status: &str) -> impl Iterator<Item = &'static Transition> + '_ {
+    TRANSITIONS.iter().filter(move |t| t.from == status)
+}
+
+/// Actions a key can take on an issue in `state`, in table order.
+pub fn allowed_actions(state: &IssueState) -> Vec<IssueAction> {
+    actions_from(state.status())
+}
+
+fn actions_from(status: &str) -> Vec<IssueAction> {
+    let mut actions = Vec::new();
+    for transition in transitions_from(status) {
+        if let Trigger::Key { action, .. } = transition.trigger {
+            if !actions.contains(&action) {
+                actions.push(action);
+            }
+        }
+    }
+    actions
+}
+
+/// Whether `action` can be taken on an issue in `status`. Actions that
+/// don't change the status (merging) always can.
+pub fn allows(status: &str, action: IssueAction) -> bool {
+    let action = match action {
+        IssueAction::AnalyzeFromList => IssueAction::Analyze,
+        IssueAction::Merge => return true,
+        action => action,
+    };
+    actions_from(status).contains(&action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::STATUSES;
+
+    #[test]
+    fn test_every_status_is_covered() {
+        for status in STATUSES {
+            assert!(transitions_from(status).next().is_some(), "{} is a dead end", status);
+        }
+        for t in &TRANSITIONS {
+            assert!(STATUSES.contains(&t.from) && STATUSES.contains(&t.to));
+        }
+    }
+
+    #[test]
+    fn test_allowed_actions() {
+        let approval = IssueState::PendingApproval {
+            analysis_session_id: "s".to_string(),
+            proposal: String::new(),
+        };
+        assert_eq!(
+            allowed_actions(&approval),
+            vec![IssueAction::Approve, IssueAction::Reject, IssueAction::Analyze]
+        );
+        assert_eq!(allowed_actions(&IssueState::Pending), vec![IssueAction::Analyze]);
+        let error = IssueState::Error {
+            previous_status: "analyzing".to_string(),
+            session_id: "s".to_string(),
+            error: "boom".to_string(),
+        };
+        assert_eq!(allowed_actions(&error), vec![IssueAction::Retry, IssueAction::Analyze]);
+    }
+
+    #[test]
+    fn test_allows() {
+        assert!(allows("pending", IssueAction::AnalyzeFromList));
+        assert!(allows("pending_approval", IssueAction::Approve));
+        assert!(!allows("pending", IssueAction::Approve));
+        assert!(!allows("in_progress", IssueAction::Analyze));
+        assert!(!allows("pending_review", IssueAction::Retry));
+        assert!(allows("in_progress", IssueAction::Merge));
+    }
+}
@@ -0,0 +1,162 @@
+//! Guided tutorial that walks through the core workflow on demo data.
+//!
+//! While a tutorial runs, the list and detail hold bundled demo issues and
+//! agent actions are simulated locally instead of going to the server.
+
+use std::collections::VecDeque;
+
+use serde::Deserialize;
+
+use crate::api::{AnalysisEvent, Issue, IssueDetail, IssueState};
+use super::state::{AppState, Screen};
+
+/// Demo issues and analysis bundled with the binary.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TutorialData {
+    pub issues: Vec<Issue>,
+    /// Detail for the issue the tutorial walks through
+    pub detail: IssueDetail,
+    /// Events replayed when the demo issue is analyzed
+    pub analysis: Vec<AnalysisEvent>,
+}
+
+impl TutorialData {
+    pub fn load() -> Self {
+        serde_json::from_str(include_str!("../../assets/tutorial.json"))
+            .expect("bundled tutorial data is valid")
+    }
+}
+
+/// One prompt in the tutorial, finished once `is_done` holds.
+pub struct TutorialStep {
+    pub prompt: &'static str,
+    pub keys: &'static str,
+    is_done: fn(&AppState) -> bool,
+}
+
+pub const TUTORIAL_STEPS: &[TutorialStep] = &[
+    TutorialStep {
+        prompt: "Move the selection down to DEMO-2",
+        keys: "j or ↓",
+        is_done: |s| s.selected_issue_id() == Some("demo-2"),
+    },
+    TutorialStep {
+        prompt: "Open the issue to see its stack trace and context",
+        keys: "Enter",
+        is_done: |s| s.screen == Screen::Detail && s.current_issue.is_some(),
+    },
+    TutorialStep {
+        prompt: "Step through the stack frames",
+        keys: "] and [",
+        is_done: |s| s.frame_focus.focused().is_some(),
+    },
+    TutorialStep {
+        prompt: "Ask the agent to analyze the issue",
+        keys: "a",
+        is_done: |s| s.screen == Screen::Analysis,
+    },
+    TutorialStep {
+        prompt: "Watch the agent work; its proposal opens when it's done",
+        keys: "wait, or scroll with j/k",
+        is_done: |s| s.screen == Screen::Proposal,
+    },
+    TutorialStep {
+        prompt: "Approve the proposal so the agent implements it",
+        keys: "A, then Enter",
+        is_done: |s| {
+            s.current_issue
+                .as_ref()
+                .is_some_and(|i| matches!(i.state, IssueState::InProgress { .. }))
+        },
+    },
+    TutorialStep {
+        prompt: "Every screen lists its keys in the help overlay",
+        keys: "?",
+        is_done: |s| s.show_help,
+    },
+    TutorialStep {
+        prompt: "That's the core loop. Close help, then leave the tutorial",
+        keys: "Esc, then Q",
+        is_done: |_| false,
+    },
+];
+
+/// A running tutorial.
+#[derive(Debug)]
+pub struct Tutorial {
+    /// Index into `TUTORIAL_STEPS`
+    pub step: usize,
+    pub data: TutorialData,
+    /// Demo analysis events not yet played back (one per tick)
+    pub pending_events: VecDeque<AnalysisEvent>,
+    /// Real issues and selection to restore when the tutorial ends
+    pub saved_issues: Vec<Issue>,
+    pub saved_selected: usize,
+    /// Started with `--tutorial`, so ending it quits
+    pub quit_on_exit: bool,
+}
+
+impl Tutorial {
+    pub fn new(saved_issues: Vec<Issue>, saved_selected: usize, quit_on_exit: bool) -> Self {
+        Self {
+            step: 0,
+            data: TutorialData::load(),
+            pending_events: VecDeque::new(),
+            saved_issues,
+            saved_selected,
+            quit_on_exit,
+        }
+    }
+
+    pub fn current(&self) -> &'static TutorialStep {
+        &TUTORIAL_STEPS[self.step.min(TUTORIAL_STEPS.len() - 1)]
+    }
+
+    /// Move past every step the state already satisfies.
+    pub fn advance(&mut self, state: &AppState) {
+        while self.step + 1 < TUTORIAL_STEPS.len() && (TUTORIAL_STEPS[self.step].is_done)(state) {
+            self.step += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::focus::FocusRing;
+
+    #[test]
+    fn test_bundled_data_loads() {
+        let data = TutorialData::load();
+        assert!(data.issues.iter().any(|i| i.id == data.detail.id));
+        assert!(matches!(data.analysis.last(), Some(AnalysisEvent::Complete { .. })));
+    }
+
+    #[test]
+    fn test_advance_follows_state() {
+        let mut tutorial = Tutorial::new(Vec::new(), 0, false);
+        let mut state = AppState::default();
+        state.set_issues(tutorial.data.issues.clone());
+
+        tutorial.advance(&state);
+        assert_eq!(tutorial.step, 0);
+
+        state.selected_index = 1;
+        tutorial.advance(&state);
+        assert_eq!(tutorial.step, 1);
+
+        state.screen = Screen::Detail;
+        state.current_issue = Some(tutorial.data.detail.clone());
+        state.frame_focus = FocusRing::new([0]);
+        tutorial.advance(&state);
+        assert_eq!(tutorial.step, 3);
+    }
+
+    #[test]
+    fn test_last_step_waits_for_exit() {
+        let mut tutorial = Tutorial::new(Vec::new(), 0, false);
+        tutorial.step = TUTORIAL_STEPS.len() - 1;
+        tutorial.advance(&AppState::default());
+        assert_eq!(tutorial.step, TUTORIAL_STEPS.len() - 1);
+    }
+}
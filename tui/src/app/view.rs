@@ -0,0 +1,419 @@
+//! Filtering, sorting and grouping of the issue list, set from the command
+//! line.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::Issue;
+use crate::config::IgnoreConfig;
+
+/// Issue statuses in pipeline order.
+pub const STATUSES: [&str; 6] = [
+    "pending",
+    "analyzing",
+    "pending_approval",
+    "in_progress",
+    "pending_review",
+    "error",
+];
+
+/// An issue field the list can be filtered on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterField {
+    Status,
+    /// Project of an aggregated list
+    Project,
+    Source,
+    /// Environment of the latest event
+    Environment,
+    /// Release of the latest event
+    Release,
+    /// Anywhere in the title or short ID
+    Text,
+}
+
+impl FilterField {
+    pub const ALL: [FilterField; 6] = [
+        FilterField::Status,
+        FilterField::Project,
+        FilterField::Source,
+        FilterField::Environment,
+        FilterField::Release,
+        FilterField::Text,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            FilterField::Status => "status",
+            FilterField::Project => "project",
+            FilterField::Source => "source",
+            FilterField::Environment => "env",
+            FilterField::Release => "release",
+            FilterField::Text => "text",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|field| field.name() == name)
+    }
+}
+
+/// `field=value`, compared case-insensitively.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueFilter {
+    pub field: FilterField,
+    pub value: String,
+}
+
+impl IssueFilter {
+    pub fn matches(&self, issue: &Issue) -> bool {
+        let value = self.value.to_lowercase();
+        match self.field {
+            FilterField::Status => issue.status == value,
+            FilterField::Project => issue
+                .project
+                .as_deref()
+                .is_some_and(|project| project.to_lowercase() == value),
+            FilterField::Source => issue.source_type.to_lowercase() == value,
+            FilterField::Environment => issue
+                .environment
+                .as_deref()
+                .is_some_and(|environment| environment.to_lowercase() == value),
+            FilterField::Release => issue
+                .release
+                .as_deref()
+                .is_some_and(|release| release.to_lowercase() == value),
+            FilterField::Text => {
+                issue.title.to_lowercase().contains(&value)
+                    || issue.short_id.to_lowercase().contains(&value)
+            }
+        }
+    }
+}
+
+impl fmt::Display for IssueFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.field.name(), self.value)
+    }
+}
+
+/// What the list can be sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    LastSeen,
+    FirstSeen,
+    Events,
+    Users,
+    /// Pipeline order
+    Status,
+    Title,
+    ShortId,
+}
+
+impl SortKey {
+    pub const ALL: [SortKey; 7] = [
+        SortKey::LastSeen,
+        SortKey::FirstSeen,
+        SortKey::Events,
+        SortKey::Users,
+        SortKey::Status,
+        SortKey::Title,
+        SortKey::ShortId,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SortKey::LastSeen => "seen",
+            SortKey::FirstSeen => "first",
+            SortKey::Events => "events",
+            SortKey::Users => "users",
+            SortKey::Status => "status",
+            SortKey::Title => "title",
+            SortKey::ShortId => "id",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|key| key.name() == name)
+    }
+
+    /// Times and counts read best biggest first, names alphabetically.
+    pub fn descending_by_default(self) -> bool {
+        matches!(
+            self,
+            SortKey::LastSeen | SortKey::FirstSeen | SortKey::Events | SortKey::Users
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueSort {
+    pub key: SortKey,
+    pub descending: bool,
+}
+
+impl IssueSort {
+    fn compare(&self, a: &Issue, b: &Issue) -> Ordering {
+        let status_rank = |issue: &Issue| {
+            STATUSES
+                .iter()
+                .position(|s| *s == issue.status)
+                .unwrap_or(STATUSES.len())
+        };
+        let ordering = match self.key {
+            SortKey::LastSeen => a.last_seen.cmp(&b.last_seen),
+            SortKey::FirstSeen => a.first_seen.cmp(&b.first_seen),
+            SortKey::Events => a.event_count.cmp(&b.event_count),
+            SortKey::Users => a.user_count.cmp(&b.user_count),
+            SortKey::Status => status_rank(a).cmp(&status_rank(b)),
+            SortKey::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            SortKey::ShortId => a.short_id.cmp(&b.short_id),
+        };
+        if self.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+impl fmt::Display for IssueSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = if self.descending { "desc" } else { "asc" };
+        write!(f, "{} {}", self.key.name(), direction)
+    }
+}
+
+/// What the list's issues can be grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+    /// Culprit of the latest event, from servers that list it
+    Culprit,
+    /// The exception type the title starts with ("TypeError")
+    ErrorType,
+}
+
+impl GroupBy {
+    pub const ALL: [GroupBy; 2] = [GroupBy::Culprit, GroupBy::ErrorType];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GroupBy::Culprit => "culprit",
+            GroupBy::ErrorType => "type",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|group| group.name() == name)
+    }
+
+    /// The group an issue falls in.
+    pub fn key(self, issue: &Issue) -> String {
+        match self {
+            GroupBy::Culprit => issue
+                .culprit
+                .clone()
+                .filter(|culprit| !culprit.is_empty())
+                .unwrap_or_else(|| "(no culprit)".to_string()),
+            GroupBy::ErrorType => error_type(&issue.title).unwrap_or("(other)").to_string(),
+        }
+    }
+}
+
+/// The exception type a title starts with: "TypeError" in "TypeError:
+/// x is undefined". Titles without one ("Health check failed") have none.
+fn error_type(title: &str) -> Option<&str> {
+    let (name, _) = title.split_once(':')?;
+    let is_type = !name.is_empty()
+        && name.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '$'));
+    is_type.then_some(name)
+}
+
+/// How the issue list is narrowed down and ordered.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListView {
+    pub filters: Vec<IssueFilter>,
+    /// `None` keeps the server's order
+    pub sort: Option<IssueSort>,
+    /// Issues of a group are listed together under a header
+    pub group: Option<GroupBy>,
+    /// Project an aggregated list is narrowed to, picked in the switcher
+    pub project: Option<String>,
+}
+
+impl ListView {
+    pub fn is_default(&self) -> bool {
+        self.filters.is_empty() && self.sort.is_none() && self.group.is_none()
+    }
+
+    /// Whether an issue is shown. Filters on the same field are
+    /// alternatives; different fields must all match.
+    pub fn matches(&self, issue: &Issue) -> bool {
+        if self.project.as_ref().is_some_and(|project| issue.project.as_ref() != Some(project)) {
+            return false;
+        }
+        FilterField::ALL.into_iter().all(|field| {
+            let mut filters = self.filters.iter().filter(|f| f.field == field).peekable();
+            filters.peek().is_none() || filters.any(|f| f.matches(issue))
+        })
+    }
+
+    /// The rows of the list: indexes into `issues` of those shown, in the
+    /// order they're listed. Issues the `ignore` rules match are left out
+    /// too. `issues` itself is left alone, so it always holds every issue.
+    pub fn rows(&self, issues: &[Issue], ignore: Option<&IgnoreConfig>) -> Vec<usize> {
+        let mut rows: Vec<usize> = (0..issues.len())
+            .filter(|&i| self.matches(&issues[i]) && !ignore.is_some_and(|rules| rules.matches(&issues[i])))
+            .collect();
+        if let Some(sort) = self.sort {
+            rows.sort_by(|&a, &b| sort.compare(&issues[a], &issues[b]));
+        }
+        // Groups keep the place of their first issue
+        if let Some(group) = self.group {
+            let mut firsts: Vec<String> = Vec::new();
+            for &row in &rows {
+                let key = group.key(&issues[row]);
+                if !firsts.contains(&key) {
+                    firsts.push(key);
+                }
+            }
+            rows.sort_by_cached_key(|&row| {
+                let key = group.key(&issues[row]);
+                firsts.iter().position(|first| *first == key)
+            });
+        }
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_issue;
+
+    fn issue(short_id: &str, status: &str, events: u64, last_seen: &str) -> Issue {
+        Issue {
+            event_count: events,
+            last_seen: last_seen.to_string(),
+            ..test_issue(short_id, status)
+        }
+    }
+
+    fn ids<'a>(issues: &'a [Issue], rows: &[usize]) -> Vec<&'a str> {
+        rows.iter().map(|&row| issues[row].short_id.as_str()).collect()
+    }
+
+    fn filter(field: FilterField, value: &str) -> IssueFilter {
+        IssueFilter { field, value: value.to_string() }
+    }
+
+    #[test]
+    fn test_same_field_filters_are_alternatives() {
+        let view = ListView {
+            filters: vec![
+                filter(FilterField::Status, "error"),
+                filter(FilterField::Status, "pending"),
+                filter(FilterField::Text, "p-"),
+            ],
+            sort: None,
+            group: None,
+            project: None,
+        };
+        assert!(view.matches(&issue("P-1", "pending", 0, "")));
+        assert!(!view.matches(&issue("P-2", "analyzing", 0, "")));
+        assert!(!view.matches(&issue("Q1", "error", 0, "")));
+    }
+
+    #[test]
+    fn test_grouping_keeps_the_first_issue_order() {
+        let titled = |short_id: &str, title: &str| {
+            let mut issue = issue(short_id, "pending", 0, "");
+            issue.title = title.to_string();
+            issue
+        };
+        let issues = vec![
+            titled("A", "TypeError: a is undefined"),
+            titled("B", "Health check failed"),
+            titled("C", "RangeError: too deep"),
+            titled("D", "TypeError: d is undefined"),
+        ];
+        let view = ListView {
+            group: Some(GroupBy::ErrorType),
+            ..Default::default()
+        };
+        let rows = view.rows(&issues, None);
+        assert_eq!(ids(&issues, &rows), vec!["A", "D", "B", "C"]);
+        assert_eq!(GroupBy::ErrorType.key(&issues[rows[2]]), "(other)");
+        assert_eq!(error_type("module.Error: boom"), Some("module.Error"));
+    }
+
+    #[test]
+    fn test_environment_and_release_filters() {
+        let mut production = issue("P-1", "pending", 0, "");
+        production.environment = Some("Production".to_string());
+        production.release = Some("web@2.4.1".to_string());
+        let view = ListView {
+            filters: vec![
+                filter(FilterField::Environment, "production"),
+                filter(FilterField::Release, "web@2.4.1"),
+            ],
+            sort: None,
+            group: None,
+            project: None,
+        };
+        assert!(view.matches(&production));
+        // Issues without them don't match
+        assert!(!view.matches(&issue("P-2", "pending", 0, "")));
+        assert_eq!(FilterField::from_name("env"), Some(FilterField::Environment));
+    }
+
+    #[test]
+    fn test_rows_filter_and_sort_without_touching_the_issues() {
+        let issues = vec![
+            issue("A", "error", 5, "2026-01-03"),
+            issue("B", "pending", 50, "2026-01-02"),
+            issue("C", "error", 20, "2026-01-01"),
+        ];
+        let mut view = ListView {
+            filters: vec![filter(FilterField::Status, "error")],
+            sort: Some(IssueSort { key: SortKey::Events, descending: true }),
+            group: None,
+            project: None,
+        };
+        assert_eq!(ids(&issues, &view.rows(&issues, None)), vec!["C", "A"]);
+
+        view = ListView::default();
+        assert_eq!(ids(&issues, &view.rows(&issues, None)), vec!["A", "B", "C"]);
+
+        // Ignore rules hide issues without any filters
+        let ignore = IgnoreConfig {
+            min_events: 10,
+            ..Default::default()
+        };
+        assert_eq!(ids(&issues, &view.rows(&issues, Some(&ignore))), vec!["B", "C"]);
+    }
+
+    #[test]
+    fn test_project_scope() {
+        let in_project = |short_id: &str, project: &str| {
+            let mut issue = issue(short_id, "pending", 0, "");
+            issue.project = Some(project.to_string());
+            issue
+        };
+        let issues = vec![in_project("W-1", "web"), in_project("B-1", "billing"), in_project("W-2", "web")];
+        let mut view = ListView {
+            project: Some("web".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(ids(&issues, &view.rows(&issues, None)), vec!["W-1", "W-2"]);
+
+        view.project = None;
+        assert_eq!(view.rows(&issues, None).len(), 3);
+    }
+}
@@ -0,0 +1,74 @@
+//! Watching completed issues for new events, to find out whether a fix
+//! held once it shipped.
+//!
+//! A watch starts when a review is completed (if `watch_hours` is set),
+//! keeps the event count at that moment, and is checked against Sentry
+//! every few minutes until it runs out.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// How often watched issues are checked.
+pub const WATCH_CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// A completed issue being watched for new events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixWatch {
+    pub issue_id: String,
+    pub short_id: String,
+    /// Events when the review was completed
+    pub baseline: u64,
+    /// Unix time the watch ends at
+    pub until: i64,
+    /// Events since the fix, once there are any
+    pub new_events: Option<u64>,
+}
+
+impl FixWatch {
+    pub fn new(issue_id: String, short_id: String, baseline: u64, hours: u64, now: i64) -> Self {
+        Self {
+            issue_id,
+            short_id,
+            baseline,
+            until: now + hours as i64 * 3600,
+            new_events: None,
+        }
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.until
+    }
+
+    /// Record the issue's current event count. Returns whether there are
+    /// more new events than last time, which is worth telling someone.
+    pub fn check(&mut self, events: u64) -> bool {
+        let new = events.saturating_sub(self.baseline);
+        if new == 0 || self.new_events.is_some_and(|seen| seen >= new) {
+            return false;
+        }
+        self.new_events = Some(new);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alerts_once_per_increase() {
+        let mut watch = FixWatch::new("i".into(), "PROJ-1".into(), 40, 24, 1_000);
+        assert_eq!(watch.until, 1_000 + 24 * 3600);
+        assert!(!watch.is_expired(1_000));
+        assert!(watch.is_expired(watch.until));
+
+        assert!(!watch.check(40));
+        assert_eq!(watch.new_events, None);
+        assert!(watch.check(43));
+        assert!(!watch.check(43));
+        assert!(watch.check(45));
+        assert_eq!(watch.new_events, Some(5));
+    }
+}
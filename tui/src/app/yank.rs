@@ -0,0 +1,224 @@
+//! The yank menu: parts of an issue that can be copied to the clipboard.
+
+use crate::api::{Issue, IssueDetail, IssueSource, IssueState, StackFrame};
+use crate::config::SentryConfig;
+use crate::git::BlameInfo;
+
+/// Something the yank menu can copy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YankItem {
+    ShortId,
+    Title,
+    SentryUrl,
+    StackTrace,
+    Proposal,
+    /// The focused frame's path and line
+    FrameLocation,
+    /// The commit `git blame` found for the focused frame's line
+    FrameBlame,
+}
+
+impl YankItem {
+    pub const ALL: [YankItem; 7] = [
+        YankItem::ShortId,
+        YankItem::Title,
+        YankItem::SentryUrl,
+        YankItem::StackTrace,
+        YankItem::Proposal,
+        YankItem::FrameLocation,
+        YankItem::FrameBlame,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            YankItem::ShortId => "short ID",
+            YankItem::Title => "title",
+            YankItem::SentryUrl => "Sentry URL",
+            YankItem::StackTrace => "stack trace",
+            YankItem::Proposal => "proposal",
+            YankItem::FrameLocation => "frame path",
+            YankItem::FrameBlame => "frame blame",
+        }
+    }
+
+    /// Key that copies the item straight from the menu.
+    pub fn key(self) -> char {
+        match self {
+            YankItem::ShortId => 'i',
+            YankItem::Title => 't',
+            YankItem::SentryUrl => 'u',
+            YankItem::StackTrace => 's',
+            YankItem::Proposal => 'p',
+            YankItem::FrameLocation => 'f',
+            YankItem::FrameBlame => 'b',
+        }
+    }
+}
+
+/// The open yank menu, with each item's text taken when it opened.
+#[derive(Debug)]
+pub struct YankMenu {
+    pub items: Vec<(YankItem, String)>,
+    pub selected: usize,
+}
+
+impl YankMenu {
+    pub fn new(items: Vec<(YankItem, String)>) -> Self {
+        Self { items, selected: 0 }
+    }
+
+    pub fn text(&self, item: YankItem) -> Option<&str> {
+        self.items.iter().find(|(i, _)| *i == item).map(|(_, text)| text.as_str())
+    }
+}
+
+/// Link to a Sentry issue, from its `sentry:<id>` ID.
+fn sentry_url(id: &str, sentry: &SentryConfig) -> Option<String> {
+    sentry.issue_url(id.strip_prefix("sentry:")?)
+}
+
+/// What can be copied from an issue in the list.
+pub fn issue_items(issue: &Issue, sentry: &SentryConfig) -> Vec<(YankItem, String)> {
+    let mut items = vec![
+        (YankItem::ShortId, issue.short_id.clone()),
+        (YankItem::Title, issue.title.clone()),
+    ];
+    if let Some(url) = sentry_url(&issue.id, sentry) {
+        items.push((YankItem::SentryUrl, url));
+    }
+    items
+}
+
+/// What can be copied from a loaded issue, the focused frame (and its
+/// blame, once looked up) first.
+pub fn detail_items(
+    issue: &IssueDetail,
+    focused_frame: Option<&StackFrame>,
+    blame: Option<&BlameInfo>,
+    sentry: &SentryConfig,
+) -> Vec<(YankItem, String)> {
+    let source = &issue.source;
+    let proposal = match &issue.state {
+        IssueState::PendingApproval { proposal, .. } => Some(proposal.clone()),
+        IssueState::PendingReview { proposal, .. } => proposal.clone(),
+        _ => None,
+    };
+    [
+        (YankItem::FrameLocation, focused_frame.and_then(frame_location)),
+        (YankItem::FrameBlame, blame.map(BlameInfo::one_line)),
+        (YankItem::ShortId, source.short_id.clone()),
+        (YankItem::Title, source.title.clone()),
+        (YankItem::SentryUrl, sentry_url(&issue.id, sentry)),
+        (YankItem::StackTrace, stack_trace(source)),
+        (YankItem::Proposal, proposal.filter(|p| !p.is_empty())),
+    ]
+    .into_iter()
+    .filter_map(|(item, text)| Some((item, text?)))
+    .collect()
+}
+
+/// `path:line`, or just the path when the line isn't known.
+pub fn frame_location(frame: &StackFrame) -> Option<String> {
+    let filename = frame.filename.as_ref()?;
+    Some(match frame.lineno {
+        Some(line) => format!("{}:{}", filename, line),
+        None => filename.clone(),
+    })
+}
+
+/// Each exception's type and message followed by its frames, in the order
+/// the detail screen shows them, or the trace pasted into a manual issue.
+pub fn stack_trace(source: &IssueSource) -> Option<String> {
+    let Some(exceptions) = source.exceptions.as_ref().filter(|e| !e.is_empty()) else {
+        return source.stacktrace.clone().filter(|t| !t.trim().is_empty());
+    };
+    let mut text = String::new();
+    for exception in exceptions {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&exception.error_type);
+        if let Some(value) = &exception.value {
+            text.push_str(": ");
+            text.push_str(value);
+        }
+        text.push('\n');
+        for frame in exception.stacktrace.iter().flat_map(|s| &s.frames) {
+            let function = frame.function.as_deref().unwrap_or("<anonymous>");
+            match frame_location(frame) {
+                Some(location) => text.push_str(&format!("  at {} ({})\n", function, location)),
+                None => text.push_str(&format!("  at {}\n", function)),
+            }
+        }
+    }
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(state: serde_json::Value) -> IssueDetail {
+        serde_json::from_value(serde_json::json!({
+            "id": "sentry:42", "sourceType": "sentry", "status": state["status"],
+            "source": {
+                "title": "TypeError: x is undefined", "shortId": "WEB-7",
+                "exceptions": [{
+                    "type": "TypeError", "value": "x is undefined",
+                    "stacktrace": {"frames": [
+                        {"filename": "src/app.ts", "function": "render", "lineno": 12},
+                        {"function": "<module>"},
+                    ]},
+                }],
+            },
+            "state": state, "createdAt": "", "updatedAt": "",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_detail_items() {
+        let sentry = SentryConfig {
+            organization: Some("acme".to_string()),
+            region: None,
+        };
+        let issue = detail(serde_json::json!({
+            "status": "pending_approval", "analysisSessionId": "s", "proposal": "Fix it",
+        }));
+        let frame = issue.source.frames().next();
+        let blame = BlameInfo {
+            commit: "abc1234".to_string(),
+            author: "Jane Doe".to_string(),
+            date: "2026-01-02".to_string(),
+            summary: "Fix the thing".to_string(),
+        };
+        let items = detail_items(&issue, frame, Some(&blame), &sentry);
+        let kinds: Vec<_> = items.iter().map(|(item, _)| *item).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                YankItem::FrameLocation,
+                YankItem::FrameBlame,
+                YankItem::ShortId,
+                YankItem::Title,
+                YankItem::SentryUrl,
+                YankItem::StackTrace,
+                YankItem::Proposal,
+            ]
+        );
+        let menu = YankMenu::new(items);
+        assert_eq!(menu.text(YankItem::FrameLocation), Some("src/app.ts:12"));
+        assert_eq!(menu.text(YankItem::FrameBlame), Some("abc1234 Jane Doe, 2026-01-02: Fix the thing"));
+        assert_eq!(menu.text(YankItem::SentryUrl), Some("https://acme.sentry.io/issues/42/"));
+        assert_eq!(
+            menu.text(YankItem::StackTrace),
+            Some("TypeError: x is undefined\n  at render (src/app.ts:12)\n  at <module>\n")
+        );
+
+        // Nothing to link to without an organization, and no proposal yet
+        let issue = detail(serde_json::json!({"status": "pending"}));
+        let items = detail_items(&issue, None, None, &SentryConfig::default());
+        let kinds: Vec<_> = items.iter().map(|(item, _)| *item).collect();
+        assert_eq!(kinds, vec![YankItem::ShortId, YankItem::Title, YankItem::StackTrace]);
+    }
+}
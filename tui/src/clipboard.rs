@@ -0,0 +1,112 @@
+//! Copying to the clipboard, with the platform's clipboard tool or an
+//! OSC 52 escape sequence.
+//!
+//! Over SSH a local tool would copy on the wrong machine, so OSC 52 (which
+//! the user's own terminal handles) is used there and whenever no tool is
+//! installed.
+
+use std::io::Write;
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use base64::Engine;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::{info, warn};
+
+/// How long a clipboard tool gets before OSC 52 is used instead.
+const NATIVE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Clipboard tools, with their arguments and the environment variable that
+/// says they can work (if any).
+const NATIVE_TOOLS: [(&str, &[&str], Option<&str>); 5] = [
+    ("pbcopy", &[], None),
+    ("wl-copy", &[], Some("WAYLAND_DISPLAY")),
+    ("xclip", &["-selection", "clipboard"], Some("DISPLAY")),
+    ("xsel", &["--clipboard", "--input"], Some("DISPLAY")),
+    ("clip.exe", &[], Some("WSL_DISTRO_NAME")),
+];
+
+/// How text gets onto the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Clipboard {
+    /// A clipboard tool, fed the text on stdin
+    Native {
+        program: &'static str,
+        args: &'static [&'static str],
+    },
+    /// An escape sequence written to the terminal
+    Osc52,
+}
+
+impl Clipboard {
+    /// OSC 52 in SSH sessions, otherwise the first usable clipboard tool.
+    pub fn detect() -> Self {
+        let is_set = |name: &str| std::env::var_os(name).is_some();
+        let clipboard = if is_set("SSH_CONNECTION") || is_set("SSH_TTY") {
+            Clipboard::Osc52
+        } else {
+            NATIVE_TOOLS
+                .iter()
+                .find(|(program, _, env)| env.is_none_or(is_set) && which::which(program).is_ok())
+                .map_or(Clipboard::Osc52, |(program, args, _)| Clipboard::Native { program, args })
+        };
+        info!(?clipboard, "Clipboard");
+        clipboard
+    }
+
+    /// Copy `text`, writing OSC 52 to `w` if the clipboard tool fails.
+    pub async fn copy<W: Write>(&self, w: &mut W, text: &str) -> Result<()> {
+        if let Clipboard::Native { program, args } = self {
+            let native = tokio::time::timeout(NATIVE_TIMEOUT, run_native(program, args, text));
+            match native.await.context("timed out").and_then(|result| result) {
+                Ok(()) => return Ok(()),
+                Err(e) => warn!(program, error = %e, "Clipboard tool failed, using OSC 52"),
+            }
+        }
+        write_osc52(w, text)
+    }
+}
+
+async fn run_native(program: &str, args: &[&str], text: &str) -> Result<()> {
+    // Output would land on top of the UI
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes()).await?;
+    }
+    let status = child.wait().await?;
+    if !status.success() {
+        bail!("exited with {}", status);
+    }
+    Ok(())
+}
+
+/// Put `text` on the system clipboard with an OSC 52 escape sequence.
+///
+/// Works over SSH and needs no platform tools; terminals that don't support
+/// it just ignore the sequence.
+pub fn write_osc52<W: Write>(w: &mut W, text: &str) -> Result<()> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    write!(w, "\x1b]52;c;{}\x07", encoded)?;
+    w.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_osc52_sequence() {
+        let mut out = Vec::new();
+        Clipboard::Osc52.copy(&mut out, "PROJ-123").await.unwrap();
+        assert_eq!(out, b"\x1b]52;c;UFJPSi0xMjM=\x07");
+    }
+}
@@ -0,0 +1,573 @@
+//! Per-project TUI settings from the `[tui]` section of `glass.toml`.
+//!
+//! The server reads the same file and ignores this section. Everything is
+//! optional; a missing or invalid file falls back to the defaults.
+
+use serde::Deserialize;
+
+use crate::api::{AuthToken, Issue};
+use crate::app::DEFAULT_MAX_LINES;
+use crate::escape::DEFAULT_ESCAPE_COMMAND;
+use crate::locale::{Locale, NumberStyle};
+use crate::util::glob_match;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// Config file looked up in the project directory.
+pub const CONFIG_FILE: &str = "glass.toml";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TuiConfig {
+    #[serde(default)]
+    pub paths: PathConfig,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    #[serde(default)]
+    pub analysis: AnalysisConfig,
+    #[serde(default)]
+    pub review: ReviewConfig,
+    #[serde(default)]
+    pub spellcheck: SpellcheckConfig,
+    #[serde(default)]
+    pub escape: EscapeConfig,
+    /// Other projects' servers whose issues are listed alongside this one's.
+    /// Capabilities and the analysis queue still come from this project's
+    /// server alone.
+    #[serde(default)]
+    pub servers: Vec<ServerConfig>,
+    #[serde(default)]
+    pub ignore: IgnoreConfig,
+    /// Sent to a server that requires one; `GLASS_TOKEN` takes precedence
+    #[serde(default)]
+    pub token: Option<AuthToken>,
+    /// From the server's `[sentry]` section rather than `[tui]`
+    #[serde(skip)]
+    pub sentry: SentryConfig,
+}
+
+/// The parts of the server's `[sentry]` section the TUI needs, to link to
+/// issues on sentry.io.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SentryConfig {
+    #[serde(default)]
+    pub organization: Option<String>,
+    /// "us" or "de"
+    #[serde(default)]
+    pub region: Option<String>,
+}
+
+impl SentryConfig {
+    /// Link to a Sentry issue by its numeric ID.
+    pub fn issue_url(&self, sentry_id: &str) -> Option<String> {
+        let organization = self.organization.as_deref().filter(|o| !o.is_empty())?;
+        let host = match self.region.as_deref() {
+            Some("de") => "de.sentry.io",
+            _ => "sentry.io",
+        };
+        Some(format!("https://{}.{}/issues/{}/", organization, host, sentry_id))
+    }
+}
+
+/// Spell checking of prompt input, in builds with the `spellcheck` feature.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SpellcheckConfig {
+    /// Word list or Hunspell `.dic` file (default `/usr/share/dict/words`)
+    #[serde(default)]
+    pub dictionary: Option<PathBuf>,
+    /// Extra words to accept, e.g. the project's jargon
+    #[serde(default)]
+    pub words: Vec<String>,
+}
+
+/// A Glass server running for another project, e.g.
+///
+/// ```toml
+/// [[tui.servers]]
+/// name = "billing"
+/// url = "http://localhost:7421"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerConfig {
+    /// Shown in the list's project column
+    pub name: String,
+    pub url: String,
+    /// Sent to this server if it requires one
+    #[serde(default)]
+    pub token: Option<AuthToken>,
+}
+
+impl ServerConfig {
+    /// Parse `NAME=URL`, as given on the command line.
+    pub fn parse_arg(arg: &str) -> Result<Self, String> {
+        match arg.split_once('=') {
+            Some((name, url)) if !name.trim().is_empty() && !url.trim().is_empty() => Ok(Self {
+                name: name.trim().to_string(),
+                url: url.trim().to_string(),
+                token: None,
+            }),
+            _ => Err(format!("expected NAME=URL, got {:?}", arg)),
+        }
+    }
+}
+
+/// Add `extra` servers to the configured ones, replacing any configured
+/// server of the same name.
+pub fn merge_servers(configured: &mut Vec<ServerConfig>, extra: Vec<ServerConfig>) {
+    configured.retain(|server| !extra.iter().any(|e| e.name == server.name));
+    configured.extend(extra);
+}
+
+/// Known-noisy issues kept out of the list, e.g.
+///
+/// ```toml
+/// [tui.ignore]
+/// titles = ["*health check*timed out*"]
+/// culprits = ["bots/*"]
+/// min_events = 5
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct IgnoreConfig {
+    /// Globs (`*` and `?`, ignoring case) matched against the whole title
+    #[serde(default)]
+    pub titles: Vec<String>,
+    /// Globs matched against the culprit, from servers that list it
+    #[serde(default)]
+    pub culprits: Vec<String>,
+    /// Issues with fewer events than this are ignored
+    #[serde(default)]
+    pub min_events: u64,
+}
+
+impl IgnoreConfig {
+    pub fn is_empty(&self) -> bool {
+        self.titles.is_empty() && self.culprits.is_empty() && self.min_events == 0
+    }
+
+    /// Whether any rule matches an issue.
+    pub fn matches(&self, issue: &Issue) -> bool {
+        issue.event_count < self.min_events
+            || self.titles.iter().any(|glob| glob_match(glob, &issue.title))
+            || issue
+                .culprit
+                .as_deref()
+                .is_some_and(|culprit| self.culprits.iter().any(|glob| glob_match(glob, culprit)))
+    }
+}
+
+/// Reviewing a fix in its worktree.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ReviewConfig {
+    /// Shell command that runs the project's tests, e.g. "npm test"
+    #[serde(default)]
+    pub test_command: Option<String>,
+    /// Hours to keep checking a completed issue for new events, e.g. 24
+    /// (off unless set)
+    #[serde(default)]
+    pub watch_hours: Option<u64>,
+}
+
+/// The escape hatch into an interactive agent session.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EscapeConfig {
+    /// Shell command that resumes a session, with `{session}` replaced by
+    /// the session file's path and `{session_id}` by its ID, e.g.
+    /// "claude --resume {session_id}"
+    #[serde(default = "default_escape_command")]
+    pub command: String,
+    /// Where sessions open when glass runs inside tmux or zellij
+    #[serde(default)]
+    pub open_in: OpenIn,
+}
+
+impl Default for EscapeConfig {
+    fn default() -> Self {
+        Self {
+            command: default_escape_command(),
+            open_in: OpenIn::default(),
+        }
+    }
+}
+
+/// Where the escape hatch opens a session inside a terminal multiplexer.
+/// Outside one, glass always hands over its own terminal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OpenIn {
+    /// Ask each time
+    #[default]
+    Ask,
+    /// Suspend glass and use its terminal
+    Terminal,
+    Pane,
+    Window,
+}
+
+fn default_escape_command() -> String {
+    DEFAULT_ESCAPE_COMMAND.to_string()
+}
+
+/// Limits on the analysis activity pane.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct AnalysisConfig {
+    /// Lines kept before the oldest are dropped
+    #[serde(default = "default_max_lines")]
+    pub max_lines: usize,
+    /// Append dropped lines to a file in the log directory
+    #[serde(default)]
+    pub spill_overflow: bool,
+}
+
+impl Default for AnalysisConfig {
+    fn default() -> Self {
+        Self {
+            max_lines: default_max_lines(),
+            spill_overflow: false,
+        }
+    }
+}
+
+fn default_max_lines() -> usize {
+    DEFAULT_MAX_LINES
+}
+
+/// Colors, status markers and icons.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub palette: Palette,
+    /// Show a letter badge next to each status glyph
+    #[serde(default)]
+    pub status_badges: bool,
+    #[serde(default)]
+    pub icons: IconSet,
+    /// Keep a line of per-status counts and the pinned issue at the top of
+    /// every screen
+    #[serde(default)]
+    pub summary_line: bool,
+    /// Keep things still: no bell flash, no following streaming output,
+    /// and clocks that change by the minute
+    #[serde(default)]
+    pub reduced_motion: bool,
+    /// Show when things happened as local times rather than "3h ago"
+    #[serde(default)]
+    pub absolute_times: bool,
+    /// How event and user counts are written
+    #[serde(default)]
+    pub numbers: NumberStyle,
+    /// Whose digit grouping and date order to follow, e.g. "de-DE", or
+    /// "system" for the system locale; dates are ISO-style by default
+    #[serde(default)]
+    pub locale: Locale,
+    /// Keep the list full width on wide terminals rather than previewing
+    /// the selected issue beside it
+    #[serde(default)]
+    pub list_only: bool,
+}
+
+/// Characters used for icons and status glyphs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IconSet {
+    #[default]
+    Unicode,
+    /// Plain ASCII, for terminals that draw emoji as tofu or misjudge their width
+    Ascii,
+}
+
+/// Color set used for issue statuses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Palette {
+    #[default]
+    Default,
+    /// Okabe-Ito colors, distinguishable with common color vision deficiencies
+    ColorBlind,
+    /// Darker colors that stay readable on a light terminal background
+    Light,
+}
+
+/// How stack frame paths are shortened for display.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PathConfig {
+    /// Prefixes removed from the start of paths, e.g. "/app/" or "webpack:///"
+    #[serde(default)]
+    pub strip_prefixes: Vec<String>,
+    /// Dependency directories whose nested contents are collapsed
+    #[serde(default = "default_collapse_dirs")]
+    pub collapse_dirs: Vec<String>,
+}
+
+impl Default for PathConfig {
+    fn default() -> Self {
+        Self {
+            strip_prefixes: Vec::new(),
+            collapse_dirs: default_collapse_dirs(),
+        }
+    }
+}
+
+fn default_collapse_dirs() -> Vec<String> {
+    ["node_modules", "site-packages", "dist-packages", "vendor"]
+        .map(String::from)
+        .to_vec()
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    tui: TuiConfig,
+    #[serde(default)]
+    sentry: SentryConfig,
+}
+
+impl TuiConfig {
+    /// Load the config for the project at `project`.
+    pub fn load(project: &Path) -> Self {
+        let path = project.join(CONFIG_FILE);
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        Self::parse(&content).unwrap_or_else(|e| {
+            warn!(path = %path.display(), error = %e, "Ignoring invalid TUI config");
+            Self::default()
+        })
+    }
+
+    /// Parse the `[tui]` section (and what it needs of `[sentry]`) out of a
+    /// `glass.toml` document.
+    pub fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str::<ConfigFile>(content).map(|f| TuiConfig { sentry: f.sentry, ..f.tui })
+    }
+}
+
+impl PathConfig {
+    /// Whether a path is inside a dependency directory.
+    pub fn is_library(&self, path: &str) -> bool {
+        path.split('/').any(|s| self.collapse_dirs.iter().any(|d| d == s))
+    }
+
+    /// Shorten a frame path for display.
+    ///
+    /// Strips the first matching prefix, then collapses everything between
+    /// the first and last dependency directory, so a pnpm path like
+    /// `node_modules/.pnpm/x@1/node_modules/x/lib/a.js` becomes
+    /// `node_modules/…/x/lib/a.js`.
+    pub fn shorten(&self, path: &str) -> String {
+        let stripped = self
+            .strip_prefixes
+            .iter()
+            .filter(|p| !p.is_empty())
+            .find_map(|p| path.strip_prefix(p.as_str()))
+            .unwrap_or(path);
+
+        let segments: Vec<&str> = stripped.split('/').collect();
+        let is_collapsible = |s: &&str| self.collapse_dirs.iter().any(|d| d == s);
+        let first = segments.iter().position(is_collapsible);
+        let last = segments.iter().rposition(is_collapsible);
+
+        match (first, last) {
+            (Some(first), Some(last)) if last > first + 1 => {
+                let mut parts = segments[..=first].to_vec();
+                parts.push("…");
+                parts.extend(&segments[last + 1..]);
+                parts.join("/")
+            }
+            _ => stripped.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(strip: &[&str]) -> PathConfig {
+        PathConfig {
+            strip_prefixes: strip.iter().map(|s| s.to_string()).collect(),
+            ..PathConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_recognizes_library_paths() {
+        let config = config(&[]);
+        assert!(config.is_library("/app/node_modules/express/lib/router.js"));
+        assert!(config.is_library("/usr/lib/python3/site-packages/django/views.py"));
+        assert!(!config.is_library("/app/src/vendors.ts"));
+    }
+
+    #[test]
+    fn test_strips_the_first_matching_prefix() {
+        let config = config(&["/srv/", "/app/"]);
+        assert_eq!(config.shorten("/app/src/handlers/user.ts"), "src/handlers/user.ts");
+        assert_eq!(config.shorten("/other/file.ts"), "/other/file.ts");
+    }
+
+    #[test]
+    fn test_collapses_nested_dependency_dirs() {
+        let config = config(&["/app/"]);
+        assert_eq!(
+            config.shorten("/app/node_modules/.pnpm/react-dom@18.2.0/node_modules/react-dom/cjs/index.js"),
+            "node_modules/…/react-dom/cjs/index.js"
+        );
+        // A single dependency dir has nothing to collapse
+        assert_eq!(
+            config.shorten("/app/node_modules/express/lib/router.js"),
+            "node_modules/express/lib/router.js"
+        );
+    }
+
+    #[test]
+    fn test_parses_the_tui_section() {
+        let config = TuiConfig::parse(
+            r#"
+            [display]
+            page_size = 50
+
+            [tui.paths]
+            strip_prefixes = ["/app/"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.paths.strip_prefixes, vec!["/app/"]);
+        assert_eq!(config.paths.collapse_dirs, default_collapse_dirs());
+
+        let empty = TuiConfig::parse("[display]\npage_size = 50\n").unwrap();
+        assert!(empty.paths.strip_prefixes.is_empty());
+        assert_eq!(empty.theme.palette, Palette::Default);
+        assert_eq!(empty.theme.icons, IconSet::Unicode);
+    }
+
+    #[test]
+    fn test_parses_the_theme() {
+        let config = TuiConfig::parse(
+            r#"
+            [tui.theme]
+            palette = "color-blind"
+            status_badges = true
+            icons = "ascii"
+            numbers = "compact"
+            locale = "de-DE"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.theme.palette, Palette::ColorBlind);
+        assert!(config.theme.status_badges);
+        assert_eq!(config.theme.icons, IconSet::Ascii);
+        assert_eq!(config.theme.numbers, NumberStyle::Compact);
+        assert_eq!(config.theme.locale, Locale::from_tag("de-DE"));
+    }
+
+    #[test]
+    fn test_parses_analysis_limits() {
+        let config = TuiConfig::parse("[tui.analysis]\nspill_overflow = true\n").unwrap();
+        assert_eq!(config.analysis.max_lines, DEFAULT_MAX_LINES);
+        assert!(config.analysis.spill_overflow);
+
+        let config = TuiConfig::parse("[tui.analysis]\nmax_lines = 500\n").unwrap();
+        assert_eq!(config.analysis.max_lines, 500);
+        assert!(!config.analysis.spill_overflow);
+    }
+
+    #[test]
+    fn test_parses_the_test_command() {
+        let config = TuiConfig::parse("[tui.review]\ntest_command = \"npm test\"\n").unwrap();
+        assert_eq!(config.review.test_command.as_deref(), Some("npm test"));
+        assert_eq!(TuiConfig::parse("").unwrap().review.test_command, None);
+    }
+
+    #[test]
+    fn test_parses_the_escape_command() {
+        let config = TuiConfig::parse("[tui.escape]\ncommand = \"aider --load {session}\"\n").unwrap();
+        assert_eq!(config.escape.command, "aider --load {session}");
+        assert_eq!(TuiConfig::parse("").unwrap().escape.command, DEFAULT_ESCAPE_COMMAND);
+        assert_eq!(TuiConfig::parse("").unwrap().escape.open_in, OpenIn::Ask);
+
+        let config = TuiConfig::parse("[tui.escape]\nopen_in = \"window\"\n").unwrap();
+        assert_eq!(config.escape.open_in, OpenIn::Window);
+    }
+
+    #[test]
+    fn test_parses_aggregated_servers() {
+        let config = TuiConfig::parse(
+            "[[tui.servers]]\nname = \"billing\"\nurl = \"http://localhost:7421\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.servers.len(), 1);
+        assert_eq!(config.servers[0].name, "billing");
+        assert!(TuiConfig::parse("").unwrap().servers.is_empty());
+    }
+
+    #[test]
+    fn test_parses_tokens() {
+        let config = TuiConfig::parse(
+            "[tui]\ntoken = \"abc\"\n\n\
+             [[tui.servers]]\nname = \"billing\"\nurl = \"http://localhost:7421\"\ntoken = \"def\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.token, "abc".parse().ok());
+        assert_eq!(config.servers[0].token, "def".parse().ok());
+        assert_eq!(TuiConfig::parse("").unwrap().token, None);
+        assert!(TuiConfig::parse("[tui]\ntoken = \"\"\n").is_err());
+    }
+
+    #[test]
+    fn test_command_line_servers_override_configured_ones() {
+        let mut servers = TuiConfig::parse(
+            "[[tui.servers]]\nname = \"billing\"\nurl = \"http://localhost:7421\"\n\n\
+             [[tui.servers]]\nname = \"search\"\nurl = \"http://localhost:7422\"\n",
+        )
+        .unwrap()
+        .servers;
+        let billing = ServerConfig::parse_arg("billing=http://localhost:7500").unwrap();
+        let mobile = ServerConfig::parse_arg("mobile = http://localhost:7423").unwrap();
+        merge_servers(&mut servers, vec![billing, mobile]);
+
+        let servers: Vec<_> = servers.iter().map(|s| (s.name.as_str(), s.url.as_str())).collect();
+        assert_eq!(
+            servers,
+            vec![
+                ("search", "http://localhost:7422"),
+                ("billing", "http://localhost:7500"),
+                ("mobile", "http://localhost:7423"),
+            ]
+        );
+        assert!(ServerConfig::parse_arg("billing").is_err());
+        assert!(ServerConfig::parse_arg("=http://localhost:7421").is_err());
+    }
+
+    #[test]
+    fn test_parses_ignore_rules() {
+        let config = TuiConfig::parse(
+            r#"
+            [tui.ignore]
+            titles = ["*health check*"]
+            min_events = 5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.ignore.titles, vec!["*health check*"]);
+        assert!(config.ignore.culprits.is_empty());
+        assert_eq!(config.ignore.min_events, 5);
+        assert!(TuiConfig::parse("").unwrap().ignore.is_empty());
+    }
+
+    #[test]
+    fn test_parses_sentry_links() {
+        let config = TuiConfig::parse(
+            r#"
+            [sentry]
+            organization = "acme"
+            project = "web"
+            team = "core"
+            region = "de"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.sentry.issue_url("12345").as_deref(),
+            Some("https://acme.de.sentry.io/issues/12345/")
+        );
+        assert_eq!(TuiConfig::default().sentry.issue_url("12345"), None);
+    }
+}
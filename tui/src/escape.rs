@@ -1,20 +1,306 @@
-//! Escape hatch to interactive pi session.
+//! Escape hatches that hand the terminal to another program: an interactive
+//! agent session (pi unless configured otherwise), or the editor for a
+//! stack frame's source or a fix's commit messages. Inside tmux or zellij, agent sessions can open in
+//! a new pane or window instead, leaving glass running.
 
-use anyhow::Result;
-use std::process::Command;
+use anyhow::{bail, Result};
+use std::path::Path;
+use std::process::{Command, Stdio};
 
-/// Run pi interactively with the given session file.
+use crate::api::SessionRef;
+
+/// Command the escape hatch runs when the config doesn't name one.
+pub const DEFAULT_ESCAPE_COMMAND: &str = "pi --session {session}";
+
+/// Resume an agent session interactively by running `template` (see
+/// [`command_line`]) through the shell.
 ///
-/// This takes over the terminal completely until pi exits.
-pub fn run_pi_interactive(session_path: &str) -> Result<()> {
-    let status = Command::new("pi")
-        .arg("--session")
-        .arg(session_path)
+/// This takes over the terminal completely until the agent exits.
+pub fn run_agent_interactive(template: &str, session: &SessionRef) -> Result<()> {
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(command_line(template, session))
         .status()?;
 
     if !status.success() {
-        eprintln!("pi exited with status: {}", status);
+        eprintln!("{} exited with status: {}", program(template), status);
+    }
+
+    Ok(())
+}
+
+/// The shell command for a session: `{session}` becomes the session file's
+/// path and `{session_id}` its ID, both quoted for the shell.
+pub fn command_line(template: &str, session: &SessionRef) -> String {
+    template
+        .replace("{session_id}", &shell_quote(&session.id))
+        .replace("{session}", &shell_quote(&session.path))
+}
+
+/// Resume an agent session in a new pane or window of `multiplexer`, in
+/// glass's working directory.
+pub fn open_agent_in(
+    multiplexer: Multiplexer,
+    placement: Placement,
+    template: &str,
+    session: &SessionRef,
+) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let output = multiplexer
+        .open_command(placement, &command_line(template, session), &cwd)
+        .stdin(Stdio::null())
+        .output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("{}", stderr.lines().next().unwrap_or("failed to open a pane"));
+    }
+    Ok(())
+}
+
+/// Terminal multiplexer glass is running inside.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Multiplexer {
+    Tmux,
+    Zellij,
+}
+
+/// Where a multiplexer opens the agent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    /// Beside glass
+    Pane,
+    /// A tmux window, or a floating pane in zellij (which can't start a
+    /// command in a new tab)
+    Window,
+}
+
+impl Multiplexer {
+    /// The multiplexer the environment says glass is in, if any.
+    pub fn detect() -> Option<Self> {
+        if std::env::var_os("TMUX").is_some() {
+            Some(Multiplexer::Tmux)
+        } else if std::env::var_os("ZELLIJ").is_some() {
+            Some(Multiplexer::Zellij)
+        } else {
+            None
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Multiplexer::Tmux => "tmux",
+            Multiplexer::Zellij => "zellij",
+        }
+    }
+
+    /// What a placement opens here, for messages.
+    pub fn describe(self, placement: Placement) -> &'static str {
+        match (self, placement) {
+            (_, Placement::Pane) => "pane",
+            (Multiplexer::Tmux, Placement::Window) => "window",
+            (Multiplexer::Zellij, Placement::Window) => "floating pane",
+        }
+    }
+
+    /// Command that runs the shell command `command` in a new pane or window.
+    fn open_command(self, placement: Placement, command: &str, cwd: &Path) -> Command {
+        match self {
+            Multiplexer::Tmux => {
+                let mut tmux = Command::new("tmux");
+                match placement {
+                    Placement::Pane => tmux.args(["split-window", "-h"]),
+                    Placement::Window => tmux.arg("new-window"),
+                };
+                tmux.arg("-c").arg(cwd).arg(command);
+                tmux
+            }
+            Multiplexer::Zellij => {
+                let mut zellij = Command::new("zellij");
+                zellij.arg("run");
+                if placement == Placement::Window {
+                    zellij.arg("--floating");
+                }
+                zellij
+                    .args(["--close-on-exit", "--cwd"])
+                    .arg(cwd)
+                    .args(["--", "sh", "-c", command]);
+                zellij
+            }
+        }
+    }
+}
+
+/// The program a command template runs, for messages.
+pub fn program(template: &str) -> &str {
+    template.split_whitespace().next().unwrap_or("agent")
+}
+
+/// Single-quote `value` unless it's plainly safe.
+fn shell_quote(value: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "-_./:@%+=,".contains(c);
+    if !value.is_empty() && value.chars().all(safe) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+/// The user's editor: `VISUAL`, then `EDITOR`, then vi.
+pub fn editor() -> String {
+    ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+/// Arguments that open `file` at `line` in `editor`. Terminal editors take
+/// `+LINE FILE`; GUI ones that would return straight away are told to wait.
+pub fn editor_args(editor: &str, file: &Path, line: Option<u32>) -> Vec<String> {
+    let file = file.display().to_string();
+    let Some(line) = line else {
+        return vec![file];
+    };
+    let name = Path::new(program(editor))
+        .file_name()
+        .map_or(String::new(), |name| name.to_string_lossy().into_owned());
+    match name.as_str() {
+        "code" | "codium" | "cursor" => vec!["--wait".into(), "--goto".into(), format!("{}:{}", file, line)],
+        "subl" | "zed" => vec!["--wait".into(), format!("{}:{}", file, line)],
+        "hx" | "helix" => vec![format!("{}:{}", file, line)],
+        _ => vec![format!("+{}", line), file],
     }
+}
 
+/// Open `file` at `line` in the user's editor, which takes over the
+/// terminal until it exits. The editor setting may carry its own flags, so
+/// it goes through the shell.
+pub fn open_in_editor(file: &Path, line: Option<u32>) -> Result<()> {
+    let editor = editor();
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$@\"", editor))
+        .arg("sh")
+        .args(editor_args(&editor, file, line))
+        .status()?;
+    if !status.success() {
+        bail!("{} exited with {}", program(&editor), status);
+    }
     Ok(())
 }
+
+/// A change to a fix's commits before the review is completed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitEdit {
+    /// Reword the newest commit
+    Amend,
+    /// Fold every commit since `base` into one, starting from their
+    /// combined messages
+    Squash { base: String },
+}
+
+/// Apply `edit` in the worktree, with git opening the user's editor
+/// (`GIT_EDITOR`, `core.editor`, `VISUAL` or `EDITOR`) for the message.
+///
+/// Returns whether the history changed; quitting the editor with an empty
+/// message leaves it as it was.
+pub fn edit_commits(worktree: &Path, edit: &CommitEdit) -> Result<bool> {
+    match edit {
+        CommitEdit::Amend => Ok(git(worktree, &["commit", "--amend"]).status()?.success()),
+        CommitEdit::Squash { base } => {
+            let head = output(worktree, &["rev-parse", "HEAD"])?;
+            let range = format!("{}..HEAD", base);
+            let message = output(worktree, &["log", "--reverse", "--format=%B", &range])?;
+
+            if !git(worktree, &["reset", "--soft", base]).status()?.success() {
+                bail!("git reset failed");
+            }
+            let committed = git(worktree, &["commit", "--edit", "--message", &message])
+                .status()?
+                .success();
+            if !committed {
+                // Put the original commits back
+                git(worktree, &["reset", "--soft", head.trim()]).status()?;
+            }
+            Ok(committed)
+        }
+    }
+}
+
+fn git(worktree: &Path, args: &[&str]) -> Command {
+    let mut command = Command::new("git");
+    command.arg("-C").arg(worktree).args(args);
+    command
+}
+
+/// Run git quietly and return its stdout.
+fn output(worktree: &Path, args: &[&str]) -> Result<String> {
+    let output = git(worktree, args).stdin(Stdio::null()).output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("{}", stderr.lines().next().unwrap_or("git failed"));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(id: &str, path: &str) -> SessionRef {
+        SessionRef {
+            id: id.to_string(),
+            path: path.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_command_line_substitutes_and_quotes() {
+        let plain = session("ses_1", "/home/me/.pi/sessions/a.jsonl");
+        assert_eq!(
+            command_line(DEFAULT_ESCAPE_COMMAND, &plain),
+            "pi --session /home/me/.pi/sessions/a.jsonl"
+        );
+        assert_eq!(command_line("claude --resume {session_id}", &plain), "claude --resume ses_1");
+
+        let spaced = session("x", "/tmp/my sessions/it's.jsonl");
+        assert_eq!(
+            command_line("aider --load {session}", &spaced),
+            "aider --load '/tmp/my sessions/it'\\''s.jsonl'"
+        );
+    }
+
+    #[test]
+    fn test_multiplexer_commands() {
+        let args = |multiplexer: Multiplexer, placement| {
+            let command = multiplexer.open_command(placement, "pi --session a.jsonl", Path::new("/src"));
+            let mut args = vec![command.get_program().to_string_lossy().into_owned()];
+            args.extend(command.get_args().map(|arg| arg.to_string_lossy().into_owned()));
+            args.join(" ")
+        };
+        assert_eq!(
+            args(Multiplexer::Tmux, Placement::Pane),
+            "tmux split-window -h -c /src pi --session a.jsonl"
+        );
+        assert_eq!(
+            args(Multiplexer::Tmux, Placement::Window),
+            "tmux new-window -c /src pi --session a.jsonl"
+        );
+        assert_eq!(
+            args(Multiplexer::Zellij, Placement::Window),
+            "zellij run --floating --close-on-exit --cwd /src -- sh -c pi --session a.jsonl"
+        );
+    }
+
+    #[test]
+    fn test_editor_args() {
+        let file = Path::new("/repo/src/app.ts");
+        assert_eq!(editor_args("nvim", file, Some(12)), vec!["+12", "/repo/src/app.ts"]);
+        assert_eq!(editor_args("emacs -nw", file, Some(12)), vec!["+12", "/repo/src/app.ts"]);
+        assert_eq!(
+            editor_args("/usr/bin/code", file, Some(12)),
+            vec!["--wait", "--goto", "/repo/src/app.ts:12"]
+        );
+        assert_eq!(editor_args("hx", file, None), vec!["/repo/src/app.ts"]);
+    }
+}
@@ -0,0 +1,168 @@
+//! Pull requests for reviewed fixes.
+//!
+//! Pushes a fix's worktree branch to `origin` and opens a pull request with
+//! the forge's own CLI, `gh` for GitHub or `glab` for GitLab, so Glass never
+//! handles a token.
+
+use std::path::Path;
+use std::process::Stdio;
+
+use tokio::process::Command;
+
+use crate::api::{IssueDetail, IssueState};
+use crate::git;
+
+/// Where the project's `origin` is hosted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Forge {
+    GitHub,
+    GitLab,
+}
+
+impl Forge {
+    /// Guess the forge from a remote URL. Anything unrecognised is taken to
+    /// be GitHub, e.g. GitHub Enterprise on its own domain.
+    pub fn from_remote(url: &str) -> Self {
+        if url.to_ascii_lowercase().contains("gitlab") {
+            Forge::GitLab
+        } else {
+            Forge::GitHub
+        }
+    }
+
+    fn program(self) -> &'static str {
+        match self {
+            Forge::GitHub => "gh",
+            Forge::GitLab => "glab",
+        }
+    }
+
+    fn create_args<'a>(self, branch: &'a str, title: &'a str, body: &'a str) -> Vec<&'a str> {
+        match self {
+            Forge::GitHub => vec!["pr", "create", "--head", branch, "--title", title, "--body", body],
+            Forge::GitLab => vec![
+                "mr",
+                "create",
+                "--source-branch",
+                branch,
+                "--title",
+                title,
+                "--description",
+                body,
+                "--yes",
+            ],
+        }
+    }
+}
+
+/// Pull request title: "Fix PROJ-123: TypeError: ...".
+pub fn title(issue: &IssueDetail) -> String {
+    let title = issue.source.title.as_deref().unwrap_or("Untitled issue");
+    match &issue.source.short_id {
+        Some(short_id) => format!("Fix {}: {}", short_id, title),
+        None => format!("Fix: {}", title),
+    }
+}
+
+/// Pull request body: the issue it fixes, then the approved proposal when
+/// the server kept it.
+pub fn body(issue: &IssueDetail) -> String {
+    let mut body = match (&issue.source.short_id, &issue.source.culprit) {
+        (Some(short_id), Some(culprit)) => format!("Fixes {} in `{}`.", short_id, culprit),
+        (Some(short_id), None) => format!("Fixes {}.", short_id),
+        (None, _) => format!("Fixes {} issue {}.", issue.source_type, issue.id),
+    };
+    if let IssueState::PendingReview { proposal: Some(proposal), .. } = &issue.state {
+        body.push_str("\n\n## Proposal\n\n");
+        body.push_str(proposal.trim());
+    }
+    body
+}
+
+/// Push `branch` from the worktree and open a pull request for it,
+/// returning its URL. A pull request that already exists for the branch
+/// counts as opened.
+pub async fn open_pull_request(
+    worktree: &Path,
+    branch: &str,
+    title: &str,
+    body: &str,
+) -> Result<String, String> {
+    git::git(worktree, &["push", "--quiet", "--set-upstream", "origin", branch], false).await?;
+    let remote = git::git(worktree, &["remote", "get-url", "origin"], false).await?;
+    let forge = Forge::from_remote(remote.trim());
+    let program = forge.program();
+
+    let output = Command::new(program)
+        .args(forge.create_args(branch, title, body))
+        .current_dir(worktree)
+        // The TUI owns the terminal: fail rather than prompt for anything
+        .env("GH_PROMPT_DISABLED", "1")
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() {
+        // gh fails with "a pull request for branch ... already exists: <url>"
+        if stderr.contains("already exists") {
+            if let Some(url) = find_url(&stderr) {
+                return Ok(url);
+            }
+        }
+        let reason = stderr.lines().rev().find(|l| !l.trim().is_empty());
+        return Err(format!("{} failed: {}", program, reason.unwrap_or("no output")));
+    }
+    find_url(&stdout).ok_or_else(|| format!("{} didn't print the pull request's URL", program))
+}
+
+/// The last URL in CLI output.
+fn find_url(output: &str) -> Option<String> {
+    output
+        .split_whitespace()
+        .rev()
+        .find(|word| word.starts_with("https://") || word.starts_with("http://"))
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forge_from_remote() {
+        assert_eq!(Forge::from_remote("git@github.com:acme/web.git"), Forge::GitHub);
+        assert_eq!(Forge::from_remote("https://gitlab.example.com/acme/web.git"), Forge::GitLab);
+        assert_eq!(Forge::from_remote("git@git.acme.dev:web.git"), Forge::GitHub);
+    }
+
+    #[test]
+    fn test_find_url() {
+        let glab = "Creating merge request for fix/proj-123 into main in acme/web\n\n!42 Fix PROJ-123 (fix/proj-123)\n https://gitlab.com/acme/web/-/merge_requests/42\n";
+        assert_eq!(
+            find_url(glab).as_deref(),
+            Some("https://gitlab.com/acme/web/-/merge_requests/42")
+        );
+        assert_eq!(find_url("Warning: 2 uncommitted changes\n"), None);
+    }
+
+    #[test]
+    fn test_title_and_body() {
+        let issue: IssueDetail = serde_json::from_str(
+            r#"{"id":"i","sourceType":"sentry","status":"pending_review",
+                "source":{"title":"TypeError: x is undefined","shortId":"PROJ-123","culprit":"src/user.ts"},
+                "state":{"status":"pending_review","analysisSessionId":"a","implementationSessionId":"b",
+                         "worktreePath":"w","worktreeBranch":"fix/proj-123","proposal":"Guard the lookup.\n"},
+                "createdAt":"","updatedAt":""}"#,
+        )
+        .unwrap();
+        assert_eq!(title(&issue), "Fix PROJ-123: TypeError: x is undefined");
+        assert_eq!(
+            body(&issue),
+            "Fixes PROJ-123 in `src/user.ts`.\n\n## Proposal\n\nGuard the lookup."
+        );
+    }
+}
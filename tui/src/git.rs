@@ -0,0 +1,540 @@
+//! Git lookups in the project checkout.
+
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use time::OffsetDateTime;
+use tokio::process::Command;
+
+use crate::api::FileChange;
+
+/// The last commit that touched a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlameInfo {
+    /// Abbreviated commit hash
+    pub commit: String,
+    pub author: String,
+    /// Author date as YYYY-MM-DD
+    pub date: String,
+    pub summary: String,
+}
+
+impl BlameInfo {
+    /// "abc1234 Jane Doe, 2026-01-02: Fix the thing"
+    pub fn one_line(&self) -> String {
+        format!("{} {}, {}: {}", self.commit, self.author, self.date, self.summary)
+    }
+}
+
+/// Find the file a stack frame path refers to inside `repo`.
+///
+/// Frame paths are often from the deployed build (`/app/src/x.ts`,
+/// `webpack:///./src/x.ts`), so leading components are dropped until what's
+/// left exists in the checkout.
+pub fn resolve_in_repo(repo: &Path, frame_path: &str) -> Option<PathBuf> {
+    let path = frame_path
+        .split_once("://")
+        .map_or(frame_path, |(_, rest)| rest);
+    if let Ok(relative) = Path::new(path).strip_prefix(repo) {
+        return Some(relative.to_path_buf());
+    }
+
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect();
+    (0..segments.len())
+        .map(|start| segments[start..].iter().collect::<PathBuf>())
+        .find(|candidate| repo.join(candidate).is_file())
+}
+
+/// Run `git blame` for a single line of `file` (relative to `repo`).
+pub async fn blame_line(repo: &Path, file: &Path, line: u32) -> Result<BlameInfo, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(["blame", "--porcelain", "-L"])
+        .arg(format!("{},{}", line, line))
+        .arg("--")
+        .arg(file)
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.lines().next().unwrap_or("git blame failed").to_string());
+    }
+
+    parse_porcelain(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| "Unexpected git blame output".to_string())
+}
+
+/// Parse the first entry of `git blame --porcelain` output.
+fn parse_porcelain(output: &str) -> Option<BlameInfo> {
+    let mut lines = output.lines();
+    let hash = lines.next()?.split_whitespace().next()?;
+
+    let mut author = None;
+    let mut time = None;
+    let mut summary = None;
+    for line in lines {
+        // The blamed line itself ends the header
+        if line.starts_with('\t') {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("author ") {
+            author = Some(value.to_string());
+        } else if let Some(value) = line.strip_prefix("author-time ") {
+            time = value.parse::<i64>().ok();
+        } else if let Some(value) = line.strip_prefix("summary ") {
+            summary = Some(value.to_string());
+        }
+    }
+
+    // Lines that aren't committed yet blame to the all-zero hash
+    if hash.bytes().all(|b| b == b'0') {
+        return Some(BlameInfo {
+            commit: "0000000".to_string(),
+            author: "You".to_string(),
+            date: String::new(),
+            summary: "Not committed yet".to_string(),
+        });
+    }
+
+    let date = time
+        .and_then(|t| OffsetDateTime::from_unix_timestamp(t).ok())
+        .map(|t| format!("{:04}-{:02}-{:02}", t.year(), u8::from(t.month()), t.day()))
+        .unwrap_or_default();
+
+    Some(BlameInfo {
+        commit: hash.chars().take(7).collect(),
+        author: author?,
+        date,
+        summary: summary.unwrap_or_default(),
+    })
+}
+
+/// One file's section of a unified diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    /// Path relative to the worktree (the new path for renames)
+    pub path: String,
+    pub change: FileChange,
+    pub additions: usize,
+    pub deletions: usize,
+    /// The section's lines, from its `diff --git` header on
+    pub lines: Vec<String>,
+}
+
+/// Everything a fix's worktree changes relative to where it branched off the
+/// project checkout: commits, uncommitted edits, and untracked files.
+pub async fn worktree_diff(project: &Path, worktree: &Path) -> Result<Vec<FileDiff>, String> {
+    // Without a branch point, just the uncommitted changes
+    let base = branch_point(project, worktree)
+        .await
+        .unwrap_or_else(|| "HEAD".to_string());
+
+    let mut diff = git(worktree, &["diff", "--no-color", "--no-ext-diff", &base], false).await?;
+    let untracked = git(worktree, &["ls-files", "--others", "--exclude-standard"], false).await?;
+    for file in untracked.lines().filter(|f| !f.is_empty()) {
+        // Exits 1 when the files differ, which they always do here
+        let args = ["diff", "--no-color", "--no-index", "--", "/dev/null", file];
+        diff.push_str(&git(worktree, &args, true).await?);
+    }
+    Ok(split_diff(&diff))
+}
+
+/// A commit on a fix's branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commit {
+    /// Abbreviated commit hash
+    pub hash: String,
+    pub subject: String,
+}
+
+/// The commits a fix's branch adds on top of the project checkout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchLog {
+    /// Where the branch left the project's HEAD
+    pub base: String,
+    /// Newest first
+    pub commits: Vec<Commit>,
+}
+
+/// The commits in a fix's worktree since it branched off the project.
+pub async fn branch_log(project: &Path, worktree: &Path) -> Result<BranchLog, String> {
+    let base = branch_point(project, worktree)
+        .await
+        .ok_or("The worktree's branch has no common history with the project")?;
+    let range = format!("{}..HEAD", base);
+    let log = git(worktree, &["log", "--format=%h%x09%s", &range], false).await?;
+    Ok(BranchLog {
+        base,
+        commits: parse_log(&log),
+    })
+}
+
+/// Work in a fix's worktree that removing it would lose.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AtRisk {
+    /// Files with uncommitted changes, untracked ones included
+    pub files: Vec<String>,
+    /// Commits the branch's upstream doesn't have (none without an upstream)
+    pub unpushed: usize,
+}
+
+impl AtRisk {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.unpushed == 0
+    }
+}
+
+/// Uncommitted changes and unpushed commits in a worktree, such as manual
+/// tweaks made from the shell.
+pub async fn at_risk(worktree: &Path) -> Result<AtRisk, String> {
+    let status = git(worktree, &["status", "--porcelain", "--untracked-files=all"], false).await?;
+    // Fails when the branch has no upstream, leaving nothing to compare
+    let unpushed = git(worktree, &["rev-list", "--count", "@{upstream}..HEAD"], false)
+        .await
+        .ok()
+        .and_then(|count| count.trim().parse().ok())
+        .unwrap_or(0);
+    Ok(AtRisk {
+        files: parse_status(&status),
+        unpushed,
+    })
+}
+
+/// Paths from `git status --porcelain` output, the new path for renames.
+fn parse_status(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| line.get(3..))
+        .map(|path| path.rsplit_once(" -> ").map_or(path, |(_, new)| new).to_string())
+        .collect()
+}
+
+/// Where the worktree's HEAD branched off the project checkout's HEAD.
+async fn branch_point(project: &Path, worktree: &Path) -> Option<String> {
+    let head = git(project, &["rev-parse", "HEAD"], false).await.ok()?;
+    let base = git(worktree, &["merge-base", "HEAD", head.trim()], false).await.ok()?;
+    Some(base.trim().to_string())
+}
+
+/// Parse `git log --format=%h%x09%s` output.
+fn parse_log(output: &str) -> Vec<Commit> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (hash, subject) = line.split_once('\t')?;
+            Some(Commit {
+                hash: hash.to_string(),
+                subject: subject.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// A linked worktree of the project checkout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Worktree {
+    pub path: PathBuf,
+    /// Checked-out branch, without `refs/heads/` (`None` when detached)
+    pub branch: Option<String>,
+    pub locked: bool,
+    /// Its directory is gone, leaving only git's bookkeeping
+    pub prunable: bool,
+}
+
+/// The project's linked worktrees, leaving out the main checkout.
+pub async fn list_worktrees(project: &Path) -> Result<Vec<Worktree>, String> {
+    let output = git(project, &["worktree", "list", "--porcelain"], false).await?;
+    Ok(parse_worktree_list(&output).into_iter().skip(1).collect())
+}
+
+/// Remove a worktree and delete its branch if it's merged, returning what
+/// was done. Worktrees with uncommitted changes are refused; one whose
+/// directory is gone just loses git's record of it.
+pub async fn remove_worktree(project: &Path, worktree: &Worktree) -> Result<String, String> {
+    let path = worktree.path.to_string_lossy();
+    // Without its directory git only removes the record when forced, and
+    // there are no changes left to lose
+    let args: &[&str] = if worktree.prunable {
+        &["worktree", "remove", "--force", &path]
+    } else {
+        &["worktree", "remove", &path]
+    };
+    git(project, args, false).await?;
+    let Some(branch) = &worktree.branch else {
+        return Ok(format!("Removed {}", path));
+    };
+    // -d keeps branches with unmerged commits
+    Ok(match git(project, &["branch", "-d", branch], false).await {
+        Ok(_) => format!("Removed {} and branch {}", path, branch),
+        Err(_) => format!("Removed {}; kept unmerged branch {}", path, branch),
+    })
+}
+
+/// Drop git's records of worktrees whose directories are gone.
+pub async fn prune_worktrees(project: &Path) -> Result<String, String> {
+    git(project, &["worktree", "prune"], false).await?;
+    Ok("Pruned stale worktree records".to_string())
+}
+
+/// Parse `git worktree list --porcelain` output, main checkout first.
+fn parse_worktree_list(output: &str) -> Vec<Worktree> {
+    let mut worktrees: Vec<Worktree> = Vec::new();
+    for line in output.lines() {
+        if let Some(path) = line.strip_prefix("worktree ") {
+            worktrees.push(Worktree {
+                path: PathBuf::from(path),
+                branch: None,
+                locked: false,
+                prunable: false,
+            });
+            continue;
+        }
+        let Some(worktree) = worktrees.last_mut() else {
+            continue;
+        };
+        if let Some(branch) = line.strip_prefix("branch ") {
+            worktree.branch = Some(branch.trim_start_matches("refs/heads/").to_string());
+        } else if line == "locked" || line.starts_with("locked ") {
+            worktree.locked = true;
+        } else if line == "prunable" || line.starts_with("prunable ") {
+            worktree.prunable = true;
+        }
+    }
+    worktrees
+}
+
+/// Run git in `repo` and return its stdout. Exit status 1 counts as success
+/// when `differs_ok` is set, as `git diff --no-index` uses it for "differs".
+///
+/// Git never gets the terminal, so a push that needs credentials fails
+/// instead of waiting on a prompt nobody can see.
+pub(crate) async fn git(repo: &Path, args: &[&str], differs_ok: bool) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+
+    let ok = output.status.success() || (differs_ok && output.status.code() == Some(1));
+    if !ok {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.lines().next().unwrap_or("git failed").to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Split unified diff output into per-file sections.
+pub fn split_diff(diff: &str) -> Vec<FileDiff> {
+    let mut files: Vec<FileDiff> = Vec::new();
+    // Whether the current section's hunks have started, after which "---"
+    // and "+++" are removed and added lines rather than headers
+    let mut in_hunk = false;
+
+    for line in diff.lines() {
+        if let Some(paths) = line.strip_prefix("diff --git ") {
+            files.push(FileDiff {
+                path: header_path(paths),
+                change: FileChange::Modify,
+                additions: 0,
+                deletions: 0,
+                lines: Vec::new(),
+            });
+            in_hunk = false;
+        }
+        let Some(file) = files.last_mut() else {
+            continue;
+        };
+        file.lines.push(line.to_string());
+
+        if line.starts_with("@@ ") {
+            in_hunk = true;
+        } else if in_hunk {
+            match line.chars().next() {
+                Some('+') => file.additions += 1,
+                Some('-') => file.deletions += 1,
+                _ => {}
+            }
+        } else if line.starts_with("new file mode") {
+            file.change = FileChange::Create;
+        } else if line.starts_with("deleted file mode") {
+            file.change = FileChange::Delete;
+        } else if let Some(path) = line.strip_prefix("+++ b/") {
+            file.path = path.to_string();
+        } else if let Some(path) = line.strip_prefix("rename to ") {
+            file.path = path.to_string();
+        }
+    }
+    files
+}
+
+/// The new path from "a/src/x.ts b/src/x.ts" (or "/dev/null b/x" for
+/// untracked files), before the "+++" line confirms it.
+fn header_path(paths: &str) -> String {
+    match paths.rsplit_once(" b/") {
+        Some((_, path)) => path.to_string(),
+        None => paths.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PORCELAIN: &str = "\
+8f3c2a1d9e0b4c5a6f7e8d9c0b1a2f3e4d5c6b7a 42 42 1
+author Jane Doe
+author-mail <jane@example.com>
+author-time 1767312000
+author-tz +0000
+committer Jane Doe
+committer-mail <jane@example.com>
+committer-time 1767312000
+committer-tz +0000
+summary Guard against missing user
+filename src/user.ts
+\tif (!user) return null;
+";
+
+    #[test]
+    fn test_parse_porcelain() {
+        let blame = parse_porcelain(PORCELAIN).unwrap();
+        assert_eq!(
+            blame,
+            BlameInfo {
+                commit: "8f3c2a1".to_string(),
+                author: "Jane Doe".to_string(),
+                date: "2026-01-02".to_string(),
+                summary: "Guard against missing user".to_string(),
+            }
+        );
+        assert_eq!(blame.one_line(), "8f3c2a1 Jane Doe, 2026-01-02: Guard against missing user");
+    }
+
+    #[test]
+    fn test_parse_porcelain_uncommitted() {
+        let output = "0000000000000000000000000000000000000000 3 3 1\nauthor Not Committed Yet\n\tx\n";
+        assert_eq!(parse_porcelain(output).unwrap().summary, "Not committed yet");
+    }
+
+    const DIFF: &str = "\
+diff --git a/src/user.ts b/src/user.ts
+index 1111111..2222222 100644
+--- a/src/user.ts
++++ b/src/user.ts
+@@ -1,3 +1,4 @@
+ export function getUser(id) {
+-  return users[id].name;
++  const user = users[id];
++  return user?.name;
+--- a/comment
+ }
+diff --git a/test/user.test.ts b/test/user.test.ts
+new file mode 100644
+index 0000000..3333333
+--- /dev/null
++++ b/test/user.test.ts
+@@ -0,0 +1 @@
++test('missing user', () => {});
+diff --git a/old.ts b/old.ts
+deleted file mode 100644
+--- a/old.ts
++++ /dev/null
+@@ -1 +0,0 @@
+-gone
+";
+
+    #[test]
+    fn test_split_diff_by_file() {
+        let files = split_diff(DIFF);
+        let summary: Vec<_> = files
+            .iter()
+            .map(|f| (f.path.as_str(), f.change, f.additions, f.deletions))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                // "--- a/comment" inside the hunk is a removed line
+                ("src/user.ts", FileChange::Modify, 2, 2),
+                ("test/user.test.ts", FileChange::Create, 1, 0),
+                ("old.ts", FileChange::Delete, 0, 1),
+            ]
+        );
+        assert_eq!(files[0].lines.len(), 11);
+        assert!(files[1].lines[0].starts_with("diff --git"));
+    }
+
+    #[test]
+    fn test_split_diff_ignores_preamble() {
+        assert!(split_diff("").is_empty());
+        assert!(split_diff("warning: something\n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_log() {
+        let commits = parse_log("a1b2c3d\tGuard against missing user\n9f8e7d6\tAdd a test\tfor it\n");
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].hash, "a1b2c3d");
+        assert_eq!(commits[1].subject, "Add a test\tfor it");
+    }
+
+    #[test]
+    fn test_parse_status() {
+        let files = parse_status(" M src/app.ts\n?? notes.txt\nR  old.ts -> new.ts\n");
+        assert_eq!(files, vec!["src/app.ts", "notes.txt", "new.ts"]);
+        assert!(parse_status("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_worktree_list() {
+        let output = "\
+worktree /src/web
+HEAD 1111111111111111111111111111111111111111
+branch refs/heads/main
+
+worktree /src/web/.glass/worktrees/proj-123
+HEAD 2222222222222222222222222222222222222222
+branch refs/heads/glass/proj-123
+locked
+
+worktree /tmp/gone
+HEAD 3333333333333333333333333333333333333333
+detached
+prunable gitdir file points to non-existent location
+";
+        let worktrees = parse_worktree_list(output);
+        assert_eq!(worktrees.len(), 3);
+        assert_eq!(worktrees[1].branch.as_deref(), Some("glass/proj-123"));
+        assert!(worktrees[1].locked && !worktrees[1].prunable);
+        assert_eq!(worktrees[2].branch, None);
+        assert!(worktrees[2].prunable);
+    }
+
+    #[test]
+    fn test_resolve_in_repo_drops_build_prefixes() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(
+            resolve_in_repo(repo, "/app/src/git.rs"),
+            Some(PathBuf::from("src/git.rs"))
+        );
+        assert_eq!(
+            resolve_in_repo(repo, "webpack:///./src/git.rs"),
+            Some(PathBuf::from("src/git.rs"))
+        );
+        assert_eq!(
+            resolve_in_repo(repo, &format!("{}/src/git.rs", repo.display())),
+            Some(PathBuf::from("src/git.rs"))
+        );
+        assert_eq!(resolve_in_repo(repo, "/app/src/missing.rs"), None);
+    }
+}
@@ -4,9 +4,22 @@
 
 pub mod api;
 pub mod app;
+pub mod clipboard;
+pub mod config;
 pub mod escape;
+pub mod forge;
+pub mod git;
+pub mod locale;
 pub mod logging;
+pub mod notify;
+pub mod recording;
 pub mod screens;
 pub mod server;
+#[cfg(feature = "spellcheck")]
+pub mod spell;
+pub mod startup;
+pub mod terminal;
+pub mod transcript;
 pub mod ui;
 pub mod util;
+pub mod workspace;
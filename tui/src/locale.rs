@@ -0,0 +1,230 @@
+//! Locale conventions for numbers and dates: digit grouping, the decimal
+//! mark and the order of day, month and year.
+//!
+//! Only the conventions glass shows are covered, looked up from a language
+//! tag like "de-DE" or "en_US.UTF-8" rather than a full locale database.
+
+use std::env;
+use std::path::Path;
+
+use chrono::TimeZone;
+use serde::Deserialize;
+use time::{OffsetDateTime, UtcOffset};
+
+/// How counts are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NumberStyle {
+    /// 127345
+    #[default]
+    Plain,
+    /// 127,345 (with the locale's separator)
+    Grouped,
+    /// 127k
+    Compact,
+}
+
+/// Order of the parts of a date.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DateOrder {
+    /// 2026-02-01
+    #[default]
+    YearMonthDay,
+    /// 01.02.2026
+    DayMonthYear,
+    /// 02/01/2026
+    MonthDayYear,
+}
+
+/// Conventions for writing numbers and dates.
+///
+/// Configured as a language tag; "system" follows `LC_ALL`, `LC_NUMERIC`
+/// and `LANG`. The default writes dates ISO-style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(from = "String")]
+pub struct Locale {
+    pub thousands: char,
+    pub decimal: char,
+    pub dates: DateOrder,
+    /// Between day, month and year, unless dates go year first
+    pub date_separator: char,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self {
+            thousands: ',',
+            decimal: '.',
+            dates: DateOrder::YearMonthDay,
+            date_separator: '-',
+        }
+    }
+}
+
+impl From<String> for Locale {
+    fn from(tag: String) -> Self {
+        if tag.eq_ignore_ascii_case("system") {
+            Self::from_env()
+        } else {
+            Self::from_tag(&tag)
+        }
+    }
+}
+
+impl Locale {
+    /// Conventions of a language tag: "de-DE", "en_US.UTF-8", "fr".
+    /// Unknown languages get the default.
+    pub fn from_tag(tag: &str) -> Self {
+        let tag = tag.split(['.', '@']).next().unwrap_or_default().to_lowercase();
+        let mut parts = tag.split(['-', '_']);
+        let language = parts.next().unwrap_or_default();
+        let region = parts.next().unwrap_or_default();
+
+        let (thousands, decimal) = match (language, region) {
+            ("de", "ch") | ("it", "ch") => ('\'', '.'),
+            ("de" | "es" | "it" | "nl" | "pt" | "da" | "id" | "tr" | "el" | "ro", _) => ('.', ','),
+            ("fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "nb" | "nn" | "no" | "fi" | "uk" | "hu" | "bg", _) => {
+                (' ', ',')
+            }
+            ("en" | "ja" | "zh" | "ko" | "he" | "th", _) => (',', '.'),
+            _ => return Self::default(),
+        };
+        let (dates, date_separator) = match (language, region) {
+            ("en", "" | "us" | "ph") => (DateOrder::MonthDayYear, '/'),
+            ("ja" | "zh" | "ko" | "hu" | "sv" | "lt", _) | ("en", "ca") => (DateOrder::YearMonthDay, '-'),
+            ("de" | "ru" | "pl" | "cs" | "sk" | "nb" | "nn" | "no" | "fi" | "uk" | "da" | "tr" | "ro" | "bg", _) => {
+                (DateOrder::DayMonthYear, '.')
+            }
+            ("nl", _) => (DateOrder::DayMonthYear, '-'),
+            _ => (DateOrder::DayMonthYear, '/'),
+        };
+        Self {
+            thousands,
+            decimal,
+            dates,
+            date_separator,
+        }
+    }
+
+    /// Conventions of the system locale, or the default if none is set.
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|tag| !tag.is_empty() && tag != "C" && tag != "POSIX")
+            .map_or_else(Self::default, |tag| Self::from_tag(&tag))
+    }
+
+    /// A count in `style`: "127345", "127,345" or "127k".
+    pub fn count(&self, n: u64, style: NumberStyle) -> String {
+        match style {
+            NumberStyle::Plain => n.to_string(),
+            NumberStyle::Grouped => self.group(n),
+            NumberStyle::Compact => self.compact(n),
+        }
+    }
+
+    fn group(&self, n: u64) -> String {
+        let digits = n.to_string();
+        let mut grouped = String::with_capacity(digits.len() * 4 / 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push(self.thousands);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// One decimal below ten of a unit, whole units above: 950, 1.2k, 127k, 3.4M.
+    fn compact(&self, n: u64) -> String {
+        const UNITS: [(u64, &str); 3] = [(1_000_000_000, "B"), (1_000_000, "M"), (1_000, "k")];
+        let Some(&(size, suffix)) = UNITS.iter().find(|(size, _)| n >= *size) else {
+            return n.to_string();
+        };
+        if n < size * 10 {
+            // Truncated rather than rounded, so 9,990 isn't "10.0k"
+            let tenths = n / (size / 10);
+            let fraction = tenths % 10;
+            if fraction == 0 {
+                format!("{}{}", tenths / 10, suffix)
+            } else {
+                format!("{}{}{}{}", tenths / 10, self.decimal, fraction, suffix)
+            }
+        } else {
+            format!("{}{}", n / size, suffix)
+        }
+    }
+
+    /// Date and time, e.g. "2026-02-01 14:30" or "01.02.2026 14:30".
+    pub fn date_time(&self, at: &OffsetDateTime) -> String {
+        let s = self.date_separator;
+        let (year, month, day) = (at.year(), u8::from(at.month()), at.day());
+        let date = match self.dates {
+            DateOrder::YearMonthDay => format!("{year:04}-{month:02}-{day:02}"),
+            DateOrder::DayMonthYear => format!("{day:02}{s}{month:02}{s}{year:04}"),
+            DateOrder::MonthDayYear => format!("{month:02}{s}{day:02}{s}{year:04}"),
+        };
+        format!("{} {:02}:{:02}", date, at.hour(), at.minute())
+    }
+}
+
+/// The local timezone's offset from UTC at `at`, which differs on either
+/// side of a daylight saving change.
+///
+/// `time` can only read the current offset, and only while the process has
+/// a single thread, so the zone's rules come from chrono (which reads `TZ`
+/// or the system zone). That's UTC when there's no zone to be found; see
+/// `local_zone_known`.
+pub fn local_offset(at: OffsetDateTime) -> UtcOffset {
+    let seconds = chrono::Local
+        .timestamp_opt(at.unix_timestamp(), 0)
+        .single()
+        .map_or(0, |local| local.offset().local_minus_utc());
+    UtcOffset::from_whole_seconds(seconds).unwrap_or(UtcOffset::UTC)
+}
+
+/// Whether there's a local timezone to show times in, rather than UTC.
+pub fn local_zone_known() -> bool {
+    cfg!(windows) || env::var_os("TZ").is_some() || Path::new("/etc/localtime").exists()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::{Date, Month};
+
+    #[test]
+    fn test_counts() {
+        let en = Locale::from_tag("en_US.UTF-8");
+        let de = Locale::from_tag("de-DE");
+        assert_eq!(en.count(127345, NumberStyle::Plain), "127345");
+        assert_eq!(en.count(127345, NumberStyle::Grouped), "127,345");
+        assert_eq!(de.count(1234567, NumberStyle::Grouped), "1.234.567");
+        assert_eq!(en.count(999, NumberStyle::Grouped), "999");
+
+        assert_eq!(en.count(950, NumberStyle::Compact), "950");
+        assert_eq!(en.count(1000, NumberStyle::Compact), "1k");
+        assert_eq!(en.count(1234, NumberStyle::Compact), "1.2k");
+        assert_eq!(de.count(1234, NumberStyle::Compact), "1,2k");
+        assert_eq!(en.count(9999, NumberStyle::Compact), "9.9k");
+        assert_eq!(en.count(127345, NumberStyle::Compact), "127k");
+        assert_eq!(en.count(3_400_000, NumberStyle::Compact), "3.4M");
+    }
+
+    #[test]
+    fn test_date_order() {
+        let at = Date::from_calendar_date(2026, Month::February, 1)
+            .unwrap()
+            .with_hms(14, 30, 0)
+            .unwrap()
+            .assume_utc();
+        assert_eq!(Locale::default().date_time(&at), "2026-02-01 14:30");
+        assert_eq!(Locale::from_tag("en-US").date_time(&at), "02/01/2026 14:30");
+        assert_eq!(Locale::from_tag("en-GB").date_time(&at), "01/02/2026 14:30");
+        assert_eq!(Locale::from_tag("de").date_time(&at), "01.02.2026 14:30");
+        assert_eq!(Locale::from_tag("ja-JP").date_time(&at), "2026-02-01 14:30");
+        // Unknown languages keep the default
+        assert_eq!(Locale::from_tag("xx"), Locale::default());
+    }
+}
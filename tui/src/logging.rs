@@ -4,7 +4,8 @@
 
 use anyhow::Result;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
@@ -65,3 +66,98 @@ fn get_log_dir() -> Result<PathBuf> {
 pub fn log_file_path() -> Option<PathBuf> {
     get_log_dir().ok().map(|d| d.join("tui.log"))
 }
+
+/// Get the path to the server log file (it shares our state directory).
+pub fn server_log_path() -> Option<PathBuf> {
+    get_log_dir().ok().map(|d| d.join("server.log"))
+}
+
+/// Where the output of a server glass starts goes.
+pub fn server_output_path() -> Option<PathBuf> {
+    get_log_dir().ok().map(|d| d.join("server-output.log"))
+}
+
+/// Size at which the server output file is rotated to `server-output.log.1`
+/// when the next server starts.
+const SERVER_OUTPUT_MAX_BYTES: u64 = 1024 * 1024;
+
+/// Open the server output file to append a new server's output to,
+/// rotating it first if it's grown too big. Returns the file and the
+/// offset this server's output starts at.
+pub fn open_server_output() -> Option<(fs::File, u64)> {
+    let path = server_output_path()?;
+    fs::create_dir_all(path.parent()?).ok()?;
+    if fs::metadata(&path).is_ok_and(|m| m.len() > SERVER_OUTPUT_MAX_BYTES) {
+        let _ = fs::rename(&path, path.with_extension("log.1"));
+    }
+    let file = fs::OpenOptions::new().create(true).append(true).open(&path).ok()?;
+    let start = file.metadata().map(|m| m.len()).unwrap_or(0);
+    Some((file, start))
+}
+
+/// The last `max_lines` lines of server output written since `start`.
+pub fn server_output_since(start: u64, max_lines: usize) -> Vec<String> {
+    let Some(lines) = server_output_path().and_then(|path| read_tail(&path, start)) else {
+        return Vec::new();
+    };
+    let lines: Vec<String> = lines
+        .into_iter()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let skip = lines.len().saturating_sub(max_lines);
+    lines.into_iter().skip(skip).collect()
+}
+
+/// File that analysis output dropped from the activity pane is appended to.
+pub fn analysis_spill_path(issue: &str) -> Option<PathBuf> {
+    let name: String = issue
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    get_log_dir()
+        .ok()
+        .map(|d| d.join("analysis").join(format!("{}-{}.log", name, timestamp)))
+}
+
+/// How much of the end of the server log to scan for excerpts.
+const SERVER_LOG_TAIL_BYTES: u64 = 256 * 1024;
+
+/// Read the most recent server log lines that mention any of `needles`.
+///
+/// Returns an empty list when the log isn't readable, e.g. when talking to a
+/// server on another machine.
+pub fn recent_server_log_lines(needles: &[&str], max_lines: usize) -> Vec<String> {
+    let Some(lines) = server_log_path().and_then(|path| read_tail(&path, 0)) else {
+        return Vec::new();
+    };
+
+    let matching: Vec<String> = lines
+        .into_iter()
+        .filter(|line| needles.iter().any(|n| !n.is_empty() && line.contains(n)))
+        .collect();
+
+    let skip = matching.len().saturating_sub(max_lines);
+    matching.into_iter().skip(skip).collect()
+}
+
+/// The lines of `path` from `from` on, or of its last
+/// `SERVER_LOG_TAIL_BYTES` if that's less.
+fn read_tail(path: &Path, from: u64) -> Option<Vec<String>> {
+    let mut file = fs::File::open(path).ok()?;
+    let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let start = len.saturating_sub(SERVER_LOG_TAIL_BYTES).max(from.min(len));
+    let mut buf = Vec::new();
+    file.seek(SeekFrom::Start(start)).ok()?;
+    file.read_to_end(&mut buf).ok()?;
+
+    let text = String::from_utf8_lossy(&buf);
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+    if start > from && !lines.is_empty() {
+        // First line is likely cut in half
+        lines.remove(0);
+    }
+    Some(lines)
+}
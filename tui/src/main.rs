@@ -3,30 +3,25 @@
 //! Connects to the Glass server and provides a keyboard-driven interface
 //! for managing Sentry issues and agent workflows.
 
-mod api;
-mod app;
-mod escape;
-mod logging;
-mod screens;
-mod server;
-mod ui;
-mod util;
-
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use clap::Parser;
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
+use futures_util::StreamExt;
+use crossterm::event::{Event, EventStream, KeyCode, KeyEventKind};
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::io;
-use std::path::Path;
-use tracing::info;
-
-use app::{App, Screen};
-use screens::Action;
-use server::ServerProcess;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{info, warn};
+
+use glass_tui::api::{ApiClient, AuthToken, SessionRef};
+use glass_tui::app::{ActivityLog, App, BellMode, Screen};
+use glass_tui::screens::{self, Action};
+use glass_tui::server::{free_port, resolve_server, PortChoice, ServerLaunch, ServerProcess};
+use glass_tui::startup::StartupProfile;
+use glass_tui::clipboard::Clipboard;
+use glass_tui::terminal::TerminalModes;
+use glass_tui::config::{merge_servers, ServerConfig, TuiConfig};
+use glass_tui::{escape, locale, logging, recording, ui};
 
 /// Glass TUI - Issue orchestration interface
 #[derive(Parser, Debug)]
@@ -37,6 +32,11 @@ struct Args {
     #[arg(short, long, default_value = "http://localhost:7420")]
     server: String,
 
+    /// Port for the server, overriding the one in --server; "auto" starts
+    /// one on a free port
+    #[arg(long, value_name = "PORT|auto")]
+    port: Option<PortChoice>,
+
     /// Project path
     #[arg(default_value = ".")]
     project: String,
@@ -44,6 +44,73 @@ struct Args {
     /// Don't automatically start the server
     #[arg(long)]
     no_server: bool,
+
+    /// Bearer token for a server that requires one, overriding the
+    /// config's; set it in the environment to keep it out of `ps`
+    #[arg(long, env = "GLASS_TOKEN", hide_env_values = true, value_name = "TOKEN")]
+    token: Option<AuthToken>,
+
+    /// Refresh the issue list every N seconds (0 disables auto-refresh)
+    #[arg(long, env = "GLASS_REFRESH_INTERVAL", default_value_t = 0, value_name = "SECONDS")]
+    refresh_interval: u64,
+
+    /// Send a desktop notification when an analysis or implementation
+    /// finishes while you're elsewhere
+    #[arg(long, env = "GLASS_NOTIFY")]
+    notify: bool,
+
+    /// Walk through the core workflow on demo data (no server needed)
+    #[arg(long)]
+    tutorial: bool,
+
+    /// Play back an analysis recording exported with X (no server needed)
+    #[arg(long, value_name = "FILE")]
+    replay: Option<PathBuf>,
+
+    /// Signal when an analysis completes or fails
+    #[arg(long, env = "GLASS_BELL", value_enum, default_value_t = Bell::Off)]
+    bell: Bell,
+
+    /// Time each startup phase and print a summary on exit
+    #[arg(long)]
+    profile_startup: bool,
+
+    /// Another project's server to list issues from, as NAME=URL; repeat
+    /// for more (adds to the config's [[tui.servers]])
+    #[arg(long = "project-server", value_name = "NAME=URL", value_parser = ServerConfig::parse_arg)]
+    project_servers: Vec<ServerConfig>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Add a Sentry issue by URL or ID, even one the server's query doesn't
+    /// match, without opening the UI
+    Add {
+        /// Sentry issue URL, numeric ID or short ID
+        issue: String,
+    },
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum Bell {
+    Off,
+    /// Ring the terminal bell
+    Audible,
+    /// Flash the status bar
+    Visual,
+}
+
+impl From<Bell> for BellMode {
+    fn from(bell: Bell) -> Self {
+        match bell {
+            Bell::Off => BellMode::Off,
+            Bell::Audible => BellMode::Audible,
+            Bell::Visual => BellMode::Visual,
+        }
+    }
 }
 
 #[tokio::main]
@@ -53,6 +120,7 @@ async fn main() -> Result<()> {
 
     let args = Args::parse();
     info!(?args, "Starting Glass TUI");
+    let profile = StartupProfile::new(args.profile_startup);
 
     // Resolve project path to absolute
     let project_path = Path::new(&args.project)
@@ -60,87 +128,254 @@ async fn main() -> Result<()> {
         .unwrap_or_else(|_| Path::new(&args.project).to_path_buf());
     let project_path_str = project_path.to_string_lossy().to_string();
 
-    // Start server if needed (keep handle alive to maintain server process)
-    let _server = if args.no_server {
-        None
-    } else {
-        match ServerProcess::start(&project_path_str).await {
-            Ok(server) => server,
-            Err(e) => {
-                eprintln!("Failed to start server: {}", e);
-                eprintln!(
-                    "You can start it manually with: glass-server {}",
-                    project_path_str
-                );
-                return Err(e);
-            }
-        }
+    // The API and health checks go to whichever port the server starts on
+    let port = match args.port {
+        Some(PortChoice::Auto) => Some(free_port()?),
+        Some(PortChoice::Fixed(port)) => Some(port),
+        None => None,
+    };
+    let (server_url, port) = resolve_server(&args.server, port)?;
+    let launch = (!args.no_server).then(|| ServerLaunch {
+        project: project_path_str.clone(),
+        port,
+    });
+
+    let mut config = TuiConfig::load(&project_path);
+    let token = args.token.or(config.token.take());
+
+    if let Some(Command::Add { issue }) = &args.command {
+        return add_issue(&server_url, token, launch.as_ref(), issue).await;
+    }
+
+    // Load a recording before touching the terminal, so errors print plainly
+    let replay = match &args.replay {
+        Some(path) => Some(
+            recording::load(path).map_err(|e| anyhow!("Failed to load recording: {}", e))?,
+        ),
+        None => None,
     };
 
     // Setup terminal
-    enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let modes = TerminalModes::enter(&mut stdout)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app state
-    let mut app = App::new(args.server);
+    let mut app = App::new(server_url, token);
+    if args.refresh_interval > 0 {
+        app.set_auto_refresh(Duration::from_secs(args.refresh_interval));
+    }
+    app.state.desktop_notifications = args.notify;
+    app.state.keyboard_enhancement = modes.keyboard_enhancement();
+    app.state.bell_mode = args.bell.into();
+    app.state.startup = profile;
+
+    // Frame paths are shown relative to the project where possible
+    config
+        .paths
+        .strip_prefixes
+        .push(format!("{}/", project_path_str.trim_end_matches('/')));
+    app.state.path_config = config.paths;
+    app.state.ignore = config.ignore;
+    app.state.theme = config.theme;
+    app.state.apply_motion();
+    if !locale::local_zone_known() {
+        warn!("No local timezone found (set TZ); absolute times are in UTC");
+        if app.state.theme.absolute_times {
+            app.state.toasts.warning("No local timezone found (set TZ); times are in UTC");
+        }
+    }
+    app.state.analysis_lines = ActivityLog::new(config.analysis.max_lines);
+    app.state.implementation_lines = ActivityLog::new(config.analysis.max_lines);
+    app.state.spill_analysis_overflow = config.analysis.spill_overflow;
+    app.state.test_command = config.review.test_command;
+    app.state.watch_hours = config.review.watch_hours.filter(|&hours| hours > 0);
+    app.state.escape_command = config.escape.command;
+    app.state.escape_open_in = config.escape.open_in;
+    app.state.sentry = config.sentry;
+    app.state.multiplexer = escape::Multiplexer::detect();
+    #[cfg(feature = "spellcheck")]
+    {
+        app.state.dictionary = glass_tui::spell::Dictionary::from_config(&config.spellcheck);
+    }
+    app.state.project_path = project_path.clone();
+    merge_servers(&mut config.servers, args.project_servers);
+    if !config.servers.is_empty() {
+        let name = project_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| project_path_str.clone());
+        app.aggregate(&name, config.servers);
+    }
 
-    // Initial data fetch: load cached first (fast), then refresh from Sentry in background
-    app.load_cached().await;
-    app.start_refresh();
+    if let Some(recording) = replay {
+        app.start_playback(recording, true);
+    } else if args.tutorial {
+        app.start_tutorial(true);
+    } else {
+        // Saved filters apply to the first load
+        app.restore_workspace();
+        // The server starts (unless running or --no-server) and cached
+        // issues load, then refresh from Sentry, all behind the first frame
+        app.start_initial_load(launch);
+    }
 
     // Main loop
-    let res = run_app(&mut terminal, &mut app).await;
+    let res = run_app(&mut terminal, &mut app, modes).await;
 
     // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
+    modes.suspend(terminal.backend_mut())?;
     terminal.show_cursor()?;
 
     if let Err(err) = res {
         eprintln!("Error: {err:?}");
     }
+    if let Some(summary) = app.state.startup.summary() {
+        eprint!("{}", summary);
+    }
 
     Ok(())
 }
 
+/// `glass add`: add an issue from the command line, starting the server
+/// for the duration if it isn't running.
+async fn add_issue(
+    server_url: &str,
+    token: Option<AuthToken>,
+    launch: Option<&ServerLaunch>,
+    issue: &str,
+) -> Result<()> {
+    let _server = match launch {
+        Some(launch) if !ServerProcess::is_running(launch.port).await => {
+            let mut server = ServerProcess::spawn(launch)?;
+            if let Err(e) = server.wait_for_ready().await {
+                // Say what the server said on the way down
+                let output: String = server.output_tail(20).iter().map(|line| format!("\n  {}", line)).collect();
+                return Err(anyhow!("{}{}", e, output));
+            }
+            Some(server)
+        }
+        _ => None,
+    };
+
+    let detail = ApiClient::new(server_url.to_string(), token).import_issue(issue).await?;
+    println!(
+        "Added {}: {}",
+        detail.source.short_id.as_deref().unwrap_or(&detail.id),
+        detail.source.title.as_deref().unwrap_or("(untitled)")
+    );
+    Ok(())
+}
+
 async fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
+    modes: TerminalModes,
 ) -> Result<()> {
-    loop {
-        // Poll for background task completions
-        app.poll_background();
+    let mut events = EventStream::new();
+    let clipboard = Clipboard::detect();
 
-        // Update terminal size for text wrapping
-        let size = terminal.size()?;
-        app.set_terminal_size(size.width, size.height);
+    // Update terminal size for text wrapping
+    let size = terminal.size()?;
+    app.set_terminal_size(size.width, size.height);
 
-        // Draw UI
-        terminal.draw(|f| ui::draw(f, app))?;
+    // Only redraw when something changed, so an idle TUI costs no CPU
+    let mut dirty = true;
 
-        // Handle input (with timeout for async polling)
-        if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                // Only handle key press events (not release)
-                if key.kind != KeyEventKind::Press {
-                    continue;
-                }
+    // Drives countdowns and expiry (only polled when something is pending)
+    let mut ticker = tokio::time::interval(Duration::from_secs(1));
+
+    loop {
+        if app.take_bell() {
+            terminal.backend_mut().write_all(b"\x07")?;
+            terminal.backend_mut().flush()?;
+        }
+        if let Some(text) = app.take_clipboard() {
+            clipboard.copy(terminal.backend_mut(), &text).await?;
+        }
 
-                // Get action from input handler
-                let action = screens::handle_input(app, key);
+        if dirty {
+            terminal.draw(|f| ui::draw(f, app))?;
+            app.state.startup.record("first frame");
+            dirty = false;
+        }
 
-                // Execute the action
-                execute_action(terminal, app, action).await?;
+        // Wait for either terminal input or a background task result
+        let action = tokio::select! {
+            event = events.next() => match event {
+                Some(Ok(Event::Key(key))) => {
+                    // Only handle presses and repeats, and not a modifier on its own
+                    if key.kind == KeyEventKind::Release || matches!(key.code, KeyCode::Modifier(_)) {
+                        continue;
+                    }
+                    dirty = true;
+                    let action = screens::handle_input(app, key);
+                    app.accelerate(action)
+                }
+                Some(Ok(Event::Paste(text))) => {
+                    app.paste(&text);
+                    dirty = true;
+                    continue;
+                }
+                Some(Ok(Event::FocusGained)) => {
+                    app.state.terminal_focused = true;
+                    continue;
+                }
+                Some(Ok(Event::FocusLost)) => {
+                    app.state.terminal_focused = false;
+                    continue;
+                }
+                Some(Ok(Event::Resize(width, height))) => {
+                    app.set_terminal_size(width, height);
+                    dirty = true;
+                    continue;
+                }
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => return Err(e.into()),
+                None => return Ok(()),
+            },
+            Some(msg) = app.next_background() => {
+                app.handle_background(msg);
+                // Take what else is waiting before redrawing, within a budget
+                app.poll_background();
+                dirty = true;
+                continue;
+            }
+            _ = ticker.tick(), if app.state.needs_tick() => {
+                app.tick();
+                dirty = true;
+                continue;
             }
+        };
+
+        // A command runs as the action it names
+        let action = match action {
+            Action::SubmitCommand => app.submit_command(),
+            action => action,
+        };
+
+        if matches!(
+            action,
+            Action::InteractivePi
+                | Action::LaunchSession(_)
+                | Action::ResumeIn(None)
+                | Action::OpenFrameInEditor
+                | Action::EditCommitMessage
+                | Action::SquashCommits
+        ) {
+            // Stop reading terminal input while pi or the editor owns the terminal
+            drop(events);
+            execute_action(terminal, app, modes, action).await?;
+            events = EventStream::new();
+            terminal.clear()?;
+        } else {
+            execute_action(terminal, app, modes, action).await?;
         }
 
+        // Drain anything that arrived while the action was running
+        app.poll_background();
+
         // Check if app wants to quit
         if app.state.should_quit {
             return Ok(());
@@ -152,11 +387,27 @@ async fn run_app(
 async fn execute_action(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
+    modes: TerminalModes,
     action: Action,
 ) -> Result<()> {
+    // The tutorial simulates anything that would reach the server
+    if app.tutorial_action(&action) {
+        app.advance_tutorial();
+        return Ok(());
+    }
+    if !app.transition_allowed(&action) {
+        return Ok(());
+    }
+
     match action {
         Action::None => {}
-        Action::Quit => app.state.should_quit = true,
+        Action::Quit => app.request_quit(),
+        Action::ToggleHelp => app.state.show_help = !app.state.show_help,
+        Action::StartTutorial => app.start_tutorial(false),
+        Action::EndTutorial => app.end_tutorial(),
+        Action::EndPlayback => app.end_playback(),
+        Action::ToggleErrorDetails => app.toggle_error_details(),
+        Action::RetryFailedAction => app.retry_failed_action().await,
 
         // Navigation
         Action::MoveSelection(delta) => app.move_selection(delta),
@@ -165,6 +416,14 @@ async fn execute_action(
         Action::ScrollDetail(delta) => app.scroll_detail(delta),
         Action::ScrollAnalysis(delta) => app.scroll_analysis(delta),
         Action::ScrollProposal(delta) => app.scroll_proposal(delta),
+        Action::ScrollImplementation(delta) => app.scroll_implementation(delta),
+        Action::ScrollReview(delta) => app.scroll_review(delta),
+        Action::ScrollTestOutput(delta) => app.scroll_test_output(delta),
+        Action::SelectReviewFile(delta) => app.select_review_file(delta),
+        Action::ScrollError(delta) => app.scroll_error(delta),
+        Action::SelectErrorAction(delta) => app.select_error_action(delta),
+        Action::FocusFrame(delta) => app.focus_frame(delta),
+        Action::ZoomTimeline(delta) => app.zoom_timeline(delta),
 
         // Screen transitions
         Action::OpenSelected => {
@@ -172,6 +431,7 @@ async fn execute_action(
             app.load_cached_detail().await;
             app.start_detail_refresh();
         }
+        Action::TogglePeek => app.toggle_peek(),
         Action::BackToList => app.back_to_list(),
         Action::BackToDetail => {
             app.back_to_detail();
@@ -180,53 +440,205 @@ async fn execute_action(
         Action::BackFromProposal => app.back_from_proposal(),
         Action::OpenProposal => app.open_proposal(),
         Action::OpenAnalysis => app.state.screen = Screen::Analysis,
+        Action::OpenImplementation => app.open_implementation(),
+        Action::OpenReview => app.open_review(),
+        Action::OpenError => app.open_error(),
+        Action::BackFromError => app.back_from_error(),
+        Action::OpenWorktrees => app.open_worktrees(),
+        Action::BackFromWorktrees => app.back_from_worktrees(),
+        Action::ReloadWorktrees => app.reload_worktrees(),
+        Action::MoveWorktreeSelection(delta) => app.move_worktree_selection(delta),
+        Action::RemoveWorktree => app.remove_worktree(),
+        Action::PruneWorktrees => app.prune_worktrees(),
 
         // Data operations
         Action::Refresh => app.start_refresh(),
+        Action::OpenAddIssue => app.open_add_issue_prompt(),
+        Action::AddIssue(issue) => app.add_issue(issue),
+        Action::OpenNewIssue => app.open_new_issue(),
+        Action::OpenProjectSwitcher => app.open_project_switcher(),
+        Action::MoveProjectSelection(delta) => app.move_project_selection(delta),
+        Action::SwitchProject(project) => app.switch_project(project),
+        Action::CloseProjectSwitcher => app.close_project_switcher(),
+        Action::NewIssueInput(key) => app.edit_new_issue(key),
+        Action::MoveNewIssueFocus(delta) => app.move_new_issue_focus(delta),
+        Action::SubmitNewIssue => app.submit_new_issue(),
+        Action::CancelNewIssue => app.cancel_new_issue(),
         Action::RefreshDetail => app.start_detail_refresh(),
+        Action::ReloadReview => app.reload_review(),
 
         // Agent actions
         Action::AnalyzeFromList => app.analyze_issue_from_list().await,
-        Action::AnalyzeFromDetail => app.analyze_issue().await,
-        Action::ApproveProposal => {
-            app.approve_proposal().await;
-            app.back_from_proposal();
+        Action::AnalyzeFromDetail => app.analyze_issue(None, None).await,
+        Action::AnalyzeFromEvent => app.analyze_from_event().await,
+        Action::AnalyzeWithInstructions => app.open_analyze_prompt(),
+        Action::ApproveProposal => app.start_approval().await,
+        Action::RejectProposal => app.start_rejection().await,
+        Action::SubmitPrompt => app.submit_prompt().await,
+        Action::PromptInput(key) => app.edit_prompt(key),
+        Action::CancelPrompt => app.cancel_prompt(),
+        Action::OpenCommandLine => app.open_command_line(),
+        Action::CommandInput(key) => app.edit_command(key),
+        Action::CompleteCommand => app.complete_command(),
+        Action::CommandHistory(delta) => app.command_history(delta),
+        Action::CancelCommand => app.cancel_command(),
+        Action::SubmitCommand => {}
+        Action::FilterIssues(filters) => app.filter_issues(filters),
+        Action::SortIssues(sort) => app.sort_issues(sort),
+        Action::ToggleIgnored => app.toggle_ignored(),
+        Action::GroupIssues(group) => app.group_issues(group),
+        Action::ToggleGroup => app.toggle_group(),
+        Action::ToggleAllGroups => app.toggle_all_groups(),
+        Action::OpenIssue(id) => {
+            if app.select_issue(&id) {
+                app.open_selected();
+                app.load_cached_detail().await;
+                app.start_detail_refresh();
+            }
         }
-        Action::RejectProposal => {
-            app.reject_proposal().await;
-            app.back_from_proposal();
+        Action::SetOption(name, value) => app.set_option(&name, &value),
+        Action::CompleteReview => app.finish_review(),
+        Action::ConfirmCompleteReview => app.confirm_finish_review(false).await,
+        Action::DiscardAndCompleteReview => app.confirm_finish_review(true).await,
+        Action::CancelCompleteReview => app.cancel_finish_review(),
+        Action::EditCommitMessage => edit_commits(terminal, app, modes, false)?,
+        Action::SquashCommits => edit_commits(terminal, app, modes, true)?,
+        Action::RunWorktreeTests => app.run_worktree_tests(),
+        Action::CancelWorktreeTests => app.cancel_worktree_tests(),
+        Action::OpenPullRequest => app.open_pull_request(),
+        Action::CopyPullRequestUrl => app.copy_pull_request_url(),
+        Action::ConfirmMerge => app.confirm_merge().await,
+        Action::RetryError => {
+            app.retry_error().await;
+            if *app.screen() == Screen::Error {
+                app.back_from_error();
+            }
         }
-        Action::CompleteReview => app.complete_review().await,
-        Action::RetryError => app.retry_error().await,
+
+        Action::ToggleMergeMark => app.toggle_merge_mark(),
+        Action::TogglePin => app.toggle_pin(),
+        Action::OpenMergeDialog => app.open_merge_dialog(),
+        Action::MoveMergePrimary(delta) => app.move_merge_primary(delta),
+        Action::CancelMerge => app.cancel_merge(),
+        Action::OpenReplay => app.open_replay(),
+        Action::OpenFrameInEditor => open_frame_in_editor(terminal, app, modes)?,
+        Action::UnfocusFrame => app.unfocus_frame(),
+        Action::UnfoldFrames => app.unfold_frames(),
+        Action::OpenBreadcrumbs => app.open_breadcrumbs(),
+        Action::MoveBreadcrumbSelection(delta) => app.move_breadcrumb_selection(delta),
+        Action::ToggleBreadcrumbData => app.toggle_breadcrumb_data(),
+        Action::CycleBreadcrumbCategory(delta) => app.cycle_breadcrumb_category(delta),
+        Action::OpenRawJson => app.open_raw_json(),
+        Action::MoveJsonSelection(delta) => app.move_json_selection(delta),
+        Action::FoldJson(fold) => app.fold_json(fold),
+        Action::ToggleFrameContext => app.toggle_frame_context(),
+        Action::FocusSection(delta) => app.focus_section(delta),
+        Action::ToggleSection => app.toggle_section(),
+        Action::UnfocusSection => app.unfocus_section(),
+        Action::OpenYankMenu => app.open_yank_menu(),
+        Action::MoveYankSelection(delta) => app.move_yank_selection(delta),
+        Action::Yank(item) => app.yank(item),
+        Action::CloseYankMenu => app.close_yank_menu(),
+        Action::BlameFrame => app.blame_frame(),
+        Action::ToggleTimeline => app.toggle_timeline(),
+        Action::ToggleTagBreakdown => app.toggle_tag_breakdown(),
+        Action::ToggleIssueChanges => app.toggle_issue_changes(),
+        Action::ToggleEventPicker => app.toggle_event_picker(),
+        Action::MoveEventSelection(delta) => app.move_event_selection(delta),
+        Action::ViewPickedEvent => app.view_picked_event(),
+        Action::BrowseEvents(target) => app.browse_events(target),
+        Action::ScrollTagBreakdown(delta) => app.scroll_tag_breakdown(delta),
+        Action::ToggleDiffLineNumbers => app.toggle_diff_line_numbers(),
+        Action::ToggleWrap => app.toggle_wrap(),
+        Action::ScrollColumns(delta) => app.scroll_columns(delta),
+        Action::TogglePlanPreview => app.toggle_plan_preview(),
+        Action::ScrollPlanPreview(delta) => app.scroll_plan_preview(delta),
+        Action::ToggleCritique => app.toggle_critique(),
+        Action::ScrollCritique(delta) => app.scroll_critique(delta),
+        Action::ExportTranscript => app.export_transcript(),
+        Action::ExportRecording => app.export_recording(),
 
         // Interactive Pi escape hatch
         Action::InteractivePi => {
-            if let Some(session_path) = app.get_session_path().await {
-                // Restore terminal before exec
-                disable_raw_mode()?;
-                execute!(
-                    terminal.backend_mut(),
-                    LeaveAlternateScreen,
-                    DisableMouseCapture
-                )?;
-                terminal.show_cursor()?;
-
-                // Run pi interactively
-                escape::run_pi_interactive(&session_path)?;
-
-                // Restore TUI
-                enable_raw_mode()?;
-                execute!(
-                    terminal.backend_mut(),
-                    EnterAlternateScreen,
-                    EnableMouseCapture
-                )?;
-
-                // Refresh state after returning
-                app.refresh_current_issue().await;
+            let session = app.get_session().await;
+            if let Some(session) = session.and_then(|session| app.resume_session(session)) {
+                run_agent(terminal, app, modes, &session).await?;
+            }
+        }
+        Action::ToggleSessionSelection => app.toggle_session_selection(),
+        Action::CancelSessionPicker => app.cancel_session_picker(),
+        Action::LaunchSession(kind) => {
+            let session = app.pick_session(kind);
+            if let Some(session) = session.and_then(|session| app.resume_session(session)) {
+                run_agent(terminal, app, modes, &session).await?;
             }
         }
+        Action::ResumeIn(placement) => {
+            if let Some(session) = app.resume_in(placement) {
+                run_agent(terminal, app, modes, &session).await?;
+            }
+        }
+        Action::CancelPlacementPrompt => app.cancel_placement_prompt(),
     }
+    app.advance_tutorial();
+    app.follow_preview();
 
     Ok(())
 }
+
+/// Hand the terminal to the configured agent to resume a session.
+async fn run_agent(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+    modes: TerminalModes,
+    session: &SessionRef,
+) -> Result<()> {
+    // Restore terminal before exec
+    modes.suspend(terminal.backend_mut())?;
+    terminal.show_cursor()?;
+
+    // Run the agent interactively
+    escape::run_agent_interactive(&app.state.escape_command, session)?;
+
+    // Restore TUI
+    modes.resume(terminal.backend_mut())?;
+
+    // Refresh state after returning
+    app.refresh_current_issue().await;
+    Ok(())
+}
+
+/// Hand the terminal to the editor, opened at the focused frame's line.
+fn open_frame_in_editor(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+    modes: TerminalModes,
+) -> Result<()> {
+    let Some((file, line)) = app.frame_to_edit() else {
+        return Ok(());
+    };
+    modes.suspend(terminal.backend_mut())?;
+    terminal.show_cursor()?;
+    let result = escape::open_in_editor(&file, line);
+    modes.resume(terminal.backend_mut())?;
+    app.editor_closed(result);
+    Ok(())
+}
+
+/// Hand the terminal to the editor to reword or squash a fix's commits.
+fn edit_commits(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+    modes: TerminalModes,
+    squash: bool,
+) -> Result<()> {
+    let Some((worktree, edit)) = app.commit_edit(squash) else {
+        return Ok(());
+    };
+    modes.suspend(terminal.backend_mut())?;
+    terminal.show_cursor()?;
+    let result = escape::edit_commits(&worktree, &edit);
+    modes.resume(terminal.backend_mut())?;
+    app.commits_edited(result);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! Desktop notifications through the platform's notifier command.
+
+use std::process::Stdio;
+use tokio::process::Command;
+use tracing::debug;
+
+/// Notification title for a status change, if it marks the end of
+/// long-running agent work.
+pub fn transition_title(from: &str, to: &str) -> Option<&'static str> {
+    match (from, to) {
+        ("analyzing", "pending_approval") => Some("Analysis complete"),
+        ("analyzing", "error") => Some("Analysis failed"),
+        ("in_progress", "pending_review") => Some("Implementation complete"),
+        ("in_progress", "error") => Some("Implementation failed"),
+        _ => None,
+    }
+}
+
+/// Show a desktop notification without blocking. Failures are only logged,
+/// since a missing notifier shouldn't interrupt the TUI.
+pub fn send(title: &str, body: &str) {
+    let mut cmd = if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification {} with title {}",
+            applescript_string(body),
+            applescript_string(title)
+        );
+        let mut cmd = Command::new("osascript");
+        cmd.arg("-e").arg(script);
+        cmd
+    } else {
+        let mut cmd = Command::new("notify-send");
+        cmd.arg("--app-name=Glass").arg(title).arg(body);
+        cmd
+    };
+
+    cmd.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
+    match cmd.spawn() {
+        Ok(mut child) => {
+            // Reap the notifier so it doesn't linger as a zombie
+            tokio::spawn(async move {
+                let _ = child.wait().await;
+            });
+        }
+        Err(e) => debug!(error = %e, "Failed to send desktop notification"),
+    }
+}
+
+/// Quote a string as an AppleScript literal.
+fn applescript_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_finished_work_is_notified() {
+        assert_eq!(transition_title("analyzing", "pending_approval"), Some("Analysis complete"));
+        assert_eq!(transition_title("in_progress", "error"), Some("Implementation failed"));
+        assert_eq!(transition_title("pending", "analyzing"), None);
+        assert_eq!(transition_title("pending_approval", "in_progress"), None);
+    }
+
+    #[test]
+    fn test_escapes_applescript_strings() {
+        assert_eq!(applescript_string(r#"say "hi" \o/"#), r#""say \"hi\" \\o/""#);
+    }
+}
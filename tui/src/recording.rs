@@ -0,0 +1,238 @@
+//! Analysis recordings.
+//!
+//! Exports the activity pane of an analysis, with the time each line
+//! appeared, as an asciicast v2 file under
+//! `<project>/.glass/recordings/<issue>-<timestamp>.cast`. The file plays in
+//! `asciinema play` and on asciinema.org, and `glass --replay` loads it back
+//! into the analysis screen.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use time::OffsetDateTime;
+
+use crate::app::{ActivityLine, ActivityStyle};
+use crate::transcript::file_name;
+
+/// Directory under the project checkout that recordings are written to.
+pub const RECORDING_DIR: &str = ".glass/recordings";
+
+/// Icons the activity pane uses, so loaded lines can point at them.
+const ICONS: [&str; 7] = ["◐", "🔧", "✓", "✗", "↻", "▶", "  "];
+
+/// What to put in a recording.
+pub struct Recording<'a> {
+    /// Issue short ID, e.g. "PROJ-123"
+    pub issue: &'a str,
+    pub title: &'a str,
+    /// Activity lines with when they appeared
+    pub frames: &'a [(Duration, ActivityLine)],
+    /// Terminal size the cast is declared at
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Recording<'_> {
+    /// Render as asciicast v2: a header line, then one output event per line.
+    pub fn to_cast(&self, recorded_at: OffsetDateTime) -> String {
+        let header = json!({
+            "version": 2,
+            "width": self.width,
+            "height": self.height,
+            "timestamp": recorded_at.unix_timestamp(),
+            "title": if self.title.is_empty() {
+                self.issue.to_string()
+            } else {
+                format!("{} — {}", self.issue, self.title)
+            },
+        });
+        let mut out = header.to_string();
+        out.push('\n');
+
+        for (offset, line) in self.frames {
+            let text = match sgr(line.style) {
+                Some(code) => format!("\x1b[{}m{} {}\x1b[0m\r\n", code, line.icon, line.text),
+                None => format!("{} {}\r\n", line.icon, line.text),
+            };
+            let event = json!([offset.as_secs_f64(), "o", text]);
+            out.push_str(&event.to_string());
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Write the recording under `project`, returning the file's path.
+    pub fn write(&self, project: &Path) -> io::Result<PathBuf> {
+        let now = OffsetDateTime::now_utc();
+        let dir = project.join(RECORDING_DIR);
+        fs::create_dir_all(&dir)?;
+
+        let path = dir.join(file_name(self.issue, now, "cast"));
+        fs::write(&path, self.to_cast(now))?;
+        Ok(path)
+    }
+}
+
+/// A recording read back from disk.
+#[derive(Debug)]
+pub struct LoadedRecording {
+    pub title: String,
+    pub frames: Vec<(Duration, ActivityLine)>,
+}
+
+/// Read a recording written by [`Recording::write`].
+pub fn load(path: &Path) -> Result<LoadedRecording, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse_cast(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Parse asciicast v2 text. Output that didn't come from Glass still loads,
+/// one activity line per output line, with escape sequences dropped.
+pub fn parse_cast(text: &str) -> Result<LoadedRecording, String> {
+    let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+    let header: Value = lines
+        .next()
+        .ok_or("empty recording")
+        .and_then(|l| serde_json::from_str(l).map_err(|_| "invalid header"))?;
+    if header["version"].as_u64() != Some(2) {
+        return Err("not an asciicast v2 recording".to_string());
+    }
+    let title = header["title"].as_str().unwrap_or("Recording").to_string();
+
+    let mut frames = Vec::new();
+    for (number, line) in lines.enumerate() {
+        let event: Value = serde_json::from_str(line)
+            .map_err(|_| format!("invalid event on line {}", number + 2))?;
+        let (Some(time), Some("o"), Some(data)) =
+            (event[0].as_f64(), event[1].as_str(), event[2].as_str())
+        else {
+            continue;
+        };
+        let offset = Duration::from_secs_f64(time.max(0.0));
+        for output in data.split("\r\n").filter(|l| !l.is_empty()) {
+            frames.push((offset, activity_line(output)));
+        }
+    }
+    Ok(LoadedRecording { title, frames })
+}
+
+/// SGR color code for a style; `None` for plain text.
+fn sgr(style: ActivityStyle) -> Option<u8> {
+    match style {
+        ActivityStyle::Normal => None,
+        ActivityStyle::Dimmed => Some(90),
+        ActivityStyle::Tool => Some(36),
+        ActivityStyle::Thinking => Some(33),
+        ActivityStyle::Error => Some(31),
+        ActivityStyle::Success => Some(32),
+        ActivityStyle::Code => Some(37),
+    }
+}
+
+/// Rebuild an activity line from one line of output.
+fn activity_line(output: &str) -> ActivityLine {
+    let mut style = ActivityStyle::Normal;
+    let mut text = String::new();
+    let mut rest = output;
+    while let Some(start) = rest.find("\x1b[") {
+        text.push_str(&rest[..start]);
+        let sequence = &rest[start + 2..];
+        let Some(end) = sequence.find(|c: char| c.is_ascii_alphabetic()) else {
+            rest = "";
+            break;
+        };
+        if text.is_empty() && &sequence[end..=end] == "m" {
+            style = [
+                ActivityStyle::Dimmed,
+                ActivityStyle::Tool,
+                ActivityStyle::Thinking,
+                ActivityStyle::Error,
+                ActivityStyle::Success,
+                ActivityStyle::Code,
+            ]
+            .into_iter()
+            .find(|s| sgr(*s).map(|c| c.to_string()).as_deref() == Some(&sequence[..end]))
+            .unwrap_or(style);
+        }
+        rest = &sequence[end + 1..];
+    }
+    text.push_str(rest);
+
+    for icon in ICONS {
+        if let Some(body) = text.strip_prefix(icon).and_then(|t| t.strip_prefix(' ')) {
+            return ActivityLine {
+                icon,
+                text: body.to_string(),
+                style,
+            };
+        }
+    }
+    ActivityLine {
+        icon: "  ",
+        text,
+        style,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(icon: &'static str, text: &str, style: ActivityStyle) -> ActivityLine {
+        ActivityLine {
+            icon,
+            text: text.to_string(),
+            style,
+        }
+    }
+
+    #[test]
+    fn test_cast_round_trips() {
+        let frames = vec![
+            (Duration::ZERO, line("◐", "Thinking...", ActivityStyle::Thinking)),
+            (Duration::from_millis(1500), line("🔧", "read src/user.ts", ActivityStyle::Tool)),
+            (Duration::from_millis(1500), line("  ", "  let x = 1;", ActivityStyle::Code)),
+            (Duration::from_secs(4), line("  ", "The bug is here.", ActivityStyle::Normal)),
+        ];
+        let recording = Recording {
+            issue: "PROJ-123",
+            title: "TypeError",
+            frames: &frames,
+            width: 100,
+            height: 30,
+        };
+        let cast = recording.to_cast(OffsetDateTime::UNIX_EPOCH);
+        assert!(cast.lines().next().unwrap().contains(r#""version":2"#));
+        assert_eq!(cast.lines().count(), 5);
+
+        let loaded = parse_cast(&cast).unwrap();
+        assert_eq!(loaded.title, "PROJ-123 — TypeError");
+        assert_eq!(loaded.frames.len(), 4);
+        for ((offset, original), (loaded_offset, loaded)) in frames.iter().zip(&loaded.frames) {
+            assert_eq!(offset, loaded_offset);
+            assert_eq!(original.icon, loaded.icon);
+            assert_eq!(original.text, loaded.text);
+            assert_eq!(original.style, loaded.style);
+        }
+    }
+
+    #[test]
+    fn test_foreign_output_loads_as_plain_lines() {
+        let cast = "{\"version\":2,\"width\":80,\"height\":24}\n[0.5,\"o\",\"$ ls\\r\\n\\u001b[1mbold\\u001b[0m\\r\\n\"]\n[1.0,\"i\",\"x\"]\n";
+        let loaded = parse_cast(cast).unwrap();
+        assert_eq!(loaded.title, "Recording");
+        let texts: Vec<&str> = loaded.frames.iter().map(|(_, l)| l.text.as_str()).collect();
+        assert_eq!(texts, vec!["$ ls", "bold"]);
+        assert_eq!(loaded.frames[0].0, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_rejects_other_formats() {
+        assert!(parse_cast("").is_err());
+        assert!(parse_cast("{\"version\":1}\n").is_err());
+        assert!(parse_cast("# Analysis transcript\n").is_err());
+    }
+}
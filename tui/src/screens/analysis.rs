@@ -1,7 +1,8 @@
 //! Analysis screen input handling.
 
 use crossterm::event::{KeyCode, KeyEvent};
-use super::Action;
+use crate::app::App;
+use super::{wrap_hints, Action, KeyHint, SIDEWAYS_STEP};
 
 /// Handle input on the analysis screen.
 pub fn handle_analysis_input(key: KeyEvent) -> Action {
@@ -9,6 +10,31 @@ pub fn handle_analysis_input(key: KeyEvent) -> Action {
         KeyCode::Char('q') | KeyCode::Esc => Action::BackToDetail,
         KeyCode::Char('j') | KeyCode::Down => Action::ScrollAnalysis(1),
         KeyCode::Char('k') | KeyCode::Up => Action::ScrollAnalysis(-1),
+        KeyCode::Char('S') => Action::ExportTranscript,
+        KeyCode::Char('X') => Action::ExportRecording,
+        KeyCode::Char('w') => Action::ToggleWrap,
+        KeyCode::Char('h') | KeyCode::Left => Action::ScrollColumns(-SIDEWAYS_STEP),
+        KeyCode::Char('l') | KeyCode::Right => Action::ScrollColumns(SIDEWAYS_STEP),
         _ => Action::None,
     }
 }
+
+/// Keybinding hints for the analysis screen.
+pub fn analysis_hints(app: &App) -> Vec<KeyHint> {
+    let back = if app.state.playback.is_some() {
+        "end replay"
+    } else if app.state.is_streaming_analysis {
+        "back"
+    } else {
+        "back to detail"
+    };
+
+    let mut hints = vec![
+        KeyHint::new("q/Esc", back, 0),
+        KeyHint::new("↑↓/C-d/u", "scroll", 2),
+        KeyHint::new("S", "save transcript", 1),
+        KeyHint::new("X", "export replay", 1),
+    ];
+    hints.extend(wrap_hints(&app.state.analysis_scroll));
+    hints
+}
@@ -0,0 +1,35 @@
+//! Breadcrumbs screen input handling.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::app::App;
+use super::{Action, KeyHint};
+
+/// Handle input on the breadcrumbs screen.
+pub fn handle_breadcrumbs_input(key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('B') => Action::BackToDetail,
+        KeyCode::Char('j') | KeyCode::Down => Action::MoveBreadcrumbSelection(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::MoveBreadcrumbSelection(-1),
+        KeyCode::Char('g') | KeyCode::Home => Action::MoveBreadcrumbSelection(i32::MIN),
+        KeyCode::Char('G') | KeyCode::End => Action::MoveBreadcrumbSelection(i32::MAX),
+        KeyCode::Enter | KeyCode::Char(' ') => Action::ToggleBreadcrumbData,
+        KeyCode::Char('f') | KeyCode::Tab => Action::CycleBreadcrumbCategory(1),
+        KeyCode::Char('F') | KeyCode::BackTab => Action::CycleBreadcrumbCategory(-1),
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints for the breadcrumbs screen.
+pub fn breadcrumbs_hints(app: &App) -> Vec<KeyHint> {
+    let filter = match &app.state.breadcrumb_view.category {
+        Some(_) => "next category",
+        None => "filter by category",
+    };
+    vec![
+        KeyHint::new("↑↓/jk/C-d/u", "select", 2),
+        KeyHint::new("Enter", "show data", 0),
+        KeyHint::new("f/F", filter, 1),
+        KeyHint::new("g/G", "oldest/newest", 3),
+        KeyHint::new("q/Esc", "back", 0),
+    ]
+}
@@ -0,0 +1,298 @@
+//! The `:` command line: commands parsed into actions, with tab completion.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::api::Issue;
+use crate::app::{FilterField, GroupBy, IssueFilter, IssueSort, SortKey, STATUSES};
+use super::{Action, KeyHint};
+
+/// Commands, as (name, usage).
+pub const COMMANDS: [(&str, &str); 12] = [
+    ("filter", "filter [status|project|source|env|release|text=VALUE]... (none clears)"),
+    ("sort", "sort [seen|first|events|users|status|title|id] [asc|desc]"),
+    ("group", "group [culprit|type] (none lists issues one by one)"),
+    ("open", "open ISSUE"),
+    ("add", "add SENTRY_URL|ID"),
+    ("new", "new"),
+    ("project", "project [NAME|all] (none opens the switcher)"),
+    ("set", "set OPTION=VALUE"),
+    ("refresh", "refresh"),
+    ("worktrees", "worktrees"),
+    ("help", "help"),
+    ("quit", "quit (or q)"),
+];
+
+/// Options `:set` can change, with their values.
+pub const OPTIONS: [(&str, &[&str]); 10] = [
+    ("theme", &["dark", "light"]),
+    ("palette", &["default", "color-blind", "light"]),
+    ("icons", &["unicode", "ascii"]),
+    ("badges", &["on", "off"]),
+    ("summary", &["on", "off"]),
+    ("motion", &["full", "reduced"]),
+    ("linenumbers", &["on", "off"]),
+    ("times", &["relative", "absolute"]),
+    ("numbers", &["plain", "grouped", "compact"]),
+    ("preview", &["on", "off"]),
+];
+
+/// Handle input while the command line is open.
+pub fn handle_command_input(key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Enter => Action::SubmitCommand,
+        KeyCode::Esc => Action::CancelCommand,
+        KeyCode::Tab => Action::CompleteCommand,
+        KeyCode::Up => Action::CommandHistory(-1),
+        KeyCode::Down => Action::CommandHistory(1),
+        _ => Action::CommandInput(key),
+    }
+}
+
+/// Keybinding hints while the command line is open.
+pub fn command_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("Enter", "run", 0),
+        KeyHint::new("Tab", "complete", 1),
+        KeyHint::new("↑↓", "history", 2),
+        KeyHint::new("Esc", "cancel", 0),
+    ]
+}
+
+/// Parse a command line (without the `:`) into the action it runs.
+pub fn parse_command(line: &str) -> Result<Action, String> {
+    let mut words = line.split_whitespace();
+    let Some(command) = words.next() else {
+        return Ok(Action::None);
+    };
+    let args: Vec<&str> = words.collect();
+
+    match command {
+        "q" | "quit" => Ok(Action::Quit),
+        "refresh" => Ok(Action::Refresh),
+        "worktrees" => Ok(Action::OpenWorktrees),
+        "help" => Ok(Action::ToggleHelp),
+        "new" => Ok(Action::OpenNewIssue),
+        "filter" => args
+            .iter()
+            .map(|arg| parse_filter(arg))
+            .collect::<Result<_, _>>()
+            .map(Action::FilterIssues),
+        "sort" => parse_sort(&args).map(Action::SortIssues),
+        "group" => match args.as_slice() {
+            [] | ["none"] => Ok(Action::GroupIssues(None)),
+            [name] => GroupBy::from_name(name).map(|group| Action::GroupIssues(Some(group))).ok_or_else(|| {
+                let names: Vec<_> = GroupBy::ALL.iter().map(|g| g.name()).collect();
+                format!("Can't group by {} (try {})", name, names.join(", "))
+            }),
+            _ => Err("Usage: :group [culprit|type]".to_string()),
+        },
+        "open" => match args.as_slice() {
+            [issue] => Ok(Action::OpenIssue(issue.to_string())),
+            _ => Err("Usage: :open ISSUE".to_string()),
+        },
+        "add" => match args.as_slice() {
+            [issue] => Ok(Action::AddIssue(issue.to_string())),
+            _ => Err("Usage: :add SENTRY_URL|ID".to_string()),
+        },
+        "project" => match args.as_slice() {
+            [] => Ok(Action::OpenProjectSwitcher),
+            ["all"] => Ok(Action::SwitchProject(None)),
+            [name] => Ok(Action::SwitchProject(Some(name.to_string()))),
+            _ => Err("Usage: :project [NAME|all]".to_string()),
+        },
+        "set" => match args.as_slice() {
+            [setting] => parse_setting(setting),
+            _ => Err("Usage: :set OPTION=VALUE".to_string()),
+        },
+        _ => Err(format!("Unknown command: {}", command)),
+    }
+}
+
+/// `field=value`, or a bare word to search titles for.
+fn parse_filter(arg: &str) -> Result<IssueFilter, String> {
+    let Some((name, value)) = arg.split_once('=') else {
+        return Ok(IssueFilter {
+            field: FilterField::Text,
+            value: arg.to_string(),
+        });
+    };
+    let field = FilterField::from_name(name).ok_or_else(|| {
+        let names: Vec<_> = FilterField::ALL.iter().map(|f| f.name()).collect();
+        format!("Can't filter on {} (try {})", name, names.join(", "))
+    })?;
+    if value.is_empty() {
+        return Err(format!("Missing value for {}", name));
+    }
+    Ok(IssueFilter {
+        field,
+        value: value.to_string(),
+    })
+}
+
+/// `[key] [asc|desc]`; no key (or `none`) goes back to the server's order.
+fn parse_sort(args: &[&str]) -> Result<Option<IssueSort>, String> {
+    let (name, direction) = match args {
+        [] | ["none"] => return Ok(None),
+        [name] => (*name, None),
+        [name, direction] => (*name, Some(*direction)),
+        _ => return Err("Usage: :sort KEY [asc|desc]".to_string()),
+    };
+    let key = SortKey::from_name(name).ok_or_else(|| {
+        let names: Vec<_> = SortKey::ALL.iter().map(|k| k.name()).collect();
+        format!("Can't sort by {} (try {})", name, names.join(", "))
+    })?;
+    let descending = match direction {
+        None => key.descending_by_default(),
+        Some("asc") => false,
+        Some("desc") => true,
+        Some(other) => return Err(format!("Sort direction is asc or desc, not {}", other)),
+    };
+    Ok(Some(IssueSort { key, descending }))
+}
+
+fn parse_setting(setting: &str) -> Result<Action, String> {
+    let (name, value) = setting.split_once('=').unwrap_or((setting, "on"));
+    let Some((_, values)) = OPTIONS.iter().find(|(option, _)| *option == name) else {
+        return Err(format!("Unknown option: {}", name));
+    };
+    if !values.contains(&value) {
+        return Err(format!("{} is one of {}", name, values.join(", ")));
+    }
+    Ok(Action::SetOption(name.to_string(), value.to_string()))
+}
+
+/// Completions of the command line's last word, as whole lines.
+pub fn complete_command(line: &str, issues: &[Issue], projects: &[String]) -> Vec<String> {
+    let (head, word) = match line.rfind(' ') {
+        Some(space) => line.split_at(space + 1),
+        None => ("", line),
+    };
+    let mut previous = head.split_whitespace();
+    let command = previous.next();
+
+    let candidates: Vec<String> = match (command, previous.count()) {
+        (None, _) => COMMANDS.iter().map(|(name, _)| name.to_string()).collect(),
+        (Some("filter"), _) => match word.split_once('=') {
+            None => FilterField::ALL.iter().map(|f| format!("{}=", f.name())).collect(),
+            Some((name, _)) => filter_values(name, issues)
+                .into_iter()
+                .map(|value| format!("{}={}", name, value))
+                .collect(),
+        },
+        (Some("sort"), 0) => SortKey::ALL.iter().map(|k| k.name().to_string()).collect(),
+        (Some("sort"), 1) => vec!["asc".to_string(), "desc".to_string()],
+        (Some("group"), 0) => GroupBy::ALL.iter().map(|g| g.name().to_string()).collect(),
+        (Some("open"), 0) => issues.iter().map(|i| i.short_id.clone()).collect(),
+        (Some("project"), 0) if !projects.is_empty() => {
+            std::iter::once("all".to_string()).chain(projects.iter().cloned()).collect()
+        }
+        (Some("set"), 0) => match word.split_once('=') {
+            None => OPTIONS.iter().map(|(name, _)| format!("{}=", name)).collect(),
+            Some((name, _)) => OPTIONS
+                .iter()
+                .filter(|(option, _)| *option == name)
+                .flat_map(|(_, values)| values.iter().map(move |v| format!("{}={}", name, v)))
+                .collect(),
+        },
+        _ => Vec::new(),
+    };
+
+    let word = word.to_lowercase();
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.to_lowercase().starts_with(&word))
+        .map(|candidate| format!("{}{}", head, candidate))
+        .collect()
+}
+
+/// Values worth suggesting for a filter field.
+fn filter_values(name: &str, issues: &[Issue]) -> Vec<String> {
+    let mut values: Vec<String> = match FilterField::from_name(name) {
+        Some(FilterField::Status) => return STATUSES.iter().map(|s| s.to_string()).collect(),
+        Some(FilterField::Project) => issues.iter().filter_map(|i| i.project.clone()).collect(),
+        Some(FilterField::Source) => issues.iter().map(|i| i.source_type.clone()).collect(),
+        Some(FilterField::Environment) => issues.iter().filter_map(|i| i.environment.clone()).collect(),
+        Some(FilterField::Release) => issues.iter().filter_map(|i| i.release.clone()).collect(),
+        Some(FilterField::Text) | None => Vec::new(),
+    };
+    values.sort();
+    values.dedup();
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_issue;
+
+    fn issue(short_id: &str) -> Issue {
+        test_issue(short_id, "pending")
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert!(matches!(parse_command("q"), Ok(Action::Quit)));
+        assert!(matches!(
+            parse_command("filter status=error boom"),
+            Ok(Action::FilterIssues(filters))
+                if filters == vec![
+                    IssueFilter { field: FilterField::Status, value: "error".into() },
+                    IssueFilter { field: FilterField::Text, value: "boom".into() },
+                ]
+        ));
+        assert!(matches!(
+            parse_command("sort events"),
+            Ok(Action::SortIssues(Some(IssueSort { key: SortKey::Events, descending: true })))
+        ));
+        assert!(matches!(parse_command("sort"), Ok(Action::SortIssues(None))));
+        assert!(matches!(parse_command("group type"), Ok(Action::GroupIssues(Some(GroupBy::ErrorType)))));
+        assert!(matches!(parse_command("group none"), Ok(Action::GroupIssues(None))));
+        assert!(matches!(
+            parse_command("add https://acme.sentry.io/issues/12345/"),
+            Ok(Action::AddIssue(issue)) if issue == "https://acme.sentry.io/issues/12345/"
+        ));
+        assert!(matches!(parse_command("new"), Ok(Action::OpenNewIssue)));
+        assert!(matches!(parse_command("project"), Ok(Action::OpenProjectSwitcher)));
+        assert!(matches!(parse_command("project all"), Ok(Action::SwitchProject(None))));
+        assert!(matches!(
+            parse_command("project billing"),
+            Ok(Action::SwitchProject(Some(name))) if name == "billing"
+        ));
+        assert!(matches!(
+            parse_command("set badges"),
+            Ok(Action::SetOption(name, value)) if name == "badges" && value == "on"
+        ));
+        assert!(matches!(
+            parse_command("set theme=light"),
+            Ok(Action::SetOption(name, value)) if name == "theme" && value == "light"
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_command("frobnicate").unwrap_err(), "Unknown command: frobnicate");
+        assert!(parse_command("filter severity=high").is_err());
+        assert!(parse_command("sort events sideways").is_err());
+        assert!(parse_command("group severity").is_err());
+        assert!(parse_command("add").is_err());
+        assert!(parse_command("set palette=neon").is_err());
+    }
+
+    #[test]
+    fn test_completion() {
+        let issues = [issue("PROJ-12"), issue("WEB-3")];
+        assert_eq!(complete_command("so", &issues, &[]), vec!["sort"]);
+        assert_eq!(complete_command("filter st", &issues, &[]), vec!["filter status="]);
+        assert_eq!(
+            complete_command("filter status=pe", &issues, &[]),
+            vec!["filter status=pending", "filter status=pending_approval", "filter status=pending_review"]
+        );
+        assert_eq!(complete_command("sort events d", &issues, &[]), vec!["sort events desc"]);
+        assert_eq!(complete_command("open proj", &issues, &[]), vec!["open PROJ-12"]);
+        assert!(complete_command("quit now", &issues, &[]).is_empty());
+
+        let projects = ["web".to_string(), "billing".to_string()];
+        assert_eq!(complete_command("project ", &issues, &projects), vec!["project all", "project web", "project billing"]);
+        assert_eq!(complete_command("project b", &issues, &projects), vec!["project billing"]);
+        assert!(complete_command("project ", &issues, &[]).is_empty());
+    }
+}
@@ -1,13 +1,27 @@
 //! Detail screen input handling.
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use crate::api::IssueState;
-use crate::app::App;
-use super::Action;
+use crate::app::{App, EventTarget, SessionKind, YankItem};
+use crate::escape::Placement;
+use super::list::pin_label;
+use super::review::pull_request_url;
+use super::{supported, Action, KeyHint};
 
 /// Handle input on the detail screen.
 pub fn handle_detail_input(app: &App, key: KeyEvent) -> Action {
+    // A focused frame takes Enter and `e` (and Esc, to let go of them), and
+    // a focused section header takes Enter
+    let frame_focused = app.state.frame_focus.focused().is_some();
+    let section_focused = app.state.section_focus.focused().is_some();
     match key.code {
+        KeyCode::Enter if frame_focused && app.focused_fold().is_some() => Action::UnfoldFrames,
+        KeyCode::Enter | KeyCode::Char('e') if frame_focused => Action::OpenFrameInEditor,
+        KeyCode::Esc if frame_focused => Action::UnfocusFrame,
+        KeyCode::Enter if section_focused => Action::ToggleSection,
+        KeyCode::Esc if section_focused => Action::UnfocusSection,
+        KeyCode::Tab => Action::FocusSection(1),
+        KeyCode::BackTab => Action::FocusSection(-1),
         KeyCode::Char('q') | KeyCode::Esc => Action::BackToList,
         KeyCode::Char('j') | KeyCode::Down => Action::ScrollDetail(1),
         KeyCode::Char('k') | KeyCode::Up => Action::ScrollDetail(-1),
@@ -15,21 +29,310 @@ pub fn handle_detail_input(app: &App, key: KeyEvent) -> Action {
         KeyCode::Char('i') => Action::InteractivePi,
         KeyCode::Enter => handle_enter(app),
         KeyCode::Char('a') => Action::AnalyzeFromDetail,
+        KeyCode::Char('A') => Action::AnalyzeWithInstructions,
         KeyCode::Char('d') => Action::CompleteReview,
+        KeyCode::Char('P') => Action::OpenPullRequest,
+        KeyCode::Char('*') => Action::TogglePin,
         KeyCode::Char('R') => Action::RetryError,
+        KeyCode::Char('o') => Action::OpenReplay,
+        KeyCode::Char(']') => Action::FocusFrame(1),
+        KeyCode::Char('[') => Action::FocusFrame(-1),
+        KeyCode::Char('{') => Action::BrowseEvents(EventTarget::Older(1)),
+        KeyCode::Char('}') => Action::BrowseEvents(EventTarget::Older(-1)),
+        KeyCode::Char('<') => Action::BrowseEvents(EventTarget::Oldest),
+        KeyCode::Char('>') => Action::BrowseEvents(EventTarget::Latest),
+        KeyCode::Char('y') => Action::OpenYankMenu,
+        KeyCode::Char('b') => Action::BlameFrame,
+        KeyCode::Char('c') => Action::ToggleFrameContext,
+        KeyCode::Char('t') => Action::ToggleTimeline,
+        KeyCode::Char('B') => Action::OpenBreadcrumbs,
+        KeyCode::Char('J') => Action::OpenRawJson,
+        KeyCode::Char('T') => Action::ToggleTagBreakdown,
+        KeyCode::Char('D') => Action::ToggleIssueChanges,
+        KeyCode::Char('e') => Action::ToggleEventPicker,
+        KeyCode::Char('+') | KeyCode::Char('=') => Action::ZoomTimeline(-1),
+        KeyCode::Char('-') => Action::ZoomTimeline(1),
         _ => Action::None,
     }
 }
 
+/// Handle input while the tag breakdown popup is open.
+pub fn handle_tag_breakdown_input(app: &App, key: KeyEvent) -> Action {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char('T') | KeyCode::Char('q') | KeyCode::Esc => Action::ToggleTagBreakdown,
+        KeyCode::Char('j') | KeyCode::Down => Action::ScrollTagBreakdown(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::ScrollTagBreakdown(-1),
+        KeyCode::Char('d') if ctrl => Action::ScrollTagBreakdown(app.half_page()),
+        KeyCode::Char('u') if ctrl => Action::ScrollTagBreakdown(-app.half_page()),
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints while the tag breakdown is open.
+pub fn tag_breakdown_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("T/q/Esc", "close", 0),
+        KeyHint::new("↑↓/jk/C-d/u", "scroll", 1),
+    ]
+}
+
+/// Handle input while the "what changed" popup is open.
+pub fn handle_issue_changes_input(key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Char('D') | KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => Action::ToggleIssueChanges,
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints while the "what changed" popup is open.
+pub fn issue_changes_hints() -> Vec<KeyHint> {
+    vec![KeyHint::new("D/q/Esc", "close", 0)]
+}
+
+/// Handle input while the events browser is open.
+pub fn handle_event_picker_input(app: &App, key: KeyEvent) -> Action {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char('e') | KeyCode::Char('q') | KeyCode::Esc => Action::ToggleEventPicker,
+        KeyCode::Char('j') | KeyCode::Down => Action::MoveEventSelection(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::MoveEventSelection(-1),
+        KeyCode::Char('d') if ctrl => Action::MoveEventSelection(app.half_page()),
+        KeyCode::Char('u') if ctrl => Action::MoveEventSelection(-app.half_page()),
+        KeyCode::Enter if can_analyze(app) => Action::AnalyzeFromEvent,
+        KeyCode::Char('v') => Action::ViewPickedEvent,
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints while the events browser is open.
+pub fn event_picker_hints(app: &App) -> Vec<KeyHint> {
+    let mut hints = vec![
+        KeyHint::new("e/q/Esc", "close", 0),
+        KeyHint::new("↑↓/jk", "select", 1),
+    ];
+    if can_analyze(app) {
+        hints.push(KeyHint::new("Enter", "re-analyze from event", 0));
+    }
+    if supported(app, &Action::ViewPickedEvent) {
+        hints.push(KeyHint::new("v", "view event", 1));
+    }
+    hints
+}
+
+/// Handle input while the session picker is open.
+pub fn handle_session_picker_input(key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Char('k') | KeyCode::Down | KeyCode::Up | KeyCode::Tab => {
+            Action::ToggleSessionSelection
+        }
+        KeyCode::Enter => Action::LaunchSession(None),
+        KeyCode::Char('a') => Action::LaunchSession(Some(SessionKind::Analysis)),
+        KeyCode::Char('i') => Action::LaunchSession(Some(SessionKind::Implementation)),
+        KeyCode::Char('q') | KeyCode::Esc => Action::CancelSessionPicker,
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints while the session picker is open.
+pub fn session_picker_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("↑↓/jk", "select", 1),
+        KeyHint::new("Enter", "open", 0),
+        KeyHint::new("a", "analysis", 1),
+        KeyHint::new("i", "implementation", 1),
+        KeyHint::new("q/Esc", "cancel", 0),
+    ]
+}
+
+/// Handle input while asking whether to resume a session in a new tmux or
+/// zellij pane.
+pub fn handle_placement_prompt_input(key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Enter | KeyCode::Char('p') => Action::ResumeIn(Some(Placement::Pane)),
+        KeyCode::Char('w') => Action::ResumeIn(Some(Placement::Window)),
+        KeyCode::Char('t') => Action::ResumeIn(None),
+        KeyCode::Char('q') | KeyCode::Esc => Action::CancelPlacementPrompt,
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints while the placement prompt is open.
+pub fn placement_prompt_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("Enter/p", "pane", 0),
+        KeyHint::new("w", "window", 1),
+        KeyHint::new("t", "this terminal", 1),
+        KeyHint::new("q/Esc", "cancel", 0),
+    ]
+}
+
+/// Handle input while the yank menu is open, on any screen.
+pub fn handle_yank_menu_input(key: KeyEvent) -> Action {
+    let item = YankItem::ALL.into_iter().find(|item| key.code == KeyCode::Char(item.key()));
+    match key.code {
+        _ if item.is_some() => Action::Yank(item),
+        KeyCode::Char('j') | KeyCode::Down => Action::MoveYankSelection(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::MoveYankSelection(-1),
+        KeyCode::Enter | KeyCode::Char('y') => Action::Yank(None),
+        KeyCode::Char('q') | KeyCode::Esc => Action::CloseYankMenu,
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints while the yank menu is open.
+pub fn yank_menu_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("↑↓/jk", "select", 1),
+        KeyHint::new("Enter/y", "copy", 0),
+        KeyHint::new("q/Esc", "cancel", 0),
+    ]
+}
+
+/// Whether the current issue can be (re-)analyzed from here.
+fn can_analyze(app: &App) -> bool {
+    app.state.current_issue.as_ref().is_some_and(|issue| {
+        !matches!(issue.state, IssueState::InProgress { .. } | IssueState::PendingReview { .. })
+    })
+}
+
 /// Handle Enter key based on current issue state.
 fn handle_enter(app: &App) -> Action {
     if let Some(issue) = &app.state.current_issue {
         match &issue.state {
             IssueState::PendingApproval { .. } => Action::OpenProposal,
             IssueState::Analyzing { .. } => Action::OpenAnalysis,
+            IssueState::InProgress { .. } => Action::OpenImplementation,
+            IssueState::PendingReview { .. } => Action::OpenReview,
+            IssueState::Error { .. } => Action::OpenError,
             _ => Action::None,
         }
     } else {
         Action::None
     }
 }
+
+/// Keybinding hints for the detail screen.
+///
+/// State-specific actions come first in priority since they are the
+/// reason to be on this screen.
+pub fn detail_hints(app: &App) -> Vec<KeyHint> {
+    let mut hints = vec![
+        KeyHint::new("↑↓/jk/C-d/u", "scroll", 3),
+        KeyHint::new("r", "refresh", 2),
+        KeyHint::new("q/Esc", "back", 1),
+    ];
+
+    // Add state-specific keybinds based on current issue (only if loaded and not refreshing)
+    let details_ready = app.state.current_issue.is_some() && !app.state.is_refreshing_detail;
+    if let Some(issue) = &app.state.current_issue {
+        match &issue.state {
+            IssueState::Pending => {
+                if details_ready {
+                    hints.push(KeyHint::new("a", "analyze", 0));
+                    hints.push(KeyHint::new("A", "analyze with notes", 2));
+                }
+            }
+            IssueState::Analyzing { .. } => {
+                if details_ready {
+                    hints.push(KeyHint::new("a", "re-analyze", 2));
+                    hints.push(KeyHint::new("A", "re-analyze with notes", 3));
+                }
+                hints.push(KeyHint::new("Enter", "view analysis", 0));
+                hints.push(KeyHint::new("i", "interactive", 1));
+            }
+            IssueState::PendingApproval { .. } => {
+                if details_ready {
+                    hints.push(KeyHint::new("a", "re-analyze", 2));
+                    hints.push(KeyHint::new("A", "re-analyze with notes", 3));
+                }
+                hints.push(KeyHint::new("Enter", "view proposal", 0));
+                hints.push(KeyHint::new("i", "interactive", 1));
+            }
+            IssueState::InProgress { .. } => {
+                if supported(app, &Action::OpenImplementation) {
+                    hints.push(KeyHint::new("Enter", "watch implementation", 0));
+                }
+                hints.push(KeyHint::new("i", "interactive", 1));
+            }
+            IssueState::PendingReview { .. } => {
+                hints.push(KeyHint::new("Enter", "review diff", 0));
+                hints.push(KeyHint::new("d", "done", 0));
+                let label = if pull_request_url(app).is_some() { "copy PR URL" } else { "open PR" };
+                hints.push(KeyHint::new("P", label, 1));
+                hints.push(KeyHint::new("i", "interactive", 1));
+            }
+            IssueState::Error { .. } => {
+                if details_ready {
+                    hints.push(KeyHint::new("a", "re-analyze", 1));
+                    hints.push(KeyHint::new("A", "re-analyze with notes", 2));
+                }
+                hints.push(KeyHint::new("R", "retry", 0));
+                hints.push(KeyHint::new("Enter", "error details", 0));
+            }
+        }
+
+        if issue.source.replay.is_some() {
+            hints.push(KeyHint::new("o", "open replay", 2));
+        }
+        if issue.source.frames().next().is_some() {
+            hints.push(KeyHint::new("[/]", "frames", 3));
+        }
+        if supported(app, &Action::ToggleTagBreakdown) {
+            hints.push(KeyHint::new("T", "tag breakdown", 3));
+        }
+        if supported(app, &Action::ToggleEventPicker) {
+            hints.push(KeyHint::new("e", "events", 3));
+        }
+        if supported(app, &Action::BrowseEvents(EventTarget::Latest)) {
+            hints.push(KeyHint::new("{/}", "older/newer event", 3));
+            hints.push(KeyHint::new("</>", "oldest/latest event", 3));
+        }
+        if issue.source.breadcrumbs.as_ref().is_some_and(|b| !b.is_empty()) {
+            if app.state.breadcrumb_zoom.is_some() {
+                hints.push(KeyHint::new("+/-", "zoom timeline", 3));
+                hints.push(KeyHint::new("t", "breadcrumb list", 3));
+            } else {
+                hints.push(KeyHint::new("t", "breadcrumb timeline", 3));
+            }
+            hints.push(KeyHint::new("B", "all breadcrumbs", 3));
+        }
+        hints.push(KeyHint::new("J", "raw JSON", 3));
+        if app.state.issue_changes.as_ref().is_some_and(|changes| !changes.is_empty()) {
+            hints.push(KeyHint::new("D", "what changed", 2));
+        }
+        if app.focused_fold().is_some() {
+            hints.retain(|h| !matches!(h.key, "Enter" | "q/Esc"));
+            hints.push(KeyHint::new("Enter", "show library frames", 1));
+            hints.push(KeyHint::new("Esc", "unfocus frame", 2));
+            hints.push(KeyHint::new("q", "back", 1));
+        } else if app.state.frame_focus.focused().is_some() {
+            hints.retain(|h| !matches!(h.key, "Enter" | "e" | "q/Esc"));
+            hints.push(KeyHint::new("Enter/e", "open in editor", 1));
+            hints.push(KeyHint::new("b", "blame", 2));
+            if app.focused_frame().is_some_and(|f| f.context.as_ref().is_some_and(|c| !c.is_empty())) {
+                let label = match app.state.frame_focus.focused() {
+                    Some(index) if app.state.expanded_frames.contains(&index) => "hide source",
+                    _ => "show source",
+                };
+                hints.push(KeyHint::new("c", label, 2));
+            }
+            hints.push(KeyHint::new("Esc", "unfocus frame", 2));
+            hints.push(KeyHint::new("q", "back", 1));
+        }
+        hints.push(KeyHint::new("Tab", "sections", 3));
+        if let Some(section) = app.state.section_focus.focused() {
+            hints.retain(|h| !matches!(h.key, "Enter" | "q/Esc"));
+            let label = if app.is_collapsed(section) { "expand" } else { "collapse" };
+            hints.push(KeyHint::new("Enter", label, 1));
+            hints.push(KeyHint::new("Esc", "unfocus section", 2));
+            hints.push(KeyHint::new("q", "back", 1));
+        }
+        hints.push(KeyHint::new("y", "copy", 2));
+    }
+    if app.state.theme.summary_line {
+        hints.push(KeyHint::new("*", pin_label(app), 3));
+    }
+
+    hints
+}
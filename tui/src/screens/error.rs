@@ -0,0 +1,53 @@
+//! Error screen input handling.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::app::App;
+use super::{Action, KeyHint};
+
+/// Remediation actions offered as buttons, in display order.
+pub const ERROR_ACTIONS: [(&str, &str); 3] = [
+    ("R", "Retry"),
+    ("a", "Re-analyze"),
+    ("i", "Open interactive"),
+];
+
+/// Handle input on the error screen.
+pub fn handle_error_input(app: &App, key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => Action::BackFromError,
+        KeyCode::Char('j') | KeyCode::Down => Action::ScrollError(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::ScrollError(-1),
+        KeyCode::Char('h') | KeyCode::Left | KeyCode::BackTab => Action::SelectErrorAction(-1),
+        KeyCode::Char('l') | KeyCode::Right | KeyCode::Tab => Action::SelectErrorAction(1),
+        KeyCode::Enter => error_action(app.state.error_action_index),
+        KeyCode::Char('R') => Action::RetryError,
+        KeyCode::Char('a') => Action::AnalyzeFromDetail,
+        KeyCode::Char('i') => Action::InteractivePi,
+        KeyCode::Char('y') => Action::OpenYankMenu,
+        _ => Action::None,
+    }
+}
+
+/// The action behind the button at `index`.
+fn error_action(index: usize) -> Action {
+    match index {
+        0 => Action::RetryError,
+        1 => Action::AnalyzeFromDetail,
+        2 => Action::InteractivePi,
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints for the error screen.
+pub fn error_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("q/Esc", "back", 1),
+        KeyHint::new("←→/Tab", "select", 2),
+        KeyHint::new("Enter", "run selected", 0),
+        KeyHint::new("R", "retry", 0),
+        KeyHint::new("a", "re-analyze", 1),
+        KeyHint::new("i", "interactive", 1),
+        KeyHint::new("↑↓/C-d/u", "scroll", 3),
+        KeyHint::new("y", "copy", 3),
+    ]
+}
@@ -0,0 +1,34 @@
+//! Implementation screen input handling.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::app::App;
+use super::{wrap_hints, Action, KeyHint, SIDEWAYS_STEP};
+
+/// Handle input on the implementation screen.
+pub fn handle_implementation_input(key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => Action::BackToDetail,
+        KeyCode::Char('j') | KeyCode::Down => Action::ScrollImplementation(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::ScrollImplementation(-1),
+        KeyCode::Char('w') => Action::ToggleWrap,
+        KeyCode::Char('h') | KeyCode::Left => Action::ScrollColumns(-SIDEWAYS_STEP),
+        KeyCode::Char('l') | KeyCode::Right => Action::ScrollColumns(SIDEWAYS_STEP),
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints for the implementation screen.
+pub fn implementation_hints(app: &App) -> Vec<KeyHint> {
+    let back = if app.state.is_streaming_implementation {
+        "back"
+    } else {
+        "back to detail"
+    };
+
+    let mut hints = vec![
+        KeyHint::new("q/Esc", back, 0),
+        KeyHint::new("↑↓/C-d/u", "scroll", 2),
+    ];
+    hints.extend(wrap_hints(&app.state.implementation_scroll));
+    hints
+}
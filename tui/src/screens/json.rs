@@ -0,0 +1,36 @@
+//! Raw JSON viewer input handling.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::app::{App, JsonRowKind};
+use super::{Action, KeyHint};
+
+/// Handle input in the raw JSON viewer.
+pub fn handle_json_input(key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('J') => Action::BackToDetail,
+        KeyCode::Char('j') | KeyCode::Down => Action::MoveJsonSelection(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::MoveJsonSelection(-1),
+        KeyCode::Char('g') | KeyCode::Home => Action::MoveJsonSelection(i32::MIN),
+        KeyCode::Char('G') | KeyCode::End => Action::MoveJsonSelection(i32::MAX),
+        KeyCode::Enter | KeyCode::Char(' ') => Action::FoldJson(None),
+        KeyCode::Char('h') | KeyCode::Left => Action::FoldJson(Some(true)),
+        KeyCode::Char('l') | KeyCode::Right => Action::FoldJson(Some(false)),
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints for the raw JSON viewer.
+pub fn json_hints(app: &App) -> Vec<KeyHint> {
+    let rows = app.json_rows();
+    let selected = app.state.json_view.as_ref().and_then(|view| rows.get(view.selected));
+    let mut hints = vec![KeyHint::new("↑↓/jk/C-d/u", "select", 2)];
+    match selected.map(|row| row.kind) {
+        Some(JsonRowKind::Open { folded: true, .. }) => hints.push(KeyHint::new("Enter/l", "unfold", 0)),
+        Some(JsonRowKind::Open { .. } | JsonRowKind::Close) => hints.push(KeyHint::new("Enter/h", "fold", 0)),
+        Some(JsonRowKind::Value) if rows.len() > 1 => hints.push(KeyHint::new("h", "fold parent", 1)),
+        _ => {}
+    }
+    hints.push(KeyHint::new("g/G", "top/bottom", 3));
+    hints.push(KeyHint::new("q/Esc", "back", 0));
+    hints
+}
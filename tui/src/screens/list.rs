@@ -1,10 +1,12 @@
 //! List screen input handling.
 
 use crossterm::event::{KeyCode, KeyEvent};
-use super::Action;
+use crate::app::App;
+use super::{supported, Action, KeyHint};
 
 /// Handle input on the list screen.
-pub fn handle_list_input(key: KeyEvent) -> Action {
+pub fn handle_list_input(app: &App, key: KeyEvent) -> Action {
+    let grouped = app.state.view.group.is_some();
     match key.code {
         KeyCode::Char('q') => Action::Quit,
         KeyCode::Char('j') | KeyCode::Down => Action::MoveSelection(1),
@@ -12,8 +14,127 @@ pub fn handle_list_input(key: KeyEvent) -> Action {
         KeyCode::Char('g') => Action::JumpToTop,
         KeyCode::Char('G') => Action::JumpToBottom,
         KeyCode::Char('r') => Action::Refresh,
+        KeyCode::Char('+') => Action::OpenAddIssue,
+        KeyCode::Char('n') => Action::OpenNewIssue,
+        KeyCode::Char('p') => Action::OpenProjectSwitcher,
         KeyCode::Char('a') => Action::AnalyzeFromList,
+        // Enter on a folded group's header unfolds it
+        KeyCode::Enter if grouped && header_folded(app) => Action::ToggleGroup,
         KeyCode::Enter => Action::OpenSelected,
+        KeyCode::Char('z') if grouped => Action::ToggleGroup,
+        KeyCode::Char('Z') if grouped => Action::ToggleAllGroups,
+        KeyCode::Char(' ') | KeyCode::Char('K') => Action::TogglePeek,
+        KeyCode::Char('m') => Action::ToggleMergeMark,
+        KeyCode::Char('M') => Action::OpenMergeDialog,
+        KeyCode::Char('*') => Action::TogglePin,
+        KeyCode::Char('w') => Action::OpenWorktrees,
+        KeyCode::Char('y') => Action::OpenYankMenu,
+        KeyCode::Char('I') => Action::ToggleIgnored,
         _ => Action::None,
     }
 }
+
+/// Handle input while the project switcher is open.
+pub fn handle_project_switcher_input(app: &App, key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => Action::MoveProjectSelection(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::MoveProjectSelection(-1),
+        KeyCode::Enter => {
+            let row = app.state.project_switcher.unwrap_or(0);
+            let project = row.checked_sub(1).and_then(|index| app.state.projects.get(index));
+            Action::SwitchProject(project.cloned())
+        }
+        KeyCode::Char('q') | KeyCode::Char('p') | KeyCode::Esc => Action::CloseProjectSwitcher,
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints while the project switcher is open.
+pub fn project_switcher_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("↑↓/jk", "select", 1),
+        KeyHint::new("Enter", "switch", 0),
+        KeyHint::new("q/Esc", "cancel", 0),
+    ]
+}
+
+/// Whether the selected issue heads a folded group.
+fn header_folded(app: &App) -> bool {
+    let (Some(group), Some(issue)) = (app.state.view.group, app.state.selected_issue()) else {
+        return false;
+    };
+    app.state.collapsed_groups.contains(&group.key(issue))
+}
+
+/// Keybinding hints for the list screen.
+pub fn list_hints(app: &App) -> Vec<KeyHint> {
+    let mut hints = vec![
+        KeyHint::new("↑↓/jk/C-d/u", "navigate", 2),
+        KeyHint::new("Enter", "open", 0),
+        KeyHint::new("Space", "peek", 2),
+        KeyHint::new("a", "analyze", 1),
+        KeyHint::new("r", "refresh", 1),
+        KeyHint::new("w", "worktrees", 3),
+        KeyHint::new("+", "add issue", 3),
+        KeyHint::new("n", "new issue", 3),
+        KeyHint::new("y", "copy", 3),
+        KeyHint::new("q", "quit", 0),
+    ];
+    if app.state.theme.summary_line {
+        hints.push(KeyHint::new("*", pin_label(app), 3));
+    }
+    if app.state.view.group.is_some() {
+        let label = if header_folded(app) { "unfold group" } else { "fold group" };
+        hints.push(KeyHint::new("z", label, 2));
+        hints.push(KeyHint::new("Z", "fold all", 3));
+        if header_folded(app) {
+            hints.retain(|h| h.key != "Enter");
+            hints.push(KeyHint::new("Enter", "unfold group", 0));
+        }
+    }
+    if !app.state.projects.is_empty() {
+        hints.push(KeyHint::new("p", "switch project", 2));
+    }
+    if !app.state.ignore.is_empty() {
+        let label = if app.state.show_ignored { "hide ignored" } else { "show ignored" };
+        hints.push(KeyHint::new("I", label, 3));
+    }
+    if supported(app, &Action::ToggleMergeMark) {
+        hints.insert(8, KeyHint::new("m", "mark duplicate", 3));
+        if !app.state.merge_marks.is_empty() {
+            hints.push(KeyHint::new("M", "merge marked", 1));
+        }
+    }
+    if !supported(app, &Action::OpenNewIssue) {
+        hints.retain(|h| h.key != "n");
+    }
+    hints
+}
+
+/// Hint for pinning the selected issue to the summary line.
+pub fn pin_label(app: &App) -> &'static str {
+    let selected = app.state.selected_issue_id();
+    if app.state.pinned_issue.as_deref().is_some_and(|id| Some(id) == selected) {
+        "unpin"
+    } else {
+        "pin"
+    }
+}
+
+/// Keybinding hints while the merge confirmation is open.
+pub fn merge_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("↑↓/jk", "pick primary", 0),
+        KeyHint::new("Enter", "merge", 0),
+        KeyHint::new("Esc", "cancel", 0),
+    ]
+}
+
+/// Keybinding hints while the peek popup is open.
+pub fn peek_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("↑↓/jk", "next issue", 1),
+        KeyHint::new("Enter", "open", 0),
+        KeyHint::new("Space/Esc", "close", 0),
+    ]
+}
@@ -5,15 +5,45 @@
 mod list;
 mod detail;
 mod analysis;
+mod implementation;
+mod review;
 mod proposal;
+mod error;
+mod prompt;
+mod command;
+mod worktrees;
+mod breadcrumbs;
+mod json;
 
-pub use list::handle_list_input;
-pub use detail::handle_detail_input;
-pub use analysis::handle_analysis_input;
-pub use proposal::handle_proposal_input;
+pub use list::{
+    handle_list_input, handle_project_switcher_input, list_hints, merge_hints, peek_hints, project_switcher_hints,
+};
+pub use detail::{
+    detail_hints, event_picker_hints, handle_detail_input, handle_event_picker_input, handle_issue_changes_input,
+    handle_placement_prompt_input, handle_session_picker_input, handle_tag_breakdown_input, handle_yank_menu_input,
+    issue_changes_hints, placement_prompt_hints, session_picker_hints, tag_breakdown_hints, yank_menu_hints,
+};
+pub use analysis::{analysis_hints, handle_analysis_input};
+pub use implementation::{handle_implementation_input, implementation_hints};
+pub use review::{finish_review_hints, handle_finish_review_input, handle_review_input, review_hints};
+pub use proposal::{
+    handle_plan_preview_input, handle_proposal_input, plan_preview_hints, proposal_hints,
+};
+pub use error::{error_hints, handle_error_input, ERROR_ACTIONS};
+pub use prompt::{handle_new_issue_input, handle_prompt_input, new_issue_hints, prompt_hints};
+pub use breadcrumbs::{breadcrumbs_hints, handle_breadcrumbs_input};
+pub use json::{handle_json_input, json_hints};
+pub use command::{command_hints, complete_command, handle_command_input, parse_command, COMMANDS, OPTIONS};
+pub use worktrees::{handle_worktrees_input, worktrees_hints};
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use crate::app::{App, Screen};
+use crate::api::Capability;
+use crate::app::{
+    App, EventTarget, GroupBy, IssueAction, IssueFilter, IssueSort, Screen, ScrollView, SessionKind,
+    YankItem,
+};
+use crate::escape::Placement;
+use crate::ui::focus::FocusRing;
 
 /// Actions that can be performed by the application.
 #[derive(Debug, Clone)]
@@ -28,30 +58,413 @@ pub enum Action {
     JumpToBottom,
     ScrollDetail(i32),
     ScrollAnalysis(i32),
+    ScrollImplementation(i32),
+    ScrollReview(i32),
+    ScrollTestOutput(i32),
     ScrollProposal(i32),
+    ScrollError(i32),
+    FocusFrame(i32),
+    ZoomTimeline(i32),
+    SelectErrorAction(i32),
+    MoveMergePrimary(i32),
+    SelectReviewFile(i32),
+    MoveWorktreeSelection(i32),
     /// Screen transitions
     OpenSelected,
+    TogglePeek,
     BackToList,
     BackToDetail,
     BackFromProposal,
     OpenProposal,
     OpenAnalysis,
+    OpenImplementation,
+    OpenReview,
+    OpenError,
+    BackFromError,
+    OpenWorktrees,
+    BackFromWorktrees,
     /// Data operations (async)
     Refresh,
+    /// Ask for an issue to add by Sentry URL or ID
+    OpenAddIssue,
+    AddIssue(String),
+    /// The form for creating an issue by hand
+    OpenNewIssue,
+    /// Narrowing the list to one project
+    OpenProjectSwitcher,
+    MoveProjectSelection(isize),
+    SwitchProject(Option<String>),
+    CloseProjectSwitcher,
+    NewIssueInput(KeyEvent),
+    MoveNewIssueFocus(isize),
+    SubmitNewIssue,
+    CancelNewIssue,
     RefreshDetail,
+    ReloadReview,
+    ReloadWorktrees,
     /// Agent actions (async)
     AnalyzeFromList,
     AnalyzeFromDetail,
+    AnalyzeWithInstructions,
+    AnalyzeFromEvent,
     ApproveProposal,
     RejectProposal,
     CompleteReview,
+    ConfirmCompleteReview,
+    /// Complete even though the worktree has uncommitted or unpushed work
+    DiscardAndCompleteReview,
+    CancelCompleteReview,
+    EditCommitMessage,
+    SquashCommits,
+    RunWorktreeTests,
+    CancelWorktreeTests,
+    OpenPullRequest,
+    CopyPullRequestUrl,
     RetryError,
+    ConfirmMerge,
+    SubmitPrompt,
+    /// Prompts
+    PromptInput(KeyEvent),
+    CancelPrompt,
+    /// Command line
+    OpenCommandLine,
+    CommandInput(KeyEvent),
+    CompleteCommand,
+    CommandHistory(i32),
+    CancelCommand,
+    SubmitCommand,
+    /// List view (from commands)
+    FilterIssues(Vec<IssueFilter>),
+    SortIssues(Option<IssueSort>),
+    ToggleIgnored,
+    GroupIssues(Option<GroupBy>),
+    ToggleGroup,
+    ToggleAllGroups,
+    OpenIssue(String),
+    SetOption(String, String),
+    /// Merging
+    ToggleMergeMark,
+    TogglePin,
+    OpenMergeDialog,
+    CancelMerge,
+    /// Worktree cleanup
+    RemoveWorktree,
+    PruneWorktrees,
     /// Special
     InteractivePi,
+    ToggleSessionSelection,
+    LaunchSession(Option<SessionKind>),
+    CancelSessionPicker,
+    /// Open the session waiting on the placement prompt in a new pane or
+    /// window, or (`None`) this terminal
+    ResumeIn(Option<Placement>),
+    CancelPlacementPrompt,
+    OpenReplay,
+    /// Open the focused frame's file at its line in $EDITOR
+    OpenFrameInEditor,
+    UnfocusFrame,
+    /// Show the run of library frames folded at the focused frame
+    UnfoldFrames,
+    /// Breadcrumbs screen
+    OpenBreadcrumbs,
+    MoveBreadcrumbSelection(i32),
+    ToggleBreadcrumbData,
+    CycleBreadcrumbCategory(i32),
+    /// Raw JSON viewer; `FoldJson(None)` toggles the selected container
+    OpenRawJson,
+    MoveJsonSelection(i32),
+    FoldJson(Option<bool>),
+    /// Show or hide the source lines around the focused frame
+    ToggleFrameContext,
+    /// Detail screen sections: move between headers, fold the focused one
+    FocusSection(i32),
+    ToggleSection,
+    UnfocusSection,
+    /// Yank menu
+    OpenYankMenu,
+    MoveYankSelection(i32),
+    /// Copy an item (`None` for the selected one)
+    Yank(Option<YankItem>),
+    CloseYankMenu,
+    ToggleTimeline,
+    ToggleTagBreakdown,
+    ToggleIssueChanges,
+    ScrollTagBreakdown(i32),
+    ToggleEventPicker,
+    MoveEventSelection(i32),
+    /// Show the event picked in the events popup on the detail screen
+    ViewPickedEvent,
+    /// Step the detail screen through the issue's events
+    BrowseEvents(EventTarget),
+    ToggleDiffLineNumbers,
+    /// Switch the screen between wrapping long lines and scrolling sideways
+    ToggleWrap,
+    /// Scroll the screen sideways by this many columns while unwrapped
+    ScrollColumns(i32),
+    TogglePlanPreview,
+    ScrollPlanPreview(i32),
+    ToggleCritique,
+    ScrollCritique(i32),
+    BlameFrame,
+    ExportTranscript,
+    ExportRecording,
+    EndPlayback,
+    ToggleHelp,
+    StartTutorial,
+    EndTutorial,
+    ToggleErrorDetails,
+    RetryFailedAction,
 }
 
-/// Route input to the appropriate screen handler.
+/// A keybinding hint shown in the action bar and help overlay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyHint {
+    pub key: &'static str,
+    pub desc: &'static str,
+    /// Lower values are kept longer when the action bar is too narrow.
+    pub priority: u8,
+}
+
+impl KeyHint {
+    pub const fn new(key: &'static str, desc: &'static str, priority: u8) -> Self {
+        Self { key, desc, priority }
+    }
+}
+
+/// Hint appended to the action bar when some hints had to be hidden.
+pub const MORE_HINT: KeyHint = KeyHint::new("?", "more", 0);
+
+/// Keybinding hints for the current screen, in display order.
+pub fn hints(app: &App) -> Vec<KeyHint> {
+    let mut hints = match app.screen() {
+        _ if app.state.prompt.is_some() => prompt_hints(app),
+        _ if app.state.command_line.is_some() => command_hints(),
+        _ if app.state.new_issue.is_some() => new_issue_hints(app),
+        _ if app.state.session_picker.is_some() => session_picker_hints(),
+        _ if app.state.placement_prompt.is_some() => placement_prompt_hints(),
+        _ if app.state.yank_menu.is_some() => yank_menu_hints(),
+        _ if app.state.project_switcher.is_some() => project_switcher_hints(),
+        Screen::List if app.state.merge_dialog.is_some() => merge_hints(),
+        Screen::List if app.state.peek.is_some() => peek_hints(),
+        Screen::List => list_hints(app),
+        Screen::Detail | Screen::Review if app.state.finish_review.is_some() => finish_review_hints(app),
+        Screen::Detail if app.state.tag_breakdown.is_some() => tag_breakdown_hints(),
+        Screen::Detail if app.state.show_issue_changes => issue_changes_hints(),
+        Screen::Detail if app.state.event_picker.is_some() => event_picker_hints(app),
+        Screen::Detail => detail_hints(app),
+        Screen::Analysis => analysis_hints(app),
+        Screen::Implementation => implementation_hints(app),
+        Screen::Review => review_hints(app),
+        Screen::Proposal if app.state.plan_preview.is_some() => plan_preview_hints(),
+        Screen::Proposal => proposal_hints(app),
+        Screen::Error => error_hints(),
+        Screen::Worktrees => worktrees_hints(),
+        Screen::Breadcrumbs => breadcrumbs_hints(app),
+        Screen::RawJson => json_hints(app),
+    };
+    // Leave out keys whose workflow step the issue's status doesn't allow
+    hints.retain(|hint| {
+        let code = match hint.key {
+            "Enter" => KeyCode::Enter,
+            key => match key.chars().collect::<Vec<_>>()[..] {
+                [c] => KeyCode::Char(c),
+                _ => return true,
+            },
+        };
+        app.blocked_transition(&route_input(app, KeyEvent::from(code))).is_none()
+    });
+    if app.state.error_details().is_some() {
+        hints.push(KeyHint::new("E", "error details", 1));
+    }
+    if app.state.tutorial.is_some() {
+        hints.push(KeyHint::new("Q", "exit tutorial", 0));
+    }
+    hints
+}
+
+/// The server feature an action depends on, if it isn't a core one.
+fn required_capability(action: &Action) -> Option<Capability> {
+    match action {
+        Action::ToggleTagBreakdown => Some(Capability::TagStats),
+        Action::ToggleEventPicker | Action::AnalyzeFromEvent => Some(Capability::EventSamples),
+        Action::BrowseEvents(_) | Action::ViewPickedEvent => Some(Capability::EventBrowsing),
+        Action::ToggleMergeMark | Action::OpenMergeDialog => Some(Capability::Merge),
+        Action::ToggleCritique => Some(Capability::Critique),
+        Action::TogglePlanPreview => Some(Capability::Plan),
+        Action::OpenImplementation => Some(Capability::ImplementationEvents),
+        Action::OpenNewIssue => Some(Capability::ManualIssues),
+        _ => None,
+    }
+}
+
+/// The workflow step an action asks the server for, checked against the
+/// transition table before it runs.
+pub fn requested_transition(action: &Action) -> Option<IssueAction> {
+    match action {
+        Action::AnalyzeFromList => Some(IssueAction::AnalyzeFromList),
+        Action::AnalyzeFromDetail | Action::AnalyzeWithInstructions | Action::AnalyzeFromEvent => {
+            Some(IssueAction::Analyze)
+        }
+        Action::ApproveProposal => Some(IssueAction::Approve),
+        Action::RejectProposal => Some(IssueAction::Reject),
+        Action::CompleteReview => Some(IssueAction::Complete),
+        Action::RetryError => Some(IssueAction::Retry),
+        _ => None,
+    }
+}
+
+/// Route a key on a screen made of focusable widgets: Tab and Shift+Tab
+/// move the focus with `move_focus`, and anything else goes to
+/// `widget_input` for the focused widget (or none).
+fn route_focus<W: Copy + PartialEq>(
+    focus: &FocusRing<W>,
+    key: KeyEvent,
+    move_focus: fn(isize) -> Action,
+    widget_input: impl FnOnce(Option<W>, KeyEvent) -> Action,
+) -> Action {
+    match key.code {
+        KeyCode::Tab => move_focus(1),
+        KeyCode::BackTab => move_focus(-1),
+        _ => widget_input(focus.focused(), key),
+    }
+}
+
+/// Columns moved per press when scrolling unwrapped text sideways.
+const SIDEWAYS_STEP: i32 = 8;
+
+/// Hints for a screen whose long lines can be left unwrapped.
+fn wrap_hints(view: &ScrollView) -> Vec<KeyHint> {
+    if view.wraps() {
+        vec![KeyHint::new("w", "no wrap", 3)]
+    } else {
+        vec![KeyHint::new("←→/hl", "scroll sideways", 2), KeyHint::new("w", "wrap", 3)]
+    }
+}
+
+/// Whether the server supports everything `action` needs.
+pub fn supported(app: &App, action: &Action) -> bool {
+    required_capability(action).is_none_or(|c| app.state.capabilities.supports(c))
+}
+
+/// Route input to the appropriate screen handler, dropping actions the
+/// server doesn't support.
 pub fn handle_input(app: &App, key: KeyEvent) -> Action {
+    let action = route_input(app, key);
+    if supported(app, &action) {
+        action
+    } else {
+        Action::None
+    }
+}
+
+fn route_input(app: &App, key: KeyEvent) -> Action {
+    // Raw mode turns Ctrl+C into a key, so it quits like q does from
+    // anywhere; pressed twice it gets past the in-flight work check
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        return Action::Quit;
+    }
+
+    // Nothing is visible on the too-small notice, so only allow quitting
+    if app.state.terminal_too_small() {
+        return match key.code {
+            KeyCode::Char('q') => Action::Quit,
+            _ => Action::None,
+        };
+    }
+
+    // An open prompt takes every key as text
+    if app.state.prompt.is_some() {
+        return handle_prompt_input(app, key);
+    }
+
+    // So does the command line
+    if app.state.command_line.is_some() {
+        return handle_command_input(key);
+    }
+
+    // And the new issue form
+    if app.state.new_issue.is_some() {
+        return handle_new_issue_input(app, key);
+    }
+
+    // And the session picker, wherever the escape hatch was opened
+    if app.state.session_picker.is_some() {
+        return handle_session_picker_input(key);
+    }
+    if app.state.placement_prompt.is_some() {
+        return handle_placement_prompt_input(key);
+    }
+    if app.state.yank_menu.is_some() {
+        return handle_yank_menu_input(key);
+    }
+    if app.state.project_switcher.is_some() {
+        return handle_project_switcher_input(app, key);
+    }
+
+    // The help overlay swallows input until it is closed
+    if app.state.show_help {
+        return match key.code {
+            KeyCode::Char('?') | KeyCode::Char('q') | KeyCode::Esc => Action::ToggleHelp,
+            KeyCode::Char('t') if app.state.tutorial.is_none() => Action::StartTutorial,
+            _ => Action::None,
+        };
+    }
+
+    // So does the error details modal
+    if app.state.show_error_details {
+        return match key.code {
+            KeyCode::Char('E') | KeyCode::Char('q') | KeyCode::Esc => Action::ToggleErrorDetails,
+            KeyCode::Char('r') | KeyCode::Enter => Action::RetryFailedAction,
+            _ => Action::None,
+        };
+    }
+
+    // And the merge confirmation
+    if app.state.merge_dialog.is_some() && *app.screen() == Screen::List {
+        return match key.code {
+            KeyCode::Char('j') | KeyCode::Down => Action::MoveMergePrimary(1),
+            KeyCode::Char('k') | KeyCode::Up => Action::MoveMergePrimary(-1),
+            KeyCode::Enter => Action::ConfirmMerge,
+            KeyCode::Char('q') | KeyCode::Esc => Action::CancelMerge,
+            _ => Action::None,
+        };
+    }
+
+    // And the review completion dialog
+    if app.state.finish_review.is_some() && matches!(app.screen(), Screen::Detail | Screen::Review) {
+        return handle_finish_review_input(key);
+    }
+
+    // And the tag breakdown, which scrolls on its own
+    if app.state.tag_breakdown.is_some() && *app.screen() == Screen::Detail {
+        return handle_tag_breakdown_input(app, key);
+    }
+    if app.state.event_picker.is_some() && *app.screen() == Screen::Detail {
+        return handle_event_picker_input(app, key);
+    }
+    if app.state.show_issue_changes && *app.screen() == Screen::Detail {
+        return handle_issue_changes_input(key);
+    }
+    if app.state.plan_preview.is_some() && *app.screen() == Screen::Proposal {
+        return handle_plan_preview_input(app, key);
+    }
+
+    if key.code == KeyCode::Char('?') {
+        return Action::ToggleHelp;
+    }
+
+    if key.code == KeyCode::Char(':') {
+        return Action::OpenCommandLine;
+    }
+
+    if key.code == KeyCode::Char('Q') && app.state.tutorial.is_some() {
+        return Action::EndTutorial;
+    }
+
+    if key.code == KeyCode::Char('E') && app.state.error_details().is_some() {
+        return Action::ToggleErrorDetails;
+    }
+
     // Handle Ctrl+D/U for half-page scrolling on all screens
     if key.modifiers.contains(KeyModifiers::CONTROL) {
         match (app.screen(), key.code) {
@@ -61,17 +474,142 @@ pub fn handle_input(app: &App, key: KeyEvent) -> Action {
             (Screen::Detail, KeyCode::Char('u')) => return Action::ScrollDetail(-app.half_page()),
             (Screen::Analysis, KeyCode::Char('d')) => return Action::ScrollAnalysis(app.half_page()),
             (Screen::Analysis, KeyCode::Char('u')) => return Action::ScrollAnalysis(-app.half_page()),
+            (Screen::Implementation, KeyCode::Char('d')) => {
+                return Action::ScrollImplementation(app.half_page())
+            }
+            (Screen::Implementation, KeyCode::Char('u')) => {
+                return Action::ScrollImplementation(-app.half_page())
+            }
+            (Screen::Review, KeyCode::Char('d')) => return Action::ScrollReview(app.half_page()),
+            (Screen::Review, KeyCode::Char('u')) => return Action::ScrollReview(-app.half_page()),
             (Screen::Proposal, KeyCode::Char('d')) => return Action::ScrollProposal(app.half_page()),
             (Screen::Proposal, KeyCode::Char('u')) => return Action::ScrollProposal(-app.half_page()),
+            (Screen::Error, KeyCode::Char('d')) => return Action::ScrollError(app.half_page()),
+            (Screen::Error, KeyCode::Char('u')) => return Action::ScrollError(-app.half_page()),
+            (Screen::Breadcrumbs, KeyCode::Char('d')) => {
+                return Action::MoveBreadcrumbSelection(app.half_page())
+            }
+            (Screen::Breadcrumbs, KeyCode::Char('u')) => {
+                return Action::MoveBreadcrumbSelection(-app.half_page())
+            }
+            (Screen::RawJson, KeyCode::Char('d')) => return Action::MoveJsonSelection(app.half_page()),
+            (Screen::RawJson, KeyCode::Char('u')) => return Action::MoveJsonSelection(-app.half_page()),
+            _ => {}
+        }
+    }
+
+    // Leaving a replay ends it rather than going back to a detail screen
+    if app.state.playback.is_some() && *app.screen() == Screen::Analysis {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::EndPlayback,
+            _ => {}
+        }
+    }
+
+    // The peek popup keeps list navigation but closes on q/Esc
+    if app.state.peek.is_some() && *app.screen() == Screen::List {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::TogglePeek,
             _ => {}
         }
     }
 
     // Delegate to screen-specific handler
     match app.screen() {
-        Screen::List => handle_list_input(key),
+        Screen::List => handle_list_input(app, key),
         Screen::Detail => handle_detail_input(app, key),
         Screen::Analysis => handle_analysis_input(key),
+        Screen::Implementation => handle_implementation_input(key),
+        Screen::Review => handle_review_input(key),
         Screen::Proposal => handle_proposal_input(key),
+        Screen::Error => handle_error_input(app, key),
+        Screen::Worktrees => handle_worktrees_input(key),
+        Screen::Breadcrumbs => handle_breadcrumbs_input(key),
+        Screen::RawJson => handle_json_input(key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_issue;
+    use crate::app::{ServerCapabilities, Trigger, TRANSITIONS};
+
+    #[test]
+    fn test_transition_keys_match_the_keymaps() {
+        let mut app = App::new("http://localhost:1".into(), None);
+        app.set_terminal_size(90, 24);
+        for transition in &TRANSITIONS {
+            let Trigger::Key { key, screen, action } = &transition.trigger else {
+                continue;
+            };
+            app.state.screen = screen.clone();
+            let routed = route_input(&app, KeyEvent::from(KeyCode::Char(*key)));
+            assert_eq!(requested_transition(&routed), Some(*action), "{} on {:?}", key, screen);
+        }
+    }
+
+    #[test]
+    fn test_hints_leave_out_disallowed_steps() {
+        let mut app = App::new("http://localhost:1".into(), None);
+        app.set_terminal_size(90, 24);
+        let issue = |status: &str| test_issue("A-1", status);
+        let has_analyze = |app: &App| hints(app).iter().any(|h| h.key == "a");
+
+        app.state.set_issues(vec![issue("pending")]);
+        assert!(has_analyze(&app));
+        app.state.set_issues(vec![issue("in_progress")]);
+        assert!(!has_analyze(&app));
+    }
+
+    #[test]
+    fn test_new_issue_needs_manual_issue_support() {
+        let mut app = App::new("http://localhost:1".into(), None);
+        app.set_terminal_size(90, 24);
+        let n = KeyEvent::from(KeyCode::Char('n'));
+        let has_new = |app: &App| hints(app).iter().any(|h| h.key == "n");
+
+        assert!(matches!(handle_input(&app, n), Action::OpenNewIssue));
+        assert!(has_new(&app));
+
+        app.state.capabilities = ServerCapabilities::Advertised(Default::default());
+        assert!(matches!(handle_input(&app, n), Action::None));
+        assert!(!has_new(&app));
+        assert!(!supported(&app, &parse_command("new").unwrap()));
+    }
+
+    #[test]
+    fn test_ctrl_c_quits_from_anywhere() {
+        let mut app = App::new("http://localhost:1".into(), None);
+        app.set_terminal_size(90, 24);
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+
+        app.state.screen = Screen::Detail;
+        assert!(matches!(route_input(&app, ctrl_c), Action::Quit));
+        // Even a prompt, which takes other keys as text
+        app.state.prompt = Some(crate::app::Prompt::RejectReason);
+        assert!(matches!(route_input(&app, ctrl_c), Action::Quit));
+        // And the too-small notice
+        app.state.prompt = None;
+        app.set_terminal_size(40, 10);
+        assert!(matches!(route_input(&app, ctrl_c), Action::Quit));
+    }
+
+    #[test]
+    fn test_shift_enter_is_a_newline_with_the_kitty_protocol() {
+        let mut app = App::new("http://localhost:1".into(), None);
+        app.set_terminal_size(90, 24);
+        app.state.prompt = Some(crate::app::Prompt::AnalyzeInstructions);
+        let enter = KeyEvent::from(KeyCode::Enter);
+        let shift_enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT);
+
+        // A legacy terminal sends both as plain Enter, so both are newlines
+        assert!(matches!(route_input(&app, enter), Action::PromptInput(_)));
+        assert!(matches!(route_input(&app, shift_enter), Action::PromptInput(_)));
+
+        app.state.keyboard_enhancement = true;
+        assert!(matches!(route_input(&app, enter), Action::SubmitPrompt));
+        assert!(matches!(route_input(&app, shift_enter), Action::PromptInput(_)));
+        assert!(hints(&app).iter().any(|h| h.key == "S-Enter"));
     }
 }
@@ -0,0 +1,76 @@
+//! Input handling for modal text prompts and the new issue form.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::app::App;
+use super::{route_focus, Action, KeyHint};
+
+/// Handle input while a prompt is open; everything else is an edit.
+///
+/// Multi-line prompts submit on Ctrl+S. Enter is a newline there unless the
+/// terminal can tell Shift+Enter apart, in which case Enter submits and
+/// Shift+Enter is the newline.
+pub fn handle_prompt_input(app: &App, key: KeyEvent) -> Action {
+    let multiline = app.state.prompt.is_some_and(|p| p.multiline());
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+    match key.code {
+        KeyCode::Enter if !multiline => Action::SubmitPrompt,
+        KeyCode::Enter if app.state.keyboard_enhancement && !shift => Action::SubmitPrompt,
+        KeyCode::Char('s') if ctrl && multiline => Action::SubmitPrompt,
+        KeyCode::Esc => Action::CancelPrompt,
+        _ => Action::PromptInput(key),
+    }
+}
+
+/// Keybinding hints while a prompt is open.
+pub fn prompt_hints(app: &App) -> Vec<KeyHint> {
+    let Some(prompt) = app.state.prompt else {
+        return Vec::new();
+    };
+    let mut hints = if prompt.multiline() && app.state.keyboard_enhancement {
+        vec![
+            KeyHint::new("Enter", prompt.submit_label(), 0),
+            KeyHint::new("S-Enter", "new line", 1),
+        ]
+    } else if prompt.multiline() {
+        vec![
+            KeyHint::new("C-s", prompt.submit_label(), 0),
+            KeyHint::new("Enter", "new line", 1),
+        ]
+    } else {
+        vec![KeyHint::new("Enter", prompt.submit_label(), 0)]
+    };
+    hints.push(KeyHint::new("Esc", "cancel", 0));
+    hints.push(KeyHint::new("C-u", "clear", 2));
+    hints
+}
+
+/// Handle input while the new issue form is open; Tab moves between
+/// fields and everything else goes to the focused one.
+pub fn handle_new_issue_input(app: &App, key: KeyEvent) -> Action {
+    let Some(form) = &app.state.new_issue else {
+        return Action::None;
+    };
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char('s') if ctrl => Action::SubmitNewIssue,
+        KeyCode::Esc => Action::CancelNewIssue,
+        _ => route_focus(&form.focus, key, Action::MoveNewIssueFocus, |field, key| match field {
+            // Enter in the title moves on to the description
+            Some(field) if key.code == KeyCode::Enter && !field.multiline() => Action::MoveNewIssueFocus(1),
+            _ => Action::NewIssueInput(key),
+        }),
+    }
+}
+
+/// Keybinding hints while the new issue form is open.
+pub fn new_issue_hints(app: &App) -> Vec<KeyHint> {
+    let multiline = app.state.new_issue.as_ref().is_some_and(|f| f.focused().multiline());
+    vec![
+        KeyHint::new("C-s", "create", 0),
+        KeyHint::new("Tab", "next field", 1),
+        KeyHint::new("Enter", if multiline { "new line" } else { "next field" }, 2),
+        KeyHint::new("Esc", "cancel", 0),
+        KeyHint::new("C-u", "clear", 3),
+    ]
+}
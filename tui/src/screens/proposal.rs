@@ -1,7 +1,8 @@
 //! Proposal screen input handling.
 
-use crossterm::event::{KeyCode, KeyEvent};
-use super::Action;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::app::{App, CritiqueStatus};
+use super::{supported, wrap_hints, Action, KeyHint, SIDEWAYS_STEP};
 
 /// Handle input on the proposal screen.
 pub fn handle_proposal_input(key: KeyEvent) -> Action {
@@ -11,6 +12,65 @@ pub fn handle_proposal_input(key: KeyEvent) -> Action {
         KeyCode::Char('k') | KeyCode::Up => Action::ScrollProposal(-1),
         KeyCode::Char('A') => Action::ApproveProposal,
         KeyCode::Char('x') => Action::RejectProposal,
+        KeyCode::Char('n') => Action::ToggleDiffLineNumbers,
+        KeyCode::Char('p') => Action::TogglePlanPreview,
+        KeyCode::Char('c') => Action::ToggleCritique,
+        KeyCode::Char('J') => Action::ScrollCritique(1),
+        KeyCode::Char('K') => Action::ScrollCritique(-1),
+        KeyCode::Char('y') => Action::OpenYankMenu,
+        KeyCode::Char('w') => Action::ToggleWrap,
+        KeyCode::Char('h') | KeyCode::Left => Action::ScrollColumns(-SIDEWAYS_STEP),
+        KeyCode::Char('l') | KeyCode::Right => Action::ScrollColumns(SIDEWAYS_STEP),
         _ => Action::None,
     }
 }
+
+/// Handle input while the plan preview is open.
+pub fn handle_plan_preview_input(app: &App, key: KeyEvent) -> Action {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char('p') | KeyCode::Char('q') | KeyCode::Esc => Action::TogglePlanPreview,
+        KeyCode::Char('j') | KeyCode::Down => Action::ScrollPlanPreview(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::ScrollPlanPreview(-1),
+        KeyCode::Char('d') if ctrl => Action::ScrollPlanPreview(app.half_page()),
+        KeyCode::Char('u') if ctrl => Action::ScrollPlanPreview(-app.half_page()),
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints while the plan preview is open.
+pub fn plan_preview_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("p/q/Esc", "close", 0),
+        KeyHint::new("↑↓/jk/C-d/u", "scroll", 1),
+    ]
+}
+
+/// Keybinding hints for the proposal screen.
+pub fn proposal_hints(app: &App) -> Vec<KeyHint> {
+    let mut hints = vec![
+        KeyHint::new("q/Esc", "back", 1),
+        KeyHint::new("↑↓/C-d/u", "scroll", 2),
+        KeyHint::new("A", "approve", 0),
+        KeyHint::new("x", "reject", 0),
+        KeyHint::new("y", "copy", 3),
+    ];
+    if supported(app, &Action::TogglePlanPreview) {
+        hints.push(KeyHint::new("p", "preview plan", 1));
+    }
+    match &app.state.critique {
+        None if supported(app, &Action::ToggleCritique) => {
+            hints.push(KeyHint::new("c", "critic pass", 1));
+        }
+        None => {}
+        Some(critique) => {
+            hints.push(KeyHint::new("J/K", "scroll critique", 2));
+            if critique.status != CritiqueStatus::Streaming {
+                hints.push(KeyHint::new("c", "close critique", 1));
+            }
+        }
+    }
+    hints.push(KeyHint::new("n", "diff line numbers", 3));
+    hints.extend(wrap_hints(&app.state.proposal_scroll));
+    hints
+}
@@ -0,0 +1,99 @@
+//! Review screen input handling.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::app::{App, PullRequest, TestStatus};
+use super::{Action, KeyHint};
+
+/// Handle input on the review screen.
+pub fn handle_review_input(key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => Action::BackToDetail,
+        KeyCode::Char('j') | KeyCode::Down => Action::ScrollReview(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::ScrollReview(-1),
+        KeyCode::Char(']') | KeyCode::Tab => Action::SelectReviewFile(1),
+        KeyCode::Char('[') | KeyCode::BackTab => Action::SelectReviewFile(-1),
+        KeyCode::Char('n') => Action::ToggleDiffLineNumbers,
+        KeyCode::Char('r') => Action::ReloadReview,
+        KeyCode::Char('d') => Action::CompleteReview,
+        KeyCode::Char('t') => Action::RunWorktreeTests,
+        KeyCode::Char('T') => Action::CancelWorktreeTests,
+        KeyCode::Char('P') => Action::OpenPullRequest,
+        KeyCode::Char('y') => Action::CopyPullRequestUrl,
+        KeyCode::Char('J') => Action::ScrollTestOutput(1),
+        KeyCode::Char('K') => Action::ScrollTestOutput(-1),
+        _ => Action::None,
+    }
+}
+
+/// Handle input while the review completion dialog is open.
+pub fn handle_finish_review_input(key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Enter | KeyCode::Char('d') => Action::ConfirmCompleteReview,
+        KeyCode::Char('D') => Action::DiscardAndCompleteReview,
+        KeyCode::Char('e') => Action::EditCommitMessage,
+        KeyCode::Char('s') => Action::SquashCommits,
+        KeyCode::Char('q') | KeyCode::Esc => Action::CancelCompleteReview,
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints while the review completion dialog is open.
+pub fn finish_review_hints(app: &App) -> Vec<KeyHint> {
+    let dialog = app.state.finish_review.as_ref();
+    let commits = match dialog.and_then(|d| d.log.as_ref()) {
+        Some(Ok(log)) => log.commits.len(),
+        _ => 0,
+    };
+    let at_risk = matches!(dialog.and_then(|d| d.at_risk.as_ref()), Some(Ok(a)) if !a.is_empty());
+    let mut hints = if at_risk {
+        vec![KeyHint::new("D", "discard changes and complete", 0)]
+    } else {
+        vec![KeyHint::new("Enter", "complete", 0)]
+    };
+    if commits > 0 {
+        hints.push(KeyHint::new("e", "edit message", 0));
+    }
+    if commits > 1 {
+        hints.push(KeyHint::new("s", "squash", 0));
+    }
+    hints.push(KeyHint::new("q/Esc", "cancel", 0));
+    hints
+}
+
+/// Keybinding hints for the review screen.
+pub fn review_hints(app: &App) -> Vec<KeyHint> {
+    let mut hints = vec![
+        KeyHint::new("d", "done", 0),
+        KeyHint::new("q/Esc", "back", 0),
+        KeyHint::new("↑↓/jk/C-d/u", "scroll", 2),
+        KeyHint::new("r", "reload", 3),
+    ];
+    if matches!(&app.state.review_diff, Some(Ok(files)) if files.len() > 1) {
+        hints.insert(1, KeyHint::new("[/]", "files", 1));
+    }
+    let testing = app.state.test_run.as_ref().is_some_and(|r| r.status == TestStatus::Running);
+    if testing {
+        hints.push(KeyHint::new("T", "stop tests", 1));
+    } else {
+        hints.push(KeyHint::new("t", "run tests", 1));
+    }
+    if pull_request_url(app).is_some() {
+        hints.push(KeyHint::new("y", "copy PR URL", 1));
+    } else {
+        hints.push(KeyHint::new("P", "push & open PR", 1));
+    }
+    if app.state.test_run.is_some() {
+        hints.push(KeyHint::new("J/K", "scroll tests", 2));
+    }
+    hints.push(KeyHint::new("n", "diff line numbers", 3));
+    hints
+}
+
+/// URL of the pull request opened for the issue under review.
+pub fn pull_request_url(app: &App) -> Option<&str> {
+    let issue = app.state.current_issue.as_ref()?;
+    match app.state.pull_requests.get(&issue.id)? {
+        PullRequest::Opened(url) => Some(url),
+        PullRequest::Opening => None,
+    }
+}
@@ -0,0 +1,28 @@
+//! Worktree management screen input handling.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use super::{Action, KeyHint};
+
+/// Handle input on the worktrees screen.
+pub fn handle_worktrees_input(key: KeyEvent) -> Action {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => Action::BackFromWorktrees,
+        KeyCode::Char('j') | KeyCode::Down => Action::MoveWorktreeSelection(1),
+        KeyCode::Char('k') | KeyCode::Up => Action::MoveWorktreeSelection(-1),
+        KeyCode::Char('d') => Action::RemoveWorktree,
+        KeyCode::Char('p') => Action::PruneWorktrees,
+        KeyCode::Char('r') => Action::ReloadWorktrees,
+        _ => Action::None,
+    }
+}
+
+/// Keybinding hints for the worktrees screen.
+pub fn worktrees_hints() -> Vec<KeyHint> {
+    vec![
+        KeyHint::new("↑↓/jk", "navigate", 2),
+        KeyHint::new("d d", "remove", 0),
+        KeyHint::new("p", "prune stale", 1),
+        KeyHint::new("r", "reload", 1),
+        KeyHint::new("q/Esc", "back", 0),
+    ]
+}
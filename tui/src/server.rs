@@ -2,63 +2,146 @@
 //!
 //! Handles finding, starting, and stopping the glass-server process.
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
+use reqwest::Url;
 use std::env;
+use std::net::{IpAddr, Ipv4Addr, TcpListener};
 use std::path::PathBuf;
-use std::process::{Child, Command, Stdio};
+use std::process::{Child, Command, ExitStatus, Stdio};
 use std::time::Duration;
 
-const SERVER_PORT: u16 = 7420;
+use crate::logging;
+
+/// Port glass-server listens on unless told otherwise.
+pub const DEFAULT_PORT: u16 = 7420;
 const SERVER_BINARY: &str = "glass-server";
+/// How long a just-started server gets to answer a health check. The UI is
+/// up meanwhile, so this can be generous.
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The `--port` flag: a port, or "auto" for any free one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortChoice {
+    Fixed(u16),
+    Auto,
+}
+
+impl std::str::FromStr for PortChoice {
+    type Err = String;
+
+    fn from_str(arg: &str) -> Result<Self, String> {
+        if arg.eq_ignore_ascii_case("auto") {
+            return Ok(PortChoice::Auto);
+        }
+        match arg.parse::<u16>() {
+            Ok(port) if port > 0 => Ok(PortChoice::Fixed(port)),
+            _ => Err(format!("expected a port (1-65535) or \"auto\", got {:?}", arg)),
+        }
+    }
+}
+
+/// A server for glass to start for a project, unless one is already
+/// listening on its port.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerLaunch {
+    pub project: String,
+    pub port: u16,
+}
+
+impl ServerLaunch {
+    /// How to start the same server by hand.
+    pub fn command_line(&self) -> String {
+        if self.port == DEFAULT_PORT {
+            format!("{} {}", SERVER_BINARY, self.project)
+        } else {
+            format!("{} --port {} {}", SERVER_BINARY, self.port, self.project)
+        }
+    }
+}
+
+/// Why a server glass started never came up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartFailure {
+    pub error: String,
+    /// The last lines it printed, if it got as far as printing any
+    pub output: Vec<String>,
+}
 
 /// Manages the glass-server process lifecycle.
 pub struct ServerProcess {
     child: Child,
+    port: u16,
+    /// Where this server's output starts in the output file, if it's
+    /// being captured
+    output_start: Option<u64>,
 }
 
 impl ServerProcess {
-    /// Start the server, or return None if it's already running.
-    pub async fn start(project_path: &str) -> Result<Option<Self>> {
-        // Check if server is already running
-        if is_server_running().await {
-            return Ok(None);
-        }
+    /// Whether a server is already running on `port`, so there's nothing
+    /// to start.
+    pub async fn is_running(port: u16) -> bool {
+        is_server_running(port).await
+    }
 
+    /// Start the server. It takes a moment to come up; `wait_for_ready`
+    /// says when it has.
+    pub fn spawn(launch: &ServerLaunch) -> Result<Self> {
         // Find the server binary
         let server_path = find_server_binary()?;
 
+        // Its output goes to a file to look at when it misbehaves
+        let output = logging::open_server_output()
+            .and_then(|(file, start)| Some((file.try_clone().ok()?, file, start)));
+        let (stdout, stderr, output_start) = match output {
+            Some((stdout, stderr, start)) => (Stdio::from(stdout), Stdio::from(stderr), Some(start)),
+            None => (Stdio::null(), Stdio::null(), None),
+        };
+
         // Start the server
         let child = Command::new(&server_path)
-            .arg(project_path)
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
+            .arg("--port")
+            .arg(launch.port.to_string())
+            .arg(&launch.project)
+            .stdout(stdout)
+            .stderr(stderr)
             .spawn()
             .map_err(|e| anyhow!("Failed to start server at {:?}: {}", server_path, e))?;
 
-        let server = ServerProcess { child };
+        Ok(ServerProcess { child, port: launch.port, output_start })
+    }
 
-        // Wait for server to be ready
-        server.wait_for_ready().await?;
+    /// The last `max_lines` lines this server printed.
+    pub fn output_tail(&self, max_lines: usize) -> Vec<String> {
+        self.output_start
+            .map(|start| logging::server_output_since(start, max_lines))
+            .unwrap_or_default()
+    }
 
-        Ok(Some(server))
+    /// How it exited, if it has.
+    pub fn exit_status(&mut self) -> Option<ExitStatus> {
+        self.child.try_wait().ok().flatten()
     }
 
-    /// Wait for the server to respond to health checks.
-    async fn wait_for_ready(&self) -> Result<()> {
+    /// Wait for the server to respond to health checks, giving up early if
+    /// it exits.
+    pub async fn wait_for_ready(&mut self) -> Result<()> {
         let client = reqwest::Client::new();
-        let url = format!("http://localhost:{}/health", SERVER_PORT);
+        let url = health_url(self.port);
+        let deadline = tokio::time::Instant::now() + READY_TIMEOUT;
 
-        for _ in 0..50 {
-            // 5 seconds max
+        while tokio::time::Instant::now() < deadline {
             if let Ok(resp) = client.get(&url).send().await {
                 if resp.status().is_success() {
                     return Ok(());
                 }
             }
+            if let Some(status) = self.child.try_wait()? {
+                return Err(anyhow!("Server exited with {}", status));
+            }
             tokio::time::sleep(Duration::from_millis(100)).await;
         }
 
-        Err(anyhow!("Server failed to start within 5 seconds"))
+        Err(anyhow!("Server failed to start within {} seconds", READY_TIMEOUT.as_secs()))
     }
 }
 
@@ -70,14 +153,14 @@ impl Drop for ServerProcess {
     }
 }
 
-/// Check if a server is already running on the expected port.
-async fn is_server_running() -> bool {
+/// Check if a server is already running on `port`.
+async fn is_server_running(port: u16) -> bool {
     let client = reqwest::Client::builder()
         .timeout(Duration::from_millis(500))
         .build()
         .unwrap();
 
-    let url = format!("http://localhost:{}/health", SERVER_PORT);
+    let url = health_url(port);
 
     client
         .get(&url)
@@ -87,6 +170,49 @@ async fn is_server_running() -> bool {
         .unwrap_or(false)
 }
 
+fn health_url(port: u16) -> String {
+    format!("http://localhost:{}/health", port)
+}
+
+/// The server URL to talk to and the port to start a server on: `server`
+/// with its port replaced by `port` when one is given, or otherwise the
+/// port it names. A localhost URL without a port means the default port,
+/// where Glass starts its own server; any other URL keeps its scheme's.
+pub fn resolve_server(server: &str, port: Option<u16>) -> Result<(String, u16)> {
+    let mut url = Url::parse(server).with_context(|| format!("Invalid server URL: {}", server))?;
+    let port = match port.or(url.port()) {
+        Some(port) => port,
+        None if is_local(&url) => DEFAULT_PORT,
+        None => url
+            .port_or_known_default()
+            .ok_or_else(|| anyhow!("Server URL {} needs a port", server))?,
+    };
+    if url.port_or_known_default() != Some(port) {
+        url.set_port(Some(port))
+            .map_err(|_| anyhow!("Can't set a port on server URL {}", server))?;
+    }
+    Ok((url.as_str().trim_end_matches('/').to_string(), port))
+}
+
+/// Whether `url` points at this machine.
+fn is_local(url: &Url) -> bool {
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// A port nothing is listening on right now, for a server of our own.
+pub fn free_port() -> Result<u16> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).context("No free port to start the server on")?;
+    Ok(listener.local_addr()?.port())
+}
+
 /// Find the server binary in various locations.
 fn find_server_binary() -> Result<PathBuf> {
     // 1. Same directory as the TUI binary
@@ -124,3 +250,59 @@ fn find_server_binary() -> Result<PathBuf> {
         SERVER_BINARY
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_port_comes_from_the_flag_or_the_url() {
+        assert_eq!(
+            resolve_server("http://localhost:7420", None).unwrap(),
+            ("http://localhost:7420".to_string(), 7420)
+        );
+        assert_eq!(
+            resolve_server("http://localhost:7421/", None).unwrap(),
+            ("http://localhost:7421".to_string(), 7421)
+        );
+        assert_eq!(
+            resolve_server("http://localhost:7420", Some(7500)).unwrap(),
+            ("http://localhost:7500".to_string(), 7500)
+        );
+        // A URL without a port means the default one, not port 80
+        assert_eq!(
+            resolve_server("http://localhost", None).unwrap(),
+            ("http://localhost:7420".to_string(), 7420)
+        );
+        assert_eq!(
+            resolve_server("http://127.0.0.1", None).unwrap(),
+            ("http://127.0.0.1:7420".to_string(), 7420)
+        );
+        // A remote server without a port is on its scheme's default one
+        assert_eq!(
+            resolve_server("https://glass.example.com", None).unwrap(),
+            ("https://glass.example.com".to_string(), 443)
+        );
+        assert_eq!(
+            resolve_server("https://glass.example.com", Some(8443)).unwrap(),
+            ("https://glass.example.com:8443".to_string(), 8443)
+        );
+        assert!(resolve_server("localhost:7420", None).is_err());
+    }
+
+    #[test]
+    fn test_port_choice() {
+        assert_eq!("7421".parse(), Ok(PortChoice::Fixed(7421)));
+        assert_eq!("auto".parse(), Ok(PortChoice::Auto));
+        assert!("0".parse::<PortChoice>().is_err());
+        assert!("70000".parse::<PortChoice>().is_err());
+    }
+
+    #[test]
+    fn test_launch_command_line() {
+        let launch = ServerLaunch { project: "/work/web".to_string(), port: DEFAULT_PORT };
+        assert_eq!(launch.command_line(), "glass-server /work/web");
+        let launch = ServerLaunch { port: 7421, ..launch };
+        assert_eq!(launch.command_line(), "glass-server --port 7421 /work/web");
+    }
+}
@@ -0,0 +1,159 @@
+//! Spell checking for prompt input, behind the `spellcheck` feature.
+//!
+//! Words are looked up in a plain word list, one word per line, such as
+//! `/usr/share/dict/words`. Hunspell `.dic` files work too: the leading
+//! word count is skipped and affix flags after `/` are dropped (so only
+//! the stems are known). Anything that looks like code (identifiers with
+//! digits, underscores, dots or inner capitals) is left alone.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::config::SpellcheckConfig;
+
+/// Word list used when the config doesn't name one.
+pub const DEFAULT_DICTIONARY: &str = "/usr/share/dict/words";
+
+/// Known words, lowercased.
+#[derive(Debug, Default)]
+pub struct Dictionary {
+    words: HashSet<String>,
+}
+
+impl Dictionary {
+    /// Load the configured dictionary plus the project's own words. `None`
+    /// if there's no word list to check against.
+    pub fn from_config(config: &SpellcheckConfig) -> Option<Self> {
+        let path = config.dictionary.as_deref().unwrap_or(Path::new(DEFAULT_DICTIONARY));
+        let mut dictionary = match Self::load(path) {
+            Ok(dictionary) => dictionary,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "No spellcheck dictionary");
+                return None;
+            }
+        };
+        dictionary.extend(config.words.iter().map(String::as_str));
+        Some(dictionary)
+    }
+
+    /// Read a word list or Hunspell `.dic` file.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let mut dictionary = Self::default();
+        dictionary.extend(
+            text.lines()
+                .map(|line| line.split('/').next().unwrap_or_default().trim())
+                .filter(|word| !word.is_empty() && !word.chars().all(|c| c.is_ascii_digit())),
+        );
+        Ok(dictionary)
+    }
+
+    pub fn extend<'a>(&mut self, words: impl IntoIterator<Item = &'a str>) {
+        self.words.extend(words.into_iter().map(str::to_lowercase));
+    }
+
+    /// Whether a word is spelled right (or isn't checked at all).
+    pub fn is_known(&self, word: &str) -> bool {
+        let lower = word.to_lowercase();
+        self.words.contains(&lower)
+            || lower
+                .strip_suffix("'s")
+                .or_else(|| lower.strip_suffix('\''))
+                .is_some_and(|stem| self.words.contains(stem))
+    }
+
+    /// Byte ranges of misspelled words in `line`. A word ending at `cursor`
+    /// is still being typed and isn't flagged.
+    pub fn misspellings(&self, line: &str, cursor: Option<usize>) -> Vec<Range<usize>> {
+        words(line)
+            .filter(|range| Some(range.end) != cursor)
+            .filter(|range| checkable(&line[range.clone()]))
+            .filter(|range| !self.is_known(&line[range.clone()]))
+            .collect()
+    }
+}
+
+/// Byte ranges of whitespace-separated tokens, with surrounding punctuation
+/// trimmed and hyphenated words split.
+fn words(line: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut start = None;
+    line.char_indices()
+        .chain(std::iter::once((line.len(), ' ')))
+        .filter_map(move |(index, c)| {
+            if c.is_whitespace() || c == '-' {
+                start.take().map(|start| start..index)
+            } else {
+                start.get_or_insert(index);
+                None
+            }
+        })
+        .filter_map(|range| {
+            let token = &line[range.clone()];
+            let trimmed = token.trim_start_matches(|c: char| !c.is_alphanumeric());
+            let lead = token.len() - trimmed.len();
+            let trimmed = trimmed.trim_end_matches(|c: char| !c.is_alphanumeric() && c != '\'');
+            let trimmed = trimmed.trim_end_matches('\'');
+            (!trimmed.is_empty()).then(|| range.start + lead..range.start + lead + trimmed.len())
+        })
+}
+
+/// Whether a token is prose rather than code or a name: letters and
+/// apostrophes only, and no capitals after the first letter (except in
+/// all-caps acronyms, which are skipped too).
+fn checkable(word: &str) -> bool {
+    if word.chars().count() < 2 || !word.chars().all(|c| c.is_alphabetic() || c == '\'') {
+        return false;
+    }
+    !word.chars().skip(1).any(char::is_uppercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dictionary() -> Dictionary {
+        let mut dictionary = Dictionary::default();
+        dictionary.extend(["please", "check", "the", "null", "user", "first", "it"]);
+        dictionary
+    }
+
+    fn flagged(line: &str, cursor: Option<usize>) -> Vec<&str> {
+        dictionary()
+            .misspellings(line, cursor)
+            .into_iter()
+            .map(|range| &line[range])
+            .collect()
+    }
+
+    #[test]
+    fn test_flags_unknown_words() {
+        assert_eq!(flagged("Please chekc the user's null-check first.", None), vec!["chekc"]);
+        assert_eq!(flagged("(teh) user", None), vec!["teh"]);
+    }
+
+    #[test]
+    fn test_skips_code_and_names() {
+        assert!(flagged("check getUserName user_id v2 src/user.ts HTTP it's", None).is_empty());
+        assert!(flagged("check the TypeError", None).is_empty());
+    }
+
+    #[test]
+    fn test_word_being_typed_is_not_flagged() {
+        assert!(flagged("check teh", Some(9)).is_empty());
+        assert_eq!(flagged("check teh", Some(5)), vec!["teh"]);
+    }
+
+    #[test]
+    fn test_hunspell_dic_stems() {
+        let path = std::env::temp_dir().join(format!("glass-spell-{}.dic", std::process::id()));
+        fs::write(&path, "3\nhello/MS\nworld\n").unwrap();
+        let dictionary = Dictionary::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(dictionary.is_known("Hello"));
+        assert!(dictionary.is_known("world's"));
+        assert!(!dictionary.is_known("3"));
+    }
+}
@@ -0,0 +1,91 @@
+//! Startup profiling for `--profile-startup`.
+//!
+//! Phases overlap once the server's readiness and the cached list load run
+//! behind the first frame, so each is recorded as the time since launch it
+//! finished at rather than as a share of the total.
+
+use std::time::{Duration, Instant};
+
+use tracing::info;
+
+/// When each startup phase finished, if profiling.
+#[derive(Debug)]
+pub struct StartupProfile {
+    /// `None` unless profiling
+    start: Option<Instant>,
+    phases: Vec<(&'static str, Duration)>,
+}
+
+impl Default for StartupProfile {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl StartupProfile {
+    /// Start the clock, or a profile that records nothing.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            start: enabled.then(Instant::now),
+            phases: Vec::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.start.is_some()
+    }
+
+    /// Record that `phase` just finished. Only the first time counts, so
+    /// phases that repeat (refreshes) can be marked every time.
+    pub fn record(&mut self, phase: &'static str) {
+        let Some(start) = self.start else {
+            return;
+        };
+        if self.phases.iter().any(|(p, _)| *p == phase) {
+            return;
+        }
+        let elapsed = start.elapsed();
+        info!(phase, elapsed_ms = elapsed.as_millis() as u64, "Startup phase finished");
+        self.phases.push((phase, elapsed));
+    }
+
+    /// One line per phase with when it finished, in the order they did.
+    pub fn summary(&self) -> Option<String> {
+        self.start?;
+        let mut phases = self.phases.clone();
+        phases.sort_by_key(|(_, at)| *at);
+        let width = phases.iter().map(|(p, _)| p.len()).max().unwrap_or(0);
+        let mut text = String::from("Startup profile (time since launch):\n");
+        for (phase, at) in phases {
+            text.push_str(&format!("  {:<w$}  {:>8.1}ms\n", phase, at.as_secs_f64() * 1000.0, w = width));
+        }
+        Some(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_profile_records_nothing() {
+        let mut profile = StartupProfile::new(false);
+        profile.record("first frame");
+        assert_eq!(profile.summary(), None);
+    }
+
+    #[test]
+    fn test_summary_sorts_by_finish_time() {
+        let mut profile = StartupProfile::new(true);
+        profile.phases = vec![
+            ("health wait", Duration::from_millis(800)),
+            ("first frame", Duration::from_millis(40)),
+        ];
+        // Repeats keep the first time
+        profile.record("health wait");
+        assert_eq!(
+            profile.summary().unwrap(),
+            "Startup profile (time since launch):\n  first frame      40.0ms\n  health wait     800.0ms\n"
+        );
+    }
+}
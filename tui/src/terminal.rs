@@ -0,0 +1,97 @@
+//! Terminal mode setup and teardown.
+//!
+//! Shared by startup, shutdown, and the escape hatch, which temporarily
+//! hands the terminal to another program.
+
+use anyhow::Result;
+use crossterm::{
+    event::{
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture,
+        KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
+    execute,
+    terminal::{
+        disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
+};
+use std::io::Write;
+use tracing::info;
+
+/// Terminal modes enabled for the TUI.
+#[derive(Debug, Clone, Copy)]
+pub struct TerminalModes {
+    /// Whether the kitty keyboard protocol is in use
+    keyboard_enhancement: bool,
+}
+
+impl TerminalModes {
+    /// Enter raw mode and the alternate screen, enabling optional
+    /// protocols the terminal supports.
+    pub fn enter<W: Write>(w: &mut W) -> Result<Self> {
+        enable_raw_mode()?;
+
+        // Legacy terminals can't report e.g. Shift+Enter or tell Ctrl+I from
+        // Tab; the kitty protocol can, so opt in when it's available.
+        let keyboard_enhancement = supports_keyboard_enhancement().unwrap_or(false);
+        info!(keyboard_enhancement, "Terminal capabilities detected");
+
+        let modes = Self {
+            keyboard_enhancement,
+        };
+        modes.resume(w)?;
+        Ok(modes)
+    }
+
+    /// Whether keys are reported with the kitty protocol, so e.g.
+    /// Shift+Enter can be told from Enter.
+    pub fn keyboard_enhancement(&self) -> bool {
+        self.keyboard_enhancement
+    }
+
+    /// Re-enable all modes after a previous `suspend`.
+    pub fn resume<W: Write>(&self, w: &mut W) -> Result<()> {
+        enable_raw_mode()?;
+        // Bracketed paste delivers pastes as one event instead of keystrokes;
+        // focus reporting tells us when to fall back to desktop notifications
+        execute!(
+            w,
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableBracketedPaste,
+            EnableFocusChange
+        )?;
+        if self.keyboard_enhancement {
+            // Every key as an escape code is what sets Shift+Enter apart from
+            // Enter; alternate keys keep Shift+a arriving as 'A', and event
+            // types let the event loop drop key releases
+            execute!(
+                w,
+                PushKeyboardEnhancementFlags(
+                    KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                        | KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES
+                        | KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS
+                        | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+                )
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Restore the terminal to its normal state.
+    pub fn suspend<W: Write>(&self, w: &mut W) -> Result<()> {
+        if self.keyboard_enhancement {
+            execute!(w, PopKeyboardEnhancementFlags)?;
+        }
+        execute!(
+            w,
+            DisableFocusChange,
+            DisableBracketedPaste,
+            LeaveAlternateScreen,
+            DisableMouseCapture
+        )?;
+        disable_raw_mode()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+//! Analysis transcript export.
+//!
+//! Writes what the agent did during an analysis to
+//! `<project>/.glass/transcripts/<issue>-<timestamp>.md`: the activity pane
+//! as it was displayed, followed by the raw events as JSONL so the run can
+//! be replayed or inspected with other tools.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::app::{ActivityLog, AnalysisProgress, EventLog};
+
+/// Directory under the project checkout that transcripts are written to.
+pub const TRANSCRIPT_DIR: &str = ".glass/transcripts";
+
+/// What to put in a transcript.
+pub struct Transcript<'a> {
+    /// Issue short ID, e.g. "PROJ-123"
+    pub issue: &'a str,
+    pub title: &'a str,
+    pub lines: &'a ActivityLog,
+    pub events: &'a EventLog,
+    pub progress: &'a AnalysisProgress,
+}
+
+impl Transcript<'_> {
+    /// Render as markdown, with the raw events in a fenced JSONL block.
+    pub fn to_markdown(&self, exported_at: OffsetDateTime) -> String {
+        let mut out = format!("# Analysis transcript: {} — {}\n\n", self.issue, self.title);
+
+        let exported = exported_at.format(&Rfc3339).unwrap_or_default();
+        out.push_str(&format!("- Exported: {}\n", exported));
+        if let Some(elapsed) = self.progress.elapsed() {
+            let secs = elapsed.as_secs();
+            out.push_str(&format!("- Duration: {}:{:02}\n", secs / 60, secs % 60));
+        }
+        out.push_str(&format!(
+            "- Events: {} ({} tool calls)\n",
+            self.progress.event_count, self.progress.tool_calls
+        ));
+        if let Some(usage) = self.progress.usage_summary() {
+            out.push_str(&format!("- Usage: {}\n", usage));
+        }
+
+        out.push_str("\n## Activity\n\n");
+        if self.lines.dropped() > 0 {
+            out.push_str(&format!(
+                "_{} older lines were truncated from the TUI buffer._\n\n",
+                self.lines.dropped()
+            ));
+        }
+        out.push_str("```text\n");
+        for line in self.lines.iter() {
+            out.push_str(line.icon);
+            out.push(' ');
+            out.push_str(&line.text);
+            out.push('\n');
+        }
+        out.push_str("```\n\n## Raw events\n\n");
+        if self.events.dropped() > 0 {
+            out.push_str(&format!("_{} earlier events were dropped", self.events.dropped()));
+            if let Some(path) = self.events.spill_path() {
+                out.push_str(&format!(" and saved to `{}`", path.display()));
+            }
+            out.push_str("._\n\n");
+        }
+        out.push_str("```jsonl\n");
+        for recorded in self.events.iter() {
+            if let Ok(json) = serde_json::to_string(&recorded.event) {
+                out.push_str(&json);
+                out.push('\n');
+            }
+        }
+        out.push_str("```\n");
+        out
+    }
+
+    /// Write the transcript under `project`, returning the file's path.
+    pub fn write(&self, project: &Path) -> io::Result<PathBuf> {
+        let now = OffsetDateTime::now_utc();
+        let dir = project.join(TRANSCRIPT_DIR);
+        fs::create_dir_all(&dir)?;
+
+        let path = dir.join(file_name(self.issue, now, "md"));
+        fs::write(&path, self.to_markdown(now))?;
+        Ok(path)
+    }
+}
+
+/// "PROJ-123-20260314-091502.md", with anything path-unsafe replaced.
+pub(crate) fn file_name(issue: &str, at: OffsetDateTime, extension: &str) -> String {
+    let issue: String = issue
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    format!(
+        "{}-{:04}{:02}{:02}-{:02}{:02}{:02}.{}",
+        issue,
+        at.year(),
+        u8::from(at.month()),
+        at.day(),
+        at.hour(),
+        at.minute(),
+        at.second(),
+        extension
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use crate::api::AnalysisEvent;
+    use crate::app::{ActivityLine, ActivityStyle, RecordedEvent};
+
+    fn at(timestamp: &str) -> OffsetDateTime {
+        OffsetDateTime::parse(timestamp, &Rfc3339).unwrap()
+    }
+
+    #[test]
+    fn test_file_name_is_timestamped_and_safe() {
+        let at = at("2026-03-14T09:15:02Z");
+        assert_eq!(file_name("PROJ-123", at, "md"), "PROJ-123-20260314-091502.md");
+        assert_eq!(file_name("a/b c", at, "cast"), "a_b_c-20260314-091502.cast");
+    }
+
+    #[test]
+    fn test_markdown_includes_lines_and_raw_events() {
+        let mut lines = ActivityLog::default();
+        lines.push(ActivityLine {
+            icon: "🔧",
+            text: "read path=src/main.rs".to_string(),
+            style: ActivityStyle::Tool,
+        });
+        let mut events = EventLog::default();
+        for event in [AnalysisEvent::Thinking, AnalysisEvent::TextDelta { delta: "hi".to_string() }] {
+            events.push(RecordedEvent {
+                offset: Duration::ZERO,
+                event,
+            });
+        }
+        let progress = AnalysisProgress::default();
+        let transcript = Transcript {
+            issue: "PROJ-1",
+            title: "Boom",
+            lines: &lines,
+            events: &events,
+            progress: &progress,
+        };
+
+        let md = transcript.to_markdown(at("2026-03-14T09:15:02Z"));
+        assert!(md.starts_with("# Analysis transcript: PROJ-1 — Boom\n"));
+        assert!(md.contains("- Exported: 2026-03-14T09:15:02Z\n"));
+        assert!(md.contains("🔧 read path=src/main.rs\n"));
+        assert!(md.contains("{\"type\":\"thinking\"}\n{\"type\":\"text_delta\",\"delta\":\"hi\"}\n"));
+    }
+}
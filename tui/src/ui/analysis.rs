@@ -1,5 +1,8 @@
 //! Analysis screen rendering.
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -8,7 +11,15 @@ use ratatui::{
     Frame,
 };
 
-use crate::app::{ActivityStyle, App};
+use crate::app::{ActivityLine, ActivityLog, ActivityStyle, App, ScrollView};
+use crate::config::{Palette, ThemeConfig};
+use crate::screens;
+
+use super::highlight::{self, CodeHighlighter};
+use super::hints::{hint_line, HintStyle};
+use super::icons::{activity_icon, glyph};
+use super::status::{format_clock, status_style};
+use super::{draw_scrollbar, scroll_paragraph, wrap_title};
 
 /// Draw the fullscreen analysis view.
 pub fn draw_analysis(f: &mut Frame, app: &App, area: Rect) {
@@ -28,24 +39,65 @@ pub fn draw_analysis(f: &mut Frame, app: &App, area: Rect) {
 
 /// Draw the header with issue title.
 fn draw_header(f: &mut Frame, app: &App, area: Rect) {
-    let title = app
-        .state
-        .current_issue
-        .as_ref()
-        .and_then(|i| i.source.title.clone())
-        .unwrap_or_else(|| "Analysis".to_string());
-
-    let status_indicator = if app.state.is_streaming_analysis {
-        Span::styled(" ◐ analyzing", Style::default().fg(Color::Yellow))
+    let title = match &app.state.playback {
+        Some(playback) => playback.title.clone(),
+        None => app
+            .state
+            .current_issue
+            .as_ref()
+            .and_then(|i| i.source.title.clone())
+            .unwrap_or_else(|| "Analysis".to_string()),
+    };
+
+    let theme = &app.state.theme;
+    let status_indicator = if let Some(playback) = &app.state.playback {
+        let label = if playback.pending.is_empty() {
+            format!(" {} replay finished", glyph("✓", theme))
+        } else {
+            let clock = format_clock(playback.started_at.elapsed(), theme);
+            format!(" {} replay {}", glyph("▶", theme), clock)
+        };
+        Span::styled(label, Style::default().fg(Color::Cyan))
+    } else if app.state.is_streaming_analysis {
+        let style = status_style("analyzing", theme);
+        Span::styled(
+            format!(" {} analyzing", style.marker(theme)),
+            Style::default().fg(style.color),
+        )
     } else {
-        Span::styled(" ✓ complete", Style::default().fg(Color::Green))
+        Span::styled(
+            format!(" {} complete", glyph("✓", theme)),
+            Style::default().fg(Color::Green),
+        )
     };
 
-    let header = Paragraph::new(Line::from(vec![
+    let mut spans = vec![
         Span::raw(" "),
         Span::styled(&title, Style::default().add_modifier(Modifier::BOLD)),
         status_indicator,
-    ]))
+    ];
+
+    let progress = &app.state.analysis_progress;
+    if let Some(elapsed) = progress.elapsed() {
+        spans.push(Span::styled(
+            format!(
+                "  {} {} · {} events · {} tool calls",
+                glyph("⏱", theme),
+                format_clock(elapsed, theme),
+                progress.event_count,
+                progress.tool_calls
+            ),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    if let Some(usage) = progress.usage_summary() {
+        spans.push(Span::styled(
+            format!(" · {}", usage),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let header = Paragraph::new(Line::from(spans))
     .block(Block::default().borders(Borders::ALL).title(" Analysis "));
 
     f.render_widget(header, area);
@@ -53,78 +105,138 @@ fn draw_header(f: &mut Frame, app: &App, area: Rect) {
 
 /// Draw the analysis content.
 fn draw_content(f: &mut Frame, app: &App, area: Rect) {
+    draw_activity(
+        f,
+        area,
+        Block::default().borders(Borders::ALL),
+        &app.state.analysis_lines,
+        &app.state.analysis_scroll,
+        app.state.is_streaming_analysis,
+        &app.state.theme,
+    );
+}
+
+/// Draw activity lines in `block`, with a cursor while more are streaming.
+/// Shared with the implementation screen and the review screen's test pane.
+pub fn draw_activity(
+    f: &mut Frame,
+    area: Rect,
+    block: Block<'_>,
+    log: &ActivityLog,
+    scroll: &ScrollView,
+    streaming: bool,
+    theme: &ThemeConfig,
+) {
     let mut lines: Vec<Line> = Vec::new();
 
-    // Calculate visible height (area height minus borders)
-    let visible_height = area.height.saturating_sub(2) as usize;
-
-    // Auto-scroll: if we have more lines than visible, show the last N lines
-    let total_lines = app.state.analysis_lines.len();
-    let skip = if app.state.analysis_scroll > 0 {
-        // Manual scroll position
-        app.state.analysis_scroll
-    } else if total_lines > visible_height {
-        // Auto-scroll to bottom
-        total_lines - visible_height
+    if log.dropped() > 0 {
+        let mut notice = format!("{} {} older lines truncated", glyph("⋯", theme), log.dropped());
+        if let Some(path) = log.spill_path() {
+            notice.push_str(&format!(" (saved to {})", path.display()));
+        }
+        lines.push(Line::from(Span::styled(notice, Style::default().fg(Color::DarkGray))));
+    }
+
+    // Borders plus the icon column. Unwrapped, long lines scroll sideways
+    // so code keeps its alignment.
+    let text_width = area.width.saturating_sub(2 + 3) as usize;
+    let mut key = DefaultHasher::new();
+    (scroll.wraps().then_some(text_width), theme.palette, theme.icons).hash(&mut key);
+    lines.extend(log.styled(key.finish(), || {
+        if scroll.wraps() {
+            style_activity(log.wrapped(text_width).iter(), theme)
+        } else {
+            style_activity(log.iter(), theme)
+        }
+    }));
+
+    // Add cursor if streaming
+    if streaming {
+        lines.push(Line::from(Span::styled(
+            "  ▊",
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+
+    // The scroll view follows new output unless the user scrolled up, or
+    // motion is reduced, when the border says there's more instead
+    let block = wrap_title(block, scroll, theme);
+    let block = if streaming && theme.reduced_motion && scroll.offset() < scroll.max_offset() {
+        block.title_bottom(
+            Line::from(Span::styled(
+                format!(" {} more below ", glyph("↓", theme)),
+                Style::default().fg(Color::Yellow),
+            ))
+            .right_aligned(),
+        )
+    } else {
+        block
+    };
+    scroll.set_rendered_width(
+        lines.iter().map(Line::width).max().unwrap_or(0),
+        area.width.saturating_sub(2) as usize,
+    );
+    let paragraph = Paragraph::new(lines).block(block);
+    let paragraph = if scroll.wraps() {
+        paragraph.wrap(Wrap { trim: false })
     } else {
-        0
+        paragraph
     };
+    let paragraph = scroll_paragraph(paragraph, area, scroll);
+
+    f.render_widget(paragraph, area);
+    draw_scrollbar(f, area, scroll);
+}
 
-    for activity in app.state.analysis_lines.iter().skip(skip) {
+/// Activity lines styled for display, code blocks highlighted.
+fn style_activity<'a>(activities: impl Iterator<Item = &'a ActivityLine>, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let palette = theme.palette;
+    // Started at each opening fence; a block whose fence was truncated
+    // away is shown plain
+    let mut code: Option<CodeHighlighter> = None;
+    for activity in activities {
+        if activity.style == ActivityStyle::Code {
+            let mut spans = vec![Span::raw(format!("{} ", activity_icon(activity.icon, theme)))];
+            if let Some(language) = highlight::fence_language(&activity.text) {
+                code = match code {
+                    Some(_) => None,
+                    None => Some(CodeHighlighter::new(language, palette)),
+                };
+                spans.push(Span::styled(activity.text.clone(), Style::default().fg(Color::DarkGray)));
+            } else {
+                let highlighter = code.get_or_insert_with(|| CodeHighlighter::new("", palette));
+                spans.extend(highlighter.highlight(&activity.text));
+            }
+            lines.push(Line::from(spans));
+            continue;
+        }
+
+        // White disappears on a light background; fall back to the terminal's own text color
+        let plain = if palette == Palette::Light { Color::Reset } else { Color::White };
         let (icon_color, text_color) = match activity.style {
-            ActivityStyle::Normal => (Color::White, Color::White),
+            ActivityStyle::Normal => (plain, plain),
             ActivityStyle::Dimmed => (Color::DarkGray, Color::DarkGray),
             ActivityStyle::Tool => (Color::Cyan, Color::Cyan),
             ActivityStyle::Thinking => (Color::Yellow, Color::Yellow),
             ActivityStyle::Error => (Color::Red, Color::Red),
             ActivityStyle::Success => (Color::Green, Color::Green),
+            ActivityStyle::Code => (plain, plain),
         };
 
         lines.push(Line::from(vec![
-            Span::styled(format!("{} ", activity.icon), Style::default().fg(icon_color)),
-            Span::styled(&activity.text, Style::default().fg(text_color)),
+            Span::styled(
+                format!("{} ", activity_icon(activity.icon, theme)),
+                Style::default().fg(icon_color),
+            ),
+            Span::styled(activity.text.clone(), Style::default().fg(text_color)),
         ]));
     }
-
-    // Add cursor if streaming
-    if app.state.is_streaming_analysis {
-        lines.push(Line::from(Span::styled(
-            "  ▊",
-            Style::default().fg(Color::Yellow),
-        )));
-    }
-
-    let paragraph = Paragraph::new(lines)
-        .block(Block::default().borders(Borders::ALL))
-        .wrap(Wrap { trim: false });
-
-    f.render_widget(paragraph, area);
+    lines
 }
 
 /// Draw the footer with keybindings.
 fn draw_footer(f: &mut Frame, app: &App, area: Rect) {
-    let keys = if app.state.is_streaming_analysis {
-        vec![
-            ("q/Esc", "back"),
-            ("↑↓/C-d/u", "scroll"),
-        ]
-    } else {
-        vec![
-            ("q/Esc", "back to detail"),
-            ("↑↓/C-d/u", "scroll"),
-        ]
-    };
-
-    let spans: Vec<Span> = keys
-        .iter()
-        .flat_map(|(key, desc)| {
-            vec![
-                Span::styled(format!(" [{}]", key), Style::default().fg(Color::Cyan)),
-                Span::styled(format!(" {} ", desc), Style::default().fg(Color::DarkGray)),
-            ]
-        })
-        .collect();
-
-    let footer = Paragraph::new(Line::from(spans));
-    f.render_widget(footer, area);
+    let line = hint_line(&screens::hints(app), area.width, HintStyle::Footer);
+    f.render_widget(Paragraph::new(line), area);
 }
@@ -0,0 +1,81 @@
+//! Server status banner, shown above every screen.
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::app::{App, ServerStatus};
+use crate::logging;
+use crate::server::{ServerLaunch, StartFailure};
+
+use super::icons::glyph;
+
+/// Draw a banner while the server glass started isn't up, returning the
+/// area left below it.
+pub fn draw_server_banner(f: &mut Frame, app: &App, area: Rect) -> Rect {
+    let Some(banner) = server_banner(app) else {
+        return area;
+    };
+    // Leave the screen below at least a few rows
+    let height = (banner.len() as u16).min(area.height.saturating_sub(3).max(1));
+    let [banner_area, area] = Layout::vertical([Constraint::Length(height), Constraint::Min(0)]).areas(area);
+    f.render_widget(Paragraph::new(banner), banner_area);
+    area
+}
+
+/// What to say about the server while it's starting, restarting or failed
+/// to start, with the last of its output if it failed.
+fn server_banner(app: &App) -> Option<Vec<Line<'static>>> {
+    let theme = &app.state.theme;
+    let launch = ServerLaunch {
+        project: app.state.project_path.display().to_string(),
+        port: app.state.server_port,
+    };
+    match &app.state.server_status {
+        ServerStatus::Ready => None,
+        ServerStatus::Connecting => Some(vec![Line::from(Span::styled(
+            format!(" {} Connecting to glass-server… issues load once it's up", glyph("◐", theme)),
+            Style::default().fg(Color::Yellow),
+        ))]),
+        ServerStatus::Failed(failure) => {
+            let first = Line::from(vec![
+                Span::styled(format!(" {} {}", glyph("✗", theme), failure.error), Style::default().fg(Color::Red)),
+                Span::styled(
+                    format!(" · start it manually with: {}", launch.command_line()),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]);
+            Some(with_output(first, failure))
+        }
+        ServerStatus::Restarting { attempt, cause } => {
+            let first = Line::from(vec![
+                Span::styled(
+                    format!(" {} Server lost — restarting (attempt {})", glyph("◐", theme), attempt),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(format!(" · {}", cause.error), Style::default().fg(Color::DarkGray)),
+            ]);
+            Some(with_output(first, cause))
+        }
+    }
+}
+
+/// `first` followed by the output the server printed before `failure`.
+fn with_output(first: Line<'static>, failure: &StartFailure) -> Vec<Line<'static>> {
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut lines = vec![first];
+    lines.extend(
+        failure
+            .output
+            .iter()
+            .map(|line| Line::styled(format!("   {}", line), dim)),
+    );
+    if let Some(path) = logging::server_output_path().filter(|_| !failure.output.is_empty()) {
+        lines.push(Line::styled(format!("   Full output in {}", path.display()), dim));
+    }
+    lines
+}
@@ -0,0 +1,101 @@
+//! Breadcrumbs screen: every breadcrumb with its full message, optionally
+//! narrowed to one category, and data payloads shown on demand.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::api::Breadcrumb;
+use crate::app::App;
+use crate::config::ThemeConfig;
+use super::detail::crumb_summary;
+use super::icons::glyph;
+use super::{draw_scrollbar, scroll_paragraph};
+
+/// Draw the breadcrumb list.
+pub fn draw_breadcrumbs(f: &mut Frame, app: &App, area: Rect) {
+    let Some(breadcrumbs) = app.state.current_issue.as_ref().and_then(|i| i.source.breadcrumbs.as_ref()) else {
+        return;
+    };
+    let view = &app.state.breadcrumb_view;
+    let shown = app.shown_breadcrumbs();
+    let width = area.width.saturating_sub(2);
+
+    let mut lines = Vec::new();
+    let mut rows = Vec::with_capacity(shown.len());
+    let mut row = 0;
+    for (position, &index) in shown.iter().enumerate() {
+        let selected = position == view.selected;
+        let expanded = view.expanded.contains(&index);
+        let mut crumb = crumb_lines(&breadcrumbs[index], selected, expanded, &app.state.theme);
+        let height = Paragraph::new(crumb.clone()).wrap(Wrap { trim: false }).line_count(width);
+        rows.push(row..row + height);
+        row += height;
+        lines.append(&mut crumb);
+    }
+    *view.rows.borrow_mut() = rows;
+
+    let title = format!(
+        " Breadcrumbs · {} ({} of {}) ",
+        view.category.as_deref().unwrap_or("all"),
+        shown.len(),
+        breadcrumbs.len()
+    );
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .wrap(Wrap { trim: false });
+    let paragraph = scroll_paragraph(paragraph, area, &view.scroll);
+
+    f.render_widget(paragraph, area);
+    draw_scrollbar(f, area, &view.scroll);
+}
+
+/// A breadcrumb's time, category and full message, then its data when
+/// expanded.
+fn crumb_lines(crumb: &Breadcrumb, selected: bool, expanded: bool, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    let (category, color, message) = crumb_summary(crumb, usize::MAX);
+    // Time of day with milliseconds, without the zone
+    let time = crumb
+        .timestamp
+        .as_deref()
+        .and_then(|ts| ts.split('T').next_back())
+        .map(|t| t.trim_end_matches('Z').split('+').next().unwrap_or(t))
+        .unwrap_or("");
+    let fields = crumb.data.as_ref().map(|d| d.fields()).unwrap_or_default();
+
+    let pointer = if selected { glyph("▶", theme) } else { " " };
+    let marker = match (fields.is_empty(), expanded) {
+        (true, _) => " ",
+        (false, true) => glyph("▾", theme),
+        (false, false) => glyph("▸", theme),
+    };
+    let header = Line::from(vec![
+        Span::styled(format!("{} {} ", pointer, marker), Style::default().fg(Color::Cyan)),
+        Span::styled(format!("{:<12} ", time), Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{:<12} ", category), Style::default().fg(color)),
+        Span::raw(message),
+    ]);
+    let mut lines = vec![if selected {
+        header.style(Style::default().add_modifier(Modifier::BOLD))
+    } else {
+        header
+    }];
+
+    if expanded {
+        let key_width = fields.iter().map(|(k, _)| k.len()).max().unwrap_or(0);
+        for (key, value) in fields {
+            for (i, text) in value.lines().enumerate() {
+                let label = if i == 0 { key.as_str() } else { "" };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("      {:<w$}  ", label, w = key_width), Style::default().fg(Color::DarkGray)),
+                    Span::raw(text.to_string()),
+                ]));
+            }
+        }
+    }
+    lines
+}
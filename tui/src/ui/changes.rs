@@ -0,0 +1,94 @@
+//! "What changed" popup: how the current issue differs from the last time
+//! its detail was loaded.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::util::truncate_str;
+use super::help::centered_rect;
+use super::icons::glyph;
+use super::status::format_time;
+
+/// Widest the popup gets.
+const MAX_WIDTH: u16 = 72;
+/// Column for the row label.
+const LABEL_WIDTH: usize = 14;
+
+/// Draw the changes over the detail screen.
+pub fn draw_issue_changes(f: &mut Frame, app: &App) {
+    let Some(changes) = &app.state.issue_changes else {
+        return;
+    };
+
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width.saturating_sub(4));
+    // Borders, padding and the label
+    let value_width = (width as usize).saturating_sub(4 + LABEL_WIDTH);
+    let theme = &app.state.theme;
+    let dim = Style::default().fg(Color::DarkGray);
+    let arrow = glyph("→", theme);
+    let row = |label: &str, value: Span<'static>| {
+        Line::from(vec![Span::styled(format!("{:<w$}", label, w = LABEL_WIDTH), dim), value])
+    };
+    let delta = |n: i64| {
+        // New events are what a fix should have stopped
+        let color = if n > 0 { Color::Red } else { Color::Green };
+        Span::styled(format!("{:+}", n), Style::default().fg(color).add_modifier(Modifier::BOLD))
+    };
+
+    let mut lines = vec![row("Events", delta(changes.new_events))];
+    if changes.new_users != 0 {
+        lines.push(row("Users", delta(changes.new_users)));
+    }
+    if let Some(last_seen) = &changes.last_seen {
+        lines.push(row("Last seen", Span::raw(format_time(last_seen, theme))));
+    }
+    if let Some((old, new)) = &changes.status {
+        lines.push(row("Status", Span::raw(format!("{} {} {}", old, arrow, new))));
+    }
+    if let Some((old, new)) = &changes.release {
+        let name = |release: &Option<String>| release.clone().unwrap_or_else(|| "(none)".to_string());
+        let text = format!("{} {} {}", name(old), arrow, name(new));
+        lines.push(row("Release", Span::styled(truncate_str(&text, value_width), Style::default().fg(Color::Cyan))));
+    }
+    if !changes.tags.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled("Tags", Style::default().add_modifier(Modifier::BOLD))));
+        for tag in &changes.tags {
+            let text = match &tag.old {
+                Some(old) => format!("{} {} {}", old, arrow, tag.new),
+                None => format!("{} (new)", tag.new),
+            };
+            lines.push(row(&format!("  {}", tag.key), Span::raw(truncate_str(&text, value_width))));
+        }
+    }
+    if !changes.breadcrumb_categories.is_empty() {
+        lines.push(Line::default());
+        lines.push(row(
+            "Breadcrumbs",
+            Span::raw(truncate_str(&changes.breadcrumb_categories.join(", "), value_width)),
+        ));
+    }
+    if changes.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled("Nothing else changed", dim)));
+    }
+
+    let height = (lines.len() as u16 + 2).min(screen.height.saturating_sub(4));
+    let area = centered_rect(screen, width, height);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" What changed since the last load ")
+            .border_style(Style::default().fg(Color::Cyan))
+            .padding(Padding::horizontal(1)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
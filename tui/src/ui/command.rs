@@ -0,0 +1,55 @@
+//! The `:` command line, drawn over the bottom row like vim's.
+
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+
+/// Draw the command line, and the completions being cycled above it.
+pub fn draw_command_line(f: &mut Frame, app: &App) {
+    let (Some(line), Some(input)) = (&app.state.command_line, &app.state.input) else {
+        return;
+    };
+    let area = f.area();
+    if area.height < 2 {
+        return;
+    }
+    let row = Rect { y: area.bottom() - 1, height: 1, ..area };
+
+    // Keep the cursor in view on long commands
+    let width = row.width.saturating_sub(2) as usize;
+    let skip = input.cursor_column().saturating_sub(width);
+    let text: String = input.text().replace('\n', " ").chars().skip(skip).collect();
+    f.render_widget(Clear, row);
+    f.render_widget(Paragraph::new(format!(":{}", text)), row);
+    f.set_cursor_position(Position {
+        x: row.x + 1 + (input.cursor_column() - skip) as u16,
+        y: row.y,
+    });
+
+    if let Some((candidates, index)) = &line.completions {
+        let mut spans = vec![Span::raw(" ")];
+        for (i, candidate) in candidates.iter().enumerate() {
+            // Only the word being completed differs between candidates
+            let word = candidate.rsplit(' ').next().unwrap_or(candidate);
+            let style = if i == *index {
+                Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Cyan)
+            };
+            spans.push(Span::styled(word.to_string(), style));
+            spans.push(Span::raw("  "));
+        }
+        let above = Rect { y: row.y - 1, ..row };
+        f.render_widget(Clear, above);
+        f.render_widget(
+            Paragraph::new(Line::from(spans)).style(Style::default().bg(Color::DarkGray)),
+            above,
+        );
+    }
+}
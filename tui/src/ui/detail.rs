@@ -1,15 +1,30 @@
 //! Detail screen rendering.
 
+use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::{Line, Span, Text},
+    text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
 
-use crate::api::{IssueDetail, IssueState};
-use crate::app::App;
+use time::OffsetDateTime;
+
+use crate::api::{Breadcrumb, FrameResolution, IssueCategory, IssueDetail, IssueState, StackFrame};
+use crate::app::{App, DetailSection, TIMELINE_ZOOM_LEVELS};
+use crate::config::ThemeConfig;
+use crate::util;
+
+use super::highlight::CodeHighlighter;
+use super::icons::glyph;
+use super::status::{format_count, format_eta, format_time, status_style};
+use super::timeline::{self, TimelineRow};
+use super::waterfall::{self, format_ms};
+use super::workflow;
+use super::{draw_scrollbar, owned_line};
 
 /// Draw the issue detail screen.
 pub fn draw_detail(f: &mut Frame, app: &App, area: Rect) {
@@ -29,7 +44,7 @@ pub fn draw_detail(f: &mut Frame, app: &App, area: Rect) {
 /// Draw the main content area (issue detail or loading state).
 fn draw_content_area(f: &mut Frame, app: &App, area: Rect) {
     if let Some(issue) = &app.state.current_issue {
-        draw_content(f, issue, app.state.detail_scroll, area);
+        draw_content(f, app, issue, area);
     } else if app.state.is_loading {
         let loading = Paragraph::new("Loading...")
             .style(Style::default().fg(Color::DarkGray))
@@ -52,26 +67,43 @@ fn draw_header(f: &mut Frame, app: &App, area: Rect) {
             .unwrap_or_else(|| "Unknown".to_string());
         let status = format_status(&issue.state);
         (title, status)
-    } else if let Some(issue) = app.state.issues.get(app.state.selected_index) {
+    } else if let Some(issue) = app.state.selected_issue() {
         (issue.title.clone(), issue.status.clone())
     } else {
         ("No issue".to_string(), "".to_string())
     };
 
-    let (icon, color) = status_icon_and_color(&status);
+    let theme = &app.state.theme;
+    let style = status_style(&status, theme);
+
+    let queued = app
+        .state
+        .current_issue
+        .as_ref()
+        .filter(|issue| issue.status == "analyzing")
+        .and_then(|issue| app.state.analysis_queue.get(&issue.id))
+        .map(|entry| {
+            let eta = entry.eta_seconds.map(|s| format!(", starts in {}", format_eta(s)));
+            format!("  queued #{}{}", entry.position, eta.unwrap_or_default())
+        })
+        .unwrap_or_default();
 
     // Show spinner if refreshing
     let refresh_indicator = if app.state.is_refreshing_detail || app.state.is_loading {
-        " ◐"
+        format!(" {}", glyph("◐", theme))
     } else {
-        ""
+        String::new()
     };
 
     let header_text = vec![Line::from(vec![
         Span::raw(" "),
         Span::styled(title, Style::default().add_modifier(Modifier::BOLD)),
         Span::raw("  "),
-        Span::styled(format!("{} {}", icon, status.to_uppercase()), Style::default().fg(color)),
+        Span::styled(
+            format!("{} {}", style.marker(theme), status.to_uppercase()),
+            Style::default().fg(style.color),
+        ),
+        Span::styled(queued, Style::default().fg(Color::DarkGray)),
         Span::styled(refresh_indicator, Style::default().fg(Color::Yellow)),
     ])];
 
@@ -81,17 +113,114 @@ fn draw_header(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(header, area);
 }
 
-/// Draw the main content area.
-fn draw_content(f: &mut Frame, issue: &IssueDetail, scroll: usize, area: Rect) {
+/// "Event: 3 of 50+ · 2d ago · production" while stepping through
+/// the issue's events.
+fn event_position(app: &App, issue: &IssueDetail) -> Option<Line<'static>> {
+    let browser = app.state.event_browser.as_ref().filter(|b| b.issue_id == issue.id)?;
+    let shown = browser.shown.as_ref()?;
+    let dim = Style::default().fg(Color::DarkGray);
+    let position = match browser.events.iter().position(|e| e.id == shown.id) {
+        Some(index) => {
+            let more = if browser.complete { "" } else { "+" };
+            format!("{} of {}{}", index + 1, browser.events.len(), more)
+        }
+        None => shown.id.clone(),
+    };
+    let mut spans = vec![
+        Span::styled("Event: ", dim),
+        Span::styled(position, Style::default().fg(Color::Cyan)),
+        Span::styled(format!(" · {}", format_time(&shown.timestamp, &app.state.theme)), dim),
+    ];
+    if let Some(environment) = &shown.environment {
+        spans.push(Span::styled(format!(" · {}", environment), dim));
+    }
+    if browser.applied.as_ref().is_none_or(|applied| applied.id != shown.id) {
+        spans.push(Span::styled("  loading…", Style::default().fg(Color::Yellow)));
+    }
+    Some(Line::from(spans))
+}
+
+/// Draw the main content area. Its lines are built once and kept until
+/// something they show changes, as a long stack trace or breadcrumb trail
+/// takes a while to lay out.
+fn draw_content(f: &mut Frame, app: &App, issue: &IssueDetail, area: Rect) {
+    let scroll = &app.state.detail_scroll;
+    // Borders take two columns
+    let width = area.width.saturating_sub(2);
+    let now = OffsetDateTime::now_utc().unix_timestamp() as f64;
+
+    let cache = &app.state.detail_lines;
+    let rows = cache.lay_out(content_key(app, issue, width, now), Some(width), || {
+        content_lines(app, issue, width, now).into_iter().map(owned_line).collect()
+    });
+    // Borders included, as scroll_paragraph counts them
+    scroll.set_rendered(rows + 2, area.height as usize);
+    let (lines, skip) = cache.rows(scroll.offset(), area.height.saturating_sub(2) as usize);
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL))
+        .wrap(Wrap { trim: false })
+        .scroll((skip, 0));
+
+    f.render_widget(paragraph, area);
+    draw_scrollbar(f, area, scroll);
+}
+
+/// Everything the content lines depend on besides the width. A refresh
+/// clears the cache outright, since it can change any part of the issue.
+fn content_key(app: &App, issue: &IssueDetail, width: u16, now: f64) -> u64 {
+    let state = &app.state;
+    let sorted = |set: &HashSet<usize>| {
+        let mut items: Vec<usize> = set.iter().copied().collect();
+        items.sort_unstable();
+        items
+    };
+    let mut blamed: Vec<(usize, bool)> = state.frame_blame.iter().map(|(i, b)| (*i, b.is_ok())).collect();
+    blamed.sort_unstable();
+    let mut collapsed: Vec<u8> = state
+        .collapsed_sections
+        .get(&issue.id)
+        .into_iter()
+        .flatten()
+        .map(|section| *section as u8)
+        .collect();
+    collapsed.sort_unstable();
+
+    let mut key = DefaultHasher::new();
+    (&issue.id, &issue.updated_at, &issue.status, issue.source.event_count).hash(&mut key);
+    let browser = state.event_browser.as_ref().filter(|b| b.issue_id == issue.id);
+    browser
+        .map(|b| (b.shown.as_ref().map(|e| &e.id), b.applied.as_ref().map(|e| &e.id), b.events.len(), b.complete))
+        .hash(&mut key);
+    (state.frame_focus.focused(), sorted(&state.expanded_frames), sorted(&state.unfolded_runs), blamed).hash(&mut key);
+    (state.section_focus.focused(), collapsed, state.breadcrumb_zoom).hash(&mut key);
+    let theme = &state.theme;
+    (theme.palette, theme.icons, theme.absolute_times, theme.numbers, theme.locale).hash(&mut key);
+    // The workflow diagram and relative times move on by the minute, and
+    // some sections lay themselves out for the width
+    ((now / 60.0) as u64, width).hash(&mut key);
+    key.finish()
+}
+
+/// The content's lines, with folded sections shut.
+fn content_lines<'a>(app: &App, issue: &'a IssueDetail, width: u16, now: f64) -> Vec<Line<'a>> {
     let mut lines: Vec<Line> = Vec::new();
+    // Where each section starts in `lines`; sections with nothing to show
+    // start where the next one does
+    let mut starts: Vec<(DetailSection, usize)> = Vec::new();
 
     // Source info section
+    starts.push((DetailSection::Source, lines.len()));
     lines.push(Line::from(Span::styled(
         "── Source ──",
         Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
     )));
     lines.push(Line::default());
 
+    if let Some(line) = event_position(app, issue) {
+        lines.push(line);
+    }
+
     if let Some(culprit) = &issue.source.culprit {
         lines.push(Line::from(vec![
             Span::styled("Culprit: ", Style::default().fg(Color::DarkGray)),
@@ -113,17 +242,60 @@ fn draw_content(f: &mut Frame, issue: &IssueDetail, scroll: usize, area: Rect) {
         ]));
     }
 
-    lines.push(Line::from(vec![
-        Span::styled("Events: ", Style::default().fg(Color::DarkGray)),
-        Span::raw(format!("{}", issue.source.event_count.unwrap_or(0))),
-        Span::raw(" │ "),
-        Span::styled("Users: ", Style::default().fg(Color::DarkGray)),
-        Span::raw(format!("{}", issue.source.user_count.unwrap_or(0))),
-    ]));
+    // Issues created by hand have no events to count
+    if issue.source_type == "manual" {
+        lines.push(Line::from(vec![
+            Span::styled("Reported: ", Style::default().fg(Color::DarkGray)),
+            Span::raw(format!("by hand, {}", format_time(&issue.created_at, &app.state.theme))),
+        ]));
+    } else {
+        let theme = &app.state.theme;
+        lines.push(Line::from(vec![
+            Span::styled("Events: ", Style::default().fg(Color::DarkGray)),
+            Span::raw(format_count(issue.source.event_count.unwrap_or(0), theme)),
+            Span::raw(" │ "),
+            Span::styled("Users: ", Style::default().fg(Color::DarkGray)),
+            Span::raw(format_count(issue.source.user_count.unwrap_or(0), theme)),
+        ]));
+    }
+
+    if let (Some(first), Some(last)) = (&issue.source.first_seen, &issue.source.last_seen) {
+        let theme = &app.state.theme;
+        lines.push(Line::from(vec![
+            Span::styled("First seen: ", Style::default().fg(Color::DarkGray)),
+            Span::raw(format_time(first, theme)),
+            Span::raw(" │ "),
+            Span::styled("Last seen: ", Style::default().fg(Color::DarkGray)),
+            Span::raw(format_time(last, theme)),
+        ]));
+    }
 
     lines.push(Line::default());
 
+    // What was reported, for issues created by hand
+    starts.push((DetailSection::Description, lines.len()));
+    if let Some(description) = issue.source.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        lines.push(section_header("Description"));
+        lines.push(Line::default());
+        lines.extend(description.lines().map(Line::from));
+        lines.push(Line::default());
+    }
+
+    // Where the issue is in the pipeline
+    starts.push((DetailSection::Workflow, lines.len()));
+    lines.push(section_header("Workflow"));
+    lines.push(Line::default());
+    let stages = workflow::stages(issue, now);
+    lines.extend(workflow::diagram_lines(&stages, &app.state.theme, width as usize));
+    lines.push(Line::default());
+
+    starts.push((DetailSection::Rejections, lines.len()));
+    if !issue.rejections.is_empty() {
+        push_rejection_section(&mut lines, issue, &app.state.theme);
+    }
+
     // Request section
+    starts.push((DetailSection::Request, lines.len()));
     if let Some(request) = &issue.source.request {
         lines.push(Line::from(Span::styled(
             "── Request ──",
@@ -159,6 +331,7 @@ fn draw_content(f: &mut Frame, issue: &IssueDetail, scroll: usize, area: Rect) {
     }
 
     // User section
+    starts.push((DetailSection::User, lines.len()));
     if let Some(user) = &issue.source.user {
         lines.push(Line::from(Span::styled(
             "── User ──",
@@ -205,6 +378,7 @@ fn draw_content(f: &mut Frame, issue: &IssueDetail, scroll: usize, area: Rect) {
     }
 
     // Context section (browser, device, runtime)
+    starts.push((DetailSection::Context, lines.len()));
     if let Some(contexts) = &issue.source.contexts {
         lines.push(Line::from(Span::styled(
             "── Context ──",
@@ -268,97 +442,48 @@ fn draw_content(f: &mut Frame, issue: &IssueDetail, scroll: usize, area: Rect) {
         lines.push(Line::default());
     }
 
-    // Exception/stacktrace section
-    if let Some(exceptions) = &issue.source.exceptions {
-        lines.push(Line::from(Span::styled(
-            "── Exception ──",
-            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
-        )));
-        lines.push(Line::default());
-
-        for exc in exceptions {
-            lines.push(Line::from(vec![
-                Span::styled(&exc.error_type, Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-                Span::raw(": "),
-                Span::raw(exc.value.clone().unwrap_or_default()),
-            ]));
-
-            if let Some(stacktrace) = &exc.stacktrace {
-                lines.push(Line::default());
-                for frame in &stacktrace.frames {
-                    let filename = frame.filename.as_deref().unwrap_or("?");
-                    let function = frame.function.as_deref().unwrap_or("?");
-                    let lineno = frame.lineno.map(|n| n.to_string()).unwrap_or_default();
-
-                    lines.push(Line::from(vec![
-                        Span::styled("  at ", Style::default().fg(Color::DarkGray)),
-                        Span::styled(function, Style::default().fg(Color::Yellow)),
-                        Span::styled(" (", Style::default().fg(Color::DarkGray)),
-                        Span::raw(filename),
-                        Span::styled(":", Style::default().fg(Color::DarkGray)),
-                        Span::raw(lineno),
-                        Span::styled(")", Style::default().fg(Color::DarkGray)),
-                    ]));
-                }
-            }
+    // Category-specific section: exceptions, message, cron or transaction
+    let category = match issue.source.category() {
+        IssueCategory::Error => DetailSection::Exception,
+        IssueCategory::Message => DetailSection::Message,
+        IssueCategory::Cron => DetailSection::Cron,
+        IssueCategory::Performance => DetailSection::Transaction,
+    };
+    starts.push((category, lines.len()));
+    match issue.source.category() {
+        IssueCategory::Error if issue.source.exceptions.is_none() => push_pasted_trace_section(&mut lines, issue),
+        IssueCategory::Error => push_exception_section(&mut lines, app, issue),
+        IssueCategory::Message => push_message_section(&mut lines, issue),
+        IssueCategory::Cron => push_cron_section(&mut lines, issue, &app.state.theme),
+        IssueCategory::Performance => {
+            // Inside the borders, leaving a column for the scrollbar
+            push_transaction_section(&mut lines, issue, width.saturating_sub(1));
         }
+    }
+
+    // Replay section
+    starts.push((DetailSection::Replay, lines.len()));
+    if let Some(replay) = &issue.source.replay {
+        lines.push(section_header("Replay"));
+        lines.push(Line::default());
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} ", glyph("▶", &app.state.theme)),
+                Style::default().fg(Color::Magenta),
+            ),
+            Span::styled(&replay.url, Style::default().add_modifier(Modifier::UNDERLINED)),
+            Span::styled("  (o to open)", Style::default().fg(Color::DarkGray)),
+        ]));
         lines.push(Line::default());
     }
+    let replay_start = issue.source.replay.as_ref().and_then(|r| r.started_at.as_deref());
 
     // Breadcrumbs section
-    if let Some(breadcrumbs) = &issue.source.breadcrumbs {
-        if !breadcrumbs.is_empty() {
-            lines.push(Line::from(Span::styled(
-                "── Breadcrumbs ──",
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
-            )));
-            lines.push(Line::default());
-
-            // Show last N breadcrumbs (most recent at bottom)
-            let max_crumbs = 15;
-            let start = breadcrumbs.len().saturating_sub(max_crumbs);
-            for crumb in &breadcrumbs[start..] {
-                let category = crumb.category.as_deref().unwrap_or("?");
-                let timestamp = crumb.timestamp.as_deref()
-                    .and_then(|ts| ts.split('T').last())
-                    .and_then(|t| t.split('.').next())
-                    .unwrap_or("");
-
-                let color = match category {
-                    "http" | "fetch" | "httplib" => Color::Blue,
-                    "console" => Color::Yellow,
-                    "navigation" | "ui.click" => Color::Magenta,
-                    "error" | "exception" => Color::Red,
-                    "query" => Color::Cyan,
-                    "redis" => Color::Green,
-                    _ => Color::DarkGray,
-                };
-
-                // Build message - prefer data fields for http, fall back to message
-                let display_msg = if let Some(data) = &crumb.data {
-                    if category == "httplib" || category == "http" {
-                        let method = data.http_method.as_deref().unwrap_or("");
-                        let url = data.url.as_deref().unwrap_or("");
-                        let status = data.status_code.map(|s| format!(" → {}", s)).unwrap_or_default();
-                        format!("{} {}{}", method, truncate_str(url, 40), status)
-                    } else {
-                        crumb.message.as_deref().unwrap_or("").to_string()
-                    }
-                } else {
-                    crumb.message.as_deref().unwrap_or("").to_string()
-                };
-
-                lines.push(Line::from(vec![
-                    Span::styled(format!("{:>8} ", timestamp), Style::default().fg(Color::DarkGray)),
-                    Span::styled(format!("{:<12} ", category), Style::default().fg(color)),
-                    Span::raw(truncate_str(&display_msg, 55)),
-                ]));
-            }
-            lines.push(Line::default());
-        }
-    }
+    starts.push((DetailSection::Breadcrumbs, lines.len()));
+    push_breadcrumb_section(&mut lines, app, issue, replay_start);
 
     // Tags section
+    starts.push((DetailSection::Tags, lines.len()));
     if let Some(tags) = &issue.source.tags {
         if !tags.is_empty() {
             lines.push(Line::from(Span::styled(
@@ -369,7 +494,7 @@ fn draw_content(f: &mut Frame, issue: &IssueDetail, scroll: usize, area: Rect) {
 
             // Sort tags by key for consistent display
             let mut sorted_tags: Vec<_> = tags.iter().collect();
-            sorted_tags.sort_by(|(a, _), (b, _)| a.cmp(b));
+            sorted_tags.sort_by_key(|(k, _)| *k);
 
             let mut tag_spans: Vec<Span> = Vec::new();
             for (key, value) in sorted_tags {
@@ -385,6 +510,7 @@ fn draw_content(f: &mut Frame, issue: &IssueDetail, scroll: usize, area: Rect) {
     }
 
     // Error section (if in error state)
+    starts.push((DetailSection::Error, lines.len()));
     if let IssueState::Error { error, .. } = &issue.state {
         lines.push(Line::from(Span::styled(
             "── Error ──",
@@ -395,6 +521,7 @@ fn draw_content(f: &mut Frame, issue: &IssueDetail, scroll: usize, area: Rect) {
     }
 
     // Worktree info (if in progress or review)
+    starts.push((DetailSection::Worktree, lines.len()));
     match &issue.state {
         IssueState::InProgress { worktree_path, worktree_branch, .. }
         | IssueState::PendingReview { worktree_path, worktree_branch, .. } => {
@@ -415,25 +542,583 @@ fn draw_content(f: &mut Frame, issue: &IssueDetail, scroll: usize, area: Rect) {
         _ => {}
     }
 
-    let text = Text::from(lines);
-    let paragraph = Paragraph::new(text)
-        .block(Block::default().borders(Borders::ALL))
-        .wrap(Wrap { trim: false })
-        .scroll((scroll as u16, 0));
+    fold_sections(app, lines, &starts, width)
+}
 
-    f.render_widget(paragraph, area);
+/// Mark each section header as open or folded (highlighting the focused
+/// one), drop the bodies of folded sections, and note the row each header
+/// lands on once wrapped to `width` so Tab can scroll to it.
+fn fold_sections<'a>(
+    app: &App,
+    mut lines: Vec<Line<'a>>,
+    starts: &[(DetailSection, usize)],
+    width: u16,
+) -> Vec<Line<'a>> {
+    let theme = &app.state.theme;
+    // Split from the end so each section keeps only its own lines
+    let mut sections = Vec::new();
+    for &(section, start) in starts.iter().rev() {
+        sections.push((section, lines.split_off(start)));
+    }
+
+    let mut rows = Vec::new();
+    let mut row = 0;
+    let mut folded = lines;
+    for (section, mut body) in sections.into_iter().rev() {
+        if body.is_empty() {
+            continue;
+        }
+        let collapsed = app.is_collapsed(section);
+        let mut header = body.remove(0);
+        let style = header.spans.first().map(|s| s.style).unwrap_or_default();
+        let marker = if collapsed { glyph("▸", theme) } else { glyph("▾", theme) };
+        header.spans.insert(0, Span::styled(format!("{} ", marker), style));
+        if app.state.section_focus.is_focused(section) {
+            header = header.style(Style::default().add_modifier(Modifier::REVERSED));
+        }
+
+        let mut chunk = vec![header];
+        if collapsed {
+            chunk.push(Line::default());
+        } else {
+            chunk.append(&mut body);
+        }
+        rows.push((section, row));
+        row += Paragraph::new(chunk.clone()).wrap(Wrap { trim: false }).line_count(width);
+        folded.append(&mut chunk);
+    }
+
+    *app.state.section_rows.borrow_mut() = rows;
+    folded
+}
+
+/// Section heading used by the detail sections.
+fn section_header(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("── {} ──", title),
+        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// The line standing in for a run of folded library frames.
+fn fold_line(count: usize, focused: bool, theme: &ThemeConfig) -> Line<'static> {
+    let text = format!("{} {} library frames", glyph("⋯", theme), count);
+    if focused {
+        Line::from(vec![
+            Span::styled(format!("{} ", glyph("▶", theme)), Style::default().fg(Color::Cyan)),
+            Span::styled(text, Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("  (Enter to show)", Style::default().fg(Color::DarkGray)),
+        ])
+    } else {
+        Line::from(Span::styled(format!("  {}", text), Style::default().fg(Color::DarkGray)))
+    }
+}
+
+/// Why earlier proposals were turned down, newest first.
+fn push_rejection_section<'a>(lines: &mut Vec<Line<'a>>, issue: &'a IssueDetail, theme: &ThemeConfig) {
+    lines.push(section_header("Rejected proposals"));
+    lines.push(Line::default());
+    for rejection in issue.rejections.iter().rev() {
+        let reason = match &rejection.reason {
+            Some(reason) => Span::raw(reason.as_str()),
+            None => Span::styled("(no reason given)", Style::default().fg(Color::DarkGray)),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}  ", format_time(&rejection.at, theme)),
+                Style::default().fg(Color::DarkGray),
+            ),
+            reason,
+        ]));
+    }
+    lines.push(Line::default());
+}
+
+/// Exception types, values and stacktraces.
+fn push_exception_section<'a>(lines: &mut Vec<Line<'a>>, app: &App, issue: &'a IssueDetail) {
+    let theme = &app.state.theme;
+    let folded = app.folded_runs();
+    let mut frame_index = 0;
+    if let Some(exceptions) = &issue.source.exceptions {
+        lines.push(section_header("Exception"));
+        lines.push(Line::default());
+
+        for exc in exceptions {
+            lines.push(Line::from(vec![
+                Span::styled(&exc.error_type, Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                Span::raw(": "),
+                Span::raw(exc.value.clone().unwrap_or_default()),
+            ]));
+
+            if let Some(stacktrace) = &exc.stacktrace {
+                lines.push(Line::default());
+                for frame in &stacktrace.frames {
+                    let focused = app.state.frame_focus.is_focused(frame_index);
+                    frame_index += 1;
+
+                    // A folded run shows one line in place of its first frame
+                    if let Some(run) = folded.iter().find(|run| run.contains(&(frame_index - 1))) {
+                        if run.start == frame_index - 1 {
+                            lines.push(fold_line(run.len(), focused, theme));
+                        }
+                        continue;
+                    }
+
+                    let filename = frame.filename.as_deref().unwrap_or("?");
+                    let function = frame.function.as_deref().unwrap_or("?");
+                    let lineno = frame.lineno.map(|n| n.to_string()).unwrap_or_default();
+
+                    let mut spans = vec![
+                        if focused {
+                            Span::styled(
+                                format!("{} at ", glyph("▶", theme)),
+                                Style::default().fg(Color::Cyan),
+                            )
+                        } else {
+                            Span::styled("  at ", Style::default().fg(Color::DarkGray))
+                        },
+                        Span::styled(function, Style::default().fg(Color::Yellow)),
+                        Span::styled(" (", Style::default().fg(Color::DarkGray)),
+                        Span::raw(app.state.path_config.shorten(filename)),
+                        Span::styled(":", Style::default().fg(Color::DarkGray)),
+                        Span::raw(lineno),
+                        Span::styled(")", Style::default().fg(Color::DarkGray)),
+                    ];
+                    if frame.resolution() == FrameResolution::Failed {
+                        spans.push(Span::styled(
+                            format!(" {} unresolved", glyph("⚠", theme)),
+                            Style::default().fg(Color::Yellow),
+                        ));
+                    }
+                    let line = Line::from(spans);
+                    lines.push(if focused {
+                        line.style(Style::default().add_modifier(Modifier::BOLD))
+                    } else {
+                        line
+                    });
+
+                    // The focused frame shows its full path for reference
+                    if focused {
+                        lines.push(Line::from(vec![
+                            Span::styled(format!("       {}", filename), Style::default().fg(Color::Cyan)),
+                            Span::styled("  (Enter to edit, y to copy)", Style::default().fg(Color::DarkGray)),
+                        ]));
+                    }
+
+                    match app.state.frame_blame.get(&(frame_index - 1)) {
+                        Some(Ok(blame)) => lines.push(Line::from(vec![
+                            Span::styled(
+                                format!("       {} ", glyph("⎇", theme)),
+                                Style::default().fg(Color::DarkGray),
+                            ),
+                            Span::styled(blame.commit.clone(), Style::default().fg(Color::Yellow)),
+                            Span::raw(format!(" {}", blame.author)),
+                            Span::styled(format!(", {}: ", blame.date), Style::default().fg(Color::DarkGray)),
+                            Span::raw(blame.summary.clone()),
+                        ])),
+                        Some(Err(e)) => lines.push(Line::from(Span::styled(
+                            format!("       {} {}", glyph("⎇", theme), e),
+                            Style::default().fg(Color::DarkGray),
+                        ))),
+                        None => {}
+                    }
+
+                    // Show the minified location a sourcemap translated
+                    if frame.resolution() == FrameResolution::Resolved {
+                        if let Some(raw) = raw_location(frame) {
+                            lines.push(Line::from(Span::styled(
+                                format!("       {} minified: {}", glyph("↳", theme), raw),
+                                Style::default().fg(Color::DarkGray),
+                            )));
+                        }
+                    }
+
+                    if app.state.expanded_frames.contains(&(frame_index - 1)) {
+                        push_frame_context(lines, frame, theme);
+                    }
+                }
+            }
+        }
+        lines.push(Line::default());
+    }
+}
+
+/// The source lines around a frame, highlighted for the file's language,
+/// with the frame's own line marked.
+fn push_frame_context(lines: &mut Vec<Line<'_>>, frame: &StackFrame, theme: &ThemeConfig) {
+    let Some(context) = frame.context.as_ref().filter(|c| !c.is_empty()) else {
+        return;
+    };
+    let language = frame
+        .filename
+        .as_deref()
+        .and_then(|f| std::path::Path::new(f).extension())
+        .map_or(String::new(), |ext| ext.to_string_lossy().into_owned());
+    let mut highlighter = CodeHighlighter::new(&language, theme.palette);
+    let number_width = context.iter().map(|c| c.line.to_string().len()).max().unwrap_or(0);
+
+    lines.push(Line::default());
+    for context_line in context {
+        let current = frame.is_current(context_line);
+        let (marker, number_style) = if current {
+            (glyph("▶", theme), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        } else {
+            (" ", Style::default().fg(Color::DarkGray))
+        };
+        let mut spans = vec![
+            Span::styled(format!("       {:>2} ", marker), number_style),
+            Span::styled(format!("{:>w$} │ ", context_line.line, w = number_width), number_style),
+        ];
+        spans.extend(highlighter.highlight(&context_line.code));
+        let line = Line::from(spans);
+        lines.push(if current {
+            line.style(Style::default().add_modifier(Modifier::BOLD))
+        } else {
+            line
+        });
+    }
+    lines.push(Line::default());
+}
+
+/// Breadcrumbs as a plain list, or spaced by time when the timeline is on.
+fn push_breadcrumb_section<'a>(
+    lines: &mut Vec<Line<'a>>,
+    app: &App,
+    issue: &'a IssueDetail,
+    replay_start: Option<&str>,
+) {
+    let Some(breadcrumbs) = issue.source.breadcrumbs.as_ref().filter(|b| !b.is_empty()) else {
+        return;
+    };
+
+    if let Some(zoom) = app.state.breadcrumb_zoom {
+        let (per_row, _) = TIMELINE_ZOOM_LEVELS[zoom];
+        lines.push(section_header(&format!(
+            "Breadcrumbs · timeline, {} per row",
+            timeline::format_gap(per_row)
+        )));
+        lines.push(Line::default());
+        push_breadcrumb_timeline(lines, breadcrumbs, zoom, &app.state.theme);
+        lines.push(Line::default());
+        return;
+    }
+
+    lines.push(section_header("Breadcrumbs"));
+    lines.push(Line::default());
+
+    // Show last N breadcrumbs (most recent at bottom)
+    let max_crumbs = 15;
+    let start = breadcrumbs.len().saturating_sub(max_crumbs);
+    if start > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("{} {} earlier (B to see all)", glyph("⋯", &app.state.theme), start),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for crumb in &breadcrumbs[start..] {
+        let (category, color, display_msg) = crumb_summary(crumb, 40);
+        let timestamp = crumb.timestamp.as_deref()
+            .and_then(|ts| ts.split('T').next_back())
+            .and_then(|t| t.split('.').next())
+            .unwrap_or("");
+
+        let mut spans = vec![
+            Span::styled(format!("{:>8} ", timestamp), Style::default().fg(Color::DarkGray)),
+        ];
+        // Where this happened in the replay, so it can be scrubbed to
+        if let Some(start) = replay_start {
+            let timecode = crumb
+                .timestamp
+                .as_deref()
+                .and_then(|ts| util::replay_timecode(ts, start))
+                .unwrap_or_else(|| "--:--".to_string());
+            spans.push(Span::styled(
+                format!("{}{:>7} ", glyph("▶", &app.state.theme), timecode),
+                Style::default().fg(Color::Magenta),
+            ));
+        }
+        spans.push(Span::styled(format!("{:<12} ", category), Style::default().fg(color)));
+        spans.push(Span::raw(truncate_str(&display_msg, 55)));
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::default());
+}
+
+/// Breadcrumbs with gaps proportional to the time between them and a ruler
+/// down the left. Crumbs without a timestamp can't be placed and are left out.
+fn push_breadcrumb_timeline(
+    lines: &mut Vec<Line<'_>>,
+    breadcrumbs: &[Breadcrumb],
+    zoom: usize,
+    theme: &ThemeConfig,
+) {
+    // More history fits than in the list since quiet stretches collapse
+    let max_crumbs = 50;
+    let start = breadcrumbs.len().saturating_sub(max_crumbs);
+    let timed: Vec<(&Breadcrumb, f64)> = breadcrumbs[start..]
+        .iter()
+        .filter_map(|c| Some((c, c.timestamp.as_deref().and_then(util::timestamp_secs)?)))
+        .collect();
+    let times: Vec<f64> = timed.iter().map(|(_, t)| *t).collect();
+
+    let dim = Style::default().fg(Color::DarkGray);
+    for row in timeline::timeline_rows(&times, zoom) {
+        lines.push(match row {
+            TimelineRow::Crumb { index, offset } => {
+                let (category, color, message) = crumb_summary(timed[index].0, 40);
+                Line::from(vec![
+                    Span::styled(format!("{:>8} ", timeline::format_offset(offset)), dim),
+                    Span::styled(format!("{} ", glyph("●", theme)), Style::default().fg(color)),
+                    Span::styled(format!("{:<12} ", category), Style::default().fg(color)),
+                    Span::raw(truncate_str(&message, 55)),
+                ])
+            }
+            TimelineRow::Tick { offset } => Line::from(vec![
+                Span::styled(format!("{:>8} ", timeline::format_offset(offset)), dim),
+                Span::styled("┼", dim),
+            ]),
+            TimelineRow::Empty => Line::from(Span::styled(format!("{:>8} │", ""), dim)),
+            TimelineRow::Gap { seconds } => Line::from(vec![
+                Span::styled(format!("{:>8} ┆ ", ""), dim),
+                Span::styled(
+                    format!("{} with nothing recorded", timeline::format_gap(seconds)),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]),
+        });
+    }
+}
+
+/// Category, its color, and a one-line message for a breadcrumb.
+pub(super) fn crumb_summary(crumb: &Breadcrumb, url_width: usize) -> (&str, Color, String) {
+    let category = crumb.category();
+    let color = match category {
+        "http" | "fetch" | "httplib" => Color::Blue,
+        "console" => Color::Yellow,
+        "navigation" | "ui.click" => Color::Magenta,
+        "error" | "exception" => Color::Red,
+        "query" => Color::Cyan,
+        "redis" => Color::Green,
+        _ => Color::DarkGray,
+    };
+
+    // Prefer data fields for http, fall back to message
+    let message = match &crumb.data {
+        Some(data) if category == "httplib" || category == "http" => {
+            let method = data.http_method.as_deref().unwrap_or("");
+            let url = data.url.as_deref().unwrap_or("");
+            let status = data.status_code.map(|s| format!(" → {}", s)).unwrap_or_default();
+            format!("{} {}{}", method, truncate_str(url, url_width), status)
+        }
+        _ => crumb.message.as_deref().unwrap_or("").to_string(),
+    };
+    (category, color, message)
+}
+
+/// "function (file:line:col)" for a frame's pre-sourcemap location.
+fn raw_location(frame: &StackFrame) -> Option<String> {
+    if frame.raw_function.is_none() && frame.raw_filename.is_none() {
+        return None;
+    }
+    let mut location = frame.raw_filename.clone().unwrap_or_else(|| "?".to_string());
+    if let Some(line) = frame.raw_lineno {
+        location.push_str(&format!(":{}", line));
+        if let Some(col) = frame.raw_colno {
+            location.push_str(&format!(":{}", col));
+        }
+    }
+    Some(format!(
+        "{} ({})",
+        frame.raw_function.as_deref().unwrap_or("?"),
+        location
+    ))
+}
+
+/// Stack trace pasted into an issue created by hand, as it was pasted:
+/// there are no frames to resolve, so the first line (usually the error)
+/// is picked out and the rest left alone.
+fn push_pasted_trace_section<'a>(lines: &mut Vec<Line<'a>>, issue: &'a IssueDetail) {
+    let Some(trace) = issue.source.stacktrace.as_deref().filter(|t| !t.trim().is_empty()) else {
+        return;
+    };
+
+    lines.push(section_header("Stack Trace"));
+    lines.push(Line::default());
+    let mut trace_lines = trace.lines();
+    if let Some(first) = trace_lines.next() {
+        lines.push(Line::from(Span::styled(
+            first,
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )));
+    }
+    lines.extend(trace_lines.map(Line::from));
+    lines.push(Line::default());
+}
+
+/// Log message for issues captured without an exception.
+fn push_message_section<'a>(lines: &mut Vec<Line<'a>>, issue: &'a IssueDetail) {
+    let Some(message) = &issue.source.message else {
+        return;
+    };
+
+    lines.push(section_header("Message"));
+    lines.push(Line::default());
+
+    if let Some(level) = &issue.source.level {
+        lines.push(Line::from(vec![
+            Span::styled("Level: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(level, Style::default().fg(level_color(level))),
+        ]));
+    }
+    for line in message.lines() {
+        lines.push(Line::from(line));
+    }
+    lines.push(Line::default());
+}
+
+/// Monitor schedule and the check-ins that went wrong.
+fn push_cron_section<'a>(lines: &mut Vec<Line<'a>>, issue: &'a IssueDetail, theme: &ThemeConfig) {
+    let Some(cron) = &issue.source.cron else {
+        return;
+    };
+
+    lines.push(section_header("Cron Monitor"));
+    lines.push(Line::default());
+
+    lines.push(Line::from(vec![
+        Span::styled("Monitor: ", Style::default().fg(Color::DarkGray)),
+        Span::raw(&cron.monitor_slug),
+    ]));
+    if let Some(schedule) = &cron.schedule {
+        let timezone = cron.timezone.as_deref().unwrap_or("UTC");
+        lines.push(Line::from(vec![
+            Span::styled("Schedule: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(schedule, Style::default().fg(Color::Yellow)),
+            Span::styled(format!(" ({})", timezone), Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        Span::styled("Last check-in: ", Style::default().fg(Color::DarkGray)),
+        Span::raw(
+            cron.last_check_in
+                .as_deref()
+                .map(|at| format_time(at, theme))
+                .unwrap_or_else(|| "never".to_string()),
+        ),
+    ]));
+
+    if !cron.missed_check_ins.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(
+            "Missed windows",
+            Style::default().fg(Color::DarkGray),
+        )));
+        for check_in in &cron.missed_check_ins {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", glyph("✗", theme)), Style::default().fg(Color::Red)),
+                Span::raw(format_time(&check_in.expected_at, theme)),
+                Span::raw("  "),
+                Span::styled(&check_in.status, Style::default().fg(Color::Red)),
+            ]));
+        }
+    }
+    lines.push(Line::default());
+}
+
+/// How many operations and spans the transaction summary lists.
+const SPAN_SUMMARY_LIMIT: usize = 5;
+
+/// Transaction duration, time spent per span operation, the slowest spans
+/// and the span waterfall.
+fn push_transaction_section<'a>(lines: &mut Vec<Line<'a>>, issue: &'a IssueDetail, width: u16) {
+    let Some(transaction) = &issue.source.transaction else {
+        return;
+    };
+
+    lines.push(section_header("Transaction"));
+    lines.push(Line::default());
+
+    lines.push(Line::from(vec![
+        Span::styled(
+            transaction.op.as_deref().unwrap_or("transaction"),
+            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::raw(&transaction.name),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("Duration: ", Style::default().fg(Color::DarkGray)),
+        Span::raw(format_ms(transaction.duration_ms())),
+        Span::raw(" │ "),
+        Span::styled("Spans: ", Style::default().fg(Color::DarkGray)),
+        Span::raw(transaction.spans.len().to_string()),
+    ]));
+
+    if transaction.spans.is_empty() {
+        lines.push(Line::default());
+        return;
+    }
+
+    // Total time and count per operation
+    let mut by_op: Vec<(&str, usize, f64)> = Vec::new();
+    for span in &transaction.spans {
+        let op = span.op.as_deref().unwrap_or("?");
+        match by_op.iter_mut().find(|(o, _, _)| *o == op) {
+            Some(entry) => {
+                entry.1 += 1;
+                entry.2 += span.duration_ms();
+            }
+            None => by_op.push((op, 1, span.duration_ms())),
+        }
+    }
+    by_op.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        "Time by operation",
+        Style::default().fg(Color::DarkGray),
+    )));
+    for (op, count, total) in by_op.into_iter().take(SPAN_SUMMARY_LIMIT) {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<16}", truncate_str(op, 16)), Style::default().fg(Color::Yellow)),
+            Span::raw(format!("{:>9}", format_ms(total))),
+            Span::styled(format!("  ×{}", count), Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+
+    let mut slowest: Vec<_> = transaction.spans.iter().collect();
+    slowest.sort_by(|a, b| b.duration_ms().total_cmp(&a.duration_ms()));
+
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        "Slowest spans",
+        Style::default().fg(Color::DarkGray),
+    )));
+    for span in slowest.into_iter().take(SPAN_SUMMARY_LIMIT) {
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {:>9}  ", format_ms(span.duration_ms()))),
+            Span::styled(
+                format!("{} ", span.op.as_deref().unwrap_or("?")),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(truncate_str(span.description.as_deref().unwrap_or(""), 50)),
+        ]));
+    }
+    lines.push(Line::default());
+
+    lines.push(section_header("Span Waterfall"));
+    lines.push(Line::default());
+    lines.extend(waterfall::waterfall_lines(transaction, width));
+    lines.push(Line::default());
 }
 
-/// Get status icon and color.
-fn status_icon_and_color(status: &str) -> (&'static str, Color) {
-    match status {
-        "pending" => ("○", Color::DarkGray),
-        "analyzing" => ("◐", Color::Yellow),
-        "pending_approval" => ("◉", Color::Cyan),
-        "in_progress" => ("◐", Color::Blue),
-        "pending_review" => ("●", Color::Green),
-        "error" => ("✗", Color::Red),
-        _ => ("?", Color::White),
+/// Color for a Sentry event level.
+fn level_color(level: &str) -> Color {
+    match level {
+        "fatal" | "error" => Color::Red,
+        "warning" => Color::Yellow,
+        "info" => Color::Blue,
+        _ => Color::DarkGray,
     }
 }
 
@@ -0,0 +1,121 @@
+//! Unified diff rendering for ```diff blocks.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Whether a fence language tag marks a unified diff.
+pub fn is_diff_language(language: &str) -> bool {
+    matches!(language.to_ascii_lowercase().as_str(), "diff" | "patch" | "udiff")
+}
+
+/// Renders the lines of one diff block, tracking line numbers from hunk headers.
+pub struct DiffRenderer {
+    line_numbers: bool,
+    /// Next line number on the old and new side, once a hunk header is seen
+    old_line: Option<u32>,
+    new_line: Option<u32>,
+}
+
+impl DiffRenderer {
+    pub fn new(line_numbers: bool) -> Self {
+        Self {
+            line_numbers,
+            old_line: None,
+            new_line: None,
+        }
+    }
+
+    /// Styled line for the next line of the block.
+    pub fn render<'a>(&mut self, line: &'a str) -> Line<'a> {
+        // File headers come before a hunk, so check them first
+        if line.starts_with("+++ ") || line.starts_with("--- ") {
+            return Line::from(Span::styled(line, Style::default().add_modifier(Modifier::BOLD)));
+        }
+        if let Some((old, new)) = parse_hunk_header(line) {
+            self.old_line = Some(old);
+            self.new_line = Some(new);
+            return Line::from(Span::styled(line, Style::default().fg(Color::Cyan)));
+        }
+        if line.starts_with("diff ") || line.starts_with("index ") || line.starts_with('\\') {
+            return Line::from(Span::styled(line, Style::default().fg(Color::DarkGray)));
+        }
+
+        let (old, new, style) = match line.chars().next() {
+            Some('+') => (None, take(&mut self.new_line), Style::default().fg(Color::Green)),
+            Some('-') => (take(&mut self.old_line), None, Style::default().fg(Color::Red)),
+            _ => (take(&mut self.old_line), take(&mut self.new_line), Style::default()),
+        };
+
+        let mut spans = Vec::new();
+        if self.line_numbers {
+            let number = |n: Option<u32>| n.map(|n| format!("{:>4}", n)).unwrap_or_else(|| " ".repeat(4));
+            spans.push(Span::styled(
+                format!("{} {} │ ", number(old), number(new)),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        spans.push(Span::styled(line, style));
+        Line::from(spans)
+    }
+}
+
+/// Return the current line number and advance it.
+fn take(line: &mut Option<u32>) -> Option<u32> {
+    let current = (*line)?;
+    *line = Some(current + 1);
+    Some(current)
+}
+
+/// Starting old and new line numbers from "@@ -12,5 +12,7 @@ ...".
+fn parse_hunk_header(line: &str) -> Option<(u32, u32)> {
+    let ranges = line.strip_prefix("@@ ")?;
+    let (ranges, _) = ranges.split_once(" @@")?;
+    let (old, new) = ranges.split_once(' ')?;
+    let start = |range: &str, sign: char| -> Option<u32> {
+        range.strip_prefix(sign)?.split(',').next()?.parse().ok()
+    };
+    Some((start(old, '-')?, start(new, '+')?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &Line<'_>) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_parse_hunk_header() {
+        assert_eq!(parse_hunk_header("@@ -12,5 +14,7 @@ fn main()"), Some((12, 14)));
+        assert_eq!(parse_hunk_header("@@ -1 +1 @@"), Some((1, 1)));
+        assert_eq!(parse_hunk_header("@@ nonsense"), None);
+    }
+
+    #[test]
+    fn test_colors_changes() {
+        let mut diff = DiffRenderer::new(false);
+        assert_eq!(diff.render("--- a/src/user.ts").spans[0].style.fg, None);
+        assert_eq!(diff.render("@@ -1,2 +1,2 @@").spans[0].style.fg, Some(Color::Cyan));
+        assert_eq!(diff.render("-  return user.name;").spans[0].style.fg, Some(Color::Red));
+        assert_eq!(diff.render("+  return user?.name;").spans[0].style.fg, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_line_numbers_follow_hunks() {
+        let mut diff = DiffRenderer::new(true);
+        diff.render("@@ -10,3 +10,3 @@");
+        assert_eq!(text(&diff.render(" a")), "  10   10 │  a");
+        assert_eq!(text(&diff.render("-b")), "  11      │ -b");
+        assert_eq!(text(&diff.render("+c")), "       11 │ +c");
+        assert_eq!(text(&diff.render(" d")), "  12   12 │  d");
+    }
+
+    #[test]
+    fn test_no_numbers_before_a_hunk() {
+        let mut diff = DiffRenderer::new(true);
+        assert_eq!(text(&diff.render("+x")), "          │ +x");
+    }
+}
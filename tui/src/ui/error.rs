@@ -0,0 +1,147 @@
+//! Error screen rendering.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::api::IssueState;
+use crate::app::App;
+use crate::screens::{self, ERROR_ACTIONS};
+
+use super::hints::{hint_line, HintStyle};
+use super::icons::glyph;
+use super::{draw_scrollbar, scroll_paragraph};
+
+/// Draw the fullscreen error view.
+pub fn draw_error(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(1),    // Content
+            Constraint::Length(1), // Buttons
+            Constraint::Length(1), // Footer
+        ])
+        .split(area);
+
+    draw_header(f, app, chunks[0]);
+    draw_content(f, app, chunks[1]);
+    draw_buttons(f, app, chunks[2]);
+    draw_footer(f, app, chunks[3]);
+}
+
+/// Human-readable name of the phase that failed.
+fn failed_phase(previous_status: &str) -> &'static str {
+    match previous_status {
+        "analyzing" => "Analysis",
+        "in_progress" => "Implementation",
+        _ => "Unknown phase",
+    }
+}
+
+/// Draw the header with issue title and failed phase.
+fn draw_header(f: &mut Frame, app: &App, area: Rect) {
+    let issue = app.state.current_issue.as_ref();
+    let title = issue
+        .and_then(|i| i.source.title.clone())
+        .unwrap_or_else(|| "Error".to_string());
+    let phase = match issue.map(|i| &i.state) {
+        Some(IssueState::Error { previous_status, .. }) => failed_phase(previous_status),
+        _ => "Unknown phase",
+    };
+
+    let header = Paragraph::new(Line::from(vec![
+        Span::raw(" "),
+        Span::styled(&title, Style::default().add_modifier(Modifier::BOLD)),
+        Span::styled(
+            format!(" {} {} failed", glyph("✗", &app.state.theme), phase),
+            Style::default().fg(Color::Red),
+        ),
+    ]))
+    .block(Block::default().borders(Borders::ALL).title(" Error "));
+
+    f.render_widget(header, area);
+}
+
+/// Draw the error message, session and server log excerpt.
+fn draw_content(f: &mut Frame, app: &App, area: Rect) {
+    let mut lines: Vec<Line> = Vec::new();
+    let heading = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+
+    match app.state.current_issue.as_ref().map(|i| &i.state) {
+        Some(IssueState::Error {
+            session_id, error, ..
+        }) => {
+            lines.push(Line::from(Span::styled("Error", heading)));
+            for line in error.lines() {
+                lines.push(Line::from(Span::styled(
+                    line,
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            lines.push(Line::default());
+
+            lines.push(Line::from(Span::styled("Session", heading)));
+            lines.push(Line::from(vec![
+                Span::raw(session_id.as_str()),
+                Span::styled(
+                    "  (press i to open it interactively)",
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+
+            if !app.state.error_log_excerpt.is_empty() {
+                lines.push(Line::default());
+                lines.push(Line::from(Span::styled("Server log", heading)));
+                for line in &app.state.error_log_excerpt {
+                    lines.push(Line::from(Span::styled(
+                        line.as_str(),
+                        Style::default().fg(Color::DarkGray),
+                    )));
+                }
+            }
+        }
+        _ => lines.push(Line::from(Span::styled(
+            "No error details available",
+            Style::default().fg(Color::DarkGray),
+        ))),
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL))
+        .wrap(Wrap { trim: false });
+    let paragraph = scroll_paragraph(paragraph, area, &app.state.error_scroll);
+
+    f.render_widget(paragraph, area);
+    draw_scrollbar(f, area, &app.state.error_scroll);
+}
+
+/// Draw the row of remediation buttons.
+fn draw_buttons(f: &mut Frame, app: &App, area: Rect) {
+    let mut spans = vec![Span::raw(" ")];
+    for (i, (key, label)) in ERROR_ACTIONS.iter().enumerate() {
+        let style = if i == app.state.error_action_index {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        spans.push(Span::styled(format!(" [{}] {} ", key, label), style));
+        spans.push(Span::raw(" "));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// Draw the footer with keybindings.
+fn draw_footer(f: &mut Frame, app: &App, area: Rect) {
+    let line = hint_line(&screens::hints(app), area.width, HintStyle::Footer);
+    f.render_widget(Paragraph::new(line), area);
+}
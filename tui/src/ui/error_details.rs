@@ -0,0 +1,84 @@
+//! Modal with the server response behind a failed issue action.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+
+use super::help::centered_rect;
+
+/// Widest the modal gets; long messages wrap instead.
+const MAX_WIDTH: u16 = 72;
+
+/// Draw the error details modal centered over the current screen.
+pub fn draw_error_details(f: &mut Frame, app: &App) {
+    let Some(failed) = app.state.error_details() else {
+        return;
+    };
+    let error = &failed.error;
+
+    let label = Style::default().fg(Color::DarkGray);
+    let field = |name: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("  {:<11}", name), label),
+            Span::raw(value),
+        ])
+    };
+
+    let mut lines = vec![
+        field("Action", format!("{} ({})", failed.action.label(), failed.issue_id)),
+        field("Status", error.status_text()),
+        field("Code", error.code.clone().unwrap_or_else(|| "—".to_string())),
+        field(
+            "Request ID",
+            error.request_id.clone().unwrap_or_else(|| "—".to_string()),
+        ),
+        Line::default(),
+    ];
+
+    lines.push(Line::from(Span::styled(
+        if error.code.is_some() { "  Message" } else { "  Response body" },
+        label,
+    )));
+    for line in error.message.lines() {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", line),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    lines.push(Line::default());
+    lines.push(Line::from(vec![
+        Span::raw("  "),
+        Span::styled(
+            " [r] Retry ",
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  "),
+        Span::styled(" [Esc] Close ", Style::default().fg(Color::Cyan)),
+    ]));
+
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width.saturating_sub(4));
+    // Rough height estimate; wrapped message lines may need more rows
+    let inner = width.saturating_sub(2).max(1) as usize;
+    let rows: usize = lines.iter().map(|l| l.width().max(1).div_ceil(inner)).sum();
+    let area = centered_rect(screen, width, rows as u16 + 2);
+
+    let modal = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Request failed ")
+            .border_style(Style::default().fg(Color::Red)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(modal, area);
+}
@@ -0,0 +1,100 @@
+//! Events browser popup: the issue's sampled events, one per row, for
+//! picking the one a re-analysis should start from.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+
+use crate::api::IssueEvent;
+use crate::app::App;
+use crate::config::ThemeConfig;
+use crate::util::truncate_str;
+use super::help::centered_rect;
+use super::icons::glyph;
+use super::workflow::short_time;
+
+/// Widest the popup gets.
+const MAX_WIDTH: u16 = 100;
+/// Columns for the environment and release.
+const ENVIRONMENT_WIDTH: usize = 12;
+const RELEASE_WIDTH: usize = 16;
+
+/// Draw the events browser over the detail screen.
+pub fn draw_event_picker(f: &mut Frame, app: &App) {
+    let Some(picker) = &app.state.event_picker else {
+        return;
+    };
+
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width.saturating_sub(4));
+    // Borders and padding
+    let inner_width = width.saturating_sub(4) as usize;
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let lines = match &picker.events {
+        None => vec![Line::from(Span::styled("Loading…", dim))],
+        Some(Err(e)) => vec![Line::from(Span::styled(e.clone(), Style::default().fg(Color::Red)))],
+        Some(Ok(events)) if events.is_empty() => {
+            vec![Line::from(Span::styled("No events sampled for this issue", dim))]
+        }
+        Some(Ok(events)) => events
+            .iter()
+            .enumerate()
+            .map(|(i, event)| event_line(event, i == picker.selected, inner_width, &app.state.theme))
+            .collect(),
+    };
+
+    let height = (lines.len() as u16 + 2).min(screen.height.saturating_sub(4));
+    // Keep the selection in view
+    let visible = height.saturating_sub(2) as usize;
+    let offset = picker.selected.saturating_sub(visible.saturating_sub(1));
+
+    let area = centered_rect(screen, width, height);
+    let popup = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    " Events · {} representative ",
+                    glyph("◆", &app.state.theme)
+                ))
+                .border_style(Style::default().fg(Color::Cyan))
+                .padding(Padding::horizontal(1)),
+        )
+        .scroll((offset as u16, 0));
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+/// "› 02-01 14:32  production    web@2.14.0        ReferenceError: …"
+fn event_line<'a>(event: &'a IssueEvent, selected: bool, width: usize, theme: &ThemeConfig) -> Line<'a> {
+    let dim = Style::default().fg(Color::DarkGray);
+    let pointer = if selected { glyph("›", theme) } else { " " };
+    let column = |value: &Option<String>, w: usize| {
+        format!("{:<w$}  ", truncate_str(value.as_deref().unwrap_or("-"), w), w = w)
+    };
+
+    let mut spans = vec![
+        Span::styled(format!("{} ", pointer), Style::default().fg(Color::Cyan)),
+        Span::raw(format!("{}  ", short_time(&event.timestamp))),
+        Span::styled(column(&event.environment, ENVIRONMENT_WIDTH), dim),
+        Span::styled(column(&event.release, RELEASE_WIDTH), dim),
+    ];
+    if event.representative {
+        spans.push(Span::styled(format!("{} ", glyph("◆", theme)), Style::default().fg(Color::Yellow)));
+    }
+    let used: usize = spans.iter().map(|s| s.width()).sum();
+    let message = event.message.as_deref().unwrap_or(&event.id);
+    spans.push(Span::raw(truncate_str(message, width.saturating_sub(used))));
+
+    let line = Line::from(spans);
+    if selected {
+        line.style(Style::default().add_modifier(Modifier::BOLD))
+    } else {
+        line
+    }
+}
@@ -0,0 +1,146 @@
+//! Focus within a screen: which of its widgets (form fields, sections,
+//! stack frames) keys go to, moved through in order with Tab and Shift+Tab.
+//!
+//! Screens keep a `FocusRing` of their widgets in `AppState` rather than an
+//! index of their own, so traversal works the same everywhere. Forms also
+//! route keys through `screens::route_focus`, so each field only handles
+//! its own.
+
+/// The widget after (or before, for a negative `delta`) `current` in
+/// `widgets`, wrapping around. With nothing focused, moving forward starts
+/// at the first widget and moving back at the last.
+fn next_focus<W: Copy + PartialEq>(widgets: &[W], current: Option<W>, delta: isize) -> Option<W> {
+    if widgets.is_empty() {
+        return None;
+    }
+    let len = widgets.len() as isize;
+    let index = match current.and_then(|current| widgets.iter().position(|w| *w == current)) {
+        Some(i) => (i as isize + delta).rem_euclid(len),
+        None if delta < 0 => len - 1,
+        None => 0,
+    };
+    Some(widgets[index as usize])
+}
+
+/// A screen's focusable widgets in Tab order, and which has focus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusRing<W> {
+    widgets: Vec<W>,
+    focused: Option<W>,
+}
+
+impl<W: Copy + PartialEq> FocusRing<W> {
+    /// Widgets in Tab order, the first focused.
+    pub fn new(widgets: impl IntoIterator<Item = W>) -> Self {
+        let widgets: Vec<W> = widgets.into_iter().collect();
+        Self {
+            focused: widgets.first().copied(),
+            widgets,
+        }
+    }
+
+    /// Widgets in Tab order, none focused until Tab is pressed.
+    pub fn unfocused(widgets: impl IntoIterator<Item = W>) -> Self {
+        Self {
+            widgets: widgets.into_iter().collect(),
+            focused: None,
+        }
+    }
+
+    pub fn focused(&self) -> Option<W> {
+        self.focused
+    }
+
+    pub fn is_focused(&self, widget: W) -> bool {
+        self.focused == Some(widget)
+    }
+
+    /// Focus `widget`, if it's one of the ring's.
+    pub fn focus(&mut self, widget: W) {
+        if self.widgets.contains(&widget) {
+            self.focused = Some(widget);
+        }
+    }
+
+    /// Let go of the focus, so keys go to the screen again.
+    pub fn blur(&mut self) {
+        self.focused = None;
+    }
+
+    /// Replace the widgets, e.g. when a screen's sections change, keeping
+    /// the focus if its widget is still there.
+    pub fn set_widgets(&mut self, widgets: impl IntoIterator<Item = W>) {
+        self.widgets = widgets.into_iter().collect();
+        if self.focused.is_some_and(|focused| !self.widgets.contains(&focused)) {
+            self.focused = None;
+        }
+    }
+
+    /// Move the focus `delta` widgets along (Tab is 1, Shift+Tab -1),
+    /// wrapping at either end, and return the newly focused widget.
+    pub fn traverse(&mut self, delta: isize) -> Option<W> {
+        self.focused = next_focus(&self.widgets, self.focused, delta);
+        self.focused
+    }
+
+    /// Move the focus `delta` widgets along like `traverse`, but stop at
+    /// either end, for long runs like stack frames where wrapping around
+    /// would lose your place.
+    pub fn step(&mut self, delta: isize) -> Option<W> {
+        let last = self.widgets.len().checked_sub(1)? as isize;
+        let index = match self.focused.and_then(|focused| self.widgets.iter().position(|w| *w == focused)) {
+            Some(i) => (i as isize + delta).clamp(0, last),
+            None if delta < 0 => last,
+            None => 0,
+        };
+        self.focused = Some(self.widgets[index as usize]);
+        self.focused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_traversal_wraps_both_ways() {
+        let mut ring = FocusRing::new(['a', 'b', 'c']);
+        assert_eq!(ring.focused(), Some('a'));
+        assert_eq!(ring.traverse(-1), Some('c'));
+        assert_eq!(ring.traverse(1), Some('a'));
+        assert_eq!(ring.traverse(2), Some('c'));
+
+        // From nothing focused, Tab starts at the top and Shift+Tab at the bottom
+        let mut ring = FocusRing::unfocused(['a', 'b', 'c']);
+        assert_eq!(ring.traverse(1), Some('a'));
+        ring.blur();
+        assert_eq!(ring.traverse(-1), Some('c'));
+
+        assert_eq!(FocusRing::<char>::unfocused([]).traverse(1), None);
+    }
+
+    #[test]
+    fn test_focus_survives_only_while_its_widget_does() {
+        let mut ring = FocusRing::new(['a', 'b', 'c']);
+        ring.focus('b');
+        ring.focus('z');
+        assert!(ring.is_focused('b'));
+
+        ring.set_widgets(['b', 'c']);
+        assert!(ring.is_focused('b'));
+        ring.set_widgets(['a', 'c']);
+        assert_eq!(ring.focused(), None);
+    }
+
+    #[test]
+    fn test_step_stops_at_the_ends() {
+        let mut ring = FocusRing::unfocused([0, 1, 2]);
+        assert_eq!(ring.step(-1), Some(2));
+        assert_eq!(ring.step(1), Some(2));
+        assert_eq!(ring.step(-5), Some(0));
+        ring.blur();
+        assert_eq!(ring.step(1), Some(0));
+
+        assert_eq!(FocusRing::<usize>::unfocused([]).step(1), None);
+    }
+}
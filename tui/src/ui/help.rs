@@ -0,0 +1,169 @@
+//! Help overlay listing every keybinding for the current screen, and the
+//! issue workflow with the keys that move an issue along it.
+
+use ratatui::{
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::{transitions_from, App, Screen, Trigger, STATUSES, TRANSITIONS};
+use crate::screens::{self, KeyHint};
+use super::status::status_style;
+
+/// Column for the status each workflow line starts from.
+const STATUS_WIDTH: usize = 20;
+/// Columns between the keybindings and the workflow when side by side.
+const GUTTER: u16 = 4;
+
+/// Draw the help overlay centered over the current screen.
+pub fn draw_help(f: &mut Frame, app: &App) {
+    let mut hints = screens::hints(app);
+    hints.push(KeyHint::new("?", "toggle help", 0));
+    hints.push(KeyHint::new(":", "command line", 0));
+
+    let key_width = hints.iter().map(|h| h.key.chars().count()).max().unwrap_or(0);
+
+    let keys: Vec<Line> = hints
+        .iter()
+        .map(|h| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {:>width$}  ", h.key, width = key_width),
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(h.desc),
+            ])
+        })
+        .collect();
+    let workflow = workflow_lines(app);
+
+    // Side by side when the screen is wide enough, otherwise stacked
+    let keys_width = keys.iter().map(|l| l.width()).max().unwrap_or(0) as u16;
+    let workflow_width = workflow.iter().map(|l| l.width()).max().unwrap_or(0) as u16;
+    let side_by_side = keys_width + GUTTER + workflow_width + 4 <= f.area().width;
+    let mut lines = if side_by_side {
+        let rows = keys.len().max(workflow.len());
+        let mut keys = keys.into_iter();
+        let mut workflow = workflow.into_iter();
+        (0..rows)
+            .map(|_| {
+                let mut line = keys.next().unwrap_or_default();
+                let pad = (keys_width + GUTTER) as usize - line.width();
+                line.spans.push(Span::raw(" ".repeat(pad)));
+                line.spans.extend(workflow.next().unwrap_or_default().spans);
+                line
+            })
+            .collect()
+    } else {
+        let mut lines = keys;
+        lines.push(Line::default());
+        lines.extend(workflow);
+        lines
+    };
+
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        "  Press ? or Esc to close",
+        Style::default().fg(Color::DarkGray),
+    )));
+    if app.state.tutorial.is_none() {
+        lines.push(Line::from(Span::styled(
+            "  Press t for a guided tutorial",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let width = lines.iter().map(|l| l.width()).max().unwrap_or(0) as u16 + 4;
+    let height = lines.len() as u16 + 2;
+    let area = centered_rect(f.area(), width, height);
+
+    let help = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Help ")
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(help, area);
+}
+
+/// The workflow: a line per status with where each key (or the agent)
+/// takes it, the open issue's status marked.
+fn workflow_lines(app: &App) -> Vec<Line<'static>> {
+    let theme = &app.state.theme;
+    let current = match app.state.screen {
+        Screen::List => app.state.selected_issue().map(|i| i.status.as_str()),
+        _ => app.state.current_issue.as_ref().map(|i| i.status.as_str()),
+    };
+    let key_style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let mut lines = vec![Line::from(Span::styled(
+        "  Workflow",
+        Style::default().add_modifier(Modifier::BOLD),
+    ))];
+    for status in STATUSES {
+        let style = status_style(status, theme);
+        let marker = if current == Some(status) { "›" } else { " " };
+        let mut spans = vec![
+            Span::styled(format!("{} ", marker), key_style),
+            Span::styled(
+                format!("{:<w$}", format!("{} {}", style.glyph, status), w = STATUS_WIDTH),
+                Style::default().fg(style.color),
+            ),
+        ];
+        for (i, transition) in transitions_from(status).enumerate() {
+            if i > 0 {
+                spans.push(Span::raw("  "));
+            }
+            spans.push(match &transition.trigger {
+                Trigger::Key { key, .. } => Span::styled(key.to_string(), key_style),
+                Trigger::Agent(what) => Span::styled(what.to_string(), dim),
+            });
+            spans.push(Span::raw(format!("→{}", transition.to)));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    // Keys that only work away from the detail screen, by screen
+    let mut elsewhere: Vec<(&Screen, Vec<String>)> = Vec::new();
+    for transition in &TRANSITIONS {
+        let Trigger::Key { key, screen, .. } = &transition.trigger else {
+            continue;
+        };
+        if *screen == Screen::Detail {
+            continue;
+        }
+        match elsewhere.iter_mut().find(|(s, _)| *s == screen) {
+            Some((_, keys)) => keys.push(key.to_string()),
+            None => elsewhere.push((screen, vec![key.to_string()])),
+        }
+    }
+    let mut legend = "  Keys act on the open issue".to_string();
+    if !elsewhere.is_empty() {
+        let notes: Vec<String> = elsewhere
+            .iter()
+            .map(|(screen, keys)| {
+                format!("{} on the {} screen", keys.join(", "), format!("{:?}", screen).to_lowercase())
+            })
+            .collect();
+        legend.push_str(&format!(" ({})", notes.join("; ")));
+    }
+    lines.push(Line::from(Span::styled(legend, dim)));
+    lines
+}
+
+/// Center a rect of the given size within `area`, clamped to fit.
+pub(super) fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let [area] = Layout::horizontal([Constraint::Length(width.min(area.width))])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::vertical([Constraint::Length(height.min(area.height))])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
@@ -0,0 +1,218 @@
+//! Syntax highlighting for fenced code blocks.
+//!
+//! Code is tokenized with syntect's bundled grammars, but colors come from
+//! the TUI palette rather than a syntect theme so code blends with the rest
+//! of the interface.
+
+use std::sync::OnceLock;
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+use syntect::easy::ScopeRangeIterator;
+use syntect::parsing::{ParseState, ScopeStack, SyntaxReference, SyntaxSet};
+
+use crate::config::Palette;
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAXES.get_or_init(SyntaxSet::load_defaults_nonewlines)
+}
+
+/// Language tag of a fence line ("```rust" -> "rust"), or `None` if the
+/// line isn't a fence.
+pub fn fence_language(line: &str) -> Option<&str> {
+    let info = line.trim_start().strip_prefix("```")?;
+    Some(info.split_whitespace().next().unwrap_or(""))
+}
+
+/// Highlights one code block line by line, carrying parser state across lines.
+pub struct CodeHighlighter {
+    parser: Option<ParseState>,
+    stack: ScopeStack,
+    palette: Palette,
+}
+
+impl CodeHighlighter {
+    /// Highlighter for a block tagged `language`. Unknown or missing tags
+    /// render as plain code.
+    pub fn new(language: &str, palette: Palette) -> Self {
+        Self {
+            parser: find_syntax(language).map(ParseState::new),
+            stack: ScopeStack::new(),
+            palette,
+        }
+    }
+
+    /// Styled spans for the next line of the block.
+    pub fn highlight(&mut self, line: &str) -> Vec<Span<'static>> {
+        let plain = || vec![Span::styled(line.to_string(), Style::default().fg(Color::White))];
+        let Some(parser) = &mut self.parser else {
+            return plain();
+        };
+        let Ok(ops) = parser.parse_line(line, syntax_set()) else {
+            // A grammar error leaves the state unusable for later lines
+            self.parser = None;
+            return plain();
+        };
+
+        let mut spans = Vec::new();
+        for (range, op) in ScopeRangeIterator::new(&ops, line) {
+            if self.stack.apply(op).is_err() {
+                return plain();
+            }
+            if range.is_empty() {
+                continue;
+            }
+            spans.push(Span::styled(
+                line[range].to_string(),
+                scope_style(&self.stack, self.palette),
+            ));
+        }
+        spans
+    }
+}
+
+fn find_syntax(language: &str) -> Option<&'static SyntaxReference> {
+    let language = language.to_ascii_lowercase();
+    // The bundled grammars have no TypeScript; JavaScript is close enough
+    let token = match language.as_str() {
+        "" => return None,
+        "ts" | "tsx" | "typescript" | "jsx" => "js",
+        "shell" | "zsh" => "sh",
+        other => other,
+    };
+    syntax_set().find_syntax_by_token(token)
+}
+
+/// Token categories that get their own color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Comment,
+    String,
+    Constant,
+    Keyword,
+    Type,
+    Function,
+    Plain,
+}
+
+/// Classify by the innermost scope that names a known category.
+fn classify(stack: &ScopeStack) -> Token {
+    for scope in stack.as_slice().iter().rev() {
+        let name = scope.build_string();
+        let token = if name.starts_with("comment") {
+            Token::Comment
+        } else if name.starts_with("string") {
+            Token::String
+        } else if name.starts_with("constant") {
+            Token::Constant
+        } else if name.starts_with("keyword") || name.starts_with("storage") {
+            Token::Keyword
+        } else if name.starts_with("entity.name.type")
+            || name.starts_with("entity.name.class")
+            || name.starts_with("support.type")
+            || name.starts_with("support.class")
+        {
+            Token::Type
+        } else if name.starts_with("entity.name.function") || name.starts_with("support.function") {
+            Token::Function
+        } else {
+            continue;
+        };
+        return token;
+    }
+    Token::Plain
+}
+
+fn scope_style(stack: &ScopeStack, palette: Palette) -> Style {
+    let token = classify(stack);
+    let color = match palette {
+        Palette::Default => match token {
+            Token::Comment => Color::DarkGray,
+            Token::String => Color::Green,
+            Token::Constant => Color::Yellow,
+            Token::Keyword => Color::Magenta,
+            Token::Type => Color::Cyan,
+            Token::Function => Color::Blue,
+            Token::Plain => Color::White,
+        },
+        // Okabe-Ito, as for statuses
+        Palette::ColorBlind => match token {
+            Token::Comment => Color::DarkGray,
+            Token::String => Color::Rgb(0, 158, 115),
+            Token::Constant => Color::Rgb(230, 159, 0),
+            Token::Keyword => Color::Rgb(204, 121, 167),
+            Token::Type => Color::Rgb(86, 180, 233),
+            Token::Function => Color::Rgb(0, 114, 178),
+            Token::Plain => Color::White,
+        },
+        Palette::Light => match token {
+            Token::Comment => Color::Gray,
+            Token::String => Color::Rgb(0, 120, 40),
+            Token::Constant => Color::Rgb(153, 102, 0),
+            Token::Keyword => Color::Rgb(140, 0, 140),
+            Token::Type => Color::Rgb(0, 128, 128),
+            Token::Function => Color::Rgb(0, 70, 170),
+            Token::Plain => Color::Reset,
+        },
+    };
+    let style = Style::default().fg(color);
+    if token == Token::Comment {
+        style.add_modifier(Modifier::ITALIC)
+    } else {
+        style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colors(spans: &[Span<'_>]) -> Vec<(String, Option<Color>)> {
+        spans.iter().map(|s| (s.content.to_string(), s.style.fg)).collect()
+    }
+
+    #[test]
+    fn test_fence_language() {
+        assert_eq!(fence_language("```rust"), Some("rust"));
+        assert_eq!(fence_language("```ts title=x.ts"), Some("ts"));
+        assert_eq!(fence_language("```"), Some(""));
+        assert_eq!(fence_language("let x = 1;"), None);
+    }
+
+    #[test]
+    fn test_highlights_keywords_and_strings() {
+        let mut highlighter = CodeHighlighter::new("rust", Palette::Default);
+        let spans = highlighter.highlight(r#"let name = "glass";"#);
+        let colors = colors(&spans);
+        assert!(colors.contains(&("let".to_string(), Some(Color::Magenta))));
+        assert!(colors.iter().any(|(text, color)| text.contains("glass") && *color == Some(Color::Green)));
+        // Nothing is lost
+        let text: String = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, r#"let name = "glass";"#);
+    }
+
+    #[test]
+    fn test_state_carries_across_lines() {
+        let mut highlighter = CodeHighlighter::new("js", Palette::Default);
+        highlighter.highlight("/* start of");
+        let spans = highlighter.highlight("still a comment */");
+        assert_eq!(spans[0].style.fg, Some(Color::DarkGray));
+    }
+
+    #[test]
+    fn test_unknown_language_is_plain() {
+        let mut highlighter = CodeHighlighter::new("no-such-language", Palette::Default);
+        let spans = highlighter.highlight("let x = 1;");
+        assert_eq!(colors(&spans), vec![("let x = 1;".to_string(), Some(Color::White))]);
+    }
+
+    #[test]
+    fn test_typescript_uses_javascript_grammar() {
+        let mut highlighter = CodeHighlighter::new("typescript", Palette::ColorBlind);
+        let spans = highlighter.highlight("const x = 1;");
+        assert_eq!(spans[0].style.fg, Some(Color::Rgb(204, 121, 167)));
+    }
+}
@@ -0,0 +1,115 @@
+//! Keybinding hint rendering shared by the action bar and screen footers.
+
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+use crate::screens::{KeyHint, MORE_HINT};
+
+/// Visual style of a hint line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HintStyle {
+    /// `[key] desc` on the list/detail action bar
+    Bar,
+    /// ` [key] desc` with dimmed descriptions, used by fullscreen footers
+    Footer,
+}
+
+/// Build a hint line that fits within `width`, collapsing hints if needed.
+pub fn hint_line(hints: &[KeyHint], width: u16, style: HintStyle) -> Line<'static> {
+    let fitted = fit_hints(hints, width as usize, |h| hint_width(h, style));
+    Line::from(
+        fitted
+            .iter()
+            .flat_map(|h| hint_spans(h, style))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Choose which hints to show within `max_width`.
+///
+/// If everything fits, all hints are returned. Otherwise the hints with the
+/// highest priority value are dropped (rightmost first among equals) until
+/// the rest fit alongside a trailing "[?] more" entry.
+pub fn fit_hints(
+    hints: &[KeyHint],
+    max_width: usize,
+    measure: impl Fn(&KeyHint) -> usize,
+) -> Vec<KeyHint> {
+    let total = |hs: &[KeyHint]| hs.iter().map(&measure).sum::<usize>();
+
+    if total(hints) <= max_width {
+        return hints.to_vec();
+    }
+
+    let budget = max_width.saturating_sub(measure(&MORE_HINT));
+    let mut kept = hints.to_vec();
+    while !kept.is_empty() && total(&kept) > budget {
+        let Some((idx, _)) = kept.iter().enumerate().max_by_key(|(_, h)| h.priority) else {
+            break;
+        };
+        kept.remove(idx);
+    }
+
+    kept.push(MORE_HINT);
+    kept
+}
+
+fn hint_spans(hint: &KeyHint, style: HintStyle) -> [Span<'static>; 2] {
+    match style {
+        HintStyle::Bar => [
+            Span::styled(format!("[{}]", hint.key), Style::default().fg(Color::Cyan)),
+            Span::raw(format!(" {} ", hint.desc)),
+        ],
+        HintStyle::Footer => [
+            Span::styled(format!(" [{}]", hint.key), Style::default().fg(Color::Cyan)),
+            Span::styled(format!(" {} ", hint.desc), Style::default().fg(Color::DarkGray)),
+        ],
+    }
+}
+
+fn hint_width(hint: &KeyHint, style: HintStyle) -> usize {
+    hint_spans(hint, style).iter().map(|s| s.width()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measure(h: &KeyHint) -> usize {
+        hint_width(h, HintStyle::Bar)
+    }
+
+    fn hints() -> Vec<KeyHint> {
+        vec![
+            KeyHint::new("↑↓/jk", "scroll down", 2),
+            KeyHint::new("Enter", "open", 0),
+            KeyHint::new("r", "refresh", 1),
+            KeyHint::new("q", "quit", 0),
+        ]
+    }
+
+    #[test]
+    fn test_fit_hints_all_fit() {
+        let hints = hints();
+        assert_eq!(fit_hints(&hints, 200, measure), hints);
+    }
+
+    #[test]
+    fn test_fit_hints_drops_lowest_priority_first() {
+        let hints = hints();
+        let full: usize = hints.iter().map(measure).sum();
+        let width = full - measure(&hints[0]) + measure(&MORE_HINT);
+        let fitted = fit_hints(&hints, width, measure);
+
+        let keys: Vec<_> = fitted.iter().map(|h| h.key).collect();
+        assert_eq!(keys, vec!["Enter", "r", "q", "?"]);
+    }
+
+    #[test]
+    fn test_fit_hints_very_narrow() {
+        let fitted = fit_hints(&hints(), 5, measure);
+        assert_eq!(fitted, vec![MORE_HINT]);
+    }
+}
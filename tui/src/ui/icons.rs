@@ -0,0 +1,104 @@
+//! Icons and glyphs, swapped for plain ASCII when the theme asks for it.
+//!
+//! Some terminals draw emoji and less common symbols as tofu or at the
+//! wrong width, which shifts everything after them. Renderers pass each
+//! glyph through here rather than choosing a set themselves.
+
+use crate::config::{IconSet, ThemeConfig};
+
+/// A glyph shown inline with text: status markers, pointers, toast icons.
+pub fn glyph(glyph: &'static str, theme: &ThemeConfig) -> &'static str {
+    if theme.icons == IconSet::Unicode {
+        return glyph;
+    }
+    match glyph {
+        "○" => "o",
+        "◐" => "~",
+        "◆" => "*",
+        "▲" => "^",
+        "■" => "#",
+        "✗" => "x",
+        "·" => ".",
+        "✓" => "+",
+        "▶" => ">",
+        "▸" => "+",
+        "▾" => "-",
+        "↻" => "r",
+        "↓" => "v",
+        "⧉" => "=",
+        "⚠" => "!",
+        "ℹ" => "i",
+        "⏱" => "t",
+        "⎇" => "@",
+        "●" => "*",
+        "◉" => "*",
+        "•" => "*",
+        "›" => ">",
+        "↳" => "->",
+        "→" => "->",
+        "⋯" => "...",
+        "☐" => "[ ]",
+        "⚑" => "pin",
+        other => other,
+    }
+}
+
+/// An analysis activity icon. The ASCII ones are two columns wide, like
+/// the blank icon on continuation lines, so text lines up.
+pub fn activity_icon(icon: &'static str, theme: &ThemeConfig) -> &'static str {
+    if theme.icons == IconSet::Unicode {
+        return icon;
+    }
+    match icon {
+        "◐" => "..",
+        "🔧" => "->",
+        "✓" => "ok",
+        "✗" => "!!",
+        "↻" => "<>",
+        "▶" => ">>",
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unicode_width::UnicodeWidthStr;
+
+    const ACTIVITY_ICONS: [&str; 7] = ["◐", "🔧", "✓", "✗", "↻", "▶", "  "];
+
+    fn ascii() -> ThemeConfig {
+        ThemeConfig {
+            icons: IconSet::Ascii,
+            ..ThemeConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_unicode_set_is_unchanged() {
+        let theme = ThemeConfig::default();
+        assert_eq!(glyph("◆", &theme), "◆");
+        assert_eq!(activity_icon("🔧", &theme), "🔧");
+    }
+
+    #[test]
+    fn test_ascii_activity_icons_share_a_width() {
+        for icon in ACTIVITY_ICONS {
+            let mapped = activity_icon(icon, &ascii());
+            assert!(mapped.is_ascii(), "{} -> {}", icon, mapped);
+            assert_eq!(mapped.width(), 2, "{} -> {}", icon, mapped);
+        }
+    }
+
+    #[test]
+    fn test_ascii_status_glyphs_are_distinct() {
+        let glyphs: Vec<&str> = ["○", "◐", "◆", "▲", "■", "✗"]
+            .iter()
+            .map(|g| glyph(g, &ascii()))
+            .collect();
+        assert!(glyphs.iter().all(|g| g.is_ascii() && g.len() == 1));
+        let mut unique = glyphs.clone();
+        unique.dedup();
+        assert_eq!(unique.len(), glyphs.len());
+    }
+}
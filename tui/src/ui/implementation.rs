@@ -0,0 +1,120 @@
+//! Implementation screen rendering.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::api::IssueState;
+use crate::app::App;
+use crate::screens;
+
+use super::analysis::draw_activity;
+use super::hints::{hint_line, HintStyle};
+use super::icons::glyph;
+use super::status::{format_clock, status_style};
+
+/// Draw the fullscreen implementation view.
+pub fn draw_implementation(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(1),    // Content
+            Constraint::Length(1), // Footer
+        ])
+        .split(area);
+
+    draw_header(f, app, chunks[0]);
+    draw_activity(
+        f,
+        chunks[1],
+        Block::default().borders(Borders::ALL),
+        &app.state.implementation_lines,
+        &app.state.implementation_scroll,
+        app.state.is_streaming_implementation,
+        &app.state.theme,
+    );
+    draw_footer(f, app, chunks[2]);
+}
+
+/// Draw the header with issue title, where the session stands, and the
+/// branch it works on.
+fn draw_header(f: &mut Frame, app: &App, area: Rect) {
+    let issue = app.state.current_issue.as_ref();
+    let title = issue
+        .and_then(|i| i.source.title.clone())
+        .unwrap_or_else(|| "Implementation".to_string());
+
+    let theme = &app.state.theme;
+    let status_indicator = match issue.map(|i| &i.state) {
+        _ if app.state.is_streaming_implementation => {
+            let style = status_style("in_progress", theme);
+            Span::styled(
+                format!(" {} implementing", style.marker(theme)),
+                Style::default().fg(style.color),
+            )
+        }
+        Some(IssueState::PendingReview { .. }) => Span::styled(
+            format!(" {} ready for review", glyph("✓", theme)),
+            Style::default().fg(Color::Green),
+        ),
+        Some(IssueState::Error { .. }) => Span::styled(
+            format!(" {} failed", glyph("✗", theme)),
+            Style::default().fg(Color::Red),
+        ),
+        _ => Span::styled(" stream closed", Style::default().fg(Color::DarkGray)),
+    };
+
+    let mut spans = vec![
+        Span::raw(" "),
+        Span::styled(&title, Style::default().add_modifier(Modifier::BOLD)),
+        status_indicator,
+    ];
+
+    let branch = issue.and_then(|i| match &i.state {
+        IssueState::InProgress { worktree_branch, .. }
+        | IssueState::PendingReview { worktree_branch, .. } => Some(worktree_branch),
+        _ => None,
+    });
+    if let Some(branch) = branch {
+        spans.push(Span::styled(
+            format!("  {} {}", glyph("⎇", theme), branch),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
+    let progress = &app.state.implementation_progress;
+    if let Some(elapsed) = progress.elapsed() {
+        spans.push(Span::styled(
+            format!(
+                "  {} {} · {} events · {} tool calls",
+                glyph("⏱", theme),
+                format_clock(elapsed, theme),
+                progress.event_count,
+                progress.tool_calls
+            ),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    if let Some(usage) = progress.usage_summary() {
+        spans.push(Span::styled(
+            format!(" · {}", usage),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let header = Paragraph::new(Line::from(spans))
+        .block(Block::default().borders(Borders::ALL).title(" Implementation "));
+
+    f.render_widget(header, area);
+}
+
+/// Draw the footer with keybindings.
+fn draw_footer(f: &mut Frame, app: &App, area: Rect) {
+    let line = hint_line(&screens::hints(app), area.width, HintStyle::Footer);
+    f.render_widget(Paragraph::new(line), area);
+}
@@ -0,0 +1,110 @@
+//! Raw JSON viewer: the issue as the server sent it, as a tree of foldable
+//! objects and arrays.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+use serde_json::Value;
+
+use crate::app::{App, JsonRow, JsonRowKind};
+use crate::config::ThemeConfig;
+use super::icons::glyph;
+use super::{draw_scrollbar, scroll_paragraph};
+
+/// Draw the JSON tree.
+pub fn draw_json(f: &mut Frame, app: &App, area: Rect) {
+    let Some(view) = &app.state.json_view else {
+        return;
+    };
+    let width = area.width.saturating_sub(2);
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let mut lines = Vec::new();
+    match &view.value {
+        None => lines.push(Line::from(Span::styled("Loading…", dim))),
+        Some(Err(e)) => lines.push(Line::from(Span::styled(e.clone(), Style::default().fg(Color::Red)))),
+        Some(Ok(_)) => {
+            let mut rows = Vec::new();
+            let mut row = 0;
+            for (i, json_row) in app.json_rows().iter().enumerate() {
+                let line = row_line(json_row, i == view.selected, &app.state.theme);
+                let height = Paragraph::new(line.clone()).wrap(Wrap { trim: false }).line_count(width);
+                rows.push(row..row + height);
+                row += height;
+                lines.push(line);
+            }
+            *view.rows.borrow_mut() = rows;
+        }
+    }
+
+    let title = format!(" Raw JSON · {} ", view.issue_id);
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .wrap(Wrap { trim: false });
+    let paragraph = scroll_paragraph(paragraph, area, &view.scroll);
+
+    f.render_widget(paragraph, area);
+    draw_scrollbar(f, area, &view.scroll);
+}
+
+/// A row of the tree: its key or index, then a scalar or a bracket.
+fn row_line(row: &JsonRow, selected: bool, theme: &ThemeConfig) -> Line<'static> {
+    let pointer = if selected { glyph("▶", theme) } else { " " };
+    let marker = match row.kind {
+        JsonRowKind::Open { folded: true, .. } => glyph("▸", theme),
+        JsonRowKind::Open { folded: false, .. } => glyph("▾", theme),
+        JsonRowKind::Value | JsonRowKind::Close => " ",
+    };
+    let mut spans = vec![
+        Span::styled(format!("{} ", pointer), Style::default().fg(Color::Cyan)),
+        Span::raw("  ".repeat(row.depth)),
+        Span::styled(format!("{} ", marker), Style::default().fg(Color::Cyan)),
+    ];
+    match &row.key {
+        // Array entries are keyed by their index
+        Some(key) if row.path.ends_with(']') => {
+            spans.push(Span::styled(format!("{}: ", key), Style::default().fg(Color::DarkGray)));
+        }
+        Some(key) => spans.push(Span::styled(format!("{:?}: ", key), Style::default().fg(Color::Cyan))),
+        None => {}
+    }
+
+    let (open, close, unit) = match row.value {
+        Value::Array(_) => ("[", "]", "item"),
+        _ => ("{", "}", "key"),
+    };
+    match row.kind {
+        JsonRowKind::Open { len, folded: true } => {
+            let plural = if len == 1 { "" } else { "s" };
+            spans.push(Span::raw(open));
+            spans.push(Span::styled(format!(" {} {}{} ", len, unit, plural), Style::default().fg(Color::DarkGray)));
+            spans.push(Span::raw(close));
+        }
+        JsonRowKind::Open { .. } => spans.push(Span::raw(open)),
+        JsonRowKind::Close => spans.push(Span::raw(close)),
+        JsonRowKind::Value => spans.push(scalar(row.value)),
+    }
+
+    let line = Line::from(spans);
+    if selected {
+        line.style(Style::default().add_modifier(Modifier::BOLD))
+    } else {
+        line
+    }
+}
+
+/// A scalar (or empty container) as JSON, colored by type.
+fn scalar(value: &Value) -> Span<'static> {
+    let style = match value {
+        Value::String(_) => Style::default().fg(Color::Green),
+        Value::Number(_) => Style::default().fg(Color::Yellow),
+        Value::Bool(_) => Style::default().fg(Color::Magenta),
+        Value::Null => Style::default().fg(Color::DarkGray),
+        Value::Array(_) | Value::Object(_) => Style::default(),
+    };
+    Span::styled(value.to_string(), style)
+}
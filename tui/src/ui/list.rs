@@ -1,97 +1,251 @@
 //! List screen rendering.
 
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
+use crate::api::Issue;
 use crate::app::App;
+use super::icons::glyph;
+use super::preview::draw_preview;
+use super::status::{format_count, format_time, queue_label, status_style};
+
+/// Widest the project column gets in an aggregated list.
+const MAX_PROJECT_WIDTH: usize = 12;
+/// Widest the environment and release columns get.
+const MAX_ENVIRONMENT_WIDTH: usize = 12;
+const MAX_RELEASE_WIDTH: usize = 16;
 
 /// Draw the issue list screen.
 pub fn draw_list(f: &mut Frame, app: &App, area: Rect) {
+    // A line above the list while fixed issues are seeing events again
+    let area = match watch_banner(app) {
+        Some(banner) => {
+            let [banner_area, area] = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area);
+            f.render_widget(Paragraph::new(banner), banner_area);
+            area
+        }
+        None => area,
+    };
+
+    // Wide terminals preview the selected issue beside the list
+    let area = if app.state.shows_preview() {
+        let [area, preview_area] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(area);
+        draw_preview(f, app, preview_area);
+        area
+    } else {
+        area
+    };
+
     // Calculate available width for title column
-    // Layout: " ▶ " (4) + "○ " (2) + "STATUS   " (9) + title + "  " (2) + events (6) + "  " (2) + date (10) + padding
+    // Layout: " ▶ " (4) + merge mark (0 or 2) + "○ " (2, 3 with badges) + "STATUS   " (9) + project (0 or name + 1) + title + environment (0 or "  " + name) + release (0 or "  " + name) + "  " (2) + events (6) + "  " (2) + last seen (8, 16 absolute) + padding
     // Border takes 2 chars total
-    let fixed_width = 4 + 2 + 9 + 2 + 6 + 2 + 10 + 2; // = 37
+    let theme = &app.state.theme;
+    let marker_width = if theme.status_badges { 3 } else { 2 };
+    // Merge marks get a column only while something is marked
+    let marking = !app.state.merge_marks.is_empty();
+    let mark_width = if marking { 2 } else { 0 };
+    // Columns only some lists have, as wide as their longest value
+    let column_width = |value: fn(&Issue) -> Option<&str>, max: usize| {
+        app.state
+            .listed()
+            .filter_map(value)
+            .map(|value| value.chars().count().min(max))
+            .max()
+            .unwrap_or(0)
+    };
+    // Aggregated lists name each issue's project
+    let project_width = match column_width(|issue| issue.project.as_deref(), MAX_PROJECT_WIDTH) {
+        0 => 0,
+        width => width + 1,
+    };
+    // Servers that list them give each issue's environment and release
+    let environment_width = column_width(|issue| issue.environment.as_deref(), MAX_ENVIRONMENT_WIDTH);
+    let release_width = column_width(|issue| issue.release.as_deref(), MAX_RELEASE_WIDTH);
+    let gap = |width: usize| if width > 0 { width + 2 } else { 0 };
+    // Grouped digits can outgrow the usual six columns
+    let events_width = app
+        .state
+        .listed()
+        .map(|issue| format_count(issue.event_count, theme).chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(6);
+    // "just now" or "2026-02-01 14:30"
+    let seen_width = if theme.absolute_times { 16 } else { 8 };
+    let fixed_width = 4 + mark_width + marker_width + 9 + project_width
+        + gap(environment_width) + gap(release_width) + 2 + events_width + 2 + seen_width + 2;
     let title_width = (area.width as usize).saturating_sub(fixed_width).max(20);
 
-    let items: Vec<ListItem> = app
-        .state
-        .issues
-        .iter()
-        .map(|issue| {
-            let (icon, color, label) = status_icon_and_color(&issue.status);
-            let title = pad_or_truncate(&issue.title, title_width);
-
-            let spans = vec![
-                Span::styled(format!("{} ", icon), Style::default().fg(color)),
-                Span::styled(
-                    format!("{:9}", label),
-                    Style::default().fg(color),
-                ),
-                Span::raw(title),
-                Span::styled(
-                    format!("  {:>6}", issue.event_count),
-                    Style::default().fg(Color::DarkGray),
-                ),
-                Span::styled(
-                    format!("  {}", format_relative_time(&issue.last_seen)),
-                    Style::default().fg(Color::DarkGray),
-                ),
-            ];
-
-            ListItem::new(Line::from(spans))
-        })
-        .collect();
+    let row = |issue: &Issue| {
+        let status = status_style(&issue.status, theme);
+        let title = pad_or_truncate(&issue.title, title_width);
+        // A waiting analysis shows its place in line rather than "ANALYZE"
+        let label = match app.state.analysis_queue.get(&issue.id) {
+            Some(entry) if issue.status == "analyzing" => queue_label(entry),
+            _ => status.label.to_string(),
+        };
+
+        let mut spans = Vec::new();
+        if marking {
+            let marked = app.state.merge_marks.contains(&issue.id);
+            spans.push(Span::styled(
+                format!("{} ", if marked { glyph("⧉", theme) } else { " " }),
+                Style::default().fg(Color::Magenta),
+            ));
+        }
+        spans.extend([
+            Span::styled(format!("{} ", status.marker(theme)), Style::default().fg(status.color)),
+            Span::styled(pad_or_truncate(&label, 9), Style::default().fg(status.color)),
+        ]);
+        if project_width > 0 {
+            let project = issue.project.as_deref().unwrap_or_default();
+            spans.push(Span::styled(
+                format!("{} ", pad_or_truncate(project, project_width - 1)),
+                Style::default().fg(Color::Blue),
+            ));
+        }
+        // Ignored issues shown anyway are dimmed
+        if app.state.show_ignored && app.state.ignore.matches(issue) {
+            spans.push(Span::styled(title, Style::default().fg(Color::DarkGray)));
+        } else {
+            spans.push(Span::raw(title));
+        }
+        if environment_width > 0 {
+            let environment = issue.environment.as_deref().unwrap_or_default();
+            spans.push(Span::styled(
+                format!("  {}", pad_or_truncate(environment, environment_width)),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        if release_width > 0 {
+            let release = issue.release.as_deref().unwrap_or_default();
+            spans.push(Span::styled(
+                format!("  {}", pad_or_truncate(release, release_width)),
+                Style::default().fg(Color::Cyan),
+            ));
+        }
+        spans.extend([
+            Span::styled(
+                format!("  {:>w$}", format_count(issue.event_count, theme), w = events_width),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(
+                format!("  {:>w$}", format_time(&issue.last_seen, theme), w = seen_width),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+
+        ListItem::new(Line::from(spans))
+    };
+
+    // Grouped, each group gets a header row; a folded group is only its
+    // header, which stands in for its first issue when selected
+    let mut items: Vec<ListItem> = Vec::with_capacity(app.state.rows.len());
+    let mut selected_row = app.state.selected_index;
+    match app.state.view.group {
+        None => items.extend(app.state.listed().map(row)),
+        Some(group) => {
+            let issues: Vec<&Issue> = app.state.listed().collect();
+            let mut start = 0;
+            while start < issues.len() {
+                let key = group.key(issues[start]);
+                let len = issues[start..].iter().take_while(|issue| group.key(issue) == key).count();
+                let members = &issues[start..start + len];
+                let folded = app.state.collapsed_groups.contains(&key);
+                let events: u64 = members.iter().map(|issue| issue.event_count).sum();
+                let header = Line::from(vec![
+                    Span::raw(format!("{} ", glyph(if folded { "▸" } else { "▾" }, theme))),
+                    Span::raw(key),
+                    Span::styled(
+                        format!(
+                            " {} {} issue{} {} {} events",
+                            glyph("·", theme),
+                            len,
+                            if len == 1 { "" } else { "s" },
+                            glyph("·", theme),
+                            format_count(events, theme)
+                        ),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]);
+                let selected = (start..start + len).contains(&app.state.selected_index);
+                if folded && selected {
+                    selected_row = items.len();
+                }
+                items.push(ListItem::new(header.style(Style::default().add_modifier(Modifier::BOLD))));
+                if !folded {
+                    if selected {
+                        selected_row = items.len() + app.state.selected_index - start;
+                    }
+                    items.extend(members.iter().copied().map(row));
+                }
+                start += len;
+            }
+        }
+    }
 
-    let title = if app.state.is_loading || app.state.is_refreshing {
-        " Glass ◐ "
+    let mut title = if app.state.is_loading || app.state.is_refreshing {
+        format!(" Glass {} ", glyph("◐", theme))
     } else {
-        " Glass "
+        " Glass ".to_string()
     };
+    // The project picked in the switcher, then filters and sort order set
+    // from the command line
+    let view = &app.state.view;
+    if let Some(project) = &view.project {
+        title.push_str(&format!("· {} ", project));
+    }
+    if !view.is_default() {
+        let mut parts: Vec<String> = view.filters.iter().map(|f| f.to_string()).collect();
+        parts.extend(view.sort.map(|sort| format!("sort {}", sort)));
+        parts.extend(view.group.map(|group| format!("group {}", group.name())));
+        title.push_str(&format!("[{}] ", parts.join(", ")));
+    }
+    let watching = app.state.watches.iter().filter(|w| w.new_events.is_none()).count();
+    if watching > 0 {
+        title.push_str(&format!("(watching {} fix{}) ", watching, if watching == 1 { "" } else { "es" }));
+    }
+    let ignored = app.state.ignored_count();
+    if ignored > 0 && !app.state.show_ignored {
+        title.push_str(&format!("({} ignored) ", ignored));
+    }
 
+    let mut block = Block::default().title(title).borders(Borders::ALL);
+    if let Some(remaining) = app.state.auto_refresh_remaining() {
+        // A countdown ticks every second; reduced motion shows the interval
+        let when = match app.state.auto_refresh_interval {
+            Some(interval) if theme.reduced_motion => format!("every {}s", interval.as_secs()),
+            _ => format!("{}s", remaining),
+        };
+        block = block.title(
+            Line::from(Span::styled(
+                format!(" {} {} ", glyph("↻", theme), when),
+                Style::default().fg(Color::DarkGray),
+            ))
+            .right_aligned(),
+        );
+    }
+
+    let pointer = format!("{} ", glyph("▶", theme));
     let list = List::new(items)
-        .block(Block::default().title(title).borders(Borders::ALL))
+        .block(block)
         .highlight_style(
             Style::default()
                 .bg(Color::DarkGray)
                 .add_modifier(Modifier::BOLD),
         )
-        .highlight_symbol("▶ ");
+        .highlight_symbol(&pointer);
 
     let mut list_state = ListState::default();
-    list_state.select(Some(app.state.selected_index));
+    list_state.select(Some(selected_row));
 
     f.render_stateful_widget(list, area, &mut list_state);
-
-    // Show error if any
-    if let Some(error) = &app.state.error {
-        let error_area = Rect {
-            x: area.x + 2,
-            y: area.y + area.height.saturating_sub(2),
-            width: area.width.saturating_sub(4),
-            height: 1,
-        };
-        let error_text = ratatui::widgets::Paragraph::new(error.as_str())
-            .style(Style::default().fg(Color::Red));
-        f.render_widget(error_text, error_area);
-    }
-}
-
-/// Get status icon, color, and abbreviated label.
-fn status_icon_and_color(status: &str) -> (&'static str, Color, &'static str) {
-    match status {
-        "pending" => ("○", Color::DarkGray, "PENDING"),
-        "analyzing" => ("◐", Color::Yellow, "ANALYZE"),
-        "pending_approval" => ("◉", Color::Cyan, "APPROVAL"),
-        "in_progress" => ("◐", Color::Blue, "WORKING"),
-        "pending_review" => ("●", Color::Green, "REVIEW"),
-        "error" => ("✗", Color::Red, "ERROR"),
-        _ => ("?", Color::White, "UNKNOWN"),
-    }
 }
 
 /// Pad or truncate string to exact length.
@@ -107,13 +261,22 @@ fn pad_or_truncate(s: &str, len: usize) -> String {
     }
 }
 
-/// Format ISO timestamp as relative time.
-fn format_relative_time(iso: &str) -> String {
-    // Simple implementation - in production use chrono
-    // For now just show the date portion
-    if iso.len() >= 10 {
-        iso[..10].to_string()
-    } else {
-        iso.to_string()
+/// Completed issues that saw new events since their fix, until opened.
+fn watch_banner(app: &App) -> Option<Line<'static>> {
+    let regressed: Vec<String> = app
+        .state
+        .watches
+        .iter()
+        .filter_map(|w| w.new_events.map(|new| format!("{} +{}", w.short_id, new)))
+        .collect();
+    if regressed.is_empty() {
+        return None;
     }
+    Some(Line::from(vec![
+        Span::styled(
+            format!(" {} New events since the fix: {}", glyph("⚠", &app.state.theme), regressed.join(", ")),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(" · open an issue to dismiss it", Style::default().fg(Color::DarkGray)),
+    ]))
 }
@@ -0,0 +1,72 @@
+//! Merge confirmation: pick which marked issue the others fold into.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+use crate::util::truncate_str;
+use super::help::centered_rect;
+use super::icons::glyph;
+
+/// Widest the popup gets.
+const MAX_WIDTH: u16 = 72;
+
+/// Draw the merge confirmation over the list.
+pub fn draw_merge_dialog(f: &mut Frame, app: &App) {
+    let Some(dialog) = &app.state.merge_dialog else {
+        return;
+    };
+
+    let width = MAX_WIDTH.min(f.area().width.saturating_sub(4));
+    // Borders and padding, the "▶ ● SHORT-123 " prefix, and "  primary"
+    let title_width = width.saturating_sub(4 + 17 + 9) as usize;
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "The others become linked duplicates; their analyses, proposals and worktrees move to the primary.",
+            dim,
+        )),
+        Line::default(),
+    ];
+    for (index, id) in dialog.issue_ids.iter().enumerate() {
+        let Some(issue) = app.state.issues.iter().find(|i| i.id == *id) else {
+            continue;
+        };
+        let is_primary = index == dialog.primary;
+        let theme = &app.state.theme;
+        let (pointer, marker, style) = if is_primary {
+            (glyph("▶", theme), glyph("●", theme), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+        } else {
+            (" ", glyph("○", theme), Style::default())
+        };
+        lines.push(Line::from(vec![
+            Span::raw(format!("{} ", pointer)),
+            Span::styled(format!("{} ", marker), style),
+            Span::styled(format!("{:<12} ", issue.short_id), style),
+            Span::styled(truncate_str(&issue.title, title_width), style),
+            Span::styled(if is_primary { "  primary" } else { "" }, dim),
+        ]));
+    }
+
+    // The explanation may wrap onto a second line
+    let height = lines.len() as u16 + 3;
+    let area = centered_rect(f.area(), width, height);
+    let title = format!(" Merge {} issues ", dialog.issue_ids.len());
+    let popup = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(Color::Magenta))
+                .padding(Padding::horizontal(1)),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
@@ -1,27 +1,149 @@
 //! UI rendering with Ratatui.
 
 mod analysis;
+mod banner;
+mod breadcrumbs;
+mod changes;
+mod command;
 mod detail;
+mod diff;
+mod error;
+mod error_details;
+mod events;
+pub mod focus;
+mod help;
+mod highlight;
+mod hints;
+mod icons;
+mod implementation;
+mod json;
 mod list;
+mod merge;
+mod new_issue;
+mod peek;
+mod plan;
+mod preview;
+mod projects;
+mod prompt;
 mod proposal;
+mod review;
+mod sessions;
+mod status;
+mod summary;
+mod tags;
+mod timeline;
+mod toast;
+mod tutorial;
+mod waterfall;
+mod workflow;
+mod worktrees;
 
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
     Frame,
 };
 
-use crate::app::{App, Screen};
+use crate::app::{App, Screen, ScrollView, MIN_TERMINAL_HEIGHT, MIN_TERMINAL_WIDTH};
+use crate::config::ThemeConfig;
+use crate::screens;
+use hints::HintStyle;
+use icons::glyph;
 
 /// Main draw function - routes to appropriate screen.
 pub fn draw(f: &mut Frame, app: &App) {
+    // Layouts assume a minimum size; show a notice instead of corrupt output
+    let area = f.area();
+    if area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT {
+        draw_too_small(f, area);
+        return;
+    }
+
+    let screen = if app.state.theme.summary_line {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1)])
+            .split(area);
+        summary::draw_summary(f, app, chunks[0]);
+        chunks[1]
+    } else {
+        area
+    };
+    let screen = banner::draw_server_banner(f, app, screen);
+    draw_screen(f, app, screen);
+
+    // Visual bell: invert the status bar / footer row
+    if app.state.bell_flash_until.is_some() {
+        let row = Rect { y: area.bottom() - 1, height: 1, ..area };
+        f.buffer_mut().set_style(
+            row,
+            ratatui::style::Style::default().add_modifier(ratatui::style::Modifier::REVERSED),
+        );
+    }
+
+    if app.state.peek.is_some() && app.state.screen == Screen::List {
+        peek::draw_peek(f, app);
+    }
+    if app.state.merge_dialog.is_some() && app.state.screen == Screen::List {
+        merge::draw_merge_dialog(f, app);
+    }
+    if app.state.tag_breakdown.is_some() && app.state.screen == Screen::Detail {
+        tags::draw_tag_breakdown(f, app);
+    }
+    if app.state.event_picker.is_some() && app.state.screen == Screen::Detail {
+        events::draw_event_picker(f, app);
+    }
+    if app.state.show_issue_changes && app.state.screen == Screen::Detail {
+        changes::draw_issue_changes(f, app);
+    }
+    if app.state.finish_review.is_some() && matches!(app.state.screen, Screen::Detail | Screen::Review) {
+        review::draw_finish_review(f, app);
+    }
+    if app.state.plan_preview.is_some() && app.state.screen == Screen::Proposal {
+        plan::draw_plan_preview(f, app);
+    }
+    sessions::draw_session_picker(f, app);
+    sessions::draw_placement_prompt(f, app);
+    sessions::draw_yank_menu(f, app);
+    projects::draw_project_switcher(f, app);
+    tutorial::draw_tutorial(f, app);
+    new_issue::draw_new_issue(f, app);
+    prompt::draw_prompt(f, app);
+    command::draw_command_line(f, app);
+    toast::draw_toasts(f, app);
+
+    if app.state.show_error_details {
+        error_details::draw_error_details(f, app);
+    }
+    if app.state.show_help {
+        help::draw_help(f, app);
+    }
+}
+
+/// Draw the current screen.
+fn draw_screen(f: &mut Frame, app: &App, area: Rect) {
     // Fullscreen views (have their own footer)
     match app.state.screen {
         Screen::Analysis => {
-            analysis::draw_analysis(f, app, f.area());
+            analysis::draw_analysis(f, app, area);
+            return;
+        }
+        Screen::Implementation => {
+            implementation::draw_implementation(f, app, area);
+            return;
+        }
+        Screen::Review => {
+            review::draw_review(f, app, area);
             return;
         }
         Screen::Proposal => {
-            proposal::draw_proposal(f, app, f.area());
+            proposal::draw_proposal(f, app, area);
+            return;
+        }
+        Screen::Error => {
+            error::draw_error(f, app, area);
             return;
         }
         _ => {}
@@ -34,101 +156,163 @@ pub fn draw(f: &mut Frame, app: &App) {
             Constraint::Min(1),    // Main content
             Constraint::Length(1), // Status/action bar
         ])
-        .split(f.area());
+        .split(area);
 
     // Draw main content based on current screen
     match app.state.screen {
         Screen::List => list::draw_list(f, app, chunks[0]),
         Screen::Detail => detail::draw_detail(f, app, chunks[0]),
-        Screen::Analysis | Screen::Proposal => unreachable!(), // Handled above
+        Screen::Worktrees => worktrees::draw_worktrees(f, app, chunks[0]),
+        Screen::Breadcrumbs => breadcrumbs::draw_breadcrumbs(f, app, chunks[0]),
+        Screen::RawJson => json::draw_json(f, app, chunks[0]),
+        Screen::Analysis
+        | Screen::Implementation
+        | Screen::Review
+        | Screen::Proposal
+        | Screen::Error => {
+            unreachable!() // Handled above
+        }
     }
 
     // Draw action bar
     draw_action_bar(f, app, chunks[1]);
 }
 
-/// Draw the action bar at the bottom.
-fn draw_action_bar(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+/// Draw the notice shown when the terminal is too small.
+fn draw_too_small(f: &mut Frame, area: Rect) {
     use ratatui::{
+        layout::Alignment,
         style::{Color, Style},
         text::{Line, Span},
-        widgets::Paragraph,
+        widgets::Wrap,
     };
 
-    let keybinds = match app.state.screen {
-        Screen::List => vec![
-            ("↑↓/jk/C-d/u", "navigate"),
-            ("Enter", "open"),
-            ("a", "analyze"),
-            ("r", "refresh"),
-            ("q", "quit"),
-        ],
-        Screen::Detail => {
-            let mut binds = vec![
-                ("↑↓/jk/C-d/u", "scroll"),
-                ("r", "refresh"),
-                ("q/Esc", "back"),
-            ];
-
-            // Add state-specific keybinds based on current issue (only if loaded and not refreshing)
-            let details_ready = app.state.current_issue.is_some() && !app.state.is_refreshing_detail;
-            if let Some(issue) = &app.state.current_issue {
-                match &issue.state {
-                    crate::api::IssueState::Pending => {
-                        if details_ready {
-                            binds.push(("a", "analyze"));
-                        }
-                    }
-                    crate::api::IssueState::Analyzing { .. } => {
-                        if details_ready {
-                            binds.push(("a", "re-analyze"));
-                        }
-                        binds.push(("Enter", "view analysis"));
-                        binds.push(("i", "interactive"));
-                    }
-                    crate::api::IssueState::PendingApproval { .. } => {
-                        if details_ready {
-                            binds.push(("a", "re-analyze"));
-                        }
-                        binds.push(("Enter", "view proposal"));
-                        binds.push(("i", "interactive"));
-                    }
-                    crate::api::IssueState::InProgress { .. } => {
-                        binds.push(("i", "interactive"));
-                    }
-                    crate::api::IssueState::PendingReview { .. } => {
-                        binds.push(("d", "done"));
-                        binds.push(("i", "interactive"));
-                    }
-                    crate::api::IssueState::Error { .. } => {
-                        if details_ready {
-                            binds.push(("a", "re-analyze"));
-                        }
-                        binds.push(("R", "retry"));
-                    }
-                }
-            }
-
-            binds
-        }
-        Screen::Analysis | Screen::Proposal => {
-            // These screens have their own footer, this shouldn't be called
-            vec![]
-        }
+    let lines = vec![
+        Line::from("Terminal too small"),
+        Line::from(Span::styled(
+            format!("{}x{}", area.width, area.height),
+            Style::default().fg(Color::Red),
+        )),
+        Line::default(),
+        Line::from(format!(
+            "Please enlarge to at least {}x{}",
+            MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT
+        )),
+    ];
+
+    // Vertically center when there is room
+    let top = area.height.saturating_sub(lines.len() as u16) / 2;
+    let area = Rect {
+        y: area.y + top,
+        height: area.height - top,
+        ..area
     };
 
-    let spans: Vec<Span> = keybinds
-        .iter()
-        .flat_map(|(key, desc)| {
-            vec![
-                Span::styled(format!("[{}]", key), Style::default().fg(Color::Cyan)),
-                Span::raw(format!(" {} ", desc)),
-            ]
-        })
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
+/// Record a bordered paragraph's rendered height and apply the view's offset.
+fn scroll_paragraph<'a>(paragraph: Paragraph<'a>, area: Rect, view: &ScrollView) -> Paragraph<'a> {
+    let content_height = paragraph.line_count(area.width.saturating_sub(2));
+    view.set_rendered(content_height, area.height as usize);
+    paragraph.scroll((view.offset() as u16, view.column() as u16))
+}
+
+/// `block` with a note on its top border while `view` leaves long lines
+/// unwrapped, saying how far along they're scrolled.
+fn wrap_title<'a>(block: Block<'a>, view: &ScrollView, theme: &ThemeConfig) -> Block<'a> {
+    if view.wraps() {
+        return block;
+    }
+    let note = match view.column() {
+        0 => " no wrap ".to_string(),
+        column => format!(" no wrap {} col {} ", glyph("·", theme), column + 1),
+    };
+    block.title(Line::from(Span::styled(note, Style::default().fg(Color::DarkGray))).right_aligned())
+}
+
+/// A line that no longer borrows the text it was rendered from.
+fn owned_line(line: Line<'_>) -> Line<'static> {
+    let spans: Vec<Span<'static>> = line
+        .spans
+        .into_iter()
+        .map(|span| Span::styled(span.content.into_owned(), span.style))
         .collect();
+    Line {
+        spans,
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
 
-    let line = Line::from(spans);
-    let paragraph = Paragraph::new(line).style(Style::default().bg(Color::DarkGray));
+/// Draw a scrollbar over the right border of a bordered scrollable area.
+///
+/// Nothing is drawn when the content fits in the viewport.
+fn draw_scrollbar(f: &mut Frame, area: Rect, view: &ScrollView) {
+    use ratatui::{
+        layout::Margin,
+        widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState},
+    };
 
-    f.render_widget(paragraph, area);
+    if view.max_offset() == 0 {
+        return;
+    }
+
+    let mut state = ScrollbarState::new(view.max_offset() + 1)
+        .position(view.offset())
+        .viewport_content_length(view.viewport_height());
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None);
+
+    f.render_stateful_widget(
+        scrollbar,
+        area.inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut state,
+    );
+}
+
+/// Draw the action bar at the bottom.
+fn draw_action_bar(f: &mut Frame, app: &App, area: Rect) {
+    use ratatui::style::{Color, Style};
+
+    let bar = Style::default().bg(Color::DarkGray);
+    let latency = app.latency().map(|stats| {
+        let text = format!(" {} · p95 {} ", format_latency(stats.last), format_latency(stats.p95));
+        // Tinted once the server is slow enough to notice
+        let fg = if stats.is_slow() { Color::Yellow } else { Color::Gray };
+        (text, bar.fg(fg))
+    });
+    let latency_width = latency.as_ref().map_or(0, |(text, _)| text.chars().count() as u16);
+
+    let (hints_area, latency_area) = if latency_width > 0 && area.width >= latency_width * 3 {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(1), Constraint::Length(latency_width)])
+            .split(area);
+        (chunks[0], Some(chunks[1]))
+    } else {
+        (area, None)
+    };
+
+    let line = hints::hint_line(&screens::hints(app), hints_area.width, HintStyle::Bar);
+    f.render_widget(Paragraph::new(line).style(bar), hints_area);
+    if let (Some(area), Some((text, style))) = (latency_area, latency) {
+        f.render_widget(Paragraph::new(text).style(style), area);
+    }
+}
+
+/// `42ms`, or `1.3s` from a second up.
+fn format_latency(duration: std::time::Duration) -> String {
+    if duration.as_millis() >= 1000 {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else {
+        format!("{}ms", duration.as_millis())
+    }
 }
@@ -0,0 +1,89 @@
+//! Form for creating an issue by hand.
+
+use ratatui::{
+    layout::{Constraint, Layout, Position, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, NewIssueField, NewIssueForm};
+use super::help::centered_rect;
+use super::prompt::{scroll_to_cursor, skip_columns};
+
+/// Widest the form gets.
+const MAX_WIDTH: u16 = 88;
+/// Tallest the form gets.
+const MAX_HEIGHT: u16 = 30;
+
+/// Draw the open new issue form and place the terminal cursor in it.
+pub fn draw_new_issue(f: &mut Frame, app: &App) {
+    let Some(form) = &app.state.new_issue else {
+        return;
+    };
+
+    let width = MAX_WIDTH.min(f.area().width.saturating_sub(4));
+    let height = MAX_HEIGHT.min(f.area().height.saturating_sub(4));
+    let area = centered_rect(f.area(), width, height);
+    let title = if form.submitting { " New issue (creating…) " } else { " New issue " };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    // The title takes one line; the rest is split between the others
+    let rows = Layout::vertical([Constraint::Length(3), Constraint::Fill(1), Constraint::Fill(1)]).split(inner);
+    for (field, row) in NewIssueField::ALL.into_iter().zip(rows.iter()) {
+        draw_field(f, form, field, *row);
+    }
+}
+
+/// Draw one field, scrolled so the cursor stays in view while it has focus.
+fn draw_field(f: &mut Frame, form: &NewIssueForm, field: NewIssueField, area: Rect) {
+    let input = form.input(field);
+    let focused = form.focus.is_focused(field) && !form.submitting;
+    let border = if focused { Color::Cyan } else { Color::DarkGray };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ", field.label()))
+        .border_style(Style::default().fg(border))
+        .padding(Padding::horizontal(1));
+    let inner = block.inner(area);
+    let rows = inner.height.max(1) as usize;
+
+    let cursor_line = if focused { input.cursor_line() } else { 0 };
+    let top = (cursor_line + 1).saturating_sub(rows);
+    let (skip, cursor_column) = scroll_to_cursor(input.cursor_column(), inner.width as usize);
+
+    let lines: Vec<Line> = if input.is_empty() {
+        vec![Line::from(Span::styled(field.placeholder(), Style::default().fg(Color::DarkGray)))]
+    } else {
+        input
+            .text()
+            .split('\n')
+            .enumerate()
+            .skip(top)
+            .take(rows)
+            .map(|(i, line)| {
+                if focused && i == cursor_line {
+                    Line::raw(skip_columns(line, skip))
+                } else {
+                    Line::raw(line)
+                }
+            })
+            .collect()
+    };
+    f.render_widget(Paragraph::new(lines).block(block), area);
+
+    if focused {
+        let (column, line) = if input.is_empty() { (0, 0) } else { (cursor_column, cursor_line - top) };
+        f.set_cursor_position(Position {
+            x: inner.x + column as u16,
+            y: inner.y + line as u16,
+        });
+    }
+}
@@ -0,0 +1,162 @@
+//! Peek popup: a summary of the selected issue over the list.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::api::{Issue, IssueDetail, IssueState};
+use crate::app::App;
+use crate::util::truncate_str;
+use super::help::centered_rect;
+use super::status::{format_count, format_time, status_style};
+
+/// Widest the popup gets.
+const MAX_WIDTH: u16 = 72;
+
+/// Draw the peek popup for the selected issue.
+pub fn draw_peek(f: &mut Frame, app: &App) {
+    let Some(peek) = &app.state.peek else {
+        return;
+    };
+    let Some(issue) = app.state.issues.iter().find(|i| i.id == peek.issue_id) else {
+        return;
+    };
+
+    let width = MAX_WIDTH.min(f.area().width.saturating_sub(4));
+    let lines = issue_lines(app, issue, peek.detail.as_ref(), width.saturating_sub(4) as usize);
+
+    let height = lines.len() as u16 + 2;
+    let area = centered_rect(f.area(), width, height);
+    let title = format!(" {} ", issue.short_id);
+    let popup = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(Color::Cyan))
+                .padding(Padding::horizontal(1)),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+/// Title, status and counts from the list, then the summary once the
+/// detail has loaded.
+pub(super) fn issue_lines<'a>(
+    app: &App,
+    issue: &'a Issue,
+    detail: Option<&Result<Box<IssueDetail>, String>>,
+    inner_width: usize,
+) -> Vec<Line<'a>> {
+    let theme = &app.state.theme;
+    let status = status_style(&issue.status, theme);
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            truncate_str(&issue.title, inner_width),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(vec![
+            Span::styled(
+                format!("{} {}", status.marker(theme), status.label),
+                Style::default().fg(status.color),
+            ),
+            Span::styled(
+                format!(
+                    "  ·  {} events  ·  {} users  ·  last seen {}",
+                    format_count(issue.event_count, theme),
+                    format_count(issue.user_count, theme),
+                    format_time(&issue.last_seen, theme)
+                ),
+                dim,
+            ),
+        ]),
+        Line::default(),
+    ];
+
+    match detail {
+        None => lines.push(Line::from(Span::styled("Loading…", dim))),
+        Some(Err(e)) => lines.push(Line::from(Span::styled(e.clone(), Style::default().fg(Color::Red)))),
+        Some(Ok(detail)) => push_summary(&mut lines, app, detail, inner_width),
+    }
+    lines
+}
+
+/// Error, culprit, top frame and where the agent work stands.
+fn push_summary(lines: &mut Vec<Line<'_>>, app: &App, detail: &IssueDetail, width: usize) {
+    let source = &detail.source;
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let exception = source.exceptions.iter().flatten().next();
+    let error_type = exception
+        .map(|e| e.error_type.clone())
+        .or_else(|| source.metadata.as_ref().and_then(|m| m.error_type.clone()));
+    let value = exception
+        .and_then(|e| e.value.clone())
+        .or_else(|| source.metadata.as_ref().and_then(|m| m.value.clone()));
+    let error = match error_type {
+        Some(error_type) => Some(match value {
+            Some(value) => format!("{}: {}", error_type, value),
+            None => error_type,
+        }),
+        // A pasted trace usually starts with the error
+        None => source.stacktrace.as_deref().and_then(|t| t.lines().next()).map(str::to_string),
+    };
+    if let Some(text) = error {
+        lines.push(Line::from(Span::styled(
+            truncate_str(&text, width),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    if let Some(description) = source.description.as_deref().and_then(|d| d.lines().find(|l| !l.trim().is_empty())) {
+        lines.push(Line::from(truncate_str(description, width)));
+    }
+
+    if let Some(culprit) = &source.culprit {
+        lines.push(Line::from(vec![
+            Span::styled("Culprit:   ", dim),
+            Span::raw(truncate_str(culprit, width.saturating_sub(11))),
+        ]));
+    }
+
+    if let Some(frame) = source.frames().next() {
+        let location = format!(
+            "{} ({}:{})",
+            frame.function.as_deref().unwrap_or("?"),
+            app.state.path_config.shorten(frame.filename.as_deref().unwrap_or("?")),
+            frame.lineno.map(|n| n.to_string()).unwrap_or_default()
+        );
+        lines.push(Line::from(vec![
+            Span::styled("Top frame: ", dim),
+            Span::styled(truncate_str(&location, width.saturating_sub(11)), Style::default().fg(Color::Yellow)),
+        ]));
+    }
+
+    lines.push(Line::default());
+    let (summary, detail_line) = match &detail.state {
+        IssueState::Pending => ("Not analyzed yet", None),
+        IssueState::Analyzing { .. } => ("Analysis running", None),
+        IssueState::PendingApproval { proposal, .. } => (
+            "Proposal ready for review",
+            proposal.lines().map(str::trim).find(|l| !l.is_empty() && !l.starts_with('#')),
+        ),
+        IssueState::InProgress { worktree_branch, .. } => {
+            ("Fix in progress", Some(worktree_branch.as_str()))
+        }
+        IssueState::PendingReview { worktree_branch, .. } => {
+            ("Fix ready for review", Some(worktree_branch.as_str()))
+        }
+        IssueState::Error { error, .. } => ("Agent failed", Some(error.as_str())),
+    };
+    lines.push(Line::from(Span::styled(summary, Style::default().add_modifier(Modifier::BOLD))));
+    if let Some(text) = detail_line {
+        lines.push(Line::from(Span::styled(truncate_str(text, width), dim)));
+    }
+}
@@ -0,0 +1,159 @@
+//! Plan preview popup: the files and tests implementing the proposal is
+//! expected to touch, as a checklist, so the reviewer can judge the blast
+//! radius before approving.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::api::{FileChange, PlanResponse};
+use crate::app::App;
+use crate::config::ThemeConfig;
+use super::help::centered_rect;
+use super::icons::glyph;
+use super::{draw_scrollbar, scroll_paragraph};
+
+/// Widest the popup gets.
+const MAX_WIDTH: u16 = 90;
+
+/// Draw the plan preview over the proposal screen.
+pub fn draw_plan_preview(f: &mut Frame, app: &App) {
+    let Some(preview) = &app.state.plan_preview else {
+        return;
+    };
+
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width.saturating_sub(4));
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let (title, lines) = match &preview.plan {
+        None => (" Plan ".to_string(), vec![Line::from(Span::styled("Planning…", dim))]),
+        Some(Err(e)) => (
+            " Plan ".to_string(),
+            vec![Line::from(Span::styled(e.clone(), Style::default().fg(Color::Red)))],
+        ),
+        Some(Ok(plan)) => (
+            format!(" Plan · {} ", blast_radius(plan)),
+            plan_lines(plan, &app.state.theme),
+        ),
+    };
+
+    let popup = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(Color::Cyan))
+                .padding(Padding::horizontal(1)),
+        )
+        .wrap(Wrap { trim: false });
+    // Wrapped at the width inside borders and padding; the count includes
+    // the borders
+    let height = (popup.line_count(width.saturating_sub(4)) as u16).min(screen.height.saturating_sub(4));
+    let area = centered_rect(screen, width, height);
+    let popup = scroll_paragraph(popup, area, &preview.scroll);
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+    draw_scrollbar(f, area, &preview.scroll);
+}
+
+/// "3 files, 1 test".
+fn blast_radius(plan: &PlanResponse) -> String {
+    let plural = |n: usize, noun: &str| format!("{} {}{}", n, noun, if n == 1 { "" } else { "s" });
+    format!("{}, {}", plural(plan.files.len(), "file"), plural(plan.tests.len(), "test"))
+}
+
+/// Checklist lines: the files, then the tests.
+fn plan_lines<'a>(plan: &'a PlanResponse, theme: &ThemeConfig) -> Vec<Line<'a>> {
+    let heading = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+    let dim = Style::default().fg(Color::DarkGray);
+    let unchecked = format!("{} ", glyph("☐", theme));
+
+    let mut lines = vec![Line::from(Span::styled("Files", heading))];
+    if plan.files.is_empty() {
+        lines.push(Line::from(Span::styled("No file changes planned", dim)));
+    }
+    for file in &plan.files {
+        let (marker, color) = change_marker(file.change);
+        let mut spans = vec![
+            Span::styled(unchecked.clone(), dim),
+            Span::styled(format!("{} ", marker), Style::default().fg(color)),
+            Span::raw(file.path.as_str()),
+        ];
+        if let Some(summary) = &file.summary {
+            spans.push(Span::styled(format!("  {}", summary), dim));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    if !plan.tests.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled("Tests", heading)));
+    }
+    for test in &plan.tests {
+        let mut spans = vec![
+            Span::styled(unchecked.clone(), dim),
+            Span::raw(test.path.as_str()),
+        ];
+        if let Some(description) = &test.description {
+            spans.push(Span::styled(format!("  {}", description), dim));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// Letter and color for a change, as in `git status --short`.
+pub(super) fn change_marker(change: FileChange) -> (&'static str, Color) {
+    match change {
+        FileChange::Create => ("A", Color::Green),
+        FileChange::Modify => ("M", Color::Yellow),
+        FileChange::Delete => ("D", Color::Red),
+        FileChange::Other => ("?", Color::DarkGray),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(json: &str) -> PlanResponse {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn text(line: &Line<'_>) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_blast_radius_counts() {
+        let one = plan(r#"{"files":[{"path":"a.ts","change":"modify"}],"tests":[{"path":"a.test.ts"}]}"#);
+        assert_eq!(blast_radius(&one), "1 file, 1 test");
+        assert_eq!(blast_radius(&plan(r#"{"files":[]}"#)), "0 files, 0 tests");
+    }
+
+    #[test]
+    fn test_checklist_lists_files_then_tests() {
+        let plan = plan(
+            r#"{"files":[{"path":"src/auth.ts","change":"modify","summary":"guard user"},
+                         {"path":"src/old.ts","change":"delete"}],
+                "tests":[{"path":"test/auth.test.ts","description":"anonymous session"}]}"#,
+        );
+        let lines: Vec<String> = plan_lines(&plan, &ThemeConfig::default()).iter().map(text).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Files",
+                "☐ M src/auth.ts  guard user",
+                "☐ D src/old.ts",
+                "",
+                "Tests",
+                "☐ test/auth.test.ts  anonymous session",
+            ]
+        );
+    }
+}
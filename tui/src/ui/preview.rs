@@ -0,0 +1,61 @@
+//! Preview pane: the selected issue beside the list on wide terminals.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::App;
+use crate::util::truncate_str;
+use super::peek::issue_lines;
+
+/// Draw the preview of the selected issue into `area`.
+pub fn draw_preview(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .padding(Padding::horizontal(1));
+    let preview = app.state.preview.as_ref();
+    let Some(issue) = preview.and_then(|p| app.state.issues.iter().find(|i| i.id == p.issue_id)) else {
+        f.render_widget(block.title(" Preview "), area);
+        return;
+    };
+
+    let width = area.width.saturating_sub(4) as usize;
+    let detail = preview.and_then(|p| p.detail.as_ref());
+    let mut lines = issue_lines(app, issue, detail, width);
+
+    // The rest of the pane goes to the stack, app frames picked out
+    if let Some(Ok(detail)) = detail {
+        let frames: Vec<_> = detail.source.frames().collect();
+        if !frames.is_empty() {
+            let dim = Style::default().fg(Color::DarkGray);
+            lines.push(Line::default());
+            lines.push(Line::from(Span::styled(
+                "Stack trace",
+                Style::default().add_modifier(Modifier::BOLD),
+            )));
+            for frame in frames {
+                let location = format!(
+                    "{}:{}",
+                    app.state.path_config.shorten(frame.filename.as_deref().unwrap_or("?")),
+                    frame.lineno.map(|n| n.to_string()).unwrap_or_default()
+                );
+                let style = if frame.in_app == Some(false) { dim } else { Style::default().fg(Color::Yellow) };
+                lines.push(Line::from(vec![
+                    Span::styled(truncate_str(frame.function.as_deref().unwrap_or("?"), width / 2), style),
+                    Span::styled(format!("  {}", truncate_str(&location, width / 2)), dim),
+                ]));
+            }
+        }
+    }
+
+    let title = format!(" {} ", issue.short_id);
+    let pane = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(block.title(title));
+    f.render_widget(pane, area);
+}
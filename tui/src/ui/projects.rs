@@ -0,0 +1,67 @@
+//! Project switcher: narrow an aggregated list to one project's issues.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::util::truncate_str;
+use super::help::centered_rect;
+use super::icons::glyph;
+
+/// Widest the popup gets.
+const MAX_WIDTH: u16 = 48;
+
+/// Draw the project switcher over the current screen.
+pub fn draw_project_switcher(f: &mut Frame, app: &App) {
+    let Some(selected) = app.state.project_switcher else {
+        return;
+    };
+
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width.saturating_sub(4));
+    // Borders, padding, the pointer, the current marker and the count
+    let name_width = (width as usize).saturating_sub(4 + 4 + 7);
+    let theme = &app.state.theme;
+    let issues = &app.state.issues;
+
+    let rows = std::iter::once((None, issues.len())).chain(app.state.projects.iter().map(|project| {
+        let count = issues.iter().filter(|i| i.project.as_ref() == Some(project)).count();
+        (Some(project), count)
+    }));
+    let lines: Vec<Line> = rows
+        .enumerate()
+        .map(|(row, (project, count))| {
+            let pointer = if row == selected { glyph("›", theme) } else { " " };
+            let current = project == app.state.view.project.as_ref();
+            let marker = if current { glyph("●", theme) } else { " " };
+            let name = project.map_or("All projects", String::as_str);
+            let line = Line::from(vec![
+                Span::styled(format!("{} ", pointer), Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{} ", marker), Style::default().fg(Color::Cyan)),
+                Span::raw(format!("{:<w$}", truncate_str(name, name_width), w = name_width)),
+                Span::styled(format!("{:>6}", count), Style::default().fg(Color::DarkGray)),
+            ]);
+            if row == selected {
+                line.style(Style::default().add_modifier(Modifier::BOLD))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    let area = centered_rect(screen, width, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Projects ")
+            .border_style(Style::default().fg(Color::Cyan))
+            .padding(Padding::horizontal(1)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
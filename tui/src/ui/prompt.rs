@@ -0,0 +1,162 @@
+//! Modal text prompt, e.g. for a rejection reason.
+
+use ratatui::{
+    layout::Position,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+use std::ops::Range;
+use unicode_width::UnicodeWidthChar;
+
+use crate::app::App;
+use super::help::centered_rect;
+
+/// Widest the popup gets.
+const MAX_WIDTH: u16 = 72;
+/// Rows a multi-line prompt starts with.
+const MIN_MULTILINE_ROWS: usize = 4;
+
+/// Draw the open prompt and place the terminal cursor in it.
+pub fn draw_prompt(f: &mut Frame, app: &App) {
+    let (Some(prompt), Some(input)) = (app.state.prompt, &app.state.input) else {
+        return;
+    };
+
+    let width = MAX_WIDTH.min(f.area().width.saturating_sub(4));
+    // Borders and padding
+    let inner_width = width.saturating_sub(4) as usize;
+    let cursor_line = input.cursor_line();
+    let (skip, cursor_column) = scroll_to_cursor(input.cursor_column(), inner_width);
+
+    let lines: Vec<Line> = if input.is_empty() {
+        vec![Line::from(Span::styled(
+            prompt.placeholder(),
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        let mut line_start = 0;
+        input
+            .text()
+            .split('\n')
+            .enumerate()
+            .map(|(i, line)| {
+                let cursor = input.cursor().checked_sub(line_start).filter(|c| *c <= line.len());
+                line_start += line.len() + 1;
+                let misspelled = misspellings(app, line, cursor);
+                if i == cursor_line {
+                    let visible = skip_columns(line, skip);
+                    underline(visible, &misspelled, line.len() - visible.len())
+                } else {
+                    underline(line, &misspelled, 0)
+                }
+            })
+            .collect()
+    };
+
+    // Multi-line prompts open with room to write
+    let rows = if prompt.multiline() { lines.len().max(MIN_MULTILINE_ROWS) } else { lines.len() };
+    let height = (rows as u16 + 2).min(f.area().height.saturating_sub(4));
+    let area = centered_rect(f.area(), width, height);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", prompt.title()))
+            .border_style(Style::default().fg(Color::Cyan))
+            .padding(Padding::horizontal(1)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+    f.set_cursor_position(Position {
+        x: area.x + 2 + cursor_column as u16,
+        y: area.y + 1 + cursor_line as u16,
+    });
+}
+
+/// Byte ranges of misspelled words in a line of input, given the cursor's
+/// offset in it.
+#[cfg(feature = "spellcheck")]
+fn misspellings(app: &App, line: &str, cursor: Option<usize>) -> Vec<Range<usize>> {
+    match &app.state.dictionary {
+        Some(dictionary) => dictionary.misspellings(line, cursor),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(not(feature = "spellcheck"))]
+fn misspellings(_app: &App, _line: &str, _cursor: Option<usize>) -> Vec<Range<usize>> {
+    Vec::new()
+}
+
+/// `text`, which starts `offset` bytes into its line, with the misspelled
+/// `ranges` of the line underlined.
+fn underline<'a>(text: &'a str, ranges: &[Range<usize>], offset: usize) -> Line<'a> {
+    let style = Style::default().fg(Color::Red).add_modifier(Modifier::UNDERLINED);
+    let mut spans = Vec::new();
+    let mut at = 0;
+    for range in ranges {
+        let start = range.start.saturating_sub(offset).max(at);
+        let end = range.end.saturating_sub(offset);
+        if end <= start {
+            continue;
+        }
+        spans.push(Span::raw(&text[at..start]));
+        spans.push(Span::styled(&text[start..end], style));
+        at = end;
+    }
+    spans.push(Span::raw(&text[at..]));
+    Line::from(spans)
+}
+
+/// Columns to scroll the cursor line by so the cursor stays visible, and the
+/// cursor's column after scrolling.
+pub(super) fn scroll_to_cursor(cursor: usize, width: usize) -> (usize, usize) {
+    let width = width.max(1);
+    if cursor < width {
+        (0, cursor)
+    } else {
+        let skip = cursor + 1 - width;
+        (skip, cursor - skip)
+    }
+}
+
+/// `line` without its first `columns` display columns.
+pub(super) fn skip_columns(line: &str, columns: usize) -> &str {
+    let mut skipped = 0;
+    for (index, c) in line.char_indices() {
+        if skipped >= columns {
+            return &line[index..];
+        }
+        skipped += c.width().unwrap_or(0);
+    }
+    ""
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_lines_scroll_with_the_cursor() {
+        assert_eq!(scroll_to_cursor(5, 20), (0, 5));
+        assert_eq!(scroll_to_cursor(25, 20), (6, 19));
+        assert_eq!(skip_columns("abcdef", 2), "cdef");
+        assert_eq!(skip_columns("日本語", 2), "本語");
+        assert_eq!(skip_columns("ab", 5), "");
+    }
+
+    #[test]
+    fn test_underline_follows_scrolling() {
+        let text = |line: &Line| -> Vec<String> {
+            line.spans.iter().map(|s| s.content.to_string()).collect()
+        };
+        // "teh" in "fix teh bug"
+        let teh = std::slice::from_ref(&(4..7));
+        assert_eq!(text(&underline("fix teh bug", teh, 0)), vec!["fix ", "teh", " bug"]);
+        // Scrolled into the middle of the word
+        assert_eq!(text(&underline("eh bug", teh, 5)), vec!["", "eh", " bug"]);
+        assert_eq!(text(&underline("bug", teh, 8)), vec!["bug"]);
+    }
+}
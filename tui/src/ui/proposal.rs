@@ -1,5 +1,7 @@
 //! Proposal screen rendering.
 
+use std::hash::{DefaultHasher, Hash, Hasher};
+
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -9,7 +11,14 @@ use ratatui::{
 };
 
 use crate::api::IssueState;
-use crate::app::App;
+use crate::app::{App, Critique, CritiqueStatus};
+use crate::screens;
+
+use super::diff::{is_diff_language, DiffRenderer};
+use super::highlight::{self, CodeHighlighter};
+use super::hints::{hint_line, HintStyle};
+use super::icons::glyph;
+use super::{draw_scrollbar, owned_line, scroll_paragraph, wrap_title};
 
 /// Draw the fullscreen proposal view.
 pub fn draw_proposal(f: &mut Frame, app: &App, area: Rect) {
@@ -39,7 +48,10 @@ fn draw_header(f: &mut Frame, app: &App, area: Rect) {
     let header = Paragraph::new(Line::from(vec![
         Span::raw(" "),
         Span::styled(&title, Style::default().add_modifier(Modifier::BOLD)),
-        Span::styled(" ◉ pending approval", Style::default().fg(Color::Cyan)),
+        Span::styled(
+            format!(" {} pending approval", glyph("◉", &app.state.theme)),
+            Style::default().fg(Color::Cyan),
+        ),
     ]))
     .block(Block::default().borders(Borders::ALL).title(" Proposal "));
 
@@ -47,97 +59,207 @@ fn draw_header(f: &mut Frame, app: &App, area: Rect) {
 }
 
 /// Draw the proposal content.
+///
+/// Proposals can run to thousands of lines, so they're rendered to styled
+/// lines once (per proposal, theme and width) and each frame only draws
+/// the rows in view.
 fn draw_content(f: &mut Frame, app: &App, area: Rect) {
-    let mut lines: Vec<Line> = Vec::new();
+    let area = match &app.state.critique {
+        Some(critique) => {
+            // Side by side when there's room, otherwise stacked
+            let direction = if area.width >= 100 {
+                Direction::Horizontal
+            } else {
+                Direction::Vertical
+            };
+            let panes = Layout::default()
+                .direction(direction)
+                .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .split(area);
+            draw_critique(f, app, critique, panes[1]);
+            panes[0]
+        }
+        None => area,
+    };
+    let block = Block::default().borders(Borders::ALL);
+    let view = &app.state.proposal_scroll;
 
     // Get proposal text from issue state
-    let proposal_text = app.state.current_issue.as_ref().and_then(|issue| {
-        if let IssueState::PendingApproval { proposal, .. } = &issue.state {
-            Some(proposal.as_str())
-        } else {
-            None
-        }
+    let proposal = app.state.current_issue.as_ref().and_then(|issue| match &issue.state {
+        IssueState::PendingApproval { proposal, .. } => Some((issue, proposal.as_str())),
+        _ => None,
     });
+    let Some((issue, proposal)) = proposal else {
+        let placeholder = Paragraph::new(Span::styled("No proposal available", Style::default().fg(Color::DarkGray)))
+            .block(block);
+        f.render_widget(scroll_paragraph(placeholder, area, view), area);
+        return;
+    };
 
-    if let Some(proposal) = proposal_text {
-        // Render with basic markdown-style formatting
-        for line in proposal.lines() {
-            let styled_line = if line.starts_with("## ") {
-                Line::from(Span::styled(
-                    &line[3..],
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD),
-                ))
-            } else if line.starts_with("# ") {
-                Line::from(Span::styled(
-                    &line[2..],
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD),
-                ))
-            } else if line.starts_with("### ") {
-                Line::from(Span::styled(
-                    &line[4..],
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ))
-            } else if line.starts_with("```") {
-                Line::from(Span::styled(line, Style::default().fg(Color::DarkGray)))
-            } else if line.starts_with("- ") {
-                Line::from(vec![
-                    Span::styled("  • ", Style::default().fg(Color::DarkGray)),
-                    Span::raw(&line[2..]),
-                ])
-            } else if line.starts_with("+ ") {
-                Line::from(Span::styled(line, Style::default().fg(Color::Green)))
-            } else if line.starts_with("> ") {
-                Line::from(Span::styled(
-                    line,
-                    Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
-                ))
-            } else if line.trim().is_empty() {
-                Line::default()
-            } else {
-                Line::from(line)
-            };
-            lines.push(styled_line);
-        }
+    // Anything the rendered lines depend on. The issue is updated whenever
+    // its proposal changes.
+    let theme = &app.state.theme;
+    let mut key = DefaultHasher::new();
+    (&issue.id, &issue.updated_at, proposal.len()).hash(&mut key);
+    (theme.palette, theme.icons, app.state.diff_line_numbers).hash(&mut key);
+
+    // Unwrapped, long lines scroll sideways so code blocks keep their shape
+    let cache = &app.state.proposal_lines;
+    let width = area.width.saturating_sub(2);
+    let rows = cache.lay_out(key.finish(), view.wraps().then_some(width), || {
+        markdown_lines(app, proposal).into_iter().map(owned_line).collect()
+    });
+    // Borders included, as scroll_paragraph counts them
+    view.set_rendered(rows + 2, area.height as usize);
+    view.set_rendered_width(cache.widest(), width as usize);
+    let (lines, skip) = cache.rows(view.offset(), area.height.saturating_sub(2) as usize);
+
+    let paragraph = Paragraph::new(lines).block(wrap_title(block, view, &app.state.theme));
+    let paragraph = if view.wraps() {
+        paragraph.wrap(Wrap { trim: false })
     } else {
-        lines.push(Line::from(Span::styled(
-            "No proposal available",
-            Style::default().fg(Color::DarkGray),
-        )));
+        paragraph
+    };
+    let paragraph = paragraph.scroll((skip, view.column() as u16));
+
+    f.render_widget(paragraph, area);
+    draw_scrollbar(f, area, view);
+}
+
+/// Draw the critic pass pane.
+fn draw_critique(f: &mut Frame, app: &App, critique: &Critique, area: Rect) {
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut lines = markdown_lines(app, &critique.text);
+    match &critique.status {
+        CritiqueStatus::Streaming if critique.text.is_empty() => {
+            lines.push(Line::from(Span::styled("Waiting for the reviewer…", dim)));
+        }
+        CritiqueStatus::Streaming => {
+            // Cursor after the text still arriving
+            match lines.last_mut() {
+                Some(line) => line.push_span(Span::styled("▌", dim)),
+                None => lines.push(Line::from(Span::styled("▌", dim))),
+            }
+        }
+        CritiqueStatus::Done => {}
+        CritiqueStatus::Failed(error) => {
+            lines.push(Line::default());
+            lines.push(Line::from(Span::styled(
+                format!("{} {}", glyph("✗", &app.state.theme), error),
+                Style::default().fg(Color::Red),
+            )));
+        }
     }
 
+    let title = match &critique.reviewer {
+        Some(reviewer) => format!(" Critique · {} ", reviewer),
+        None => " Critique ".to_string(),
+    };
+    let border = match critique.status {
+        CritiqueStatus::Streaming => Color::Yellow,
+        CritiqueStatus::Done => Color::Cyan,
+        CritiqueStatus::Failed(_) => Color::Red,
+    };
+
     let paragraph = Paragraph::new(lines)
-        .block(Block::default().borders(Borders::ALL))
-        .wrap(Wrap { trim: false })
-        .scroll((app.state.proposal_scroll as u16, 0));
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(border)),
+        )
+        .wrap(Wrap { trim: false });
+    let paragraph = scroll_paragraph(paragraph, area, &critique.scroll);
 
     f.render_widget(paragraph, area);
+    draw_scrollbar(f, area, &critique.scroll);
+}
+
+/// Lines for markdown text, with fenced code highlighted and diffs colored.
+fn markdown_lines<'a>(app: &App, text: &'a str) -> Vec<Line<'a>> {
+    let mut lines: Vec<Line> = Vec::new();
+    let palette = app.state.theme.palette;
+    let mut block: Option<CodeBlock> = None;
+
+    // Render with basic markdown-style formatting
+    for line in text.lines() {
+        if let Some(language) = highlight::fence_language(line) {
+            block = match block {
+                Some(_) => None,
+                None if is_diff_language(language) => {
+                    Some(CodeBlock::Diff(DiffRenderer::new(app.state.diff_line_numbers)))
+                }
+                None => Some(CodeBlock::Code(CodeHighlighter::new(language, palette))),
+            };
+            lines.push(Line::from(Span::styled(line, Style::default().fg(Color::DarkGray))));
+            continue;
+        }
+        match &mut block {
+            Some(CodeBlock::Code(highlighter)) => {
+                lines.push(Line::from(highlighter.highlight(line)));
+                continue;
+            }
+            Some(CodeBlock::Diff(diff)) => {
+                lines.push(diff.render(line));
+                continue;
+            }
+            None => {}
+        }
+
+        let styled_line = if let Some(heading) = line.strip_prefix("## ") {
+            Line::from(Span::styled(
+                heading,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else if let Some(heading) = line.strip_prefix("# ") {
+            Line::from(Span::styled(
+                heading,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else if let Some(heading) = line.strip_prefix("### ") {
+            Line::from(Span::styled(
+                heading,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else if let Some(item) = ["- ", "* ", "+ "].iter().find_map(|p| line.strip_prefix(p)) {
+            // Diffs are only recognized inside ```diff blocks, so these
+            // are all list markers
+            Line::from(vec![
+                Span::styled(
+                    format!("  {} ", glyph("•", &app.state.theme)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::raw(item),
+            ])
+        } else if line.starts_with("> ") {
+            Line::from(Span::styled(
+                line,
+                Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+            ))
+        } else if line.trim().is_empty() {
+            Line::default()
+        } else {
+            Line::from(line)
+        };
+        lines.push(styled_line);
+    }
+    lines
+}
+
+/// Fenced block being rendered.
+enum CodeBlock {
+    Code(CodeHighlighter),
+    Diff(DiffRenderer),
 }
 
 /// Draw the footer with keybindings.
-fn draw_footer(f: &mut Frame, _app: &App, area: Rect) {
-    let keys = vec![
-        ("q/Esc", "back"),
-        ("↑↓/C-d/u", "scroll"),
-        ("A", "approve"),
-        ("x", "reject"),
-    ];
-
-    let spans: Vec<Span> = keys
-        .iter()
-        .flat_map(|(key, desc)| {
-            vec![
-                Span::styled(format!(" [{}]", key), Style::default().fg(Color::Cyan)),
-                Span::styled(format!(" {} ", desc), Style::default().fg(Color::DarkGray)),
-            ]
-        })
-        .collect();
-
-    let footer = Paragraph::new(Line::from(spans));
-    f.render_widget(footer, area);
+fn draw_footer(f: &mut Frame, app: &App, area: Rect) {
+    let line = hint_line(&screens::hints(app), area.width, HintStyle::Footer);
+    f.render_widget(Paragraph::new(line), area);
 }
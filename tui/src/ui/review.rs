@@ -0,0 +1,390 @@
+//! Review screen rendering: the fix's worktree diff, one file at a time,
+//! beside a list of the changed files.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+
+use crate::api::IssueState;
+use crate::app::{App, PullRequest, TestRun, TestStatus};
+use crate::git::FileDiff;
+use crate::screens;
+use crate::util::truncate_str;
+
+use super::analysis::draw_activity;
+use super::diff::DiffRenderer;
+use super::help::centered_rect;
+use super::hints::{hint_line, HintStyle};
+use super::icons::glyph;
+use super::plan::change_marker;
+use super::status::format_clock;
+use super::{draw_scrollbar, scroll_paragraph};
+
+/// Narrowest terminal that gets the file list beside the diff.
+const FILE_LIST_MIN_WIDTH: u16 = 100;
+/// Width of the file list pane, borders included.
+const FILE_LIST_WIDTH: u16 = 36;
+/// Widest the completion dialog gets.
+const FINISH_MAX_WIDTH: u16 = 72;
+/// Commits listed in the completion dialog before the rest are summarized.
+const FINISH_MAX_COMMITS: usize = 10;
+/// Uncommitted files listed in the completion dialog, likewise.
+const FINISH_MAX_FILES: usize = 8;
+
+/// Draw the fullscreen review view.
+pub fn draw_review(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(1),    // Content
+            Constraint::Length(1), // Footer
+        ])
+        .split(area);
+
+    draw_header(f, app, chunks[0]);
+    match test_run(app) {
+        Some(run) => {
+            let panes = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .split(chunks[1]);
+            draw_content(f, app, panes[0]);
+            draw_tests(f, app, run, panes[1]);
+        }
+        None => draw_content(f, app, chunks[1]),
+    }
+    draw_footer(f, app, chunks[2]);
+}
+
+/// Draw the confirmation before completing a review, with the commits the
+/// branch keeps and, above them, any work removing the worktree would lose.
+pub fn draw_finish_review(f: &mut Frame, app: &App) {
+    let Some(dialog) = &app.state.finish_review else {
+        return;
+    };
+
+    let width = FINISH_MAX_WIDTH.min(f.area().width.saturating_sub(4));
+    // Borders, padding, and the hash column
+    let subject_width = width.saturating_sub(4 + 8) as usize;
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let mut lines = vec![
+        Line::from(Span::styled("Completing cleans up the worktree.", dim)),
+        Line::default(),
+    ];
+    match &dialog.at_risk {
+        None => {
+            lines.push(Line::from(Span::styled("Checking for uncommitted changes…", dim)));
+            lines.push(Line::default());
+        }
+        Some(Ok(at_risk)) if !at_risk.is_empty() => {
+            let warning = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+            lines.push(Line::from(Span::styled("This would lose:", warning)));
+            for file in at_risk.files.iter().take(FINISH_MAX_FILES) {
+                lines.push(Line::from(Span::styled(
+                    truncate_str(file, subject_width + 8),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            if at_risk.files.len() > FINISH_MAX_FILES {
+                lines.push(Line::from(Span::styled(
+                    format!("… and {} more files", at_risk.files.len() - FINISH_MAX_FILES),
+                    dim,
+                )));
+            }
+            if at_risk.unpushed > 0 {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "{} commit{} not pushed upstream",
+                        at_risk.unpushed,
+                        if at_risk.unpushed == 1 { "" } else { "s" }
+                    ),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            lines.push(Line::from(Span::styled("Commit or push them first, or press D to complete anyway.", dim)));
+            lines.push(Line::default());
+        }
+        Some(_) => {}
+    }
+    match &dialog.log {
+        None => lines.push(Line::from(Span::styled("Loading commits…", dim))),
+        Some(Err(e)) => lines.push(Line::from(Span::styled(e.clone(), Style::default().fg(Color::Red)))),
+        Some(Ok(log)) if log.commits.is_empty() => {
+            lines.push(Line::from(Span::styled("The branch has no commits", dim)));
+        }
+        Some(Ok(log)) => {
+            lines.push(Line::from(format!(
+                "The branch keeps {} commit{}:",
+                log.commits.len(),
+                if log.commits.len() == 1 { "" } else { "s" }
+            )));
+            for commit in log.commits.iter().take(FINISH_MAX_COMMITS) {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{:<8}", commit.hash), Style::default().fg(Color::Yellow)),
+                    Span::raw(truncate_str(&commit.subject, subject_width)),
+                ]));
+            }
+            if log.commits.len() > FINISH_MAX_COMMITS {
+                lines.push(Line::from(Span::styled(
+                    format!("… and {} older", log.commits.len() - FINISH_MAX_COMMITS),
+                    dim,
+                )));
+            }
+        }
+    }
+
+    let height = lines.len() as u16 + 2;
+    let area = centered_rect(f.area(), width, height);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Complete review ")
+            .border_style(Style::default().fg(Color::Green))
+            .padding(Padding::horizontal(1)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+/// The test run for the issue under review, if there's been one.
+fn test_run(app: &App) -> Option<&TestRun> {
+    app.state
+        .test_run
+        .as_ref()
+        .filter(|run| app.state.review_issue.as_deref() == Some(run.issue_id.as_str()))
+}
+
+/// Draw the header with the issue title, branch, and size of the change.
+fn draw_header(f: &mut Frame, app: &App, area: Rect) {
+    let issue = app.state.current_issue.as_ref();
+    let title = issue
+        .and_then(|i| i.source.title.clone())
+        .unwrap_or_else(|| "Review".to_string());
+    let theme = &app.state.theme;
+
+    let mut spans = vec![
+        Span::raw(" "),
+        Span::styled(&title, Style::default().add_modifier(Modifier::BOLD)),
+    ];
+    if let Some(IssueState::PendingReview { worktree_branch, .. }) = issue.map(|i| &i.state) {
+        spans.push(Span::styled(
+            format!("  {} {}", glyph("⎇", theme), worktree_branch),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    if let Some(Ok(files)) = &app.state.review_diff {
+        let additions: usize = files.iter().map(|f| f.additions).sum();
+        let deletions: usize = files.iter().map(|f| f.deletions).sum();
+        spans.push(Span::styled(
+            format!(
+                "  {} file{} ",
+                files.len(),
+                if files.len() == 1 { "" } else { "s" }
+            ),
+            Style::default().fg(Color::DarkGray),
+        ));
+        spans.push(Span::styled(format!("+{}", additions), Style::default().fg(Color::Green)));
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(format!("-{}", deletions), Style::default().fg(Color::Red)));
+    }
+
+    if let Some(run) = test_run(app) {
+        spans.push(match &run.status {
+            TestStatus::Running => Span::styled(
+                format!("  {} testing", glyph("◐", theme)),
+                Style::default().fg(Color::Yellow),
+            ),
+            TestStatus::Passed => Span::styled(
+                format!("  {} tests passed", glyph("✓", theme)),
+                Style::default().fg(Color::Green),
+            ),
+            TestStatus::Failed(_) => Span::styled(
+                format!("  {} tests failed", glyph("✗", theme)),
+                Style::default().fg(Color::Red),
+            ),
+            TestStatus::Cancelled => Span::styled("  tests cancelled", Style::default().fg(Color::DarkGray)),
+        });
+    }
+
+    let pull_request = issue.and_then(|i| app.state.pull_requests.get(&i.id));
+    match pull_request {
+        Some(PullRequest::Opening) => spans.push(Span::styled(
+            format!("  {} opening PR", glyph("◐", theme)),
+            Style::default().fg(Color::Yellow),
+        )),
+        Some(PullRequest::Opened(url)) => spans.push(Span::styled(
+            format!("  PR {}", url),
+            Style::default().fg(Color::Magenta),
+        )),
+        None => {}
+    }
+
+    let header = Paragraph::new(Line::from(spans))
+        .block(Block::default().borders(Borders::ALL).title(" Review "));
+    f.render_widget(header, area);
+}
+
+/// Draw the test command's output, titled with how long it has run.
+fn draw_tests(f: &mut Frame, app: &App, run: &TestRun, area: Rect) {
+    let clock = format_clock(run.duration.unwrap_or_else(|| run.started.elapsed()), &app.state.theme);
+    let color = match run.status {
+        TestStatus::Running => Color::Yellow,
+        TestStatus::Passed => Color::Green,
+        TestStatus::Failed(_) => Color::Red,
+        TestStatus::Cancelled => Color::DarkGray,
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Tests · {} · {} ", run.command, clock))
+        .border_style(Style::default().fg(color));
+
+    draw_activity(
+        f,
+        area,
+        block,
+        &run.lines,
+        &run.scroll,
+        run.status == TestStatus::Running,
+        &app.state.theme,
+    );
+}
+
+/// Draw the file list and the selected file's diff, or where the diff is at.
+fn draw_content(f: &mut Frame, app: &App, area: Rect) {
+    let dim = Style::default().fg(Color::DarkGray);
+    let message = |text: String, style: Style| {
+        Paragraph::new(Line::from(Span::styled(text, style)))
+            .block(Block::default().borders(Borders::ALL).title(" Diff "))
+    };
+
+    let files = match &app.state.review_diff {
+        None => {
+            f.render_widget(message("Loading diff…".to_string(), dim), area);
+            return;
+        }
+        Some(Err(e)) => {
+            let text = format!("Couldn't diff the worktree: {}", e);
+            f.render_widget(message(text, Style::default().fg(Color::Red)), area);
+            return;
+        }
+        Some(Ok(files)) if files.is_empty() => {
+            let text = "The worktree has no changes".to_string();
+            f.render_widget(message(text, dim), area);
+            return;
+        }
+        Some(Ok(files)) => files,
+    };
+    let selected = app.state.review_file.min(files.len() - 1);
+
+    let diff_area = if area.width >= FILE_LIST_MIN_WIDTH {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(FILE_LIST_WIDTH), Constraint::Min(1)])
+            .split(area);
+        draw_file_list(f, files, selected, chunks[0]);
+        chunks[1]
+    } else {
+        area
+    };
+
+    let file = &files[selected];
+    let mut renderer = DiffRenderer::new(app.state.diff_line_numbers);
+    let lines: Vec<Line> = file.lines.iter().map(|l| renderer.render(l)).collect();
+    let title = format!(" {}/{} · {} ", selected + 1, files.len(), file.path);
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(title));
+    let paragraph = scroll_paragraph(paragraph, diff_area, &app.state.review_scroll);
+    f.render_widget(paragraph, diff_area);
+    draw_scrollbar(f, diff_area, &app.state.review_scroll);
+}
+
+/// Draw the changed files with their change letter and line counts.
+fn draw_file_list(f: &mut Frame, files: &[FileDiff], selected: usize, area: Rect) {
+    // Room for the pointer, letter, and counts inside the borders
+    let inner = area.height.saturating_sub(2) as usize;
+    let counts_width = files
+        .iter()
+        .map(|f| counts(f).chars().count())
+        .max()
+        .unwrap_or(0);
+    let path_width = (area.width as usize).saturating_sub(2 + 4 + 1 + counts_width);
+
+    // Keep the selected file in view
+    let first = (selected + 1).saturating_sub(inner);
+    let lines: Vec<Line> = files
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(inner)
+        .map(|(index, file)| file_line(file, index == selected, path_width, counts_width))
+        .collect();
+
+    let list = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(" Files "));
+    f.render_widget(list, area);
+}
+
+/// "› M src/user.ts     +2 -1"
+fn file_line(file: &FileDiff, selected: bool, path_width: usize, counts_width: usize) -> Line<'_> {
+    let (marker, color) = change_marker(file.change);
+    let pointer = if selected { "›" } else { " " };
+    let path = truncate_start(&file.path, path_width);
+
+    let line = Line::from(vec![
+        Span::raw(format!("{} ", pointer)),
+        Span::styled(format!("{} ", marker), Style::default().fg(color)),
+        Span::raw(format!("{:<w$} ", path, w = path_width)),
+        Span::styled(
+            format!("{:>w$}", counts(file), w = counts_width),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]);
+    if selected {
+        line.style(Style::default().add_modifier(Modifier::BOLD))
+    } else {
+        line
+    }
+}
+
+/// "+2 -1"
+fn counts(file: &FileDiff) -> String {
+    format!("+{} -{}", file.additions, file.deletions)
+}
+
+/// Keep the end of a path, where the file name is, when it doesn't fit.
+fn truncate_start(path: &str, width: usize) -> String {
+    let length = path.chars().count();
+    if length <= width {
+        return path.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let tail: String = path.chars().skip(length - width + 1).collect();
+    format!("…{}", tail)
+}
+
+/// Draw the footer with keybindings.
+fn draw_footer(f: &mut Frame, app: &App, area: Rect) {
+    let line = hint_line(&screens::hints(app), area.width, HintStyle::Footer);
+    f.render_widget(Paragraph::new(line), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_start_keeps_file_name() {
+        assert_eq!(truncate_start("src/user.ts", 20), "src/user.ts");
+        assert_eq!(truncate_start("src/handlers/user.ts", 10), "…s/user.ts");
+        assert_eq!(truncate_start("src/user.ts", 0), "");
+    }
+}
@@ -0,0 +1,153 @@
+//! Session picker popup: which of an issue's sessions to resume, and where
+//! to resume it when glass runs inside tmux or zellij. Also the yank menu,
+//! which is laid out the same way.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, SessionKind};
+use crate::escape::{self, Placement};
+use crate::util::truncate_str;
+use super::help::centered_rect;
+use super::icons::glyph;
+
+/// Widest the popup gets.
+const MAX_WIDTH: u16 = 80;
+/// Column for the session label.
+const LABEL_WIDTH: usize = 16;
+
+/// Draw the session picker over the current screen.
+pub fn draw_session_picker(f: &mut Frame, app: &App) {
+    let Some(picker) = &app.state.session_picker else {
+        return;
+    };
+
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width.saturating_sub(4));
+    // Borders, padding and the pointer
+    let path_width = (width as usize).saturating_sub(6 + LABEL_WIDTH);
+    let theme = &app.state.theme;
+
+    let lines: Vec<Line> = [
+        (SessionKind::Analysis, &picker.analysis.path),
+        (SessionKind::Implementation, &picker.implementation.path),
+    ]
+    .into_iter()
+    .map(|(kind, path)| {
+        let selected = kind == picker.selected;
+        let pointer = if selected { glyph("›", theme) } else { " " };
+        let line = Line::from(vec![
+            Span::styled(format!("{} ", pointer), Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{:<w$}", kind.label(), w = LABEL_WIDTH)),
+            Span::styled(truncate_str(path, path_width), Style::default().fg(Color::DarkGray)),
+        ]);
+        if selected {
+            line.style(Style::default().add_modifier(Modifier::BOLD))
+        } else {
+            line
+        }
+    })
+    .collect();
+
+    let area = centered_rect(screen, width, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Resume in {} ", escape::program(&app.state.escape_command)))
+            .border_style(Style::default().fg(Color::Cyan))
+            .padding(Padding::horizontal(1)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+/// Draw the prompt asking where to open the session.
+pub fn draw_placement_prompt(f: &mut Frame, app: &App) {
+    let (Some(session), Some(multiplexer)) = (&app.state.placement_prompt, app.state.multiplexer) else {
+        return;
+    };
+
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width.saturating_sub(4));
+    let key = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+    let dim = Style::default().fg(Color::DarkGray);
+    let option = |k: &'static str, text: String| {
+        Line::from(vec![Span::styled(format!("{:<3}", k), key), Span::raw(text)])
+    };
+
+    let lines = vec![
+        Line::from(Span::styled(
+            truncate_str(&session.path, (width as usize).saturating_sub(4)),
+            dim,
+        )),
+        Line::default(),
+        option("p", format!("New {} {}", multiplexer.name(), multiplexer.describe(Placement::Pane))),
+        option("w", format!("New {} {}", multiplexer.name(), multiplexer.describe(Placement::Window))),
+        option("t", "This terminal (glass waits until it exits)".to_string()),
+    ];
+
+    let area = centered_rect(screen, width, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Open {} in ", escape::program(&app.state.escape_command)))
+            .border_style(Style::default().fg(Color::Cyan))
+            .padding(Padding::horizontal(1)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+/// Draw the yank menu: each item's key, label and (first line of) text.
+pub fn draw_yank_menu(f: &mut Frame, app: &App) {
+    let Some(menu) = &app.state.yank_menu else {
+        return;
+    };
+
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width.saturating_sub(4));
+    // Borders, padding, the pointer and the key
+    let text_width = (width as usize).saturating_sub(8 + LABEL_WIDTH);
+    let theme = &app.state.theme;
+    let key = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+
+    let lines: Vec<Line> = menu
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, (item, text))| {
+            let selected = i == menu.selected;
+            let pointer = if selected { glyph("›", theme) } else { " " };
+            let preview = text.lines().next().unwrap_or_default();
+            let line = Line::from(vec![
+                Span::styled(format!("{} ", pointer), Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{} ", item.key()), key),
+                Span::raw(format!("{:<w$}", item.label(), w = LABEL_WIDTH)),
+                Span::styled(truncate_str(preview, text_width), Style::default().fg(Color::DarkGray)),
+            ]);
+            if selected {
+                line.style(Style::default().add_modifier(Modifier::BOLD))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    let area = centered_rect(screen, width, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Copy ")
+            .border_style(Style::default().fg(Color::Cyan))
+            .padding(Padding::horizontal(1)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
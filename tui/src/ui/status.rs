@@ -0,0 +1,192 @@
+//! How issue statuses look: a distinct glyph per state (so color is never
+//! the only cue), a color from the configured palette, and an optional
+//! letter badge.
+
+use std::time::Duration;
+
+use ratatui::style::Color;
+use time::OffsetDateTime;
+
+use crate::api::QueuedAnalysis;
+use crate::config::{Palette, ThemeConfig};
+use crate::util::{local_time, relative_time};
+use super::icons;
+
+/// Display attributes for one status.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatusStyle {
+    pub glyph: &'static str,
+    pub color: Color,
+    /// Abbreviated label for the list column
+    pub label: &'static str,
+    /// Letter shown next to the glyph when badges are enabled
+    pub badge: char,
+}
+
+impl StatusStyle {
+    /// Glyph plus badge, e.g. "◆" or "◆A".
+    pub fn marker(&self, theme: &ThemeConfig) -> String {
+        if theme.status_badges {
+            format!("{}{}", self.glyph, self.badge)
+        } else {
+            self.glyph.to_string()
+        }
+    }
+}
+
+/// Look up how a server status string is shown.
+pub fn status_style(status: &str, theme: &ThemeConfig) -> StatusStyle {
+    let (glyph, label, badge) = match status {
+        "pending" => ("○", "PENDING", 'P'),
+        "analyzing" => ("◐", "ANALYZE", 'A'),
+        "pending_approval" => ("◆", "APPROVAL", '?'),
+        "in_progress" => ("▲", "WORKING", 'W'),
+        "pending_review" => ("■", "REVIEW", 'R'),
+        "error" => ("✗", "ERROR", 'E'),
+        _ => ("·", "UNKNOWN", '-'),
+    };
+    StatusStyle {
+        glyph: icons::glyph(glyph, theme),
+        color: status_color(status, theme.palette),
+        label,
+        badge,
+    }
+}
+
+/// Status column label for an analysis waiting in the server's queue:
+/// "#3 ~2m", or "QUEUE #3" without an estimate.
+pub fn queue_label(entry: &QueuedAnalysis) -> String {
+    match entry.eta_seconds {
+        Some(seconds) => format!("#{} {}", entry.position, format_eta(seconds)),
+        None => format!("QUEUE #{}", entry.position),
+    }
+}
+
+/// Rough wait, rounded up: "~40s", "~3m", "~2h".
+pub fn format_eta(seconds: u64) -> String {
+    if seconds < 60 {
+        format!("~{}s", seconds)
+    } else if seconds < 3600 {
+        format!("~{}m", seconds.div_ceil(60))
+    } else {
+        format!("~{}h", seconds.div_ceil(3600))
+    }
+}
+
+/// When something happened: "3h ago", or the local time with absolute
+/// times on. Timestamps that don't parse are shown as they are.
+pub fn format_time(timestamp: &str, theme: &ThemeConfig) -> String {
+    let formatted = if theme.absolute_times {
+        local_time(timestamp, &theme.locale)
+    } else {
+        relative_time(timestamp, OffsetDateTime::now_utc())
+    };
+    formatted.unwrap_or_else(|| timestamp.to_string())
+}
+
+/// An event or user count, written the configured way.
+pub fn format_count(n: u64, theme: &ThemeConfig) -> String {
+    theme.locale.count(n, theme.numbers)
+}
+
+/// A running clock, "3:07", or whole minutes ("3m") with reduced motion so
+/// it doesn't change every second.
+pub fn format_clock(elapsed: Duration, theme: &ThemeConfig) -> String {
+    let secs = elapsed.as_secs();
+    if theme.reduced_motion {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+fn status_color(status: &str, palette: Palette) -> Color {
+    match palette {
+        Palette::Default => match status {
+            "pending" => Color::DarkGray,
+            "analyzing" => Color::Yellow,
+            "pending_approval" => Color::Cyan,
+            "in_progress" => Color::Blue,
+            "pending_review" => Color::Green,
+            "error" => Color::Red,
+            _ => Color::White,
+        },
+        Palette::ColorBlind => match status {
+            "pending" => Color::DarkGray,
+            "analyzing" => Color::Rgb(240, 228, 66),
+            "pending_approval" => Color::Rgb(86, 180, 233),
+            "in_progress" => Color::Rgb(0, 114, 178),
+            "pending_review" => Color::Rgb(0, 158, 115),
+            "error" => Color::Rgb(213, 94, 0),
+            _ => Color::White,
+        },
+        Palette::Light => match status {
+            "pending" => Color::Gray,
+            "analyzing" => Color::Rgb(153, 102, 0),
+            "pending_approval" => Color::Rgb(0, 128, 128),
+            "in_progress" => Color::Rgb(0, 70, 170),
+            "pending_review" => Color::Rgb(0, 120, 40),
+            "error" => Color::Rgb(180, 0, 0),
+            _ => Color::Reset,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUSES: [&str; 6] = [
+        "pending",
+        "analyzing",
+        "pending_approval",
+        "in_progress",
+        "pending_review",
+        "error",
+    ];
+
+    #[test]
+    fn test_every_status_has_its_own_glyph_and_badge() {
+        let theme = ThemeConfig::default();
+        let styles: Vec<_> = STATUSES.iter().map(|s| status_style(s, &theme)).collect();
+        for (i, a) in styles.iter().enumerate() {
+            for b in &styles[i + 1..] {
+                assert_ne!(a.glyph, b.glyph);
+                assert_ne!(a.badge, b.badge);
+            }
+        }
+    }
+
+    #[test]
+    fn test_marker_adds_badge_when_enabled() {
+        let mut theme = ThemeConfig::default();
+        assert_eq!(status_style("in_progress", &theme).marker(&theme), "▲");
+        theme.status_badges = true;
+        assert_eq!(status_style("in_progress", &theme).marker(&theme), "▲W");
+    }
+
+    #[test]
+    fn test_clock_holds_still_with_reduced_motion() {
+        let elapsed = Duration::from_secs(187);
+        assert_eq!(format_clock(elapsed, &ThemeConfig::default()), "3:07");
+        let still = ThemeConfig {
+            reduced_motion: true,
+            ..ThemeConfig::default()
+        };
+        assert_eq!(format_clock(elapsed, &still), "3m");
+    }
+
+    #[test]
+    fn test_queue_label_fits_the_status_column() {
+        let entry = |position, eta_seconds| QueuedAnalysis {
+            issue_id: "i".to_string(),
+            position,
+            eta_seconds,
+        };
+        assert_eq!(queue_label(&entry(3, Some(95))), "#3 ~2m");
+        assert_eq!(queue_label(&entry(3, None)), "QUEUE #3");
+        assert_eq!(format_eta(40), "~40s");
+        assert_eq!(format_eta(3601), "~2h");
+        assert!(queue_label(&entry(12, Some(3000))).chars().count() <= 9);
+    }
+}
@@ -0,0 +1,88 @@
+//! Summary line across the top of every screen, tmux-style: how many issues
+//! are in each state, and the pinned (or else the open) issue's status.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::api::Issue;
+use crate::app::{App, Screen, STATUSES};
+use super::icons::glyph;
+use super::status::{format_count, status_style};
+
+/// Draw the summary line.
+pub fn draw_summary(f: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.state.theme;
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let mut spans = vec![Span::raw(" ")];
+    for (status, count) in status_counts(&app.state.issues) {
+        let style = status_style(status, theme);
+        spans.push(Span::styled(
+            format!("{}{} ", style.marker(theme), format_count(count as u64, theme)),
+            Style::default().fg(style.color),
+        ));
+    }
+    if spans.len() == 1 {
+        spans.push(Span::styled("no issues ", dim));
+    }
+
+    let focused = match &app.state.pinned_issue {
+        Some(id) => Some((id.as_str(), true)),
+        None if app.state.screen == Screen::List => None,
+        None => app.state.current_issue.as_ref().map(|i| (i.id.as_str(), false)),
+    };
+    if let Some((id, pinned)) = focused {
+        spans.push(Span::styled("│ ", dim));
+        if pinned {
+            spans.push(Span::styled(format!("{} ", glyph("⚑", theme)), Style::default().fg(Color::Magenta)));
+        }
+        match app.state.issues.iter().find(|i| i.id == id) {
+            Some(issue) => {
+                let style = status_style(&issue.status, theme);
+                spans.push(Span::styled(
+                    format!("{} ", issue.short_id),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ));
+                spans.push(Span::styled(
+                    format!("{} {}", style.marker(theme), style.label),
+                    Style::default().fg(style.color),
+                ));
+            }
+            None => spans.push(Span::styled(id.to_string(), dim)),
+        }
+    }
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// Issues per status, in pipeline order, leaving out empty ones.
+fn status_counts(issues: &[Issue]) -> Vec<(&'static str, usize)> {
+    STATUSES
+        .iter()
+        .map(|status| (*status, issues.iter().filter(|i| i.status == *status).count()))
+        .filter(|(_, count)| *count > 0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_issue;
+
+    #[test]
+    fn test_counts_follow_the_pipeline() {
+        let issues: Vec<Issue> = ["error", "pending", "pending", "pending_review"]
+            .into_iter()
+            .map(|status| test_issue("I-1", status))
+            .collect();
+        assert_eq!(
+            status_counts(&issues),
+            vec![("pending", 2), ("pending_review", 1), ("error", 1)]
+        );
+    }
+}
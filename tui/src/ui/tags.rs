@@ -0,0 +1,184 @@
+//! Tag breakdown popup: how each tag's values are distributed across the
+//! issue's events, as horizontal bars.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+
+use crate::api::TagStats;
+use crate::app::App;
+use crate::config::ThemeConfig;
+use crate::util::truncate_str;
+use super::help::centered_rect;
+use super::status::format_count;
+use super::{draw_scrollbar, scroll_paragraph};
+
+/// Widest the popup gets.
+const MAX_WIDTH: u16 = 80;
+/// Values listed per tag; the rest are summed into "other".
+const MAX_VALUES: usize = 5;
+/// Columns for the value label.
+const LABEL_WIDTH: usize = 20;
+
+/// Draw the tag breakdown over the detail screen.
+pub fn draw_tag_breakdown(f: &mut Frame, app: &App) {
+    let Some(breakdown) = &app.state.tag_breakdown else {
+        return;
+    };
+
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width.saturating_sub(4));
+    // Borders and padding
+    let inner_width = width.saturating_sub(4) as usize;
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let mut lines = Vec::new();
+    match &breakdown.tags {
+        None => lines.push(Line::from(Span::styled("Loading…", dim))),
+        Some(Err(e)) => lines.push(Line::from(Span::styled(e.clone(), Style::default().fg(Color::Red)))),
+        Some(Ok(tags)) if tags.is_empty() => {
+            lines.push(Line::from(Span::styled("No tags recorded for this issue", dim)));
+        }
+        Some(Ok(tags)) => {
+            for tag in by_concentration(tags) {
+                push_tag(&mut lines, tag, inner_width, &app.state.theme);
+            }
+            lines.pop();
+        }
+    }
+
+    let height = (lines.len() as u16 + 2).min(screen.height.saturating_sub(4));
+    let area = centered_rect(screen, width, height);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Tags · most concentrated first ")
+            .border_style(Style::default().fg(Color::Cyan))
+            .padding(Padding::horizontal(1)),
+    );
+    let popup = scroll_paragraph(popup, area, &breakdown.scroll);
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+    draw_scrollbar(f, area, &breakdown.scroll);
+}
+
+/// Tags whose most common value covers the largest share come first, since
+/// a lopsided tag is the likeliest hint that the bug is environment-specific.
+fn by_concentration(tags: &[TagStats]) -> Vec<&TagStats> {
+    let mut sorted: Vec<&TagStats> = tags.iter().collect();
+    sorted.sort_by(|a, b| top_share(b).total_cmp(&top_share(a)));
+    sorted
+}
+
+fn top_share(tag: &TagStats) -> f64 {
+    tag.top_values
+        .first()
+        .map_or(0.0, |v| share(v.count, tag.total_values))
+}
+
+fn share(count: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+/// Heading, one bar per value, and a trailing blank line.
+fn push_tag(lines: &mut Vec<Line<'_>>, tag: &TagStats, width: usize, theme: &ThemeConfig) {
+    lines.push(Line::from(vec![
+        Span::styled(tag.key.clone(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Span::styled(
+            format!("  {} events", format_count(tag.total_values, theme)),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]));
+
+    // Label, space, bar, then " 100%  12345"
+    let bar_width = width.saturating_sub(LABEL_WIDTH + 1 + 13).max(1);
+    let mut rows: Vec<(String, u64)> = tag
+        .top_values
+        .iter()
+        .take(MAX_VALUES)
+        .map(|v| (v.value.clone(), v.count))
+        .collect();
+    let shown: u64 = rows.iter().map(|(_, count)| count).sum();
+    if shown < tag.total_values {
+        rows.push(("(other)".to_string(), tag.total_values - shown));
+    }
+
+    for (value, count) in rows {
+        let fraction = share(count, tag.total_values);
+        lines.push(Line::from(vec![
+            Span::raw(format!("{:<w$} ", truncate_str(&value, LABEL_WIDTH), w = LABEL_WIDTH)),
+            Span::styled(
+                format!("{:<w$}", bar(fraction, bar_width), w = bar_width),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(format!(" {:>3.0}%", fraction * 100.0)),
+            Span::styled(format!("  {}", format_count(count, theme)), Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+    lines.push(Line::default());
+}
+
+/// A bar `fraction` of `width` cells long, with eighth-cell precision.
+fn bar(fraction: f64, width: usize) -> String {
+    const PARTIAL: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+    let eighths = (fraction.clamp(0.0, 1.0) * width as f64 * 8.0).round() as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    bar.push_str(PARTIAL[eighths % 8]);
+    bar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::TagValueCount;
+
+    fn tag(key: &str, total: u64, counts: &[u64]) -> TagStats {
+        TagStats {
+            key: key.to_string(),
+            total_values: total,
+            top_values: counts
+                .iter()
+                .map(|&count| TagValueCount {
+                    value: format!("v{}", count),
+                    count,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_bar_uses_partial_cells() {
+        assert_eq!(bar(1.0, 4), "████");
+        assert_eq!(bar(0.5, 4), "██");
+        assert_eq!(bar(0.5 + 1.0 / 32.0, 4), "██▏");
+        assert_eq!(bar(0.0, 4), "");
+    }
+
+    #[test]
+    fn test_sorts_lopsided_tags_first() {
+        let tags = vec![
+            tag("environment", 100, &[50, 50]),
+            tag("browser", 100, &[98, 2]),
+            tag("empty", 0, &[]),
+        ];
+        let keys: Vec<&str> = by_concentration(&tags).iter().map(|t| t.key.as_str()).collect();
+        assert_eq!(keys, vec!["browser", "environment", "empty"]);
+    }
+
+    #[test]
+    fn test_remainder_is_other() {
+        let mut lines = Vec::new();
+        push_tag(&mut lines, &tag("os", 10, &[6, 3]), 60, &ThemeConfig::default());
+        // Heading, two values, "(other)", blank
+        assert_eq!(lines.len(), 5);
+        assert!(lines[3].spans[0].content.starts_with("(other)"));
+    }
+}
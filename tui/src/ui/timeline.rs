@@ -0,0 +1,150 @@
+//! Time-scaled breadcrumb layout: rows are spaced by elapsed time so bursts
+//! and stalls before the crash stand out.
+
+use crate::app::TIMELINE_ZOOM_LEVELS as ZOOM_LEVELS;
+
+/// Longest run of empty rows before a gap is collapsed to one marker.
+const MAX_EMPTY_ROWS: usize = 12;
+
+/// One row of the timeline.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimelineRow {
+    /// Breadcrumb at `index`, `offset` seconds after the first
+    Crumb { index: usize, offset: f64 },
+    /// Empty row with a ruler tick at `offset` seconds
+    Tick { offset: f64 },
+    /// Empty row
+    Empty,
+    /// A collapsed stretch of `seconds` with nothing in it
+    Gap { seconds: f64 },
+}
+
+/// Lay out breadcrumbs at `times` (seconds, ascending) for a zoom level.
+pub fn timeline_rows(times: &[f64], zoom: usize) -> Vec<TimelineRow> {
+    let (per_row, tick) = ZOOM_LEVELS[zoom.min(ZOOM_LEVELS.len() - 1)];
+    let Some(&first) = times.first() else {
+        return Vec::new();
+    };
+
+    let mut rows = Vec::new();
+    let mut previous = 0.0;
+    for (index, &time) in times.iter().enumerate() {
+        let offset = time - first;
+        if index > 0 {
+            let empty = ((offset - previous) / per_row).floor() as usize;
+            let empty = empty.saturating_sub(1);
+            if empty > MAX_EMPTY_ROWS {
+                rows.push(TimelineRow::Gap {
+                    seconds: offset - previous,
+                });
+            } else {
+                for row in 1..=empty {
+                    let start = previous + row as f64 * per_row;
+                    rows.push(match tick_in(start, per_row, tick) {
+                        Some(offset) => TimelineRow::Tick { offset },
+                        None => TimelineRow::Empty,
+                    });
+                }
+            }
+        }
+        rows.push(TimelineRow::Crumb { index, offset });
+        previous = offset;
+    }
+    rows
+}
+
+/// The ruler tick falling within `[start, start + per_row)`, if any.
+fn tick_in(start: f64, per_row: f64, tick: f64) -> Option<f64> {
+    let next = (start / tick).ceil() * tick;
+    (next < start + per_row).then_some(next)
+}
+
+/// Compact offset label: "+0.4s", "+12s", "+3m05s".
+pub fn format_offset(seconds: f64) -> String {
+    if seconds < 10.0 {
+        format!("+{:.1}s", seconds)
+    } else if seconds < 60.0 {
+        format!("+{:.0}s", seconds)
+    } else {
+        let secs = seconds as u64;
+        format!("+{}m{:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Compact duration: "800ms", "12s", "3m 5s", "2h 10m", "3d 4h".
+pub fn format_gap(seconds: f64) -> String {
+    let secs = seconds as u64;
+    if seconds < 1.0 {
+        format!("{:.0}ms", seconds * 1000.0)
+    } else if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else if secs < 86_400 {
+        format!("{}h {}m", secs / 3600, secs / 60 % 60)
+    } else {
+        format!("{}d {}h", secs / 86_400, secs / 3600 % 24)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gaps_are_proportional() {
+        // 1s per row
+        let rows = timeline_rows(&[100.0, 100.5, 104.0], 2);
+        assert_eq!(
+            rows,
+            vec![
+                TimelineRow::Crumb { index: 0, offset: 0.0 },
+                TimelineRow::Crumb { index: 1, offset: 0.5 },
+                TimelineRow::Empty,
+                TimelineRow::Empty,
+                TimelineRow::Crumb { index: 2, offset: 4.0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ticks_mark_the_ruler() {
+        // 1s per row with ticks every 5s, counted from the first crumb
+        let rows = timeline_rows(&[20.0, 27.0], 2);
+        assert_eq!(rows.len(), 8);
+        assert_eq!(rows[5], TimelineRow::Tick { offset: 5.0 });
+        assert!(rows[1..5].iter().chain(&rows[6..7]).all(|r| *r == TimelineRow::Empty));
+    }
+
+    #[test]
+    fn test_long_stalls_collapse() {
+        // 0.5s per row would need 23 empty rows
+        let rows = timeline_rows(&[0.0, 12.0], 1);
+        assert_eq!(rows[1], TimelineRow::Gap { seconds: 12.0 });
+        assert_eq!(rows.len(), 3);
+    }
+
+    #[test]
+    fn test_tick_in_row() {
+        assert_eq!(tick_in(9.5, 1.0, 10.0), Some(10.0));
+        assert_eq!(tick_in(10.5, 1.0, 10.0), None);
+    }
+
+    #[test]
+    fn test_zoom_out_shrinks_layout() {
+        let times = [0.0, 2.0, 4.0, 6.0];
+        assert!(timeline_rows(&times, 0).len() > timeline_rows(&times, 3).len());
+        assert_eq!(timeline_rows(&times, 6).len(), 4);
+    }
+
+    #[test]
+    fn test_format_offset_and_gap() {
+        assert_eq!(format_offset(0.42), "+0.4s");
+        assert_eq!(format_offset(12.0), "+12s");
+        assert_eq!(format_offset(185.0), "+3m05s");
+        assert_eq!(format_gap(0.8), "800ms");
+        assert_eq!(format_gap(185.0), "3m 5s");
+        assert_eq!(format_gap(7800.0), "2h 10m");
+        assert_eq!(format_gap(273_600.0), "3d 4h");
+    }
+}
@@ -0,0 +1,73 @@
+//! Toast stack drawn over the top-right corner of the screen.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{App, Severity};
+
+use super::icons::glyph;
+
+/// Widest a toast gets; longer messages wrap.
+const MAX_WIDTH: u16 = 48;
+/// Tallest a toast gets, including borders.
+const MAX_HEIGHT: u16 = 5;
+
+/// Draw visible toasts, newest on top.
+pub fn draw_toasts(f: &mut Frame, app: &App) {
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width / 2);
+    let inner_width = width.saturating_sub(4).max(1) as usize;
+    let mut y = screen.y + 1;
+
+    for toast in app.state.toasts.iter() {
+        let (icon, color) = severity_style(toast.severity);
+        let icon = glyph(icon, &app.state.theme);
+        let text_width = toast.message.chars().count() + 2;
+        let height = (text_width.div_ceil(inner_width) as u16 + 2).min(MAX_HEIGHT);
+        if y + height > screen.bottom().saturating_sub(1) {
+            break;
+        }
+
+        let area = Rect {
+            x: screen.right().saturating_sub(width + 1),
+            y,
+            width,
+            height,
+        };
+
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color));
+        if toast.details.is_some() {
+            block = block.title_bottom(
+                Line::from(Span::styled(" E: details ", Style::default().fg(Color::DarkGray)))
+                    .right_aligned(),
+            );
+        }
+
+        let paragraph = Paragraph::new(Line::from(vec![
+            Span::styled(format!("{} ", icon), Style::default().fg(color)),
+            Span::raw(toast.message.as_str()),
+        ]))
+        .wrap(Wrap { trim: true })
+        .block(block);
+
+        f.render_widget(Clear, area);
+        f.render_widget(paragraph, area);
+        y += height;
+    }
+}
+
+fn severity_style(severity: Severity) -> (&'static str, Color) {
+    match severity {
+        Severity::Info => ("ℹ", Color::Cyan),
+        Severity::Success => ("✓", Color::Green),
+        Severity::Warning => ("⚠", Color::Yellow),
+        Severity::Error => ("✗", Color::Red),
+    }
+}
@@ -0,0 +1,52 @@
+//! Tutorial prompt drawn above the bottom row while a tutorial runs.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, TUTORIAL_STEPS};
+
+/// Widest the prompt box gets.
+const MAX_WIDTH: u16 = 64;
+
+/// Draw the current tutorial step, if a tutorial is running.
+pub fn draw_tutorial(f: &mut Frame, app: &App) {
+    let Some(tutorial) = &app.state.tutorial else {
+        return;
+    };
+    let step = tutorial.current();
+
+    let screen = f.area();
+    let width = MAX_WIDTH.min(screen.width.saturating_sub(2));
+    let height = 4;
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.bottom().saturating_sub(height + 2),
+        width,
+        height,
+    };
+
+    let lines = vec![
+        Line::from(Span::styled(step.prompt, Style::default().add_modifier(Modifier::BOLD))),
+        Line::from(vec![
+            Span::styled("Press ", Style::default().fg(Color::DarkGray)),
+            Span::styled(step.keys, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled("  ·  Q to exit", Style::default().fg(Color::DarkGray)),
+        ]),
+    ];
+
+    let title = format!(" Tutorial {}/{} ", tutorial.step + 1, TUTORIAL_STEPS.len());
+    let prompt = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(Color::Magenta)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(prompt, area);
+}
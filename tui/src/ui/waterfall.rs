@@ -0,0 +1,190 @@
+//! Span waterfall for performance issues.
+//!
+//! Spans are nested under their parents and drawn as bars positioned and
+//! scaled by their start offset and duration within the transaction.
+
+use std::collections::{HashMap, HashSet};
+
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+use crate::api::{SpanInfo, TransactionInfo};
+use crate::util::truncate_str;
+
+/// Width of the indented "op description" column.
+const LABEL_WIDTH: usize = 30;
+/// Width of the duration column.
+const DURATION_WIDTH: usize = 9;
+/// Narrowest the bar column gets, even on small terminals.
+const MIN_BAR_WIDTH: usize = 10;
+
+/// A span placed in the waterfall.
+#[derive(Debug)]
+pub struct WaterfallRow<'a> {
+    pub span: &'a SpanInfo,
+    pub depth: usize,
+}
+
+/// Order spans depth-first under their parents, siblings by start time.
+///
+/// Spans whose parent isn't in the payload are treated as roots.
+pub fn waterfall_rows(spans: &[SpanInfo]) -> Vec<WaterfallRow<'_>> {
+    let ids: HashSet<&str> = spans.iter().map(|s| s.span_id.as_str()).collect();
+
+    let mut roots: Vec<&SpanInfo> = Vec::new();
+    let mut children: HashMap<&str, Vec<&SpanInfo>> = HashMap::new();
+    for span in spans {
+        match span.parent_span_id.as_deref().filter(|p| ids.contains(p)) {
+            Some(parent) => children.entry(parent).or_default().push(span),
+            None => roots.push(span),
+        }
+    }
+
+    let by_start = |a: &&SpanInfo, b: &&SpanInfo| a.start_timestamp.total_cmp(&b.start_timestamp);
+    roots.sort_by(by_start);
+    for list in children.values_mut() {
+        list.sort_by(by_start);
+    }
+
+    let mut rows = Vec::with_capacity(spans.len());
+    let mut visited: HashSet<&str> = HashSet::new();
+    let mut stack: Vec<(&SpanInfo, usize)> = roots.into_iter().rev().map(|s| (s, 0)).collect();
+    while let Some((span, depth)) = stack.pop() {
+        // Malformed payloads can contain parent cycles
+        if !visited.insert(span.span_id.as_str()) {
+            continue;
+        }
+        rows.push(WaterfallRow { span, depth });
+        if let Some(kids) = children.get(span.span_id.as_str()) {
+            stack.extend(kids.iter().rev().map(|k| (*k, depth + 1)));
+        }
+    }
+    rows
+}
+
+/// Start column and length of a bar within `width` columns.
+///
+/// Every span gets at least one column so short spans stay visible.
+pub fn bar_extent(offset_ms: f64, duration_ms: f64, total_ms: f64, width: usize) -> (usize, usize) {
+    if width == 0 || total_ms <= 0.0 {
+        return (0, width.min(1));
+    }
+    let scale = width as f64 / total_ms;
+    let start = ((offset_ms.max(0.0) * scale) as usize).min(width - 1);
+    let len = ((duration_ms.max(0.0) * scale).round() as usize).clamp(1, width - start);
+    (start, len)
+}
+
+/// Render the waterfall for `transaction` to fit `width` columns.
+pub fn waterfall_lines(transaction: &TransactionInfo, width: u16) -> Vec<Line<'static>> {
+    let start = transaction.start_timestamp;
+    // Spans can outlive the transaction; scale to whichever ends last
+    let end = transaction
+        .spans
+        .iter()
+        .map(|s| s.timestamp)
+        .fold(transaction.timestamp, f64::max);
+    let total_ms = (end - start) * 1000.0;
+
+    let bar_width = (width as usize)
+        .saturating_sub(LABEL_WIDTH + DURATION_WIDTH + 2)
+        .max(MIN_BAR_WIDTH);
+
+    waterfall_rows(&transaction.spans)
+        .into_iter()
+        .map(|row| {
+            let span = row.span;
+            let indent = "  ".repeat(row.depth.min(8));
+            let op = span.op.as_deref().unwrap_or("?");
+            let description = span.description.as_deref().unwrap_or("");
+            let label = truncate_str(&format!("{}{} {}", indent, op, description), LABEL_WIDTH);
+
+            let offset_ms = (span.start_timestamp - start) * 1000.0;
+            let (bar_start, bar_len) = bar_extent(offset_ms, span.duration_ms(), total_ms, bar_width);
+
+            Line::from(vec![
+                Span::raw(format!("{:<width$} ", label, width = LABEL_WIDTH)),
+                Span::raw(" ".repeat(bar_start)),
+                Span::styled("█".repeat(bar_len), Style::default().fg(op_color(op))),
+                Span::raw(" ".repeat(bar_width - bar_start - bar_len)),
+                Span::styled(
+                    format!(" {:>width$}", format_ms(span.duration_ms()), width = DURATION_WIDTH),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        })
+        .collect()
+}
+
+/// Bar color by span operation family.
+fn op_color(op: &str) -> Color {
+    match op.split('.').next().unwrap_or(op) {
+        "db" => Color::Blue,
+        "http" => Color::Magenta,
+        "cache" => Color::Green,
+        "ui" | "browser" | "resource" => Color::Cyan,
+        _ => Color::Yellow,
+    }
+}
+
+/// Format a duration in milliseconds, switching to seconds above 1s.
+pub fn format_ms(ms: f64) -> String {
+    if ms >= 1000.0 {
+        format!("{:.2}s", ms / 1000.0)
+    } else {
+        format!("{:.0}ms", ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(id: &str, parent: Option<&str>, start: f64, end: f64) -> SpanInfo {
+        SpanInfo {
+            span_id: id.to_string(),
+            parent_span_id: parent.map(str::to_string),
+            op: Some("db".to_string()),
+            description: None,
+            start_timestamp: start,
+            timestamp: end,
+        }
+    }
+
+    #[test]
+    fn test_nests_children_under_parents_in_start_order() {
+        let spans = vec![
+            span("c2", Some("a"), 0.3, 0.4),
+            span("b", None, 0.5, 0.6),
+            span("a", None, 0.0, 0.5),
+            span("c1", Some("a"), 0.1, 0.2),
+            span("orphan", Some("missing"), 0.7, 0.8),
+        ];
+        let rows: Vec<(&str, usize)> = waterfall_rows(&spans)
+            .iter()
+            .map(|r| (r.span.span_id.as_str(), r.depth))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![("a", 0), ("c1", 1), ("c2", 1), ("b", 0), ("orphan", 0)]
+        );
+    }
+
+    #[test]
+    fn test_survives_parent_cycles() {
+        let spans = vec![span("a", Some("b"), 0.0, 0.1), span("b", Some("a"), 0.1, 0.2)];
+        // Both have a known parent, so there are no roots to walk from
+        assert!(waterfall_rows(&spans).is_empty());
+    }
+
+    #[test]
+    fn test_bars_scale_to_the_transaction() {
+        assert_eq!(bar_extent(0.0, 500.0, 1000.0, 20), (0, 10));
+        assert_eq!(bar_extent(500.0, 500.0, 1000.0, 20), (10, 10));
+        // Tiny spans still get a column, overlong ones are clipped
+        assert_eq!(bar_extent(990.0, 0.1, 1000.0, 20), (19, 1));
+        assert_eq!(bar_extent(900.0, 5000.0, 1000.0, 20), (18, 2));
+    }
+}
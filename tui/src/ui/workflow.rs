@@ -0,0 +1,296 @@
+//! Workflow diagram: the pipeline stages as boxes, the current one
+//! highlighted, with when each was entered and how long it lasted.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::api::{IssueDetail, IssueState};
+use crate::config::ThemeConfig;
+use crate::util::timestamp_secs;
+use super::icons::glyph;
+use super::status::status_style;
+use super::timeline::format_gap;
+
+/// Pipeline stages in order, as (status, box label).
+const STAGES: [(&str, &str); 5] = [
+    ("pending", "Pending"),
+    ("analyzing", "Analyzing"),
+    ("pending_approval", "Approval"),
+    ("in_progress", "Working"),
+    ("pending_review", "Review"),
+];
+
+/// Inner width of a stage box.
+const BOX_WIDTH: usize = 11;
+/// Arrow shaft between boxes; the head comes from the icon set.
+const ARROW: &str = "──";
+/// Columns needed for the boxed layout.
+pub const DIAGRAM_WIDTH: usize = STAGES.len() * (BOX_WIDTH + 2) + (STAGES.len() - 1) * 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Progress {
+    Done,
+    Current,
+    /// The stage the agent failed in
+    Failed,
+    Upcoming,
+}
+
+/// One stage of the pipeline for a particular issue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stage {
+    pub status: &'static str,
+    pub label: &'static str,
+    pub progress: Progress,
+    /// When the stage was (last) entered
+    pub entered: Option<String>,
+    /// Seconds spent in the stage; for the current one, so far
+    pub seconds: Option<f64>,
+}
+
+/// Where the issue stands in each stage, from its status history.
+///
+/// Without history (older servers), only the creation time and the last
+/// update are known, and those are attributed to the first and current
+/// stages.
+pub fn stages(issue: &IssueDetail, now: f64) -> Vec<Stage> {
+    let (current, failed) = match &issue.state {
+        IssueState::Error { previous_status, .. } => (previous_status.as_str(), true),
+        _ => (issue.status.as_str(), false),
+    };
+    let current_index = STAGES.iter().position(|(s, _)| *s == current).unwrap_or(0);
+
+    // (status, entered, left) spans from the history
+    let mut spans: Vec<(&str, &str, Option<&str>)> = issue
+        .history
+        .iter()
+        .enumerate()
+        .map(|(i, change)| {
+            let left = issue.history.get(i + 1).map(|next| next.at.as_str());
+            (change.status.as_str(), change.at.as_str(), left)
+        })
+        .collect();
+    if spans.is_empty() {
+        spans.push(("pending", issue.created_at.as_str(), None));
+        if current != "pending" {
+            spans.push((current, issue.updated_at.as_str(), None));
+        }
+    }
+
+    STAGES
+        .iter()
+        .enumerate()
+        .map(|(index, (status, label))| {
+            let progress = match index.cmp(&current_index) {
+                std::cmp::Ordering::Less => Progress::Done,
+                std::cmp::Ordering::Equal if failed => Progress::Failed,
+                std::cmp::Ordering::Equal => Progress::Current,
+                std::cmp::Ordering::Greater => Progress::Upcoming,
+            };
+            let span = match progress {
+                Progress::Upcoming => None,
+                _ => spans.iter().rev().find(|(s, _, _)| s == status),
+            };
+            let seconds = span.and_then(|(_, entered, left)| {
+                let end = match left {
+                    Some(left) => timestamp_secs(left)?,
+                    None if progress == Progress::Done => return None,
+                    None => now,
+                };
+                Some((end - timestamp_secs(entered)?).max(0.0))
+            });
+            Stage {
+                status,
+                label,
+                progress,
+                entered: span.map(|(_, entered, _)| entered.to_string()),
+                seconds,
+            }
+        })
+        .collect()
+}
+
+/// The diagram as lines: boxes and arrows, then entry times and durations.
+/// Falls back to a single row of labels when narrower than `DIAGRAM_WIDTH`.
+pub fn diagram_lines(stages: &[Stage], theme: &ThemeConfig, width: usize) -> Vec<Line<'static>> {
+    let style = |stage: &Stage| match stage.progress {
+        Progress::Current => Style::default()
+            .fg(status_style(stage.status, theme).color)
+            .add_modifier(Modifier::BOLD),
+        Progress::Failed => Style::default()
+            .fg(status_style("error", theme).color)
+            .add_modifier(Modifier::BOLD),
+        Progress::Done => Style::default(),
+        Progress::Upcoming => Style::default().fg(Color::DarkGray),
+    };
+    let dim = Style::default().fg(Color::DarkGray);
+    let arrow = format!("{}{}", ARROW, glyph("▶", theme));
+
+    if width < DIAGRAM_WIDTH {
+        let mut spans = Vec::new();
+        for (i, stage) in stages.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(format!(" {} ", glyph("›", theme)), dim));
+            }
+            let label = match stage.progress {
+                Progress::Current | Progress::Failed => format!("[{}]", stage.label),
+                _ => stage.label.to_string(),
+            };
+            spans.push(Span::styled(label, style(stage)));
+        }
+        return vec![Line::from(spans)];
+    }
+
+    let mut top = Vec::new();
+    let mut middle = Vec::new();
+    let mut bottom = Vec::new();
+    let mut entered = Vec::new();
+    let mut lasted = Vec::new();
+    for (i, stage) in stages.iter().enumerate() {
+        let box_style = style(stage);
+        let gap = if i > 0 { "   " } else { "" };
+        let arrow = if i > 0 { arrow.clone() } else { String::new() };
+        let (left, right) = match stage.progress {
+            Progress::Failed => (glyph("✗", theme), " "),
+            Progress::Current => (glyph("▶", theme), " "),
+            _ => (" ", " "),
+        };
+
+        top.push(Span::raw(gap));
+        top.push(Span::styled(format!("┌{}┐", "─".repeat(BOX_WIDTH)), box_style));
+        middle.push(Span::styled(arrow, dim));
+        middle.push(Span::styled(
+            format!("│{}{:^w$}{}│", left, stage.label, right, w = BOX_WIDTH - 2),
+            box_style,
+        ));
+        bottom.push(Span::raw(gap));
+        bottom.push(Span::styled(format!("└{}┘", "─".repeat(BOX_WIDTH)), box_style));
+
+        let time = stage
+            .entered
+            .as_deref()
+            .map(short_time)
+            .unwrap_or_default();
+        entered.push(Span::styled(format!("{}{:^w$}", gap, time, w = BOX_WIDTH + 2), dim));
+        let duration = match (stage.seconds, stage.progress) {
+            (Some(seconds), Progress::Current) => {
+                let so_far = format!("{} so far", format_gap(seconds));
+                if so_far.chars().count() <= BOX_WIDTH + 2 {
+                    so_far
+                } else {
+                    format_gap(seconds)
+                }
+            }
+            (Some(seconds), _) => format_gap(seconds),
+            (None, _) => String::new(),
+        };
+        lasted.push(Span::styled(
+            format!("{}{:^w$}", gap, duration, w = BOX_WIDTH + 2),
+            box_style.remove_modifier(Modifier::BOLD),
+        ));
+    }
+
+    vec![
+        Line::from(top),
+        Line::from(middle),
+        Line::from(bottom),
+        Line::from(entered),
+        Line::from(lasted),
+    ]
+}
+
+/// "2026-02-01T14:30:00Z" -> "02-01 14:30".
+pub(super) fn short_time(timestamp: &str) -> String {
+    match (timestamp.get(5..10), timestamp.get(11..16)) {
+        (Some(date), Some(time)) => format!("{} {}", date, time),
+        _ => timestamp.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::StatusChange;
+
+    fn issue(json_state: &str, status: &str, history: &[(&str, &str)]) -> IssueDetail {
+        let mut issue: IssueDetail = serde_json::from_str(&format!(
+            r#"{{"id":"i","sourceType":"sentry","status":"{}","source":{{}},"state":{},
+                "createdAt":"2026-02-01T10:00:00Z","updatedAt":"2026-02-01T12:00:00Z"}}"#,
+            status, json_state
+        ))
+        .unwrap();
+        issue.history = history
+            .iter()
+            .map(|(status, at)| StatusChange {
+                status: status.to_string(),
+                at: at.to_string(),
+            })
+            .collect();
+        issue
+    }
+
+    fn at(timestamp: &str) -> f64 {
+        timestamp_secs(timestamp).unwrap()
+    }
+
+    #[test]
+    fn test_durations_from_history() {
+        let issue = issue(
+            r#"{"status":"pending_approval","analysisSessionId":"s","proposal":"p"}"#,
+            "pending_approval",
+            &[
+                ("pending", "2026-02-01T10:00:00Z"),
+                ("analyzing", "2026-02-01T10:05:00Z"),
+                ("pending_approval", "2026-02-01T10:17:00Z"),
+            ],
+        );
+        let stages = stages(&issue, at("2026-02-01T11:17:00Z"));
+        let summary: Vec<_> = stages.iter().map(|s| (s.progress, s.seconds)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Progress::Done, Some(300.0)),
+                (Progress::Done, Some(720.0)),
+                (Progress::Current, Some(3600.0)),
+                (Progress::Upcoming, None),
+                (Progress::Upcoming, None),
+            ]
+        );
+        assert_eq!(stages[1].entered.as_deref(), Some("2026-02-01T10:05:00Z"));
+    }
+
+    #[test]
+    fn test_error_marks_the_failed_stage() {
+        let issue = issue(
+            r#"{"status":"error","previousStatus":"analyzing","sessionId":"s","error":"boom"}"#,
+            "error",
+            &[],
+        );
+        let stages = stages(&issue, at("2026-02-01T12:30:00Z"));
+        assert_eq!(stages[0].progress, Progress::Done);
+        assert_eq!(stages[1].progress, Progress::Failed);
+        // Without history the last update stands in for when it got there
+        assert_eq!(stages[1].entered.as_deref(), Some("2026-02-01T12:00:00Z"));
+        assert_eq!(stages[0].seconds, None);
+    }
+
+    #[test]
+    fn test_narrow_fallback() {
+        let issue = issue(r#"{"status":"pending"}"#, "pending", &[]);
+        let lines = diagram_lines(&stages(&issue, 0.0), &ThemeConfig::default(), 40);
+        let text: String = lines[0].spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "[Pending] › Analyzing › Approval › Working › Review");
+    }
+
+    #[test]
+    fn test_boxes_fit_the_declared_width() {
+        let issue = issue(r#"{"status":"pending"}"#, "pending", &[]);
+        let lines = diagram_lines(&stages(&issue, 0.0), &ThemeConfig::default(), 100);
+        assert_eq!(lines.len(), 5);
+        for line in &lines {
+            assert_eq!(line.width(), DIAGRAM_WIDTH);
+        }
+    }
+}
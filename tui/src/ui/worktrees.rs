@@ -0,0 +1,86 @@
+//! Worktree management screen: the worktrees agent runs left in the
+//! project, and whether their issues still need them.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, WorktreeUse};
+use super::icons::glyph;
+
+/// Draw the worktree list.
+pub fn draw_worktrees(f: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.state.theme;
+    let dim = Style::default().fg(Color::DarkGray);
+    let block = Block::default().title(" Worktrees ").borders(Borders::ALL);
+
+    let worktrees = match &app.state.worktrees.list {
+        None => {
+            let loading = format!(" {} Listing worktrees...", glyph("◐", theme));
+            f.render_widget(Paragraph::new(Span::styled(loading, dim)).block(block), area);
+            return;
+        }
+        Some(Err(e)) => {
+            let error = Span::styled(format!(" {}", e), Style::default().fg(Color::Red));
+            f.render_widget(Paragraph::new(error).block(block), area);
+            return;
+        }
+        Some(Ok(worktrees)) if worktrees.is_empty() => {
+            let empty = Span::styled(" No worktrees left behind", dim);
+            f.render_widget(Paragraph::new(empty).block(block), area);
+            return;
+        }
+        Some(Ok(worktrees)) => worktrees,
+    };
+
+    let items: Vec<ListItem> = worktrees
+        .iter()
+        .map(|worktree| {
+            let (label, color, issue) = match app.state.worktree_use(worktree) {
+                WorktreeUse::Active(issue) => ("ACTIVE", Color::Green, Some(issue)),
+                WorktreeUse::Orphaned(issue) => ("ORPHANED", Color::Yellow, issue),
+                WorktreeUse::Stale => ("STALE", Color::Red, None),
+            };
+            let confirming = app.state.worktrees.confirm_remove.as_ref() == Some(&worktree.path);
+            let mut spans = vec![
+                Span::styled(format!("{:<9}", label), Style::default().fg(color)),
+                Span::styled(
+                    format!("{:<12}", issue.map(|i| i.short_id.as_str()).unwrap_or_default()),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!("{} ", glyph("⎇", theme))),
+                Span::raw(worktree.branch.as_deref().unwrap_or("(detached)").to_string()),
+                Span::styled(format!("  {}", worktree.path.display()), dim),
+            ];
+            if worktree.locked {
+                spans.push(Span::styled("  locked", Style::default().fg(Color::Magenta)));
+            }
+            if confirming {
+                spans.push(Span::styled(
+                    "  d again to remove",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let pointer = format!("{} ", glyph("▶", theme));
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(&pointer);
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(app.state.worktrees.selected));
+
+    f.render_stateful_widget(list, area, &mut list_state);
+}
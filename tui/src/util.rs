@@ -1,5 +1,10 @@
 //! Utility functions for text processing.
 
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::locale::{local_offset, Locale};
+
 /// Truncate a string to max length with ellipsis.
 pub fn truncate_str(s: &str, max_len: usize) -> String {
     if s.chars().count() <= max_len {
@@ -52,6 +57,97 @@ pub fn word_wrap(s: &str, width: usize) -> Vec<String> {
     lines
 }
 
+/// Whether `text` matches a glob where `*` is any run of characters and `?`
+/// any one, ignoring case.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Where the last `*` was, and how much of the text it has taken
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                // Let the `*` take one more character and try again
+                Some((star_p, star_t)) => {
+                    star = Some((star_p, star_t + 1));
+                    p = star_p + 1;
+                    t = star_t + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Seconds since the Unix epoch for an RFC 3339 timestamp.
+pub fn timestamp_secs(timestamp: &str) -> Option<f64> {
+    let at = OffsetDateTime::parse(timestamp, &Rfc3339).ok()?;
+    Some(at.unix_timestamp_nanos() as f64 / 1e9)
+}
+
+/// How long before `now` an RFC 3339 timestamp was: "just now", "5m ago",
+/// "3h ago", "2d ago", "4mo ago" or "1y ago". Times in the future (clock
+/// skew) are "just now".
+pub fn relative_time(timestamp: &str, now: OffsetDateTime) -> Option<String> {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    let at = OffsetDateTime::parse(timestamp, &Rfc3339).ok()?;
+    let secs = (now - at).whole_seconds();
+    Some(if secs < MINUTE {
+        "just now".to_string()
+    } else if secs < HOUR {
+        format!("{}m ago", secs / MINUTE)
+    } else if secs < DAY {
+        format!("{}h ago", secs / HOUR)
+    } else if secs < 30 * DAY {
+        format!("{}d ago", secs / DAY)
+    } else if secs < 365 * DAY {
+        format!("{}mo ago", secs / (30 * DAY))
+    } else {
+        format!("{}y ago", secs / (365 * DAY))
+    })
+}
+
+/// An RFC 3339 timestamp in the local timezone, as "2026-02-01 14:30" or
+/// however `locale` orders dates.
+pub fn local_time(timestamp: &str, locale: &Locale) -> Option<String> {
+    let at = OffsetDateTime::parse(timestamp, &Rfc3339).ok()?;
+    Some(locale.date_time(&at.to_offset(local_offset(at))))
+}
+
+/// Offset of `timestamp` into a replay that started at `replay_start`, as a
+/// player timecode ("1:05", or "1:02:05" past an hour).
+///
+/// Returns `None` if either timestamp doesn't parse or `timestamp` is
+/// before the recording started.
+pub fn replay_timecode(timestamp: &str, replay_start: &str) -> Option<String> {
+    let at = OffsetDateTime::parse(timestamp, &Rfc3339).ok()?;
+    let start = OffsetDateTime::parse(replay_start, &Rfc3339).ok()?;
+    let secs = (at - start).whole_seconds();
+    if secs < 0 {
+        return None;
+    }
+
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    Some(if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,4 +169,50 @@ mod tests {
         let result = word_wrap("", 10);
         assert_eq!(result, vec![""]);
     }
+
+    #[test]
+    fn test_replay_timecode() {
+        let start = "2026-02-01T14:29:00.000Z";
+        assert_eq!(replay_timecode("2026-02-01T14:29:58.500Z", start).as_deref(), Some("0:58"));
+        assert_eq!(replay_timecode("2026-02-01T15:31:05Z", start).as_deref(), Some("1:02:05"));
+        assert_eq!(replay_timecode("2026-02-01T14:28:59Z", start), None);
+        assert_eq!(replay_timecode("not a date", start), None);
+    }
+
+    #[test]
+    fn test_local_time_follows_daylight_saving() {
+        // Central European rules, as a POSIX string so no zoneinfo is needed;
+        // clocks go forward at 01:00 UTC on 29 March 2026
+        std::env::set_var("TZ", "CET-1CEST,M3.5.0,M10.5.0/3");
+        let locale = Locale::default();
+        assert_eq!(local_time("2026-03-29T00:30:00Z", &locale).as_deref(), Some("2026-03-29 01:30"));
+        assert_eq!(local_time("2026-03-29T01:30:00Z", &locale).as_deref(), Some("2026-03-29 03:30"));
+        assert_eq!(local_time("2026-10-25T01:30:00Z", &locale).as_deref(), Some("2026-10-25 02:30"));
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*health check*timed out*", "GET /health: Health check timed out after 5s"));
+        assert!(glob_match("Bot?", "bot1"));
+        assert!(!glob_match("Bot?", "bot"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("timeout", "timeout: db"));
+        assert!(glob_match("a*b*c", "aXbYbZc"));
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let now = OffsetDateTime::parse("2026-02-01T14:30:00Z", &Rfc3339).unwrap();
+        let ago = |timestamp| relative_time(timestamp, now);
+        assert_eq!(ago("2026-02-01T14:29:30Z").as_deref(), Some("just now"));
+        // Clock skew
+        assert_eq!(ago("2026-02-01T14:31:00Z").as_deref(), Some("just now"));
+        assert_eq!(ago("2026-02-01T14:25:00.500Z").as_deref(), Some("4m ago"));
+        // Offsets are honored
+        assert_eq!(ago("2026-02-01T12:30:00+01:00").as_deref(), Some("3h ago"));
+        assert_eq!(ago("2026-01-30T14:30:00Z").as_deref(), Some("2d ago"));
+        assert_eq!(ago("2025-11-01T14:30:00Z").as_deref(), Some("3mo ago"));
+        assert_eq!(ago("2024-01-01T00:00:00Z").as_deref(), Some("2y ago"));
+        assert_eq!(ago("2026-02-01"), None);
+    }
 }
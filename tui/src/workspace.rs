@@ -0,0 +1,109 @@
+//! Workspace state kept between runs.
+//!
+//! The list's filters, sort order and grouping, the pinned issue, which
+//! session pi last opened for each issue, which detail sections are folded
+//! and the completed issues being watched are saved to
+//! `<project>/.glass/workspace.json` whenever they change, and restored on
+//! startup.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::{AppState, DetailSection, FixWatch, ListView, SessionKind};
+
+/// File under the project checkout the workspace is saved to.
+pub const WORKSPACE_FILE: &str = ".glass/workspace.json";
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Workspace {
+    pub view: ListView,
+    pub pinned_issue: Option<String>,
+    pub session_choices: HashMap<String, SessionKind>,
+    pub collapsed_sections: HashMap<String, HashSet<DetailSection>>,
+    pub watches: Vec<FixWatch>,
+}
+
+impl Workspace {
+    /// The parts of the app state worth keeping.
+    pub fn capture(state: &AppState) -> Self {
+        Self {
+            view: state.view.clone(),
+            pinned_issue: state.pinned_issue.clone(),
+            session_choices: state.session_choices.clone(),
+            collapsed_sections: state.collapsed_sections.clone(),
+            watches: state.watches.clone(),
+        }
+    }
+
+    /// Put saved state back. The list is filtered once issues load.
+    pub fn restore(self, state: &mut AppState) {
+        state.view = self.view;
+        state.pinned_issue = self.pinned_issue;
+        state.session_choices = self.session_choices;
+        state.collapsed_sections = self.collapsed_sections;
+        state.watches = self.watches;
+    }
+
+    /// Load the project's workspace; a missing file is an empty one.
+    pub fn load(project: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path(project)) {
+            Ok(text) => serde_json::from_str(&text).map_err(io::Error::other),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, project: &Path) -> io::Result<()> {
+        let path = path(project);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self).map_err(io::Error::other)?)
+    }
+}
+
+fn path(project: &Path) -> PathBuf {
+    project.join(WORKSPACE_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{FilterField, GroupBy, IssueFilter, IssueSort, SortKey};
+
+    #[test]
+    fn test_round_trip() {
+        let project = std::env::temp_dir().join(format!("glass-workspace-{}", std::process::id()));
+        assert_eq!(Workspace::load(&project).unwrap(), Workspace::default());
+
+        let workspace = Workspace {
+            view: ListView {
+                filters: vec![IssueFilter {
+                    field: FilterField::Status,
+                    value: "error".to_string(),
+                }],
+                sort: Some(IssueSort {
+                    key: SortKey::Events,
+                    descending: true,
+                }),
+                group: Some(GroupBy::Culprit),
+                project: None,
+            },
+            pinned_issue: Some("a".to_string()),
+            session_choices: [("a".to_string(), SessionKind::Analysis)].into_iter().collect(),
+            collapsed_sections: [("a".to_string(), [DetailSection::Breadcrumbs].into_iter().collect())]
+                .into_iter()
+                .collect(),
+            watches: vec![FixWatch::new("a".to_string(), "PROJ-1".to_string(), 12, 24, 1_000)],
+        };
+        workspace.save(&project).unwrap();
+        let loaded = Workspace::load(&project).unwrap();
+        fs::remove_dir_all(&project).unwrap();
+        assert_eq!(loaded, workspace);
+    }
+}
@@ -4,7 +4,11 @@
 //! returned by the Glass server.
 
 use glass_tui::api::{
-    IssueDetail, IssueState, ListIssuesResponse, SessionInfo,
+    AnalysisEvent, AnalyzeRequest, ApiClient, ApiError, ApproveRequest, AuthToken, Breadcrumb, CapabilitiesResponse, Capability,
+    CreateIssueRequest, CritiqueEvent, FileChange,
+    FrameResolution, IssueCategory, IssueDetail, IssueEventsResponse, IssueSource, IssueState,
+    ListIssuesResponse, MergeRequest, MergeResponse, OccurrencesResponse, PlanResponse, QueueStatusResponse,
+    RejectRequest, ServerEvent, SessionInfo, StackFrame, TagStatsResponse,
 };
 
 fn load_fixture(name: &str) -> String {
@@ -42,6 +46,10 @@ fn test_list_issues() {
     assert_eq!(issue.status, "pending");
     assert_eq!(issue.event_count, 127);
     assert_eq!(issue.user_count, 43);
+    assert_eq!(issue.environment.as_deref(), Some("production"));
+    assert_eq!(issue.release.as_deref(), Some("web@2.4.1"));
+    // Older servers don't list them
+    assert_eq!(response.issues[1].environment, None);
 
     // Check different statuses
     assert_eq!(response.issues[1].status, "pending_approval");
@@ -81,10 +89,61 @@ fn test_issue_detail_pending() {
     assert_eq!(breadcrumbs.len(), 1);
     assert_eq!(breadcrumbs[0].category, Some("route".to_string()));
 
+    // Check replay
+    let replay = detail.source.replay.expect("Expected replay");
+    assert_eq!(replay.id, "f3a1c9d2");
+    assert_eq!(replay.started_at.as_deref(), Some("2026-02-01T14:29:30.000Z"));
+
     // Check state
     assert!(matches!(detail.state, IssueState::Pending));
 }
 
+#[test]
+fn test_frame_context_from_sentry() {
+    let frame: StackFrame = serde_json::from_value(serde_json::json!({
+        "filename": "src/handlers/user.ts", "function": "getUser", "lineNo": 42, "colNo": 7,
+        "context": [[41, "  const user = users.get(id);"], [42, "  return user.id;"], [43, "}"]],
+    }))
+    .expect("Failed to deserialize frame with context");
+
+    assert_eq!(frame.lineno, Some(42));
+    let context = frame.context.as_ref().expect("Expected context");
+    assert_eq!(context.len(), 3);
+    assert_eq!(context[1].code, "  return user.id;");
+    let current: Vec<u32> = context.iter().filter(|c| frame.is_current(c)).map(|c| c.line).collect();
+    assert_eq!(current, vec![42]);
+}
+
+#[test]
+fn test_library_runs() {
+    let frame = |filename: &str, in_app: Option<bool>| {
+        serde_json::json!({"filename": filename, "function": "f", "inApp": in_app})
+    };
+    let detail: IssueDetail = serde_json::from_value(serde_json::json!({
+        "id": "sentry:1", "sourceType": "sentry", "status": "pending",
+        "source": {"exceptions": [
+            {"type": "Error", "stacktrace": {"frames": [
+                frame("node_modules/express/lib/router.js", None),
+                frame("lib/vendored.js", Some(false)),
+                frame("src/app.ts", None),
+                frame("node_modules/a.js", None),
+            ]}},
+            {"type": "Error", "stacktrace": {"frames": [
+                frame("node_modules/b.js", None),
+                frame("node_modules/c.js", Some(true)),
+                frame("node_modules/d.js", None),
+                frame("node_modules/e.js", None),
+            ]}},
+        ]},
+        "state": {"status": "pending"}, "createdAt": "", "updatedAt": "",
+    }))
+    .expect("Failed to deserialize issue with frames");
+
+    let runs = detail.source.library_runs(|path| path.starts_with("node_modules/"));
+    // Single library frames, and runs split across exceptions, stay shown
+    assert_eq!(runs, vec![0..2, 6..8]);
+}
+
 #[test]
 fn test_issue_detail_pending_approval() {
     let json = load_fixture("issue_detail_pending_approval");
@@ -103,6 +162,10 @@ fn test_issue_detail_pending_approval() {
         }
         _ => panic!("Expected PendingApproval state, got {:?}", detail.state),
     }
+
+    assert_eq!(detail.rejections.len(), 2);
+    assert!(detail.rejections[0].reason.as_deref().unwrap().starts_with("Masks the bug"));
+    assert_eq!(detail.rejections[1].reason, None);
 }
 
 #[test]
@@ -127,6 +190,10 @@ fn test_issue_detail_in_progress() {
         }
         _ => panic!("Expected InProgress state, got {:?}", detail.state),
     }
+
+    assert_eq!(detail.history.len(), 4);
+    assert_eq!(detail.history[1].status, "analyzing");
+    assert_eq!(detail.history[1].at, "2026-02-01T14:30:00.000Z");
 }
 
 #[test]
@@ -174,3 +241,400 @@ fn test_session_info_analysis_only() {
     assert!(info.analysis_session.is_some());
     assert!(info.implementation_session.is_none());
 }
+
+#[test]
+fn test_tag_stats() {
+    let json = load_fixture("tag_stats");
+    let stats: TagStatsResponse = serde_json::from_str(&json)
+        .expect("Failed to deserialize tag stats");
+
+    assert_eq!(stats.tags.len(), 2);
+    let browser = &stats.tags[0];
+    assert_eq!(browser.key, "browser");
+    assert_eq!(browser.total_values, 250);
+    assert_eq!(browser.top_values[0].value, "Chrome 131");
+    assert_eq!(browser.top_values[0].count, 195);
+}
+
+#[test]
+fn test_plan_preview() {
+    let json = load_fixture("plan_preview");
+    let plan: PlanResponse = serde_json::from_str(&json)
+        .expect("Failed to deserialize plan preview");
+
+    assert_eq!(plan.files.len(), 3);
+    assert_eq!(plan.files[0].path, "src/auth.ts");
+    assert_eq!(plan.files[0].change, FileChange::Modify);
+    assert_eq!(plan.files[1].change, FileChange::Create);
+    // Changes this client doesn't know about still load
+    assert_eq!(plan.files[2].change, FileChange::Other);
+    assert_eq!(plan.files[2].summary, None);
+    assert_eq!(plan.tests.len(), 1);
+    assert_eq!(plan.tests[0].path, "test/auth.test.ts");
+}
+
+fn body<T: serde::Serialize>(request: &T) -> String {
+    serde_json::to_string(request).unwrap()
+}
+
+#[test]
+fn test_request_bodies_omit_missing_fields() {
+    let cases = [
+        (body(&RejectRequest { reason: None }), "{}"),
+        (
+            body(&RejectRequest {
+                reason: Some("Wrong file".to_string()),
+            }),
+            r#"{"reason":"Wrong file"}"#,
+        ),
+        (
+            body(&AnalyzeRequest {
+                instructions: None,
+                event_id: None,
+            }),
+            "{}",
+        ),
+        (
+            body(&AnalyzeRequest {
+                instructions: Some("Focus on the retry logic\nin payments.rs".to_string()),
+                event_id: None,
+            }),
+            r#"{"instructions":"Focus on the retry logic\nin payments.rs"}"#,
+        ),
+        (
+            body(&AnalyzeRequest {
+                instructions: None,
+                event_id: Some("9f3c2a".to_string()),
+            }),
+            r#"{"eventId":"9f3c2a"}"#,
+        ),
+        (body(&ApproveRequest { instructions: None }), "{}"),
+        (
+            body(&ApproveRequest {
+                instructions: Some("Add a regression test".to_string()),
+            }),
+            r#"{"instructions":"Add a regression test"}"#,
+        ),
+        (
+            body(&CreateIssueRequest {
+                title: "Checkout hangs".to_string(),
+                description: String::new(),
+                stacktrace: None,
+            }),
+            r#"{"title":"Checkout hangs","description":""}"#,
+        ),
+    ];
+    for (body, expected) in cases {
+        assert_eq!(body, expected);
+    }
+}
+#[test]
+fn test_issue_events() {
+    let json = load_fixture("issue_events");
+    let response: IssueEventsResponse = serde_json::from_str(&json)
+        .expect("Failed to deserialize issue events");
+
+    assert_eq!(response.events.len(), 3);
+    let first = &response.events[0];
+    assert_eq!(first.id, "a1b2c3d4e5f6");
+    assert_eq!(first.environment.as_deref(), Some("production"));
+    assert!(!first.representative);
+    assert!(response.events[1].representative);
+    // Only the id and timestamp are required
+    assert_eq!(response.events[2].message, None);
+    assert_eq!(response.events[2].release, None);
+}
+
+#[test]
+fn test_occurrences_page() {
+    let json = load_fixture("occurrences");
+    let page: OccurrencesResponse = serde_json::from_str(&json)
+        .expect("Failed to deserialize occurrences");
+
+    assert_eq!(page.events.len(), 2);
+    assert_eq!(page.events[1].environment.as_deref(), Some("staging"));
+    assert_eq!(page.next_cursor.as_deref(), Some("0:2:0"));
+
+    // The last page has no cursor
+    let page: OccurrencesResponse = serde_json::from_str(r#"{"events":[]}"#).unwrap();
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn test_source_with_event() {
+    let issue: IssueSource = serde_json::from_value(serde_json::json!({
+        "title": "TypeError", "eventCount": 120, "environment": "production",
+        "user": {"id": "1"}, "tags": {"browser": "Chrome"},
+    }))
+    .unwrap();
+    let event: IssueSource = serde_json::from_value(serde_json::json!({
+        "title": "ignored", "eventCount": 1, "environment": "staging",
+    }))
+    .unwrap();
+
+    let shown = issue.with_event(&event);
+    // The issue's own summary stays
+    assert_eq!(shown.title.as_deref(), Some("TypeError"));
+    assert_eq!(shown.event_count, Some(120));
+    // Everything about the occurrence is the event's, even where it has nothing
+    assert_eq!(shown.environment.as_deref(), Some("staging"));
+    assert!(shown.user.is_none());
+    assert!(shown.tags.is_none());
+}
+
+#[test]
+fn test_capabilities() {
+    let json = load_fixture("capabilities");
+    let response: CapabilitiesResponse = serde_json::from_str(&json)
+        .expect("Failed to deserialize capabilities");
+
+    assert_eq!(response.version.as_deref(), Some("0.4.0"));
+    assert_eq!(
+        response.capabilities,
+        vec![
+            Capability::TagStats,
+            Capability::Critique,
+            Capability::Plan,
+            // Newer than this TUI
+            Capability::Other,
+        ]
+    );
+}
+
+#[test]
+fn test_queue_status() {
+    let json = load_fixture("queue_status");
+    let response: QueueStatusResponse = serde_json::from_str(&json)
+        .expect("Failed to deserialize queue status");
+
+    assert_eq!(response.queued.len(), 2);
+    assert_eq!(response.queued[0].issue_id, "sentry:12345");
+    assert_eq!(response.queued[0].eta_seconds, Some(45));
+    assert_eq!(response.queued[1].position, 2);
+    assert_eq!(response.queued[1].eta_seconds, None);
+
+    // An idle server may leave the list out
+    let empty: QueueStatusResponse = serde_json::from_str("{}").unwrap();
+    assert!(empty.queued.is_empty());
+}
+
+#[test]
+fn test_merge_round_trip() {
+    let request = MergeRequest {
+        duplicate_ids: vec!["issue-456".to_string()],
+    };
+    assert_eq!(
+        serde_json::to_string(&request).unwrap(),
+        r#"{"duplicateIds":["issue-456"]}"#
+    );
+
+    let json = load_fixture("merge_response");
+    let response: MergeResponse = serde_json::from_str(&json)
+        .expect("Failed to deserialize merge response");
+    assert_eq!(response.primary_id, "issue-123");
+    assert_eq!(response.merged_ids, vec!["issue-456", "issue-789"]);
+}
+
+#[test]
+fn test_server_event_issue_updated() {
+    let json = load_fixture("server_event_issue_updated");
+    let event = ServerEvent::parse("issue_updated", &json)
+        .expect("Failed to deserialize issue_updated event")
+        .expect("Expected a known event");
+
+    match event {
+        ServerEvent::IssueUpdated(update) => {
+            assert_eq!(update.id, "12345");
+            assert_eq!(update.status, "pending_approval");
+            assert_eq!(update.updated_at, Some("2026-02-01T14:45:00.000Z".to_string()));
+        }
+    }
+}
+
+#[test]
+fn test_server_event_unknown_is_ignored() {
+    let event = ServerEvent::parse("analysis_progress", r#"{"id": "12345"}"#)
+        .expect("Unknown events should not fail to parse");
+
+    assert!(event.is_none());
+}
+
+#[test]
+fn test_api_error_parses_error_body() {
+    let json = load_fixture("error_response");
+    let error = ApiError::new(422, Some("req_from_header".to_string()), json);
+
+    assert_eq!(error.status, 422);
+    assert_eq!(error.status_text(), "422 Unprocessable Entity");
+    assert_eq!(error.code.as_deref(), Some("INVALID_STATE"));
+    assert_eq!(
+        error.message,
+        "Issue not in a valid state for approval: analyzing"
+    );
+    // Body wins over the header
+    assert_eq!(error.request_id.as_deref(), Some("req_01HZX8Q2M4"));
+}
+
+#[test]
+fn test_api_error_falls_back_to_raw_body() {
+    let error = ApiError::new(502, Some("req_abc".to_string()), "Bad Gateway\n".to_string());
+
+    assert_eq!(error.code, None);
+    assert_eq!(error.message, "Bad Gateway");
+    assert_eq!(error.request_id.as_deref(), Some("req_abc"));
+}
+
+#[test]
+fn test_issue_detail_cron() {
+    let json = load_fixture("issue_detail_cron");
+    let detail: IssueDetail = serde_json::from_str(&json)
+        .expect("Failed to deserialize cron issue detail");
+
+    assert_eq!(detail.source.category(), IssueCategory::Cron);
+    assert!(detail.source.exceptions.is_none());
+
+    let cron = detail.source.cron.expect("Expected cron info");
+    assert_eq!(cron.monitor_slug, "nightly-billing-sync");
+    assert_eq!(cron.schedule.as_deref(), Some("0 2 * * *"));
+    assert_eq!(cron.missed_check_ins.len(), 3);
+    assert_eq!(cron.missed_check_ins[1].status, "timeout");
+}
+
+#[test]
+fn test_issue_detail_performance() {
+    let json = load_fixture("issue_detail_performance");
+    let detail: IssueDetail = serde_json::from_str(&json)
+        .expect("Failed to deserialize performance issue detail");
+
+    assert_eq!(detail.source.category(), IssueCategory::Performance);
+
+    let transaction = detail.source.transaction.expect("Expected transaction");
+    assert_eq!(transaction.name, "GET /api/orders");
+    assert!((transaction.duration_ms() - 1250.0).abs() < 0.01);
+    assert_eq!(transaction.spans.len(), 3);
+    assert_eq!(transaction.spans[1].parent_span_id.as_deref(), Some("a1"));
+    assert!((transaction.spans[1].duration_ms() - 400.0).abs() < 0.01);
+}
+
+#[test]
+fn test_issue_detail_manual() {
+    let json = load_fixture("issue_detail_manual");
+    let detail: IssueDetail = serde_json::from_str(&json)
+        .expect("Failed to deserialize manual issue detail");
+
+    assert_eq!(detail.source_type, "manual");
+    assert_eq!(detail.source.category(), IssueCategory::Error);
+    assert!(detail.source.exceptions.is_none());
+    assert_eq!(detail.source.frames().count(), 0);
+    assert!(detail.source.description.unwrap().starts_with("Reported by support"));
+    assert_eq!(detail.source.stacktrace.unwrap().lines().count(), 3);
+}
+
+#[test]
+fn test_issue_category_fallback() {
+    let json = load_fixture("issue_detail_pending");
+    let detail: IssueDetail = serde_json::from_str(&json).unwrap();
+    assert_eq!(detail.source.category(), IssueCategory::Error);
+
+    let mut source = detail.source;
+    source.exceptions = None;
+    source.message = Some("Payment webhook retried 3 times".to_string());
+    assert_eq!(source.category(), IssueCategory::Message);
+}
+
+#[test]
+fn test_issue_detail_minified_frames() {
+    let json = load_fixture("issue_detail_minified");
+    let detail: IssueDetail = serde_json::from_str(&json)
+        .expect("Failed to deserialize minified issue detail");
+
+    let exceptions = detail.source.exceptions.expect("Expected exceptions");
+    let frames = &exceptions[0].stacktrace.as_ref().expect("Expected stacktrace").frames;
+
+    assert_eq!(frames[0].resolution(), FrameResolution::Resolved);
+    assert_eq!(frames[0].function.as_deref(), Some("computeTotal"));
+    assert_eq!(frames[0].raw_function.as_deref(), Some("t"));
+    assert_eq!(frames[0].raw_colno, Some(48211));
+
+    assert_eq!(frames[1].resolution(), FrameResolution::Failed);
+    assert_eq!(frames[2].resolution(), FrameResolution::NotApplicable);
+}
+
+#[test]
+fn test_analysis_usage_event() {
+    let event: AnalysisEvent = serde_json::from_str(
+        r#"{"type":"usage","inputTokens":1520,"outputTokens":310,"cost":0.0123}"#,
+    )
+    .expect("Failed to deserialize usage event");
+    match event {
+        AnalysisEvent::Usage { input_tokens, output_tokens, cost } => {
+            assert_eq!(input_tokens, 1520);
+            assert_eq!(output_tokens, 310);
+            assert_eq!(cost, Some(0.0123));
+        }
+        other => panic!("Expected Usage event, got {:?}", other),
+    }
+
+    // Cost is optional
+    let event: AnalysisEvent =
+        serde_json::from_str(r#"{"type":"usage","inputTokens":1,"outputTokens":2}"#).unwrap();
+    assert!(matches!(event, AnalysisEvent::Usage { cost: None, .. }));
+}
+
+#[test]
+fn test_critique_events() {
+    let event: CritiqueEvent =
+        serde_json::from_str(r#"{"type":"started","reviewer":"gpt-5"}"#).unwrap();
+    assert!(matches!(event, CritiqueEvent::Started { reviewer } if reviewer == "gpt-5"));
+
+    let event: CritiqueEvent =
+        serde_json::from_str(r#"{"type":"text_delta","delta":"The fix misses"}"#).unwrap();
+    assert!(matches!(event, CritiqueEvent::TextDelta { delta } if delta == "The fix misses"));
+
+    let event: CritiqueEvent = serde_json::from_str(r#"{"type":"complete"}"#).unwrap();
+    assert!(matches!(event, CritiqueEvent::Complete));
+
+    // Reviewer activity the pane doesn't show is tolerated
+    let event: CritiqueEvent = serde_json::from_str(r#"{"type":"thinking"}"#).unwrap();
+    assert!(matches!(event, CritiqueEvent::Other));
+}
+
+#[test]
+fn test_breadcrumb_data_fields() {
+    let crumb: Breadcrumb = serde_json::from_str(
+        r#"{"category":"fetch","data":{"url":"/api/users","method":"GET","retries":2,"headers":{"accept":"json"}}}"#,
+    )
+    .unwrap();
+    let fields = crumb.data.unwrap().fields();
+    let keys: Vec<_> = fields.iter().map(|(k, _)| k.as_str()).collect();
+    // Known fields first, then the rest in key order
+    assert_eq!(keys, vec!["url", "headers", "method", "retries"]);
+    assert_eq!(fields[1].1, "{\n  \"accept\": \"json\"\n}");
+    assert_eq!(fields[2].1, "GET");
+    assert_eq!(fields[3].1, "2");
+
+    let crumb: Breadcrumb = serde_json::from_str(r#"{"message":"hi"}"#).unwrap();
+    assert_eq!(crumb.category(), "?");
+}
+
+#[test]
+fn test_auth_token_goes_on_every_request() {
+    let token: AuthToken = "s3cret".parse().unwrap();
+    assert!(!format!("{:?}", token).contains("s3cret"));
+    assert!("".parse::<AuthToken>().is_err());
+    assert!("bad\ntoken".parse::<AuthToken>().is_err());
+
+    let client = ApiClient::new("http://glass.internal:7420".to_string(), Some(token));
+    for request in [
+        client.global_events_request(),
+        client.events_request("abc", Some("7")),
+        client.critique_request("abc"),
+    ] {
+        let request = request.build().unwrap();
+        assert_eq!(request.headers()["authorization"], "Bearer s3cret");
+    }
+
+    let client = ApiClient::new("http://localhost:7420".to_string(), None);
+    let request = client.global_events_request().build().unwrap();
+    assert!(request.headers().get("authorization").is_none());
+}
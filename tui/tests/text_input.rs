@@ -0,0 +1,88 @@
+//! Tests for text input editing over multi-byte and composed text.
+
+use glass_tui::app::TextInput;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Case {
+    name: String,
+    text: String,
+    graphemes: usize,
+    width: usize,
+}
+
+fn load_cases() -> Vec<Case> {
+    let json = std::fs::read_to_string("tests/fixtures/multibyte_input.json")
+        .expect("Failed to load fixture multibyte_input");
+    serde_json::from_str(&json).expect("Failed to parse multibyte fixture")
+}
+
+/// Type text the way a terminal delivers committed IME output: char by char.
+fn typed(text: &str) -> TextInput {
+    let mut input = TextInput::new();
+    for c in text.chars() {
+        input.insert_char(c);
+    }
+    input
+}
+
+#[test]
+fn test_cursor_column_uses_display_width() {
+    for case in load_cases() {
+        let input = typed(&case.text);
+        assert_eq!(input.cursor_column(), case.width, "case {}", case.name);
+    }
+}
+
+#[test]
+fn test_cursor_moves_by_grapheme() {
+    for case in load_cases() {
+        let mut input = typed(&case.text);
+        input.move_home();
+
+        let mut steps = 0;
+        while input.cursor() < input.text().len() {
+            input.move_right();
+            steps += 1;
+        }
+        assert_eq!(steps, case.graphemes, "case {}", case.name);
+
+        for _ in 0..case.graphemes {
+            input.move_left();
+        }
+        assert_eq!(input.cursor(), 0, "case {}", case.name);
+    }
+}
+
+#[test]
+fn test_backspace_removes_whole_grapheme() {
+    for case in load_cases() {
+        let mut input = typed(&case.text);
+        for _ in 0..case.graphemes {
+            input.backspace();
+        }
+        assert!(input.is_empty(), "case {}: left {:?}", case.name, input.text());
+    }
+}
+
+#[test]
+fn test_dead_key_combining_mark_joins_base() {
+    // A dead-key accent arrives as a combining mark after the base letter
+    let mut input = typed("e");
+    input.insert_char('\u{301}');
+    assert_eq!(input.cursor_column(), 1);
+
+    input.move_left();
+    assert_eq!(input.cursor(), 0);
+
+    input.delete();
+    assert!(input.is_empty());
+}
+
+#[test]
+fn test_cursor_column_on_second_line() {
+    let mut input = TextInput::new();
+    input.insert_str("スタック\n日本");
+    assert_eq!(input.cursor_line(), 1);
+    assert_eq!(input.cursor_column(), 4);
+}